@@ -0,0 +1,30 @@
+#!/usr/bin/env node
+/**
+ * Test Runner
+ *
+ * Runs every compiled `*.test.js` under the test build with Node's built-in
+ * test runner. Compile first with `tsc -p tsconfig.test.json`.
+ *
+ * Usage: npm test
+ */
+
+import { readdirSync } from 'fs';
+import { spawnSync } from 'child_process';
+import path from 'path';
+import { fileURLToPath } from 'url';
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+const ROOT_DIR = path.join(__dirname, '..');
+const BUILD_DIR = path.join(ROOT_DIR, 'node_modules', '.cache', 'test-build');
+
+const testFiles = readdirSync(BUILD_DIR, { recursive: true })
+  .filter(file => file.endsWith('.test.js'))
+  .map(file => path.join(BUILD_DIR, file));
+
+const result = spawnSync(
+  process.execPath,
+  ['--require', path.join(__dirname, 'test-aliases.cjs'), '--test', ...testFiles],
+  { stdio: 'inherit' }
+);
+process.exit(result.status ?? 1);
@@ -0,0 +1,15 @@
+/**
+ * Resolves the `@/` import alias to the compiled test build, so tests run
+ * with plain `node --test` after `tsc -p tsconfig.test.json`.
+ */
+
+const Module = require('module');
+const path = require('path');
+
+const BUILD_DIR = path.join(__dirname, '..', 'node_modules', '.cache', 'test-build');
+
+const resolveFilename = Module._resolveFilename;
+Module._resolveFilename = function (request, ...rest) {
+  const target = request.startsWith('@/') ? path.join(BUILD_DIR, request.slice(2)) : request;
+  return resolveFilename.call(this, target, ...rest);
+};
@@ -2,17 +2,23 @@
 
 import React, { useRef, useState, useCallback, useEffect, useMemo } from 'react';
 import { useCoaster } from '@/context/CoasterContext';
-import { Tile, Tool, TOOL_INFO } from '@/games/coaster/types';
+import { Tile, Tool, TOOL_INFO, BuildingOrientation, isOrientationMirrored, getShadowSize, PATH_FURNITURE, LARGE_RIDES, BuildingType, isBenchBuilding, isLampBuilding, MONEY_POPUP_TICKS } from '@/games/coaster/types';
 import { getCoasterCategory, CoasterCategory, CoasterType } from '@/games/coaster/types/tracks';
 import { getSpriteInfo, getSpriteRect, COASTER_SPRITE_PACK } from '@/games/coaster/lib/coasterRenderConfig';
+import { findPathPlacementRoute, getStrokeTiles, getStraightRunTiles } from '@/games/coaster/lib/pathPlacement';
+import { findBrokenQueues, getBrokenQueueTileKeys } from '@/games/coaster/lib/queueValidation';
+import { canPlaceTool, isPreviewTool } from '@/games/coaster/lib/placementPreview';
+import { getColorPalette } from '@/lib/colorPalettes';
+import { MAX_ELEVATION } from '@/games/coaster/lib/terrain';
 
 // Helper to get building size for a tool (defaults to 1x1)
 function getToolBuildingSize(tool: Tool): { width: number; height: number } {
   const info = TOOL_INFO[tool];
   return info?.size ?? { width: 1, height: 1 };
 }
-import { drawStraightTrack, drawCurvedTrack, drawSlopeTrack, drawLoopTrack, drawChainLift } from '@/components/coaster/tracks';
-import { drawGuest } from '@/components/coaster/guests';
+import { drawStraightTrack, drawCurvedTrack, getCurvedTrackPoint, drawSlopeTrack, drawLoopTrack, drawChainLift } from '@/components/coaster/tracks';
+import { drawGuest, drawGuestDot, getGuestOccupancy, isGuestVisible } from '@/components/coaster/guests';
+import { drawJanitor, drawLitter, getLitterAt } from '@/components/coaster/staff';
 import { useCoasterLightingSystem } from '@/components/coaster/lightingSystem';
 import { useCoasterCloudSystem, Cloud } from '@/components/coaster/cloudSystem';
 import { drawBeachOnWater } from '@/components/game/drawing';
@@ -28,7 +34,10 @@ const TRACK_DRAG_TOOLS: Tool[] = [
   'bulldoze',
 ];
 
-// Scenery tools that support drag-to-draw (flowers, bushes, trees)
+// Drag tools that paint every tile the cursor crosses instead of a straight line
+const FREEHAND_DRAG_TOOLS: Tool[] = ['path', 'queue'];
+
+// Scenery tools that support drag-to-draw (flowers, bushes, trees, ground surfaces)
 const SCENERY_DRAG_TOOLS: Tool[] = [
   // Trees
   'tree_oak', 'tree_maple', 'tree_birch', 'tree_elm', 'tree_willow',
@@ -41,6 +50,8 @@ const SCENERY_DRAG_TOOLS: Tool[] = [
   'topiary_ball', 'topiary_spiral', 'topiary_animal',
   // Flowers
   'flowers_bed', 'flowers_planter', 'flowers_hanging', 'flowers_wild', 'ground_cover',
+  // Ground surfaces
+  'terrain_sand', 'terrain_rock',
 ];
 
 // =============================================================================
@@ -52,7 +63,19 @@ const HEIGHT_RATIO = 0.60;
 const TILE_HEIGHT = TILE_WIDTH * HEIGHT_RATIO;
 const ZOOM_MIN = 0.3;
 const ZOOM_MAX = 2.5;
+const CAMERA_SMOOTH_FRAMES = 20; // Frames to glide to a camera bookmark
+const KEYBOARD_PAN_STEP = 48; // Screen pixels per arrow key press (x3 with shift)
+const KEYBOARD_ZOOM_FACTOR = 1.15;
+const GHOST_ALPHA = 0.5; // Opacity of the building preview under the cursor
 const HEIGHT_UNIT = 20;
+const GUEST_DETAIL_ZOOM = 0.5; // Below this zoom guests are drawn as dots
+const FURNITURE_DETAIL_ZOOM = 0.5; // Below this zoom benches, lamps and bins are drawn as simple markers
+const HEATMAP_CROWDED_GUESTS = 6; // Guests on one tile that tint it at full crowding
+const DEBUG_GRID_LABEL_ZOOM = 1.2; // Tile coordinates are only labeled when zoomed in past this
+const MONEY_POPUP_RISE = 24; // Screen pixels a money popup floats up over its lifetime
+const EXPORT_ZOOM = 1; // Zoom used for full-park image exports
+const MAX_EXPORT_SIZE = 8192; // Longest side of an exported image in pixels; big parks zoom out to fit
+const EXPORT_MARGIN_TOP = 400; // Room above the back corner for tall sprites and raised track
 
 // Water texture path (same as city game)
 const WATER_ASSET_PATH = '/assets/water.png';
@@ -143,7 +166,40 @@ function gridToScreen(
   return { screenX, screenY };
 }
 
-function screenToGrid(
+// Unzoomed screen-space box around the whole park, with headroom for tall sprites
+function getParkScreenBounds(gridSize: number): { left: number; top: number; right: number; bottom: number } {
+  const last = gridSize - 1;
+  return {
+    left: -last * (TILE_WIDTH / 2) - TILE_WIDTH,
+    top: -EXPORT_MARGIN_TOP,
+    right: last * (TILE_WIDTH / 2) + TILE_WIDTH * 2,
+    bottom: last * TILE_HEIGHT + TILE_HEIGHT * 2,
+  };
+}
+
+/**
+ * Camera after zooming by `factor` while keeping the screen point under
+ * (pointX, pointY) fixed - the wheel, keyboard and scripted zoom all use this
+ */
+function zoomAroundPoint(
+  offset: { x: number; y: number },
+  zoom: number,
+  factor: number,
+  pointX: number,
+  pointY: number
+): { offset: { x: number; y: number }; zoom: number } {
+  const newZoom = Math.max(ZOOM_MIN, Math.min(ZOOM_MAX, zoom * factor));
+  const zoomRatio = newZoom / zoom;
+  return {
+    offset: {
+      x: pointX - (pointX - offset.x) * zoomRatio,
+      y: pointY - (pointY - offset.y) * zoomRatio,
+    },
+    zoom: newZoom,
+  };
+}
+
+function screenToFlatGrid(
   screenX: number,
   screenY: number,
   offsetX: number,
@@ -158,6 +214,36 @@ function screenToGrid(
   return { gridX: Math.floor(gridX), gridY: Math.floor(gridY) };
 }
 
+/**
+ * Tile under a screen point, accounting for raised land: a tile at elevation e
+ * is drawn e * HEIGHT_UNIT higher, and the frontmost raised top wins
+ */
+function screenToGrid(
+  screenX: number,
+  screenY: number,
+  offsetX: number,
+  offsetY: number,
+  grid: Tile[][]
+): { gridX: number; gridY: number } {
+  const flat = screenToFlatGrid(screenX, screenY, offsetX, offsetY);
+  let best = flat;
+  let bestDepth = -Infinity;
+  
+  for (let elevation = 0; elevation <= MAX_ELEVATION; elevation++) {
+    const { gridX, gridY } = elevation === 0
+      ? flat
+      : screenToFlatGrid(screenX, screenY + elevation * HEIGHT_UNIT, offsetX, offsetY);
+    const tile = grid[gridY]?.[gridX];
+    if (!tile || (tile.elevation ?? 0) !== elevation) continue;
+    if (gridX + gridY > bestDepth) {
+      best = { gridX, gridY };
+      bestDepth = gridX + gridY;
+    }
+  }
+  
+  return best;
+}
+
 // =============================================================================
 // DRAWING FUNCTIONS
 // =============================================================================
@@ -170,12 +256,29 @@ const GRASS_COLORS = {
   stroke: '#2d4a26',
 };
 
-function drawGrassTile(ctx: CanvasRenderingContext2D, x: number, y: number, zoom: number = 1) {
+// Bare ground surfaces painted with the terrain tools
+const SAND_COLORS = {
+  top: '#d8c08a',
+  stroke: '#b49a62',
+};
+
+const ROCK_COLORS = {
+  top: '#8a8580',
+  stroke: '#625e5a',
+};
+
+function drawGrassTile(
+  ctx: CanvasRenderingContext2D,
+  x: number,
+  y: number,
+  zoom: number = 1,
+  colors: { top: string; stroke: string } = GRASS_COLORS
+) {
   const w = TILE_WIDTH;
   const h = TILE_HEIGHT;
   
   // Draw the isometric diamond (top face)
-  ctx.fillStyle = GRASS_COLORS.top;
+  ctx.fillStyle = colors.top;
   ctx.beginPath();
   ctx.moveTo(x + w / 2, y);
   ctx.lineTo(x + w, y + h / 2);
@@ -186,7 +289,52 @@ function drawGrassTile(ctx: CanvasRenderingContext2D, x: number, y: number, zoom
   
   // Draw stroke when zoomed in enough (matching city game behavior)
   if (zoom >= 0.6) {
-    ctx.strokeStyle = GRASS_COLORS.stroke;
+    ctx.strokeStyle = colors.stroke;
+    ctx.lineWidth = 0.5;
+    ctx.stroke();
+  }
+}
+
+// Exposed dirt on the sides of raised ground
+const CLIFF_COLORS = {
+  left: '#7a5633',
+  right: '#94693f',
+  stroke: '#5c3f24',
+};
+
+/**
+ * Draw the dirt faces below a raised tile's front edges. `dropLeft` and
+ * `dropRight` are how far (in pixels) the ground falls away past the
+ * lower-left (y+1) and lower-right (x+1) edges of the diamond.
+ */
+function drawCliffFaces(ctx: CanvasRenderingContext2D, x: number, y: number, dropLeft: number, dropRight: number) {
+  const w = TILE_WIDTH;
+  const h = TILE_HEIGHT;
+  
+  if (dropLeft > 0) {
+    ctx.fillStyle = CLIFF_COLORS.left;
+    ctx.beginPath();
+    ctx.moveTo(x, y + h / 2);
+    ctx.lineTo(x + w / 2, y + h);
+    ctx.lineTo(x + w / 2, y + h + dropLeft);
+    ctx.lineTo(x, y + h / 2 + dropLeft);
+    ctx.closePath();
+    ctx.fill();
+    ctx.strokeStyle = CLIFF_COLORS.stroke;
+    ctx.lineWidth = 0.5;
+    ctx.stroke();
+  }
+  
+  if (dropRight > 0) {
+    ctx.fillStyle = CLIFF_COLORS.right;
+    ctx.beginPath();
+    ctx.moveTo(x + w / 2, y + h);
+    ctx.lineTo(x + w, y + h / 2);
+    ctx.lineTo(x + w, y + h / 2 + dropRight);
+    ctx.lineTo(x + w / 2, y + h + dropRight);
+    ctx.closePath();
+    ctx.fill();
+    ctx.strokeStyle = CLIFF_COLORS.stroke;
     ctx.lineWidth = 0.5;
     ctx.stroke();
   }
@@ -198,6 +346,11 @@ const WATER_COLORS = {
   stroke: '#0284c7',
 };
 
+// Water shimmer - the texture sample drifts on a slow sine wave each tick
+const WATER_RIPPLE_SPEED = 0.08;
+const WATER_RIPPLE_AMOUNT = 0.04; // fraction of the texture crop
+const WATER_RIPPLE_WAVELENGTH = 0.6; // phase shift per tile so ripples travel
+
 function drawWaterTile(
   ctx: CanvasRenderingContext2D,
   x: number,
@@ -207,7 +360,8 @@ function drawWaterTile(
   grid: Tile[][],
   gridSize: number,
   waterImage: HTMLImageElement | null,
-  zoom: number = 1
+  zoom: number = 1,
+  tick: number = 0
 ) {
   const w = TILE_WIDTH;
   const h = TILE_HEIGHT;
@@ -243,8 +397,13 @@ function drawWaterTile(
     const cropH = imgH * cropScale;
     const maxOffsetX = imgW - cropW;
     const maxOffsetY = imgH - cropH;
-    const srcX = seedX * maxOffsetX;
-    const srcY = seedY * maxOffsetY;
+    
+    // Ripple the sample position over time so the surface shimmers
+    const ripplePhase = tick * WATER_RIPPLE_SPEED + (gridX + gridY) * WATER_RIPPLE_WAVELENGTH;
+    const rippleX = Math.sin(ripplePhase) * cropW * WATER_RIPPLE_AMOUNT;
+    const rippleY = Math.cos(ripplePhase * 0.8) * cropH * WATER_RIPPLE_AMOUNT;
+    const srcX = Math.max(0, Math.min(maxOffsetX, seedX * maxOffsetX + rippleX));
+    const srcY = Math.max(0, Math.min(maxOffsetY, seedY * maxOffsetY + rippleY));
     
     // Create a clipping path - expand toward adjacent WATER tiles only
     const expand = w * 0.4;
@@ -753,9 +912,9 @@ function drawGreyBaseTiles(
       const tile = grid[tileY]?.[tileX];
       if (tile?.trackPiece) continue;
       
-      // Convert to screen coordinates
+      // Convert to screen coordinates (lifted with raised ground)
       const screenX = (tileX - tileY) * (w / 2);
-      const screenY = (tileX + tileY) * (h / 2);
+      const screenY = (tileX + tileY) * (h / 2) - (tile?.elevation ?? 0) * HEIGHT_UNIT;
       
       // Draw the grey isometric diamond
       ctx.fillStyle = GREY_TILE_COLORS.top;
@@ -905,6 +1064,39 @@ function drawPathTile(
     ctx.fill();
   }
 
+  // Round off L-bends: with exactly two perpendicular connections the square
+  // segment ends leave a notch on the outside of the turn, so fill it with an
+  // arc around the tile center
+  const connectionCount = [north, east, south, west].filter(Boolean).length;
+  let cornerArc: { start: number; end: number; anticlockwise: boolean } | null = null;
+  if (connectionCount === 2 && !(north && south) && !(east && west)) {
+    const [a, b] = [
+      north && { dx: northDx, dy: northDy },
+      east && { dx: eastDx, dy: eastDy },
+      south && { dx: southDx, dy: southDy },
+      west && { dx: westDx, dy: westDy },
+    ].filter((dir): dir is { dx: number; dy: number } => !!dir);
+
+    // Each segment's outer side is the perpendicular facing away from the other segment
+    const outerSide = (dir: { dx: number; dy: number }, other: { dx: number; dy: number }) => {
+      const perp = getPerp(dir.dx, dir.dy);
+      return perp.nx * other.dx + perp.ny * other.dy < 0 ? perp : { nx: -perp.nx, ny: -perp.ny };
+    };
+    const outerA = outerSide(a, b);
+    const outerB = outerSide(b, a);
+    cornerArc = {
+      start: Math.atan2(outerA.ny, outerA.nx),
+      end: Math.atan2(outerB.ny, outerB.nx),
+      anticlockwise: outerA.nx * outerB.ny - outerA.ny * outerB.nx < 0,
+    };
+
+    ctx.beginPath();
+    ctx.moveTo(cx, cy);
+    ctx.arc(cx, cy, halfWidth, cornerArc.start, cornerArc.end, cornerArc.anticlockwise);
+    ctx.closePath();
+    ctx.fill();
+  }
+
   // Draw sidewalk connections to adjacent visitable buildings (shops, food stands, etc.)
   // Use the same width and style as regular paths for consistency
   const drawBuildingConnector = (
@@ -967,6 +1159,13 @@ function drawPathTile(
   if (east) drawPathEdges(eastDx, eastDy, eastEdgeX, eastEdgeY);
   if (south) drawPathEdges(southDx, southDy, southEdgeX, southEdgeY);
   if (west) drawPathEdges(westDx, westDy, westEdgeX, westEdgeY);
+
+  // Carry the outer curb around the rounded corner
+  if (cornerArc) {
+    ctx.beginPath();
+    ctx.arc(cx, cy, halfWidth, cornerArc.start, cornerArc.end, cornerArc.anticlockwise);
+    ctx.stroke();
+  }
 }
 
 // Queue colors - RCT-style stanchion barriers
@@ -1379,11 +1578,59 @@ function drawQueueTile(
   ctx.setLineDash([]);
 }
 
+/**
+ * Zoomed-out stand-in for a small piece of path furniture: a colored block
+ * at the tile center instead of the full sprite
+ */
+function drawFurnitureMarker(ctx: CanvasRenderingContext2D, buildingType: string, x: number, y: number) {
+  const centerX = x + TILE_WIDTH / 2;
+  const centerY = y + TILE_HEIGHT / 2;
+  if (isLampBuilding(buildingType)) {
+    ctx.fillStyle = '#facc15';
+    ctx.fillRect(centerX - 1.5, centerY - 10, 3, 10);
+  } else if (isBenchBuilding(buildingType)) {
+    ctx.fillStyle = '#92400e';
+    ctx.fillRect(centerX - 5, centerY - 4, 10, 4);
+  } else {
+    ctx.fillStyle = '#6b7280';
+    ctx.fillRect(centerX - 3, centerY - 6, 6, 6);
+  }
+}
+
+/**
+ * Rough height (in track height units) of what stands on a tile, used to order
+ * draws within one diagonal. Multi-tile buildings count at their front corner.
+ */
+function getTileDrawHeight(tile: Tile, frontCornerBuilding?: { width: number; height: number }): number {
+  let height = 0;
+  if (tile.trackPiece) {
+    height = Math.max(tile.trackPiece.startHeight, tile.trackPiece.endHeight);
+  }
+  const type = tile.building?.type;
+  if (frontCornerBuilding) {
+    height = Math.max(height, Math.max(frontCornerBuilding.width, frontCornerBuilding.height) * 2);
+  } else if (type && LARGE_RIDES.includes(type as BuildingType)) {
+    height = Math.max(height, 3);
+  } else if (type && type !== 'empty' && type !== 'grass' && type !== 'water' && type !== 'path' && type !== 'queue' && !type.endsWith('_footprint')) {
+    height = Math.max(height, 1);
+  }
+  return (tile.elevation ?? 0) + height;
+}
+
 // Check if a building type is a tree (for multi-tree rendering)
 function isTreeType(buildingType: string): boolean {
   return buildingType.startsWith('tree_');
 }
 
+// Check if a building type is natural scenery (safe to flip and jitter - no signage)
+function isSceneryType(buildingType: string): boolean {
+  return buildingType.startsWith('tree_') ||
+    buildingType.startsWith('bush_') ||
+    buildingType.startsWith('topiary_') ||
+    buildingType.startsWith('flowers_') ||
+    buildingType === 'ground_cover';
+}
+
 // Seeded random for consistent tree placement per tile
 function seededRandom(seed: number): () => number {
   let state = seed;
@@ -1393,6 +1640,38 @@ function seededRandom(seed: number): () => number {
   };
 }
 
+// Draw a sprite rect, optionally mirrored horizontally around its own center
+function drawSpriteImage(
+  ctx: CanvasRenderingContext2D,
+  sheetCanvas: HTMLCanvasElement,
+  rect: { sx: number; sy: number; sw: number; sh: number },
+  drawX: number,
+  drawY: number,
+  destWidth: number,
+  destHeight: number,
+  flip: boolean
+) {
+  if (!flip) {
+    ctx.drawImage(sheetCanvas, rect.sx, rect.sy, rect.sw, rect.sh, drawX, drawY, destWidth, destHeight);
+    return;
+  }
+  ctx.save();
+  ctx.translate(drawX + destWidth / 2, 0);
+  ctx.scale(-1, 1);
+  ctx.drawImage(sheetCanvas, rect.sx, rect.sy, rect.sw, rect.sh, -destWidth / 2, drawY, destWidth, destHeight);
+  ctx.restore();
+}
+
+// Soft ground shadow so sprites sit on the tile instead of floating above it
+function drawGroundShadow(ctx: CanvasRenderingContext2D, centerX: number, groundY: number, width: number) {
+  const radiusX = width / 2;
+  const radiusY = radiusX * HEIGHT_RATIO;
+  ctx.fillStyle = 'rgba(0, 0, 0, 0.2)';
+  ctx.beginPath();
+  ctx.ellipse(centerX, groundY - radiusY, radiusX, radiusY, 0, 0, Math.PI * 2);
+  ctx.fill();
+}
+
 function drawSprite(
   ctx: CanvasRenderingContext2D,
   spriteSheets: Map<string, HTMLCanvasElement>,
@@ -1400,7 +1679,9 @@ function drawSprite(
   x: number,
   y: number,
   gridX?: number,
-  gridY?: number
+  gridY?: number,
+  orientation: BuildingOrientation = 0,
+  tick: number = 0
 ) {
   const info = getSpriteInfo(buildingType);
   if (!info) return false;
@@ -1409,7 +1690,7 @@ function drawSprite(
   const sheetCanvas = spriteSheets.get(sheet.id);
   if (!sheetCanvas) return false;
   
-  const rect = getSpriteRect(sheet, sprite, sheetCanvas.width, sheetCanvas.height);
+  const rect = getSpriteRect(sheet, sprite, sheetCanvas.width, sheetCanvas.height, tick);
   const baseScale = sprite.scale || 1.0;
   
   // Get building size from TOOL_INFO for multi-tile scaling
@@ -1418,6 +1699,8 @@ function drawSprite(
   // For multi-tile buildings, scale up by the larger dimension
   // This makes 2x2 buildings roughly 2x as large, 3x3 roughly 3x, etc.
   const sizeMultiplier = Math.max(buildingSize.width, buildingSize.height);
+  const mirrored = isOrientationMirrored(orientation);
+  const shadowSize = getShadowSize(buildingType);
   
   // Check if this is a tree - if so, draw multiple trees
   if (isTreeType(buildingType) && gridX !== undefined && gridY !== undefined) {
@@ -1425,7 +1708,7 @@ function drawSprite(
     const rand = seededRandom(gridX * 997 + gridY * 1009);
     
     // Generate tree positions sorted by Y for proper depth ordering
-    const treePositions: { offsetX: number; offsetY: number; scale: number; depth: number }[] = [];
+    const treePositions: { offsetX: number; offsetY: number; scale: number; depth: number; flip: boolean }[] = [];
     
     for (let i = 0; i < numTrees; i++) {
       // Random position within the isometric tile diamond
@@ -1445,12 +1728,23 @@ function drawSprite(
         offsetY: isoOffsetY,
         scale: baseScale * scaleVariation,
         depth: isoOffsetY, // Sort by Y for proper overlap
+        flip: (rand() < 0.5) !== mirrored,
       });
     }
     
     // Sort by depth (trees further back drawn first)
     treePositions.sort((a, b) => a.depth - b.depth);
     
+    // Shadows go down first so no trunk is darkened by a neighbor's shadow
+    for (const tree of treePositions) {
+      drawGroundShadow(
+        ctx,
+        x + TILE_WIDTH / 2 + tree.offsetX,
+        y + TILE_HEIGHT + tree.offsetY,
+        TILE_WIDTH * shadowSize * (tree.scale / baseScale)
+      );
+    }
+    
     // Draw each tree
     for (const tree of treePositions) {
       const scale = tree.scale;
@@ -1466,18 +1760,28 @@ function drawSprite(
       const drawX = x + (TILE_WIDTH - destWidth) / 2 + spriteOffsetX + tree.offsetX;
       const drawY = y + TILE_HEIGHT - destHeight + spriteOffsetY + tree.offsetY;
       
-      ctx.drawImage(
-        sheetCanvas,
-        rect.sx, rect.sy, rect.sw, rect.sh,
-        drawX, drawY, destWidth, destHeight
-      );
+      drawSpriteImage(ctx, sheetCanvas, rect, drawX, drawY, destWidth, destHeight, tree.flip);
     }
     
     return true;
   }
   
+  // Scenery gets a small per-tile variation (scale, mirror, nudge) so repeated
+  // bushes and flowers don't tile identically; seeded by tile so it never changes
+  let variationScale = 1;
+  let jitterX = 0;
+  let jitterY = 0;
+  let flip = mirrored;
+  if (isSceneryType(buildingType) && gridX !== undefined && gridY !== undefined) {
+    const rand = seededRandom(gridX * 7919 + gridY * 104729 + 1);
+    variationScale = 0.92 + rand() * 0.16; // 92% to 108%
+    flip = (rand() < 0.5) !== mirrored;
+    jitterX = (rand() - 0.5) * 3;
+    jitterY = (rand() - 0.5) * 1.5;
+  }
+  
   // Standard single sprite rendering with multi-tile size scaling
-  const scale = baseScale * sizeMultiplier;
+  const scale = baseScale * sizeMultiplier * variationScale;
   const baseWidth = TILE_WIDTH * 1.2;
   const destWidth = baseWidth * scale;
   
@@ -1488,14 +1792,13 @@ function drawSprite(
   const offsetX = (sprite.offsetX || 0) * offsetScale;
   const offsetY = (sprite.offsetY || 0) * offsetScale;
   
-  const drawX = x + (TILE_WIDTH - destWidth) / 2 + offsetX;
-  const drawY = y + TILE_HEIGHT - destHeight + offsetY;
+  const drawX = x + (TILE_WIDTH - destWidth) / 2 + offsetX + jitterX;
+  const drawY = y + TILE_HEIGHT - destHeight + offsetY + jitterY;
   
-  ctx.drawImage(
-    sheetCanvas,
-    rect.sx, rect.sy, rect.sw, rect.sh,
-    drawX, drawY, destWidth, destHeight
-  );
+  if (shadowSize > 0) {
+    drawGroundShadow(ctx, x + TILE_WIDTH / 2 + jitterX, y + TILE_HEIGHT + jitterY, TILE_WIDTH * shadowSize * sizeMultiplier * variationScale);
+  }
+  drawSpriteImage(ctx, sheetCanvas, rect, drawX, drawY, destWidth, destHeight, flip);
   
   return true;
 }
@@ -1614,44 +1917,12 @@ function getTrackPoint(
   
   const heightOffset = (trackPiece.startHeight + (trackPiece.endHeight - trackPiece.startHeight) * t) * HEIGHT_UNIT;
   
-  // Edge midpoints - MUST match track drawing exactly
-  const northEdge = { x: startX + w * 0.25, y: startY + h * 0.25 - heightOffset };
-  const eastEdge = { x: startX + w * 0.75, y: startY + h * 0.25 - heightOffset };
-  const southEdge = { x: startX + w * 0.75, y: startY + h * 0.75 - heightOffset };
-  const westEdge = { x: startX + w * 0.25, y: startY + h * 0.75 - heightOffset };
-  const center = { x: startX + w / 2, y: startY + h / 2 - heightOffset };
-  
   const { type, direction } = trackPiece;
   
   if (type === 'turn_left_flat' || type === 'turn_right_flat') {
-    const turnRight = type === 'turn_right_flat';
-    
-    // Determine which edges to connect based on direction and turn
-    // This MUST match drawCurvedTrack logic exactly
-    let fromEdge: { x: number; y: number };
-    let toEdge: { x: number; y: number };
-    
-    if (direction === 'north') {
-      fromEdge = northEdge;
-      toEdge = turnRight ? eastEdge : westEdge;
-    } else if (direction === 'south') {
-      fromEdge = southEdge;
-      toEdge = turnRight ? westEdge : eastEdge;
-    } else if (direction === 'east') {
-      fromEdge = eastEdge;
-      toEdge = turnRight ? southEdge : northEdge;
-    } else { // west
-      fromEdge = westEdge;
-      toEdge = turnRight ? northEdge : southEdge;
-    }
-    
-    // Quadratic bezier with center as control point (same as drawCurvedTrack)
-    const u = 1 - t;
-    return {
-      x: u * u * fromEdge.x + 2 * u * t * center.x + t * t * toEdge.x,
-      y: u * u * fromEdge.y + 2 * u * t * center.y + t * t * toEdge.y,
-      pitch: 0, // Flat turns have no pitch
-    };
+    // Same arc drawCurvedTrack draws
+    const point = getCurvedTrackPoint(startX, startY, direction, type === 'turn_right_flat', heightOffset / HEIGHT_UNIT, t);
+    return { x: point.x, y: point.y, pitch: 0 }; // Flat turns have no pitch
   }
   
   if (type === 'loop_vertical') {
@@ -2371,28 +2642,57 @@ function drawCoasterCar(
 // COMPONENT
 // =============================================================================
 
+// Scriptable camera moves, handed out through onCameraControlsReady
+export interface CoasterCameraControls {
+  panBy: (dx: number, dy: number) => void;
+  zoomBy: (factor: number, centerX: number, centerY: number) => void;
+  centerOn: (gridX: number, gridY: number) => void;
+}
+
 interface CoasterGridProps {
   selectedTile: { x: number; y: number } | null;
   setSelectedTile: (tile: { x: number; y: number } | null) => void;
   navigationTarget?: { x: number; y: number } | null;
   onNavigationComplete?: () => void;
+  cameraTarget?: { offset: { x: number; y: number }; zoom: number; smooth?: boolean } | null;
+  onCameraTargetReached?: () => void;
   onViewportChange?: (viewport: {
     offset: { x: number; y: number };
     zoom: number;
     canvasSize: { width: number; height: number };
   }) => void;
+  exportRequested?: boolean;
+  onExportComplete?: (dataUrl: string | null) => void;
+  onGuestPick?: (guestId: string) => void;
+  onCameraControlsReady?: (controls: CoasterCameraControls) => void;
   isMobile?: boolean;
 }
 
+// Camera and target size for one pass of the scene renderer
+interface SceneView {
+  offset: { x: number; y: number };
+  zoom: number;
+  width: number; // CSS pixels
+  height: number;
+  dpr: number;
+  showCursor: boolean; // Hover highlight and placement previews (left out of exports)
+}
+
 export function CoasterGrid({
   selectedTile,
   setSelectedTile,
   navigationTarget,
   onNavigationComplete,
+  cameraTarget,
+  onCameraTargetReached,
   onViewportChange,
+  exportRequested = false,
+  onExportComplete,
+  onGuestPick,
+  onCameraControlsReady,
   isMobile = false,
 }: CoasterGridProps) {
-  const { state, latestStateRef, placeAtTile, bulldozeTile, placeTrackLine } = useCoaster();
+  const { state, latestStateRef, placeAtTile, bulldozeTile, placeTrackLine, placePathRoute, placeLine, pickGuestAt, spriteLayoutVersion, debugGrid } = useCoaster();
   const { grid, gridSize, selectedTool, tick, coasters } = state;
   
   // Create a lookup map from coaster ID to colors and category for track rendering
@@ -2510,6 +2810,7 @@ export function CoasterGrid({
   const isTouchDraggingRef = useRef(false);
   const initialPinchDistanceRef = useRef<number | null>(null);
   const initialZoomRef = useRef(1);
+  const cameraRef = useRef({ offset: { x: 620, y: 160 }, zoom: 1 });
   const lastTouchCenterRef = useRef<{ x: number; y: number } | null>(null);
   const [hoveredTile, setHoveredTile] = useState<{ x: number; y: number } | null>(null);
   const [canvasSize, setCanvasSize] = useState({ width: 800, height: 600 });
@@ -2522,6 +2823,40 @@ export function CoasterGrid({
   const [trackDragDirection, setTrackDragDirection] = useState<'h' | 'v' | null>(null);
   const [trackDragPreviewTiles, setTrackDragPreviewTiles] = useState<{ x: number; y: number }[]>([]);
   const placedTrackTilesRef = useRef<Set<string>>(new Set());
+  const lastStrokeTileRef = useRef<{ x: number; y: number } | null>(null);
+  
+  // Auto-path state (first click marks the start, second click lays the route)
+  const [autoPathStart, setAutoPathStart] = useState<{ x: number; y: number } | null>(null);
+  
+  // Line placement state (shift-click a start tile, shift-click an end tile)
+  const [lineStart, setLineStart] = useState<{ x: number; y: number } | null>(null);
+  const isLineTool = isTrackDragTool && selectedTool !== 'bulldoze';
+  
+  // Clear a pending auto-path or line start when switching tools
+  useEffect(() => {
+    if (selectedTool !== 'auto_path') {
+      setAutoPathStart(null);
+    }
+    setLineStart(null);
+  }, [selectedTool]);
+  
+  // Preview of the straight run a line would lay to the hovered tile
+  const linePreview = useMemo(() => {
+    if (!lineStart) return null;
+    return hoveredTile ? getStraightRunTiles(lineStart, hoveredTile) : [lineStart];
+  }, [lineStart, hoveredTile]);
+  
+  // Preview of the route the auto-path tool would lay to the hovered tile
+  const autoPathPreview = useMemo(() => {
+    if (selectedTool !== 'auto_path' || !autoPathStart || !hoveredTile) return null;
+    return findPathPlacementRoute(grid, gridSize, autoPathStart, hoveredTile);
+  }, [selectedTool, autoPathStart, hoveredTile, grid, gridSize]);
+  
+  // Queue tiles in runs that don't connect a path to a ride (tinted as a warning)
+  const brokenQueueTiles = useMemo(
+    () => getBrokenQueueTileKeys(findBrokenQueues(grid, gridSize)),
+    [grid, gridSize]
+  );
   
   // Load sprite sheets in parallel for faster loading
   useEffect(() => {
@@ -2597,39 +2932,117 @@ export function CoasterGrid({
   
   // Report viewport changes
   useEffect(() => {
+    cameraRef.current = { offset, zoom };
     onViewportChange?.({ offset, zoom, canvasSize });
   }, [offset, zoom, canvasSize, onViewportChange]);
   
-  // Navigate to target
+  // Move to a camera bookmark, either instantly or gliding over a few frames
   useEffect(() => {
-    if (navigationTarget) {
-      const { screenX, screenY } = gridToScreen(
-        navigationTarget.x,
-        navigationTarget.y,
-        0,
-        0
-      );
+    if (!cameraTarget) return;
+    const targetZoom = Math.max(ZOOM_MIN, Math.min(ZOOM_MAX, cameraTarget.zoom));
+    
+    if (!cameraTarget.smooth) {
+      setOffset(cameraTarget.offset);
+      setZoom(targetZoom);
+      onCameraTargetReached?.();
+      return;
+    }
+    
+    const start = cameraRef.current;
+    let frame = 0;
+    let animationId = 0;
+    const step = () => {
+      frame++;
+      const t = frame / CAMERA_SMOOTH_FRAMES;
+      const eased = t * (2 - t); // Ease out
       setOffset({
-        x: canvasSize.width / 2 - screenX * zoom,
-        y: canvasSize.height / 2 - screenY * zoom,
+        x: start.offset.x + (cameraTarget.offset.x - start.offset.x) * eased,
+        y: start.offset.y + (cameraTarget.offset.y - start.offset.y) * eased,
       });
+      setZoom(start.zoom + (targetZoom - start.zoom) * eased);
+      if (frame < CAMERA_SMOOTH_FRAMES) {
+        animationId = requestAnimationFrame(step);
+      } else {
+        onCameraTargetReached?.();
+      }
+    };
+    animationId = requestAnimationFrame(step);
+    return () => cancelAnimationFrame(animationId);
+  }, [cameraTarget, onCameraTargetReached]);
+  
+  // Camera controls shared by the mouse, the keyboard and navigation requests
+  const panBy = useCallback((dx: number, dy: number) => {
+    setOffset(prev => ({ x: prev.x + dx, y: prev.y + dy }));
+  }, []);
+  
+  const zoomBy = useCallback((factor: number, centerX: number, centerY: number) => {
+    const next = zoomAroundPoint(cameraRef.current.offset, cameraRef.current.zoom, factor, centerX, centerY);
+    setOffset(next.offset);
+    setZoom(next.zoom);
+  }, []);
+  
+  const centerOn = useCallback((gridX: number, gridY: number) => {
+    const { screenX, screenY } = gridToScreen(gridX, gridY, 0, 0);
+    const currentZoom = cameraRef.current.zoom;
+    setOffset({
+      x: canvasSize.width / 2 - (screenX + TILE_WIDTH / 2) * currentZoom,
+      y: canvasSize.height / 2 - (screenY + TILE_HEIGHT / 2) * currentZoom,
+    });
+  }, [canvasSize]);
+  
+  useEffect(() => {
+    onCameraControlsReady?.({ panBy, zoomBy, centerOn });
+  }, [onCameraControlsReady, panBy, zoomBy, centerOn]);
+  
+  // Navigate to target
+  useEffect(() => {
+    if (navigationTarget) {
+      centerOn(navigationTarget.x, navigationTarget.y);
       onNavigationComplete?.();
     }
-  }, [navigationTarget, canvasSize, zoom, onNavigationComplete]);
+  }, [navigationTarget, centerOn, onNavigationComplete]);
   
-  // Main render loop
+  // Arrow keys pan, +/- zoom around the middle of the view
   useEffect(() => {
-    const canvas = canvasRef.current;
-    if (!canvas) return;
+    const handleKeyDown = (e: KeyboardEvent) => {
+      const target = e.target as HTMLElement;
+      if (target.tagName === 'INPUT' || target.tagName === 'TEXTAREA' || target.isContentEditable) return;
+      if (e.metaKey || e.ctrlKey || e.altKey) return;
+      
+      const panStep = KEYBOARD_PAN_STEP * (e.shiftKey ? 3 : 1);
+      switch (e.key) {
+        case 'ArrowLeft': panBy(panStep, 0); break;
+        case 'ArrowRight': panBy(-panStep, 0); break;
+        case 'ArrowUp': panBy(0, panStep); break;
+        case 'ArrowDown': panBy(0, -panStep); break;
+        case '+':
+        case '=':
+          zoomBy(KEYBOARD_ZOOM_FACTOR, canvasSize.width / 2, canvasSize.height / 2);
+          break;
+        case '-':
+        case '_':
+          zoomBy(1 / KEYBOARD_ZOOM_FACTOR, canvasSize.width / 2, canvasSize.height / 2);
+          break;
+        default:
+          return;
+      }
+      e.preventDefault();
+    };
     
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [panBy, zoomBy, canvasSize]);
+  
+  // Draw the park into any canvas from any camera - the live view and image exports share this
+  const renderScene = useCallback((canvas: HTMLCanvasElement, view: SceneView) => {
     const ctx = canvas.getContext('2d');
     if (!ctx) return;
     
-    const dpr = window.devicePixelRatio || 1;
-    canvas.width = canvasSize.width * dpr;
-    canvas.height = canvasSize.height * dpr;
-    canvas.style.width = `${canvasSize.width}px`;
-    canvas.style.height = `${canvasSize.height}px`;
+    const { offset, zoom, dpr } = view;
+    canvas.width = view.width * dpr;
+    canvas.height = view.height * dpr;
+    canvas.style.width = `${view.width}px`;
+    canvas.style.height = `${view.height}px`;
     
     // Clear
     ctx.fillStyle = '#1e293b';
@@ -2651,8 +3064,11 @@ export function CoasterGrid({
     const CULL_MARGIN_BOTTOM = TILE_HEIGHT * 3; // Extra for supports and sprites
     const viewLeft = -offset.x / zoom - CULL_MARGIN_X;
     const viewTop = -offset.y / zoom - CULL_MARGIN_TOP;
-    const viewRight = canvasSize.width / zoom - offset.x / zoom + CULL_MARGIN_X;
-    const viewBottom = canvasSize.height / zoom - offset.y / zoom + CULL_MARGIN_BOTTOM;
+    const viewRight = view.width / zoom - offset.x / zoom + CULL_MARGIN_X;
+    const viewBottom = view.height / zoom - offset.y / zoom + CULL_MARGIN_BOTTOM;
+    
+    // Heatmap, placement tints and guest shirts follow the chosen colorblind mode
+    const colorPalette = getColorPalette(state.overlayPalette ?? 'none');
     
     const guestsByTile = new Map<string, typeof state.guests>();
     state.guests.forEach(guest => {
@@ -2669,6 +3085,19 @@ export function CoasterGrid({
       }
     });
     
+    const staffByTile = new Map<string, typeof state.staff>();
+    state.staff.forEach(member => {
+      const effectiveX = member.progress >= 0.5 ? member.targetTileX : member.tileX;
+      const effectiveY = member.progress >= 0.5 ? member.targetTileY : member.tileY;
+      const key = `${effectiveX},${effectiveY}`;
+      const existing = staffByTile.get(key);
+      if (existing) {
+        existing.push(member);
+      } else {
+        staffByTile.set(key, [member]);
+      }
+    });
+    
     // Enhanced car data with loading state, guest info, and pitch for slopes
     interface CarRenderData {
       x: number;
@@ -2810,6 +3239,7 @@ export function CoasterGrid({
       anchorY: number;
       width: number;
       height: number;
+      orientation?: BuildingOrientation;
     }>();
     
     // First pass: identify all multi-tile buildings and map them by their front corner
@@ -2831,29 +3261,91 @@ export function CoasterGrid({
               anchorY: y,
               width: buildingSize.width,
               height: buildingSize.height,
+              orientation: tile.building?.orientation,
             });
           }
         }
       }
     }
     
-    // Draw tiles (back to front for proper depth)
+    // Guests standing just in front of a multi-tile building (past its right or
+    // left edge) have a smaller x+y than the building's front corner, so they
+    // would be drawn before it and wrongly covered. Defer them to the front corner.
+    const deferredGuestTile = new Map<string, { key: string; depth: number }>();
+    multiTileBuildingsByFrontCorner.forEach((building, frontKey) => {
+      const frontX = building.anchorX + building.width - 1;
+      const frontY = building.anchorY + building.height - 1;
+      const frontDepth = frontX + frontY;
+      const bandTiles: { x: number; y: number }[] = [];
+      for (let by = building.anchorY; by <= frontY; by++) bandTiles.push({ x: frontX + 1, y: by });
+      for (let bx = building.anchorX; bx <= frontX; bx++) bandTiles.push({ x: bx, y: frontY + 1 });
+      for (const band of bandTiles) {
+        if (band.x + band.y >= frontDepth) continue;
+        const bandKey = `${band.x},${band.y}`;
+        const existing = deferredGuestTile.get(bandKey);
+        if (!existing || existing.depth < frontDepth) {
+          deferredGuestTile.set(bandKey, { key: frontKey, depth: frontDepth });
+        }
+      }
+    });
+    deferredGuestTile.forEach((target, bandKey) => {
+      const bandGuests = guestsByTile.get(bandKey);
+      if (!bandGuests) return;
+      guestsByTile.delete(bandKey);
+      const targetGuests = guestsByTile.get(target.key);
+      if (targetGuests) {
+        targetGuests.push(...bandGuests);
+      } else {
+        guestsByTile.set(target.key, bandGuests);
+      }
+    });
+    
+    // Within a tile, draw guests back to front by their interpolated position
+    const guestDepth = (guest: (typeof state.guests)[number]) =>
+      guest.tileX + (guest.targetTileX - guest.tileX) * guest.progress +
+      guest.tileY + (guest.targetTileY - guest.tileY) * guest.progress;
+    guestsByTile.forEach(tileGuests => {
+      if (tileGuests.length > 1) {
+        tileGuests.sort((a, b) => guestDepth(a) - guestDepth(b));
+      }
+    });
+    
+    // Draw tiles back to front, one diagonal at a time. Each diagonal lays all of
+    // its ground first and then what stands on it, shortest first, so a tall
+    // sprite's overhang isn't painted over by a neighbor's ground or by a
+    // shorter object at the same depth.
     for (let sum = 0; sum < gridSize * 2 - 1; sum++) {
+      const standing: { x: number; y: number; screenX: number; screenY: number; elevation: number; drawHeight: number }[] = [];
       for (let x = 0; x <= sum; x++) {
         const y = sum - x;
         if (x >= gridSize || y >= gridSize || y < 0) continue;
         
-        const { screenX, screenY } = gridToScreen(x, y, 0, 0);
+        const tile = grid[y][x];
+        // Raised ground lifts everything on the tile by whole height units
+        const elevation = tile.elevation ?? 0;
+        const { screenX, screenY: groundY } = gridToScreen(x, y, 0, 0);
+        const screenY = groundY - elevation * HEIGHT_UNIT;
         
         // Culling
         if (screenX < viewLeft || screenX > viewRight ||
             screenY < viewTop || screenY > viewBottom) continue;
         
-const tile = grid[y][x];
+        // Dirt cliffs where the ground drops away toward the viewer (map edges fall to sea level)
+        if (elevation > 0) {
+          const leftElevation = y + 1 < gridSize ? grid[y + 1][x].elevation ?? 0 : 0;
+          const rightElevation = x + 1 < gridSize ? grid[y][x + 1].elevation ?? 0 : 0;
+          drawCliffFaces(
+            ctx,
+            screenX,
+            screenY,
+            Math.max(0, elevation - leftElevation) * HEIGHT_UNIT,
+            Math.max(0, elevation - rightElevation) * HEIGHT_UNIT
+          );
+        }
 
         // Draw based on tile type
         if (tile.terrain === 'water') {
-          drawWaterTile(ctx, screenX, screenY, x, y, grid, gridSize, waterImage, zoom);
+          drawWaterTile(ctx, screenX, screenY, x, y, grid, gridSize, waterImage, zoom, tick);
           
           // Draw beach on water tiles at edges facing land (just like iso city)
           if (zoom >= 0.4) {
@@ -2874,18 +3366,54 @@ const tile = grid[y][x];
           // Count guests in 'queuing' state on this tile
           const queueGuests = guestsByTile.get(`${x},${y}`)?.filter(g => g.state === 'queuing') || [];
           drawQueueTile(ctx, screenX, screenY, x, y, grid, gridSize, queueGuests.length, tick);
+          
+          // Warning tint for dead-end queues (pulses so it reads as a problem, not a theme)
+          if (brokenQueueTiles.has(`${x},${y}`)) {
+            const pulse = 0.25 + 0.15 * Math.sin(tick * 0.1);
+            ctx.fillStyle = `rgba(239, 68, 68, ${pulse})`;
+            ctx.beginPath();
+            ctx.moveTo(screenX + TILE_WIDTH / 2, screenY);
+            ctx.lineTo(screenX + TILE_WIDTH, screenY + TILE_HEIGHT / 2);
+            ctx.lineTo(screenX + TILE_WIDTH / 2, screenY + TILE_HEIGHT);
+            ctx.lineTo(screenX, screenY + TILE_HEIGHT / 2);
+            ctx.closePath();
+            ctx.fill();
+          }
         } else if (tile.path) {
           drawPathTile(ctx, screenX, screenY, x, y, grid, gridSize);
           
+          const litterAmount = getLitterAt(state.litter, x, y);
+          if (litterAmount > 0 && zoom >= 0.5) {
+            drawLitter(ctx, screenX, screenY, x, y, litterAmount);
+          }
+          
           // Check if this path tile is at the edge of the map - if so, draw entrance gate
           const edgeInfo = getTileEdgeInfo(x, y, gridSize);
           if (edgeInfo.isEdge) {
             drawEntranceGate(ctx, screenX, screenY, edgeInfo, x, y);
           }
+        } else if (tile.terrain === 'sand') {
+          drawGrassTile(ctx, screenX, screenY, zoom, SAND_COLORS);
+        } else if (tile.terrain === 'rock') {
+          drawGrassTile(ctx, screenX, screenY, zoom, ROCK_COLORS);
         } else {
           drawGrassTile(ctx, screenX, screenY, zoom);
         }
         
+        standing.push({
+          x,
+          y,
+          screenX,
+          screenY,
+          elevation,
+          drawHeight: getTileDrawHeight(tile, multiTileBuildingsByFrontCorner.get(`${x},${y}`)),
+        });
+      }
+      
+      standing.sort((a, b) => a.drawHeight - b.drawHeight);
+      for (const { x, y, screenX, screenY, elevation } of standing) {
+        const tile = grid[y][x];
+        
         // Draw coaster track if present
         if (tile.trackPiece) {
           // Look up the coaster's colors and category for this track
@@ -2908,8 +3436,12 @@ const tile = grid[y][x];
             if (needsGreyBase(spriteBuildingType)) {
               drawGreyBaseTiles(ctx, x, y, 1, 1, zoom, grid, gridSize);
             }
-            // Then draw the sprite
-            drawSprite(ctx, spriteSheets, spriteBuildingType, screenX, screenY, x, y);
+            // Then draw the sprite (small furniture is just a marker when zoomed out)
+            if (zoom < FURNITURE_DETAIL_ZOOM && PATH_FURNITURE.includes(spriteBuildingType as BuildingType)) {
+              drawFurnitureMarker(ctx, spriteBuildingType, screenX, screenY);
+            } else {
+              drawSprite(ctx, spriteSheets, spriteBuildingType, screenX, screenY, x, y, tile.building?.orientation, tick);
+            }
           }
           // Multi-tile buildings are drawn when we reach their front corner (see below)
         }
@@ -2920,6 +3452,7 @@ const tile = grid[y][x];
         const multiTileBuilding = multiTileBuildingsByFrontCorner.get(frontCornerKey);
         if (multiTileBuilding) {
           const anchorScreen = gridToScreen(multiTileBuilding.anchorX, multiTileBuilding.anchorY, 0, 0);
+          anchorScreen.screenY -= (grid[multiTileBuilding.anchorY][multiTileBuilding.anchorX].elevation ?? 0) * HEIGHT_UNIT;
           
           // Draw grey base tiles for the building footprint (skipping tiles with coaster tracks)
           if (needsGreyBase(multiTileBuilding.type)) {
@@ -2943,15 +3476,35 @@ const tile = grid[y][x];
             anchorScreen.screenX,
             anchorScreen.screenY,
             multiTileBuilding.anchorX,
-            multiTileBuilding.anchorY
+            multiTileBuilding.anchorY,
+            multiTileBuilding.orientation,
+            tick
           );
         }
         
+        // Guests and cars position themselves from tile coordinates, so lift them with the ground
+        if (elevation > 0) {
+          ctx.save();
+          ctx.translate(0, -elevation * HEIGHT_UNIT);
+        }
+        
         // Draw guests on this tile
         const guests = guestsByTile.get(`${x},${y}`);
         if (guests) {
+          // Zoomed out, full bodies are too small to read; dots are cheaper and clearer
+          const detailed = zoom >= GUEST_DETAIL_ZOOM;
           guests.forEach(guest => {
-            drawGuest(ctx, guest, tick);
+            if (!isGuestVisible(guest)) return;
+            if (detailed) drawGuest(ctx, guest, tick, colorPalette.shirtColors);
+            else drawGuestDot(ctx, guest, zoom, colorPalette.shirtColors);
+          });
+        }
+        
+        // Draw staff on this tile
+        const staffMembers = staffByTile.get(`${x},${y}`);
+        if (staffMembers) {
+          staffMembers.forEach(member => {
+            if (member.type === 'handyman') drawJanitor(ctx, member, tick);
           });
         }
 
@@ -2963,6 +3516,10 @@ const tile = grid[y][x];
           });
         }
         
+        if (elevation > 0) {
+          ctx.restore();
+        }
+        
         // Draw boarding/exiting guests at station tiles
         const stationLoading = stationLoadingByTile.get(`${x},${y}`);
         if (stationLoading) {
@@ -3006,6 +3563,89 @@ const tile = grid[y][x];
       }
     }
     
+    // Guest heatmap: walkable tiles tinted from empty to crowded in the chosen palette
+    if (state.overlayMode === 'guest_density') {
+      const occupancy = getGuestOccupancy(state.guests, gridSize);
+      for (let y = 0; y < gridSize; y++) {
+        for (let x = 0; x < gridSize; x++) {
+          const count = occupancy[y * gridSize + x];
+          const tile = grid[y][x];
+          if (count === 0 && !tile.path && !tile.queue) continue;
+          const crowding = Math.min(1, count / HEATMAP_CROWDED_GUESTS);
+          const { screenX, screenY } = gridToScreen(x, y, 0, 0);
+          const sy = screenY - (tile.elevation ?? 0) * HEIGHT_UNIT;
+          ctx.fillStyle = colorPalette.heatmap(crowding);
+          ctx.beginPath();
+          ctx.moveTo(screenX + TILE_WIDTH / 2, sy);
+          ctx.lineTo(screenX + TILE_WIDTH, sy + TILE_HEIGHT / 2);
+          ctx.lineTo(screenX + TILE_WIDTH / 2, sy + TILE_HEIGHT);
+          ctx.lineTo(screenX, sy + TILE_HEIGHT / 2);
+          ctx.closePath();
+          ctx.fill();
+        }
+      }
+    }
+    
+    // Debug grid: every tile in view outlined, with its coordinates once zoomed in enough to read them
+    if (debugGrid) {
+      const showLabels = zoom >= DEBUG_GRID_LABEL_ZOOM;
+      ctx.strokeStyle = 'rgba(255, 255, 255, 0.35)';
+      ctx.lineWidth = 1 / zoom;
+      ctx.font = '8px monospace';
+      ctx.textAlign = 'center';
+      ctx.textBaseline = 'middle';
+      for (let y = 0; y < gridSize; y++) {
+        for (let x = 0; x < gridSize; x++) {
+          const { screenX, screenY } = gridToScreen(x, y, 0, 0);
+          const sy = screenY - (grid[y][x].elevation ?? 0) * HEIGHT_UNIT;
+          if (screenX < viewLeft || screenX > viewRight || sy < viewTop || sy > viewBottom) continue;
+          ctx.beginPath();
+          ctx.moveTo(screenX + TILE_WIDTH / 2, sy);
+          ctx.lineTo(screenX + TILE_WIDTH, sy + TILE_HEIGHT / 2);
+          ctx.lineTo(screenX + TILE_WIDTH / 2, sy + TILE_HEIGHT);
+          ctx.lineTo(screenX, sy + TILE_HEIGHT / 2);
+          ctx.closePath();
+          ctx.stroke();
+          if (showLabels) {
+            ctx.fillStyle = 'rgba(255, 255, 255, 0.85)';
+            ctx.fillText(`${x},${y}`, screenX + TILE_WIDTH / 2, sy + TILE_HEIGHT / 2);
+          }
+        }
+      }
+    }
+    
+    // Auto-path route preview (new tiles in green, reused path in blue)
+    if (view.showCursor && autoPathStart) {
+      const previewTiles = autoPathPreview?.tiles ?? [autoPathStart];
+      for (const previewTile of previewTiles) {
+        const { screenX, screenY } = gridToScreen(previewTile.x, previewTile.y, 0, 0);
+        const hasPath = grid[previewTile.y]?.[previewTile.x]?.path;
+        ctx.fillStyle = hasPath ? 'rgba(59, 130, 246, 0.35)' : 'rgba(34, 197, 94, 0.45)';
+        ctx.beginPath();
+        ctx.moveTo(screenX + TILE_WIDTH / 2, screenY);
+        ctx.lineTo(screenX + TILE_WIDTH, screenY + TILE_HEIGHT / 2);
+        ctx.lineTo(screenX + TILE_WIDTH / 2, screenY + TILE_HEIGHT);
+        ctx.lineTo(screenX, screenY + TILE_HEIGHT / 2);
+        ctx.closePath();
+        ctx.fill();
+      }
+    }
+    
+    // Straight line preview from the shift-clicked start tile
+    if (view.showCursor && linePreview) {
+      ctx.fillStyle = 'rgba(59, 130, 246, 0.4)';
+      for (const previewTile of linePreview) {
+        const { screenX, screenY } = gridToScreen(previewTile.x, previewTile.y, 0, 0);
+        ctx.beginPath();
+        ctx.moveTo(screenX + TILE_WIDTH / 2, screenY);
+        ctx.lineTo(screenX + TILE_WIDTH, screenY + TILE_HEIGHT / 2);
+        ctx.lineTo(screenX + TILE_WIDTH / 2, screenY + TILE_HEIGHT);
+        ctx.lineTo(screenX, screenY + TILE_HEIGHT / 2);
+        ctx.closePath();
+        ctx.fill();
+      }
+    }
+    
     // Multi-tile building sprites are now drawn inline during the main loop
     // when we reach their "front corner" tile for correct isometric depth ordering
     
@@ -3015,6 +3655,27 @@ const tile = grid[y][x];
       drawIncompleteTrackWarning(ctx, screenX, screenY, tick, end.coasterName);
     }
     
+    // Income popups rise from where the money was paid and fade out
+    if (state.moneyPopups && state.moneyPopups.length > 0) {
+      ctx.font = 'bold 10px sans-serif';
+      ctx.textAlign = 'center';
+      ctx.textBaseline = 'middle';
+      ctx.lineWidth = 2;
+      for (const popup of state.moneyPopups) {
+        const age = Math.max(0, Math.min(1, (tick - popup.tick) / MONEY_POPUP_TICKS));
+        const { screenX, screenY } = gridToScreen(popup.x, popup.y, 0, 0);
+        const px = screenX + TILE_WIDTH / 2;
+        const py = screenY - (grid[popup.y]?.[popup.x]?.elevation ?? 0) * HEIGHT_UNIT - 20 - age * MONEY_POPUP_RISE;
+        const label = `+$${Math.round(popup.amount)}`;
+        ctx.globalAlpha = 1 - age;
+        ctx.strokeStyle = 'rgba(0, 0, 0, 0.6)';
+        ctx.strokeText(label, px, py);
+        ctx.fillStyle = '#4ade80';
+        ctx.fillText(label, px, py);
+      }
+      ctx.globalAlpha = 1;
+    }
+    
     // Draw hover highlights AFTER all tiles (so they appear on top)
     // Helper to draw an isometric diamond highlight
     const drawHighlight = (sx: number, sy: number, fillColor = 'rgba(251, 191, 36, 0.3)', strokeColor = '#fbbf24') => {
@@ -3033,7 +3694,7 @@ const tile = grid[y][x];
     };
     
     // Draw hovered tile highlight with multi-tile preview for buildings
-    if (hoveredTile && hoveredTile.x >= 0 && hoveredTile.x < gridSize && 
+    if (view.showCursor && hoveredTile && hoveredTile.x >= 0 && hoveredTile.x < gridSize && 
         hoveredTile.y >= 0 && hoveredTile.y < gridSize && selectedTool !== 'select') {
       
       // Check if we're not in track drag preview mode
@@ -3042,6 +3703,23 @@ const tile = grid[y][x];
         // Get the building size for the current tool
         const buildingSize = getToolBuildingSize(selectedTool);
         
+        // Build tools get a green/red verdict; bulldoze and friends keep the plain highlight
+        const showsVerdict = isPreviewTool(selectedTool);
+        const canPlace = showsVerdict &&
+          canPlaceTool({ grid, finances: state.finances, buildingCoasterPath: state.buildingCoasterPath, buildingCoasterType: state.buildingCoasterType }, selectedTool, hoveredTile.x, hoveredTile.y);
+        
+        // Semi-transparent ghost of the building where it would land
+        if (canPlace && getSpriteInfo(selectedTool)) {
+          const ghostScreen = gridToScreen(hoveredTile.x, hoveredTile.y, 0, 0);
+          ghostScreen.screenY -= (grid[hoveredTile.y][hoveredTile.x].elevation ?? 0) * HEIGHT_UNIT;
+          const orientation = buildingSize.width === buildingSize.height ? state.placementOrientation ?? 0 : 0;
+          ctx.save();
+          ctx.globalAlpha = GHOST_ALPHA;
+          drawSprite(ctx, spriteSheets, selectedTool, ghostScreen.screenX, ghostScreen.screenY,
+            hoveredTile.x, hoveredTile.y, orientation, tick);
+          ctx.restore();
+        }
+        
         // Draw highlight for each tile in the building footprint
         for (let dx = 0; dx < buildingSize.width; dx++) {
           for (let dy = 0; dy < buildingSize.height; dy++) {
@@ -3049,7 +3727,13 @@ const tile = grid[y][x];
             const ty = hoveredTile.y + dy;
             if (tx >= 0 && tx < gridSize && ty >= 0 && ty < gridSize) {
               const { screenX, screenY } = gridToScreen(tx, ty, 0, 0);
-              drawHighlight(screenX, screenY);
+              if (!showsVerdict) {
+                drawHighlight(screenX, screenY);
+              } else if (canPlace) {
+                drawHighlight(screenX, screenY, colorPalette.placementValid.fill, colorPalette.placementValid.stroke);
+              } else {
+                drawHighlight(screenX, screenY, colorPalette.placementInvalid.fill, colorPalette.placementInvalid.stroke);
+              }
             }
           }
         }
@@ -3057,7 +3741,48 @@ const tile = grid[y][x];
     }
     
     ctx.restore();
-  }, [grid, gridSize, offset, zoom, canvasSize, tick, selectedTile, hoveredTile, selectedTool, spriteSheets, waterImage, state.guests, state.coasters, trackDragPreviewTiles, isTrackDragging, coasterInfoMap, incompleteTrackEnds]);
+  }, [grid, gridSize, tick, selectedTile, hoveredTile, selectedTool, spriteSheets, waterImage, state.guests, state.staff, state.litter, state.coasters, trackDragPreviewTiles, isTrackDragging, coasterInfoMap, incompleteTrackEnds, autoPathStart, autoPathPreview, linePreview, brokenQueueTiles, state.overlayMode, state.overlayPalette, debugGrid, spriteLayoutVersion, state.moneyPopups, state.finances, state.buildingCoasterPath, state.buildingCoasterType, state.placementOrientation]);
+  
+  // Main render loop
+  useEffect(() => {
+    const canvas = canvasRef.current;
+    if (!canvas) return;
+    renderScene(canvas, {
+      offset,
+      zoom,
+      width: canvasSize.width,
+      height: canvasSize.height,
+      dpr: window.devicePixelRatio || 1,
+      showCursor: true,
+    });
+  }, [renderScene, offset, zoom, canvasSize]);
+  
+  // Render the whole park (not just the viewport) into an offscreen canvas for sharing
+  useEffect(() => {
+    if (!exportRequested) return;
+    const bounds = getParkScreenBounds(gridSize);
+    const exportZoom = Math.min(
+      EXPORT_ZOOM,
+      MAX_EXPORT_SIZE / (bounds.right - bounds.left),
+      MAX_EXPORT_SIZE / (bounds.bottom - bounds.top)
+    );
+    const exportCanvas = document.createElement('canvas');
+    renderScene(exportCanvas, {
+      offset: { x: -bounds.left * exportZoom, y: -bounds.top * exportZoom },
+      zoom: exportZoom,
+      width: Math.ceil((bounds.right - bounds.left) * exportZoom),
+      height: Math.ceil((bounds.bottom - bounds.top) * exportZoom),
+      dpr: 1,
+      showCursor: false,
+    });
+    let dataUrl: string | null = null;
+    try {
+      dataUrl = exportCanvas.toDataURL('image/png');
+    } catch {
+      // Canvas too large for this browser
+    }
+    onExportComplete?.(dataUrl);
+  }, [exportRequested, gridSize, renderScene, onExportComplete]);
   
   // Lighting canvas sizing
   useEffect(() => {
@@ -3077,10 +3802,12 @@ const tile = grid[y][x];
     grid,
     gridSize,
     hour: state.hour,
+    minute: state.minute,
     offset,
     zoom,
     canvasWidth: canvasSize.width,
     canvasHeight: canvasSize.height,
+    weather: state.weather?.current,
   });
   
   // Cloud animation loop - runs independently for smooth cloud movement
@@ -3186,7 +3913,8 @@ const tile = grid[y][x];
       mouseX / zoom,
       mouseY / zoom,
       offset.x / zoom,
-      offset.y / zoom
+      offset.y / zoom,
+      grid
     );
     
     const isValidTile = gridX >= 0 && gridX < gridSize && gridY >= 0 && gridY < gridSize;
@@ -3198,6 +3926,28 @@ const tile = grid[y][x];
       return;
     }
     
+    // Auto-path: first click marks the start, second click lays the route
+    if (selectedTool === 'auto_path') {
+      if (!autoPathStart) {
+        setAutoPathStart({ x: gridX, y: gridY });
+      } else {
+        placePathRoute(autoPathStart, { x: gridX, y: gridY });
+        setAutoPathStart(null);
+      }
+      return;
+    }
+    
+    // Shift-click lays a straight line: first click marks the start, second commits it
+    if (e.shiftKey && isLineTool) {
+      if (!lineStart) {
+        setLineStart({ x: gridX, y: gridY });
+      } else {
+        placeLine(lineStart, { x: gridX, y: gridY });
+        setLineStart(null);
+      }
+      return;
+    }
+    
     // If it's a drag tool (track, scenery) and we're on a valid tile, start dragging
     if (isDragTool && isValidTile) {
       setIsTrackDragging(true);
@@ -3206,6 +3956,7 @@ const tile = grid[y][x];
       setTrackDragPreviewTiles([{ x: gridX, y: gridY }]);
       placedTrackTilesRef.current.clear();
       placedTrackTilesRef.current.add(`${gridX},${gridY}`);
+      lastStrokeTileRef.current = { x: gridX, y: gridY };
       // Place or bulldoze immediately on first click
       if (selectedTool === 'bulldoze') {
         bulldozeTile(gridX, gridY);
@@ -3213,13 +3964,18 @@ const tile = grid[y][x];
         placeAtTile(gridX, gridY);
       }
     } else if (selectedTool === 'select') {
-      // Select tool just selects, doesn't pan
-      setSelectedTile({ x: gridX, y: gridY });
+      // Clicking a guest inspects them; anywhere else selects the tile
+      const guestId = onGuestPick ? pickGuestAt((mouseX - offset.x) / zoom, (mouseY - offset.y) / zoom) : null;
+      if (guestId && onGuestPick) {
+        onGuestPick(guestId);
+      } else {
+        setSelectedTile({ x: gridX, y: gridY });
+      }
     } else {
       // Other tools (shops, decorations, etc.) - place on click
       placeAtTile(gridX, gridY);
     }
-  }, [offset, zoom, gridSize, isDragTool, selectedTool, placeAtTile, bulldozeTile, setSelectedTile]);
+  }, [offset, zoom, grid, gridSize, isDragTool, selectedTool, placeAtTile, bulldozeTile, setSelectedTile, autoPathStart, placePathRoute, isLineTool, lineStart, placeLine, onGuestPick, pickGuestAt]);
   
   const handleMouseMove = useCallback((e: React.MouseEvent) => {
     const rect = canvasRef.current?.getBoundingClientRect();
@@ -3233,7 +3989,8 @@ const tile = grid[y][x];
       mouseX / zoom,
       mouseY / zoom,
       offset.x / zoom,
-      offset.y / zoom
+      offset.y / zoom,
+      grid
     );
     
     // Update hovered tile
@@ -3243,7 +4000,26 @@ const tile = grid[y][x];
       setHoveredTile(null);
     }
     
-    if (isTrackDragging && trackDragStartTile) {
+    if (isTrackDragging && FREEHAND_DRAG_TOOLS.includes(selectedTool)) {
+      // Freehand painting - fill in every tile between the last painted one and the cursor
+      const lastTile = lastStrokeTileRef.current;
+      const onGrid = gridX >= 0 && gridX < gridSize && gridY >= 0 && gridY < gridSize;
+      if (lastTile && onGrid && (lastTile.x !== gridX || lastTile.y !== gridY)) {
+        const newTiles: { x: number; y: number }[] = [];
+        for (const tile of getStrokeTiles(lastTile, { x: gridX, y: gridY })) {
+          const key = `${tile.x},${tile.y}`;
+          if (!placedTrackTilesRef.current.has(key)) {
+            placedTrackTilesRef.current.add(key);
+            placeAtTile(tile.x, tile.y);
+            newTiles.push(tile);
+          }
+        }
+        lastStrokeTileRef.current = { x: gridX, y: gridY };
+        if (newTiles.length > 0) {
+          setTrackDragPreviewTiles(prev => [...prev, ...newTiles]);
+        }
+      }
+    } else if (isTrackDragging && trackDragStartTile) {
       // Track dragging mode
       const dx = Math.abs(gridX - trackDragStartTile.x);
       const dy = Math.abs(gridY - trackDragStartTile.y);
@@ -3278,7 +4054,7 @@ const tile = grid[y][x];
         y: e.clientY - dragStart.y,
       });
     }
-  }, [isDragging, isTrackDragging, dragStart, offset, zoom, gridSize, trackDragStartTile, trackDragDirection, calculateLineTiles, placeAtTile, bulldozeTile, selectedTool]);
+  }, [isDragging, isTrackDragging, dragStart, offset, zoom, grid, gridSize, trackDragStartTile, trackDragDirection, calculateLineTiles, placeAtTile, bulldozeTile, selectedTool]);
   
   const handleMouseUp = useCallback(() => {
     if (isTrackDragging) {
@@ -3288,6 +4064,7 @@ const tile = grid[y][x];
       setTrackDragDirection(null);
       setTrackDragPreviewTiles([]);
       placedTrackTilesRef.current.clear();
+      lastStrokeTileRef.current = null;
       return;
     }
     
@@ -3304,17 +4081,9 @@ const tile = grid[y][x];
     const mouseX = e.clientX - rect.left;
     const mouseY = e.clientY - rect.top;
     
-    const zoomFactor = e.deltaY > 0 ? 0.9 : 1.1;
-    const newZoom = Math.max(ZOOM_MIN, Math.min(ZOOM_MAX, zoom * zoomFactor));
-    
     // Zoom toward mouse position
-    const zoomRatio = newZoom / zoom;
-    setOffset({
-      x: mouseX - (mouseX - offset.x) * zoomRatio,
-      y: mouseY - (mouseY - offset.y) * zoomRatio,
-    });
-    setZoom(newZoom);
-  }, [zoom, offset]);
+    zoomBy(e.deltaY > 0 ? 0.9 : 1.1, mouseX, mouseY);
+  }, [zoomBy]);
 
   const getTouchDistance = useCallback((touch1: React.Touch, touch2: React.Touch) => {
     const dx = touch1.clientX - touch2.clientX;
@@ -3404,12 +4173,20 @@ const tile = grid[y][x];
               touchX,
               touchY,
               offset.x / zoom,
-              offset.y / zoom
+              offset.y / zoom,
+              grid
             );
 
             if (gridX >= 0 && gridX < gridSize && gridY >= 0 && gridY < gridSize) {
               if (selectedTool === 'select') {
                 setSelectedTile({ x: gridX, y: gridY });
+              } else if (selectedTool === 'auto_path') {
+                if (!autoPathStart) {
+                  setAutoPathStart({ x: gridX, y: gridY });
+                } else {
+                  placePathRoute(autoPathStart, { x: gridX, y: gridY });
+                  setAutoPathStart(null);
+                }
               } else if (selectedTool === 'bulldoze') {
                 bulldozeTile(gridX, gridY);
               } else {
@@ -3433,7 +4210,7 @@ const tile = grid[y][x];
       initialPinchDistanceRef.current = null;
       lastTouchCenterRef.current = null;
     }
-  }, [zoom, offset, gridSize, selectedTool, setSelectedTile, placeAtTile, bulldozeTile]);
+  }, [zoom, offset, grid, gridSize, selectedTool, setSelectedTile, placeAtTile, bulldozeTile, autoPathStart, placePathRoute]);
   
   return (
     <div
@@ -3457,6 +4234,7 @@ const tile = grid[y][x];
           setTrackDragDirection(null);
           setTrackDragPreviewTiles([]);
           placedTrackTilesRef.current.clear();
+          lastStrokeTileRef.current = null;
           setHoveredTile(null);
         }}
         onWheel={handleWheel}
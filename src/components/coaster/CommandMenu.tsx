@@ -2,7 +2,7 @@
 
 import React, { useState, useEffect, useCallback, useRef, useMemo } from 'react';
 import { useCoaster } from '@/context/CoasterContext';
-import { Tool, TOOL_INFO, ToolInfo } from '@/games/coaster/types';
+import { Tool, TOOL_INFO, ToolInfo, OverlayMode } from '@/games/coaster/types';
 import { useMobile } from '@/hooks/useMobile';
 import { Dialog, DialogContent, DialogTitle } from '@/components/ui/dialog';
 import { Input } from '@/components/ui/input';
@@ -19,9 +19,10 @@ export function openCoasterCommandMenu() {
 
 interface MenuItem {
   id: string;
-  type: 'tool' | 'panel';
+  type: 'tool' | 'panel' | 'overlay';
   tool?: Tool;
-  panel?: 'finances' | 'settings';
+  panel?: 'finances' | 'rides' | 'settings';
+  overlay?: OverlayMode;
   name: string;
   description: string;
   cost?: number;
@@ -44,6 +45,7 @@ const MENU_CATEGORIES = [
   { key: 'rides_large', label: 'Large Rides' },
   { key: 'infrastructure', label: 'Infrastructure' },
   { key: 'panels', label: 'Panels' },
+  { key: 'overlays', label: 'Overlays' },
 ] as const;
 
 const CATEGORY_LABELS = MENU_CATEGORIES.reduce<Record<string, string>>((acc, category) => {
@@ -108,6 +110,12 @@ function buildMenuItems(): MenuItem[] {
       description: 'Review park finances and income',
       keywords: ['finances', 'money', 'profit', 'budget', 'cash'],
     },
+    {
+      panel: 'rides' as const,
+      name: 'Rides',
+      description: 'Check ride queues and riders served',
+      keywords: ['rides', 'queue', 'line', 'riders', 'throughput'],
+    },
     {
       panel: 'settings' as const,
       name: 'Settings',
@@ -128,6 +136,16 @@ function buildMenuItems(): MenuItem[] {
     });
   });
 
+  items.push({
+    id: 'overlay-guest_density',
+    type: 'overlay',
+    overlay: 'guest_density',
+    name: 'Guest Heatmap',
+    description: 'Show where crowds gather',
+    category: 'overlays',
+    keywords: ['heatmap', 'crowd', 'density', 'guests', 'overlay'],
+  });
+
   return items;
 }
 
@@ -135,7 +153,7 @@ const ALL_MENU_ITEMS = buildMenuItems();
 
 export function CoasterCommandMenu() {
   const { isMobileDevice } = useMobile();
-  const { state, setTool, setActivePanel, startCoasterBuild } = useCoaster();
+  const { state, setTool, setActivePanel, setOverlayMode, startCoasterBuild } = useCoaster();
   const { finances } = state;
 
   const [open, setOpen] = useState(false);
@@ -224,9 +242,11 @@ export function CoasterCommandMenu() {
       }
     } else if (item.type === 'panel' && item.panel) {
       setActivePanel(state.activePanel === item.panel ? 'none' : item.panel);
+    } else if (item.type === 'overlay' && item.overlay) {
+      setOverlayMode(state.overlayMode === item.overlay ? 'none' : item.overlay);
     }
     setOpen(false);
-  }, [setTool, setActivePanel, startCoasterBuild, state.activePanel]);
+  }, [setTool, setActivePanel, setOverlayMode, startCoasterBuild, state.activePanel, state.overlayMode]);
 
   useEffect(() => {
     if (!listRef.current || flatItems.length === 0) return;
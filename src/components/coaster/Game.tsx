@@ -1,6 +1,6 @@
 'use client';
 
-import React, { useState, useEffect, useRef } from 'react';
+import React, { useState, useEffect, useRef, useCallback } from 'react';
 import { useCoaster } from '@/context/CoasterContext';
 import { useMultiplayerOptional } from '@/context/MultiplayerContext';
 import { useMobile } from '@/hooks/useMobile';
@@ -22,16 +22,28 @@ interface GameProps {
 }
 
 export default function CoasterGame({ onExit }: GameProps) {
-  const { state, isStateReady, setTool, setSpeed, setActivePanel } = useCoaster();
+  const { state, isStateReady, setTool, setSpeed, setActivePanel, saveViewpoint, getViewpoint, rotatePlacement, getGuestInfo } = useCoaster();
   const { isMultiplayer, roomCode, players } = useCoasterMultiplayerSync();
   const multiplayer = useMultiplayerOptional();
   const [selectedTile, setSelectedTile] = useState<{ x: number; y: number } | null>(null);
+  const [selectedGuestId, setSelectedGuestId] = useState<string | null>(null);
   const [viewport, setViewport] = useState<{
     offset: { x: number; y: number };
     zoom: number;
     canvasSize: { width: number; height: number };
   } | null>(null);
   const [navigationTarget, setNavigationTarget] = useState<{ x: number; y: number } | null>(null);
+  const [cameraTarget, setCameraTarget] = useState<{ offset: { x: number; y: number }; zoom: number; smooth?: boolean } | null>(null);
+  const clearCameraTarget = useCallback(() => setCameraTarget(null), []);
+  const [exportRequested, setExportRequested] = useState(false);
+  const handleExportComplete = useCallback((dataUrl: string | null) => {
+    setExportRequested(false);
+    if (!dataUrl) return;
+    const link = document.createElement('a');
+    link.href = dataUrl;
+    link.download = `${state.settings.name || 'park'}.png`;
+    link.click();
+  }, [state.settings.name]);
   const [showShareModal, setShowShareModal] = useState(false);
   const { copied: copiedRoomLink, handleCopyRoomLink } = useCopyRoomLink(roomCode, 'coaster/coop');
   const { isMobileDevice, isSmallScreen } = useMobile();
@@ -63,17 +75,62 @@ export default function CoasterGame({ onExit }: GameProps) {
         e.preventDefault();
         setTool('select');
         setSelectedTile(null);
+        setSelectedGuestId(null);
       } else if (e.key === 'p' || e.key === 'P') {
         e.preventDefault();
         // Toggle pause/unpause: if paused (speed 0), resume to normal (speed 1)
         // If running, pause (speed 0)
         setSpeed(state.speed === 0 ? 1 : 0);
+      } else if ((e.key === 'r' || e.key === 'R') && !e.ctrlKey && !e.metaKey) {
+        e.preventDefault();
+        rotatePlacement();
+      } else if (e.key >= '1' && e.key <= '9') {
+        // Ctrl/Cmd+digit saves a camera bookmark, digit alone glides back to it
+        const slot = Number(e.key);
+        if (e.ctrlKey || e.metaKey) {
+          e.preventDefault();
+          if (viewport) saveViewpoint(slot, viewport);
+        } else {
+          const viewpoint = getViewpoint(slot);
+          if (viewpoint) {
+            e.preventDefault();
+            setCameraTarget({ offset: { x: viewpoint.offsetX, y: viewpoint.offsetY }, zoom: viewpoint.zoom, smooth: true });
+          }
+        }
       }
     };
     
     window.addEventListener('keydown', handleKeyDown);
     return () => window.removeEventListener('keydown', handleKeyDown);
-  }, [setTool, setSpeed, state.speed]);
+  }, [setTool, setSpeed, state.speed, viewport, saveViewpoint, getViewpoint, rotatePlacement]);
+  
+  // Re-read every render so the card follows the guest; null once they've left
+  const selectedGuest = selectedGuestId ? getGuestInfo(selectedGuestId) : null;
+  const guestCard = selectedGuest && (
+    <div className="absolute bottom-4 left-4 z-20">
+      <div className="bg-slate-900/90 border border-slate-700 rounded-lg px-3 py-2 shadow-lg min-w-[180px] text-xs text-slate-300">
+        <div className="flex items-center justify-between gap-2 text-sm font-medium text-white">
+          {selectedGuest.name}
+          <button
+            onClick={() => setSelectedGuestId(null)}
+            className="px-1 hover:bg-white/10 rounded transition-colors text-slate-400"
+            title="Close"
+          >
+            ×
+          </button>
+        </div>
+        <div className="mt-1 capitalize">{selectedGuest.state.replace(/_/g, ' ')}</div>
+        <div className="mt-1 grid grid-cols-2 gap-x-3">
+          <span>Happiness {Math.round(selectedGuest.happiness)}</span>
+          <span>Energy {Math.round(selectedGuest.energy)}</span>
+          <span>Hunger {Math.round(selectedGuest.hunger)}</span>
+          <span>Thirst {Math.round(selectedGuest.thirst)}</span>
+          <span>Nausea {Math.round(selectedGuest.nausea)}</span>
+          <span>Cash ${Math.round(selectedGuest.cash)}</span>
+        </div>
+      </div>
+    </div>
+  );
   
   if (!isStateReady) {
     return (
@@ -109,7 +166,9 @@ export default function CoasterGame({ onExit }: GameProps) {
               selectedTile={selectedTile}
               setSelectedTile={setSelectedTile}
               isMobile={true}
+              onGuestPick={setSelectedGuestId}
             />
+            {guestCard}
 
             {isMultiplayer && (
               <div className="absolute top-2 right-2 z-20">
@@ -169,7 +228,7 @@ export default function CoasterGame({ onExit }: GameProps) {
         {/* Main content */}
         <div className="flex-1 flex flex-col ml-56">
           {/* Top bar */}
-          <TopBar />
+          <TopBar onExportImage={() => setExportRequested(true)} />
           
           {/* Canvas area */}
           <div className="flex-1 relative overflow-visible">
@@ -178,8 +237,14 @@ export default function CoasterGame({ onExit }: GameProps) {
               setSelectedTile={setSelectedTile}
               navigationTarget={navigationTarget}
               onNavigationComplete={() => setNavigationTarget(null)}
+              cameraTarget={cameraTarget}
+              onCameraTargetReached={clearCameraTarget}
               onViewportChange={setViewport}
+              exportRequested={exportRequested}
+              onExportComplete={handleExportComplete}
+              onGuestPick={setSelectedGuestId}
             />
+            {guestCard}
             
             {/* Minimap */}
             <MiniMap
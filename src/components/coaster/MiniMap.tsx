@@ -3,6 +3,7 @@
 import React, { useRef, useState, useCallback, useEffect } from 'react';
 import { useCoaster } from '@/context/CoasterContext';
 import { Card } from '@/components/ui/card';
+import { Tile, isFoodBuilding, isRideBuilding } from '@/games/coaster/types';
 
 // =============================================================================
 // CONSTANTS
@@ -10,6 +11,48 @@ import { Card } from '@/components/ui/card';
 
 const TILE_WIDTH = 64;
 const TILE_HEIGHT = TILE_WIDTH * 0.6;
+const MINIMAP_SIZE = 140;
+
+type Rgb = [number, number, number];
+
+const MINIMAP_COLORS: Record<string, Rgb> = {
+  grass: [45, 90, 61],
+  water: [14, 165, 233],
+  sand: [230, 200, 140],
+  rock: [120, 113, 108],
+  path: [156, 163, 175],
+  queue: [107, 114, 128],
+  track: [245, 158, 11],
+  ride: [236, 72, 153],
+  food: [249, 115, 22],
+  shop: [139, 92, 246],
+  vegetation: [34, 197, 94],
+  station: [239, 68, 68],
+  other: [100, 116, 139],
+};
+
+// =============================================================================
+// TILE COLORS
+// =============================================================================
+
+function getBuildingColor(type: string): Rgb {
+  if (type.startsWith('station_')) return MINIMAP_COLORS.station;
+  if (isRideBuilding(type)) return MINIMAP_COLORS.ride;
+  if (isFoodBuilding(type)) return MINIMAP_COLORS.food;
+  if (type.startsWith('shop_') || type === 'restroom') return MINIMAP_COLORS.shop;
+  if (type.startsWith('tree_') || type.startsWith('bush_') || type.startsWith('flowers_')) return MINIMAP_COLORS.vegetation;
+  return MINIMAP_COLORS.other;
+}
+
+function getTileColor(tile: Tile): Rgb {
+  if (tile.terrain === 'water') return MINIMAP_COLORS.water;
+  if (tile.path) return MINIMAP_COLORS.path;
+  if (tile.queue) return MINIMAP_COLORS.queue;
+  if (tile.hasCoasterTrack) return MINIMAP_COLORS.track;
+  const type = tile.building.type;
+  if (type !== 'empty' && type !== 'grass') return getBuildingColor(type);
+  return MINIMAP_COLORS[tile.terrain] ?? MINIMAP_COLORS.grass;
+}
 
 // =============================================================================
 // MINIMAP COMPONENT
@@ -28,6 +71,7 @@ export function MiniMap({ onNavigate, viewport }: MiniMapProps) {
   const { state } = useCoaster();
   const { grid, gridSize, tick } = state;
   const canvasRef = useRef<HTMLCanvasElement>(null);
+  const pixelCanvasRef = useRef<HTMLCanvasElement | null>(null);
   const [isDragging, setIsDragging] = useState(false);
   
   // Render minimap
@@ -38,48 +82,38 @@ export function MiniMap({ onNavigate, viewport }: MiniMapProps) {
     const ctx = canvas.getContext('2d');
     if (!ctx) return;
     
-    const size = 140;
+    const size = MINIMAP_SIZE;
     const scale = size / gridSize;
     
-    // Clear
-    ctx.fillStyle = '#0b1723';
-    ctx.fillRect(0, 0, size, size);
+    // One pixel per tile, written in a single pass and scaled up in one draw
+    let pixelCanvas = pixelCanvasRef.current;
+    if (!pixelCanvas) {
+      pixelCanvas = document.createElement('canvas');
+      pixelCanvasRef.current = pixelCanvas;
+    }
+    if (pixelCanvas.width !== gridSize || pixelCanvas.height !== gridSize) {
+      pixelCanvas.width = gridSize;
+      pixelCanvas.height = gridSize;
+    }
+    const pixelCtx = pixelCanvas.getContext('2d');
+    if (!pixelCtx) return;
     
-    // Draw tiles
+    const image = pixelCtx.createImageData(gridSize, gridSize);
+    const data = image.data;
     for (let y = 0; y < gridSize; y++) {
       for (let x = 0; x < gridSize; x++) {
-        const tile = grid[y][x];
-        let color = '#2d5a3d'; // Default grass
-        
-        if (tile.terrain === 'water') {
-          color = '#0ea5e9';
-        } else if (tile.path) {
-          color = '#9ca3af';
-        } else if (tile.queue) {
-          color = '#6b7280';
-        } else if (tile.hasCoasterTrack) {
-          color = '#f59e0b';
-        } else if (tile.building.type !== 'empty' && tile.building.type !== 'grass') {
-          // Different colors for building types
-          if (tile.building.type.startsWith('ride_')) {
-            color = '#ec4899'; // Pink for rides
-          } else if (tile.building.type.startsWith('food_') || tile.building.type.startsWith('drink_') || tile.building.type.startsWith('snack_')) {
-            color = '#f97316'; // Orange for food
-          } else if (tile.building.type.startsWith('shop_') || tile.building.type === 'restroom') {
-            color = '#8b5cf6'; // Purple for shops
-          } else if (tile.building.type.startsWith('tree_') || tile.building.type.startsWith('bush_') || tile.building.type.startsWith('flowers_')) {
-            color = '#22c55e'; // Green for vegetation
-          } else if (tile.building.type.startsWith('station_')) {
-            color = '#ef4444'; // Red for coaster stations
-          } else {
-            color = '#64748b'; // Gray for other buildings
-          }
-        }
-        
-        ctx.fillStyle = color;
-        ctx.fillRect(x * scale, y * scale, Math.ceil(scale), Math.ceil(scale));
+        const [r, g, b] = getTileColor(grid[y][x]);
+        const i = (y * gridSize + x) * 4;
+        data[i] = r;
+        data[i + 1] = g;
+        data[i + 2] = b;
+        data[i + 3] = 255;
       }
     }
+    pixelCtx.putImageData(image, 0, 0);
+    
+    ctx.imageSmoothingEnabled = false;
+    ctx.drawImage(pixelCanvas, 0, 0, size, size);
     
     // Draw viewport rectangle
     if (viewport) {
@@ -121,8 +155,7 @@ export function MiniMap({ onNavigate, viewport }: MiniMapProps) {
     const clickX = e.clientX - rect.left;
     const clickY = e.clientY - rect.top;
     
-    const size = 140;
-    const scale = size / gridSize;
+    const scale = MINIMAP_SIZE / gridSize;
     
     const gridX = Math.floor(clickX / scale);
     const gridY = Math.floor(clickY / scale);
@@ -155,8 +188,8 @@ export function MiniMap({ onNavigate, viewport }: MiniMapProps) {
       </div>
       <canvas
         ref={canvasRef}
-        width={140}
-        height={140}
+        width={MINIMAP_SIZE}
+        height={MINIMAP_SIZE}
         className="block rounded-md border border-border/60 cursor-pointer select-none"
         onMouseDown={handleMouseDown}
         onMouseMove={handleMouseMove}
@@ -46,7 +46,11 @@ function SuperFastIcon() {
 // TOPBAR COMPONENT
 // =============================================================================
 
-export function TopBar() {
+interface TopBarProps {
+  onExportImage?: () => void;
+}
+
+export function TopBar({ onExportImage }: TopBarProps = {}) {
   const { state, setSpeed, setActivePanel, setParkSettings } = useCoaster();
   const { settings, stats, finances, year, month, day, hour, minute, speed } = state;
   
@@ -131,8 +135,13 @@ export function TopBar() {
           <span className="text-white/40 text-xs">Guests</span>
         </div>
         
-        {/* Park Rating */}
-        <div className="flex flex-col items-center">
+        {/* Park Rating - hover for what it's made of */}
+        <div
+          className="flex flex-col items-center"
+          title={stats.ratingBreakdown
+            ? `Happiness ${stats.ratingBreakdown.happiness}/450\nGuests ${stats.ratingBreakdown.guests}/200\nVariety ${stats.ratingBreakdown.variety}/150\nPath access ${stats.ratingBreakdown.connectivity}/150\nScenery ${stats.ratingBreakdown.scenery ?? 0}/50\nLitter ${stats.ratingBreakdown.cleanliness}`
+            : undefined}
+        >
           <span className="text-yellow-400 font-medium">{stats.parkRating}</span>
           <span className="text-white/40 text-xs">Rating</span>
         </div>
@@ -167,6 +176,13 @@ export function TopBar() {
         >
           Finances
         </Button>
+        <Button
+          variant={state.activePanel === 'rides' ? 'default' : 'ghost'}
+          size="sm"
+          onClick={() => setActivePanel(state.activePanel === 'rides' ? 'none' : 'rides')}
+        >
+          Rides
+        </Button>
         <Button
           variant={state.activePanel === 'settings' ? 'default' : 'ghost'}
           size="sm"
@@ -174,6 +190,11 @@ export function TopBar() {
         >
           Settings
         </Button>
+        {onExportImage && (
+          <Button variant="ghost" size="sm" onClick={onExportImage} title="Save an image of the whole park">
+            Snapshot
+          </Button>
+        )}
       </div>
     </div>
   );
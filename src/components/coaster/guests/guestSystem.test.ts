@@ -0,0 +1,94 @@
+import { describe, it } from 'node:test';
+import assert from 'node:assert/strict';
+import { Tile, createEmptyTile } from '@/games/coaster/types';
+import { BuildingType } from '@/games/coaster/types/buildings';
+import { Guest } from '@/games/coaster/types/economy';
+import { Coaster } from '@/games/coaster/types/tracks';
+import { seedSimulationRandom } from '@/games/coaster/lib/random';
+import { createDefaultCoaster } from '@/games/coaster/lib/trackFragments';
+import { createGuest, updateGuest } from './guestSystem';
+
+const GRID_SIZE = 5;
+
+function buildGrid(): Tile[][] {
+  return Array.from({ length: GRID_SIZE }, (_, y) => Array.from({ length: GRID_SIZE }, (_, x) => createEmptyTile(x, y)));
+}
+
+/**
+ * A guest standing on (2, 2) with calm, predictable needs
+ */
+function buildGuest(overrides: Partial<Guest>): Guest {
+  seedSimulationRandom(1);
+  return {
+    ...createGuest(0, 2, GRID_SIZE),
+    tileX: 2,
+    tileY: 2,
+    targetTileX: 2,
+    targetTileY: 2,
+    hunger: 0,
+    thirst: 0,
+    bathroom: 0,
+    energy: 100,
+    nausea: 0,
+    happiness: 80,
+    ...overrides,
+  };
+}
+
+/**
+ * Nausea a middle-of-the-road guest picks up from one ride on the building at (1, 1)
+ */
+function nauseaFromRide(rideType: BuildingType, coasters: Coaster[] = []): number {
+  const grid = buildGrid();
+  grid[1][1].building = { ...grid[1][1].building, type: rideType };
+  // A station belongs to the coaster whose track runs beside it
+  if (coasters.length > 0) {
+    grid[1][2] = { ...grid[1][2], hasCoasterTrack: true, coasterTrackId: coasters[0].id };
+  }
+  const guest = buildGuest({
+    state: 'riding',
+    queueRideId: '1,1',
+    queueTimer: 0.5,
+    thrillPreference: 0.5,
+    nauseaTolerance: 5,
+  });
+
+  seedSimulationRandom(7);
+  return updateGuest(guest, grid, 1, coasters).nausea;
+}
+
+describe('updateGuest', () => {
+  it('barely upsets a guest on a carousel but shakes them up on a big coaster', () => {
+    const giga = createDefaultCoaster('giga', { x: 2, y: 1 }, 1, 'giga_coaster');
+    const carouselNausea = nauseaFromRide('ride_carousel');
+    const coasterNausea = nauseaFromRide('station_steel_1', [giga]);
+
+    assert.ok(carouselNausea < 2, `carousel added ${carouselNausea} nausea`);
+    assert.ok(coasterNausea > carouselNausea * 5, `coaster added ${coasterNausea} nausea vs ${carouselNausea}`);
+  });
+
+  it('walks a guest back out of a stall within a fixed number of ticks', () => {
+    let guest = buildGuest({
+      state: 'eating',
+      targetBuildingId: '3,2',
+      targetBuildingKind: 'food',
+      queueTimer: 0.5,
+      initialActivityTime: 20,
+    });
+    const grid = buildGrid();
+
+    guest = updateGuest(guest, grid, 1);
+    assert.equal(guest.state, 'exiting_building');
+    assert.equal(guest.approachProgress, 1);
+
+    // Headless runs tick far faster than real time, so the walk-out must be counted in ticks
+    let exitTicks = 0;
+    while (guest.state === 'exiting_building' && exitTicks < 100) {
+      guest = updateGuest(guest, grid, 1);
+      exitTicks++;
+    }
+    assert.equal(guest.state, 'walking');
+    assert.equal(guest.approachProgress, 0);
+    assert.ok(exitTicks > 1 && exitTicks <= 16, `walk-out took ${exitTicks} ticks`);
+  });
+});
@@ -3,8 +3,14 @@
  * Handles guest spawning, AI, pathfinding, and rendering
  */
 
-import { Guest, GuestState, generateGuestName } from '@/games/coaster/types/economy';
-import { Tile } from '@/games/coaster/types/game';
+import { Guest, GuestBudgetTier, GuestPreferenceStats, GuestState, GuestSurvey, GuestThought, MAX_GUEST_THOUGHTS, BUDGET_TIER_CASH, BUDGET_TIER_PRICE_TOLERANCE, DEFAULT_PRICES, DEFAULT_MAX_GUESTS, DEFAULT_SPAWN_RATE_MULTIPLIER, DEFAULT_OPEN_HOUR, DEFAULT_CLOSE_HOUR, isParkOpenAt, generateGuestName, getGuestThrillLevel } from '@/games/coaster/types/economy';
+import { Tile, RideStats } from '@/games/coaster/types/game';
+import { Building, BuildingType, RideProfile, getRideProfile, getMaxQueueLength, isRideBuilding, isFoodBuilding, isBenchBuilding, getFoodProfile } from '@/games/coaster/types/buildings';
+import { Coaster, COASTER_TYPE_STATS } from '@/games/coaster/types/tracks';
+import { getCachedPath, storeCachedPath } from './pathCache';
+import { getBuildingAccessTile } from '@/games/coaster/lib/buildingAccess';
+import { simulationRandom } from '@/games/coaster/lib/random';
+import { GUESTS_PER_QUEUE_TILE, getNextQueueTile, getQueueDistances, getQueueSlotDistance } from '@/games/coaster/lib/queueLines';
 
 // =============================================================================
 // CONSTANTS
@@ -13,6 +19,22 @@ import { Tile } from '@/games/coaster/types/game';
 const TILE_WIDTH = 64;
 const HEIGHT_RATIO = 0.60;
 const TILE_HEIGHT = TILE_WIDTH * HEIGHT_RATIO;
+const HEIGHT_UNIT = 20; // Screen pixels per elevation step (matches CoasterGrid)
+const GUEST_PICK_RADIUS = 6; // Screen pixels (at zoom 1) around a guest's body that count as a hit
+const GUEST_WALK_SPEED = 0.02; // Progress toward the next tile per tick
+const GUEST_JITTER_X = TILE_WIDTH * 0.12; // Furthest a guest stands from the path's center line
+const GUEST_JITTER_Y = TILE_HEIGHT * 0.12;
+const CROWDED_TILE_GUESTS = 6; // Wandering guests avoid tiles holding this many others
+const BENCH_SEAT_OFFSET = 0.5; // How far from the path center toward the bench a resting guest sits
+const STALL_WALK_TIME = 16; // Game minutes a guest takes to step into or out of a stall (under a second at 1x)
+const FOOD_STALL_SEAT_OFFSET = 0.4; // How far from the path center toward a food stall eating guests sit
+const QUEUE_SLOT_SPACING = 0.35; // Tiles between neighboring guests in line on one queue tile
+const GROUP_SPAWN_CHANCE = 0.3; // Share of arrivals that are a family or group rather than one guest
+const GROUP_MIN_SIZE = 2;
+const GROUP_MAX_SIZE = 5;
+// Average guests per arrival, so parties don't raise the overall spawn rate
+const EXPECTED_PARTY_SIZE = 1 + GROUP_SPAWN_CHANCE * ((GROUP_MIN_SIZE + GROUP_MAX_SIZE) / 2 - 1);
+const GROUP_FOLLOW_CHANCE = 0.8; // How often a group member goes where the leader is headed
 
 const GUEST_COLORS = {
   skin: ['#ffd5b4', '#f5c9a6', '#e5b898', '#d4a574', '#c49462', '#a67b5b', '#8b6b4a'],
@@ -21,20 +43,41 @@ const GUEST_COLORS = {
   hat: ['#ef4444', '#f97316', '#eab308', '#22c55e', '#3b82f6', '#8b5cf6', '#ffffff'],
 };
 
+// Need levels above which guests start losing happiness (and say so)
+const HUNGRY_NEED = 70;
+const THIRSTY_NEED = 70;
+const BATHROOM_NEED = 80;
+const QUEASY_NAUSEA = 50;
+export const NAUSEOUS_NAUSEA = 75; // Guests this sick won't get on another ride until it passes
+const RIDE_INTENSITY_NAUSEA = 0.08; // Extra nausea per point of ride intensity, as a share of the base gain
+const FOOD_NAUSEA_RELIEF = 25; // Nausea a snack or drink settles
+const TIRED_ENERGY = 20; // Guests below this look for a bench
+const GREAT_RIDE_HAPPINESS = 8; // Happiness gain from a ride that's worth talking about
+
 // =============================================================================
 // GUEST CREATION
 // =============================================================================
 
 function generateUUID(): string {
   return 'xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx'.replace(/[xy]/g, function(c) {
-    const r = Math.random() * 16 | 0;
+    const r = simulationRandom() * 16 | 0;
     const v = c === 'x' ? r : (r & 0x3 | 0x8);
     return v.toString(16);
   });
 }
 
 function randomFromArray<T>(arr: T[]): T {
-  return arr[Math.floor(Math.random() * arr.length)];
+  return arr[Math.floor(simulationRandom() * arr.length)];
+}
+
+/**
+ * Roll a budget tier for a new guest (35% low, 45% medium, 20% high)
+ */
+function rollBudgetTier(): GuestBudgetTier {
+  const roll = simulationRandom();
+  if (roll < 0.35) return 'low';
+  if (roll < 0.8) return 'medium';
+  return 'high';
 }
 
 export function createGuest(entranceX: number, entranceY: number, gridSize: number = 64): Guest {
@@ -66,6 +109,11 @@ export function createGuest(entranceX: number, entranceY: number, gridSize: numb
     direction = 'south';
   }
   
+  // Preferences rolled at spawn
+  const thrillPreference = simulationRandom();
+  const budgetTier = rollBudgetTier();
+  const budgetCash = BUDGET_TIER_CASH[budgetTier];
+  
   return {
     id: generateUUID(),
     name: generateGuestName(),
@@ -90,28 +138,29 @@ export function createGuest(entranceX: number, entranceY: number, gridSize: numb
     queueRideId: null,
     queuePosition: 0,
     queueTimer: 0,
-    decisionCooldown: 20 + Math.random() * 40,
+    decisionCooldown: 20 + simulationRandom() * 40,
     
     // Approach state
     approachProgress: 0,
     initialActivityTime: 0,
-    activityStartTime: 0,
     
     // Needs (0-100)
-    hunger: 20 + Math.random() * 30,
-    thirst: 20 + Math.random() * 30,
-    bathroom: 10 + Math.random() * 20,
-    energy: 80 + Math.random() * 20,
-    happiness: 70 + Math.random() * 30,
+    hunger: 20 + simulationRandom() * 30,
+    thirst: 20 + simulationRandom() * 30,
+    bathroom: 10 + simulationRandom() * 20,
+    energy: 80 + simulationRandom() * 20,
+    happiness: 70 + simulationRandom() * 30,
     nausea: 0,
     
-    // Preferences (0-10)
-    preferExcitement: 3 + Math.random() * 7,
-    preferIntensity: 2 + Math.random() * 6,
-    nauseaTolerance: 3 + Math.random() * 7,
+    // Preferences (0-10), intensity and nausea tolerance follow thrill preference
+    preferExcitement: 3 + simulationRandom() * 7,
+    preferIntensity: 2 + thrillPreference * 6,
+    nauseaTolerance: Math.min(10, 3 + thrillPreference * 5 + simulationRandom() * 2),
+    thrillPreference,
+    budgetTier,
     
     // Money
-    cash: 30 + Math.floor(Math.random() * 70),
+    cash: budgetCash.min + Math.floor(simulationRandom() * (budgetCash.max - budgetCash.min)),
     totalSpent: 0,
     
     // Tracking
@@ -123,9 +172,9 @@ export function createGuest(entranceX: number, entranceY: number, gridSize: numb
     skinColor: randomFromArray(GUEST_COLORS.skin),
     shirtColor: randomFromArray(GUEST_COLORS.shirt),
     pantsColor: randomFromArray(GUEST_COLORS.pants),
-    hasHat: Math.random() > 0.7,
+    hasHat: simulationRandom() > 0.7,
     hatColor: randomFromArray(GUEST_COLORS.hat),
-    walkOffset: Math.random() * Math.PI * 2,
+    walkOffset: simulationRandom() * Math.PI * 2,
   };
 }
 
@@ -139,11 +188,57 @@ function gridToScreen(gridX: number, gridY: number): { x: number; y: number } {
   return { x, y };
 }
 
-export function drawGuest(
-  ctx: CanvasRenderingContext2D,
-  guest: Guest,
-  tick: number
-) {
+/** Screen-space facing (8 directions, screen up = 'n') */
+export type GuestFacing = 'n' | 'ne' | 'e' | 'se' | 's' | 'sw' | 'w' | 'nw';
+
+// Facings ordered clockwise from screen +x (canvas y points down)
+const FACINGS_BY_ANGLE: GuestFacing[] = ['e', 'se', 's', 'sw', 'w', 'nw', 'n', 'ne'];
+
+const FACING_VECTORS: Record<GuestFacing, { x: number; y: number }> = {
+  n: { x: 0, y: -1 },
+  ne: { x: Math.SQRT1_2, y: -Math.SQRT1_2 },
+  e: { x: 1, y: 0 },
+  se: { x: Math.SQRT1_2, y: Math.SQRT1_2 },
+  s: { x: 0, y: 1 },
+  sw: { x: -Math.SQRT1_2, y: Math.SQRT1_2 },
+  w: { x: -1, y: 0 },
+  nw: { x: -Math.SQRT1_2, y: -Math.SQRT1_2 },
+};
+
+// One grid step in each direction
+const DIRECTION_DELTAS: Record<Guest['direction'], { dx: number; dy: number }> = {
+  south: { dx: 1, dy: 0 },
+  north: { dx: -1, dy: 0 },
+  west: { dx: 0, dy: 1 },
+  east: { dx: 0, dy: -1 },
+};
+
+// Grid directions as seen on screen (grid +x runs down-right, grid +y down-left)
+const DIRECTION_FACINGS: Record<Guest['direction'], GuestFacing> = {
+  south: 'se',
+  north: 'nw',
+  west: 'sw',
+  east: 'ne',
+};
+
+/**
+ * Nearest of the 8 facings to a screen-space movement vector.
+ * Falls back to the guest's grid direction when not moving.
+ */
+export function getGuestFacing(guest: Guest, moveX: number, moveY: number): GuestFacing {
+  if (Math.abs(moveX) < 0.001 && Math.abs(moveY) < 0.001) {
+    return DIRECTION_FACINGS[guest.direction] ?? 's';
+  }
+  const octant = Math.round(Math.atan2(moveY, moveX) / (Math.PI / 4));
+  return FACINGS_BY_ANGLE[(octant + 8) % 8];
+}
+
+/**
+ * Where a guest's feet are drawn this frame, in unzoomed screen space before
+ * the ground's elevation lift, plus the screen-space direction they're moving.
+ * Interpolates between tiles and animates walking into and out of stalls.
+ */
+export function getGuestScreenPosition(guest: Guest): { x: number; y: number; moveX: number; moveY: number } {
   // Calculate interpolated position
   let { x: startX, y: startY } = gridToScreen(guest.tileX, guest.tileY);
   const { x: endX, y: endY } = gridToScreen(guest.targetTileX, guest.targetTileY);
@@ -169,9 +264,10 @@ export function drawGuest(
   
   let x = startX + (endX - startX) * guest.progress + TILE_WIDTH / 2;
   let y = startY + (endY - startY) * guest.progress + TILE_HEIGHT / 2;
+  let moveX = endX - startX;
+  let moveY = endY - startY;
   
-  // When eating, shopping, or exiting building - animate position
-  // Use real time for smooth animation independent of game tick rate
+  // When eating, shopping, or exiting building - animate position along approachProgress
   if ((guest.state === 'eating' || guest.state === 'shopping' || guest.state === 'exiting_building') && guest.targetBuildingId) {
     const parts = guest.targetBuildingId.split(',');
     if (parts.length === 2) {
@@ -188,37 +284,137 @@ export function drawGuest(
         const buildingCenterX = buildingScreenX + TILE_WIDTH / 2;
         const buildingCenterY = buildingScreenY + TILE_HEIGHT / 2;
         
-        const walkDurationMs = 800;
-        // If activityStartTime is 0 or undefined, treat as just started
-        const now = Date.now();
-        const startTime = guest.activityStartTime && guest.activityStartTime > 0 ? guest.activityStartTime : now;
-        const elapsedMs = now - startTime;
-        
-        let progress: number;
-        
-        if (guest.state === 'exiting_building') {
-          // Walking out: 1 -> 0 over 800ms
-          progress = Math.max(0, 1 - elapsedMs / walkDurationMs);
-        } else {
-          // Walking in: 0 -> 1 over 800ms, then stay at 1
-          progress = Math.min(1, elapsedMs / walkDurationMs);
-        }
+        // Walking in: 0 -> 1, walking out: 1 -> 0 (set each tick by updateGuest)
+        const progress = Math.max(0, Math.min(1, guest.approachProgress ?? 0));
         
         // Apply easing for smoother movement
         const easedProgress = progress < 0.5 
           ? 2 * progress * progress 
           : 1 - Math.pow(-2 * progress + 2, 2) / 2;
         
-        // Walk from path center toward building center
-        x = pathCenterX + (buildingCenterX - pathCenterX) * easedProgress;
-        y = pathCenterY + (buildingCenterY - pathCenterY) * easedProgress;
+        // Walk from path center toward building center; eating guests stop to sit
+        // out front of the stall so its customers gather around it
+        const reach = guest.state === 'eating' ? FOOD_STALL_SEAT_OFFSET : 1;
+        x = pathCenterX + (buildingCenterX - pathCenterX) * easedProgress * reach;
+        y = pathCenterY + (buildingCenterY - pathCenterY) * easedProgress * reach;
+        
+        // Face the building walking in, the path walking out
+        const towardBuilding = guest.state === 'exiting_building' ? -1 : 1;
+        moveX = (buildingCenterX - pathCenterX) * towardBuilding;
+        moveY = (buildingCenterY - pathCenterY) * towardBuilding;
       }
     }
   }
   
-  // Walking animation
-  const walkCycle = Math.sin((tick * 0.2 + guest.walkOffset) * 2);
-  const bobY = Math.abs(walkCycle) * 0.5;
+  // Resting guests sit at the path's edge on the bench side, facing the path
+  if (guest.state === 'sitting' && guest.targetBuildingId) {
+    const [benchX, benchY] = guest.targetBuildingId.split(',').map(Number);
+    if (!isNaN(benchX) && !isNaN(benchY)) {
+      const { x: pathX, y: pathY } = gridToScreen(guest.tileX, guest.tileY);
+      const { x: benchScreenX, y: benchScreenY } = gridToScreen(benchX, benchY);
+      x = pathX + TILE_WIDTH / 2 + (benchScreenX - pathX) * BENCH_SEAT_OFFSET;
+      y = pathY + TILE_HEIGHT / 2 + (benchScreenY - pathY) * BENCH_SEAT_OFFSET;
+      moveX = pathX - benchScreenX;
+      moveY = pathY - benchScreenY;
+    }
+  }
+  
+  // Guests standing in line keep to the queue's center line in line order,
+  // whoever is further ahead standing nearer the front of the tile
+  if (guest.state === 'queuing' && guest.tileX === guest.targetTileX && guest.tileY === guest.targetTileY) {
+    const { dx, dy } = DIRECTION_DELTAS[guest.direction] ?? DIRECTION_DELTAS.south;
+    const { x: stepX, y: stepY } = gridToScreen(dx, dy);
+    const slot = guest.queuePosition % GUESTS_PER_QUEUE_TILE;
+    const along = ((GUESTS_PER_QUEUE_TILE - 1) / 2 - slot) * QUEUE_SLOT_SPACING;
+    return { x: x + stepX * along, y: y + stepY * along, moveX, moveY };
+  }
+  
+  // Each guest keeps their own spot across the path so crowds spread out instead of stacking
+  x += Math.cos(guest.walkOffset * 3) * GUEST_JITTER_X;
+  y += Math.sin(guest.walkOffset * 5) * GUEST_JITTER_Y;
+  
+  return { x, y, moveX, moveY };
+}
+
+/**
+ * Whether a guest is drawn at all; riders are out of sight on their ride
+ */
+export function isGuestVisible(guest: Guest): boolean {
+  return guest.state !== 'riding';
+}
+
+/**
+ * The guest drawn closest to a point (unzoomed screen space, camera offset
+ * removed), or null if nobody is within reach of it
+ */
+export function pickGuestAt(guests: Guest[], grid: Tile[][], screenX: number, screenY: number): Guest | null {
+  let best: Guest | null = null;
+  let bestDistance = GUEST_PICK_RADIUS * GUEST_PICK_RADIUS;
+  for (const guest of guests) {
+    if (!isGuestVisible(guest)) continue;
+    const { x, y } = getGuestScreenPosition(guest);
+    // Guests are lifted with the tile they're drawn on (see CoasterGrid's guest ordering)
+    const tileX = guest.progress >= 0.5 ? guest.targetTileX : guest.tileX;
+    const tileY = guest.progress >= 0.5 ? guest.targetTileY : guest.tileY;
+    const lift = (grid[tileY]?.[tileX]?.elevation ?? 0) * HEIGHT_UNIT;
+    // Aim at the middle of the body rather than the feet
+    const dx = screenX - x;
+    const dy = screenY - (y - lift - 3);
+    const distance = dx * dx + dy * dy;
+    if (distance <= bestDistance) {
+      best = guest;
+      bestDistance = distance;
+    }
+  }
+  return best;
+}
+
+/**
+ * Shirt color a guest is drawn in: a colorblind palette swaps each of the
+ * guest colors for its own color at the same index
+ */
+function getGuestShirtColor(guest: Guest, shirtColors: string[] | null | undefined): string {
+  if (!shirtColors || shirtColors.length === 0) return guest.shirtColor;
+  const index = Math.max(0, GUEST_COLORS.shirt.indexOf(guest.shirtColor));
+  return shirtColors[index % shirtColors.length];
+}
+
+/**
+ * Zoomed-out stand-in for a guest: a single dot in their shirt color, kept the
+ * same size on screen however far the camera is zoomed out
+ */
+export function drawGuestDot(ctx: CanvasRenderingContext2D, guest: Guest, zoom: number, shirtColors?: string[] | null) {
+  const { x, y } = getGuestScreenPosition(guest);
+  const radius = 1.5 / zoom;
+  ctx.fillStyle = getGuestShirtColor(guest, shirtColors);
+  ctx.beginPath();
+  ctx.arc(x, y - radius, radius, 0, Math.PI * 2);
+  ctx.fill();
+}
+
+export function drawGuest(
+  ctx: CanvasRenderingContext2D,
+  guest: Guest,
+  tick: number,
+  shirtColors?: string[] | null
+) {
+  const { x, y, moveX, moveY } = getGuestScreenPosition(guest);
+  const shirtColor = getGuestShirtColor(guest, shirtColors);
+  
+  // Eating guests sit down once they've reached the stall
+  const seated = guest.state === 'eating' && guest.approachProgress >= 1;
+  
+  // Facing from the actual movement vector (stationary guests keep their grid direction)
+  const isMoving = !seated && (guest.state === 'walking' || guest.state === 'entering' ||
+    guest.state === 'eating' || guest.state === 'shopping' || guest.state === 'exiting_building');
+  const facing = getGuestFacing(guest, isMoving || seated ? moveX : 0, isMoving || seated ? moveY : 0);
+  const facingVector = FACING_VECTORS[facing];
+  const facingAway = facingVector.y < -0.1; // Back towards the camera
+  const profile = Math.abs(facingVector.x); // 0 = front/back view, 1 = side view
+  
+  // Walking animation (seated guests hold still, a leg's length lower)
+  const walkCycle = seated ? 0 : Math.sin((tick * 0.2 + guest.walkOffset) * 2);
+  const bobY = seated ? -1 : Math.abs(walkCycle) * 0.5;
   
   // Draw shadow
   ctx.fillStyle = 'rgba(0, 0, 0, 0.2)';
@@ -229,43 +425,68 @@ export function drawGuest(
   // Draw body (simple sprite-like representation) - scaled to 25% of original
   const guestY = y - 3 - bobY;
   
-  // Pants/legs
+  // Pants/legs - stride along the facing direction when seen from the side
+  const legSpread = 0.5 * (1 - profile * 0.5);
+  const stride = isMoving ? walkCycle * 0.4 * facingVector.x : 0;
   ctx.fillStyle = guest.pantsColor;
-  ctx.fillRect(x - 0.75, guestY + 1.5, 0.5, 1.5);
-  ctx.fillRect(x + 0.25, guestY + 1.5, 0.5, 1.5);
+  if (seated) {
+    // Thighs forward along the facing direction
+    ctx.fillRect(x - 0.25 - legSpread + facingVector.x * 0.5, guestY + 1.5, 0.5, 0.5);
+    ctx.fillRect(x - 0.25 + legSpread + facingVector.x * 0.5, guestY + 1.5, 0.5, 0.5);
+  } else {
+    ctx.fillRect(x - 0.25 - legSpread + stride, guestY + 1.5, 0.5, 1.5);
+    ctx.fillRect(x - 0.25 + legSpread - stride, guestY + 1.5, 0.5, 1.5);
+  }
   
-  // Torso
-  ctx.fillStyle = guest.shirtColor;
-  ctx.fillRect(x - 1, guestY - 0.5, 2, 2);
+  // Torso (narrower in side view)
+  const torsoHalfWidth = 1 - profile * 0.25;
+  ctx.fillStyle = shirtColor;
+  ctx.fillRect(x - torsoHalfWidth, guestY - 0.5, torsoHalfWidth * 2, 2);
   
-  // Head
+  // Head, nudged toward the facing direction
+  const headX = x + facingVector.x * 0.3;
   ctx.fillStyle = guest.skinColor;
   ctx.beginPath();
-  ctx.arc(x, guestY - 1.5, 1, 0, Math.PI * 2);
+  ctx.arc(headX, guestY - 1.5, 1, 0, Math.PI * 2);
   ctx.fill();
   
-  // Hat
+  // Back of the head when walking away from the camera
+  if (facingAway && !guest.hasHat) {
+    ctx.fillStyle = 'rgba(60, 40, 25, 0.85)';
+    ctx.beginPath();
+    ctx.arc(headX, guestY - 1.6, 0.85, 0, Math.PI * 2);
+    ctx.fill();
+  }
+  
+  // Hat (brim sticks out toward the facing direction)
   if (guest.hasHat) {
     ctx.fillStyle = guest.hatColor;
-    ctx.fillRect(x - 1.25, guestY - 2.75, 2.5, 0.75);
-    ctx.fillRect(x - 0.75, guestY - 3.5, 1.5, 0.75);
+    ctx.fillRect(headX - 1.25 + facingVector.x * 0.25, guestY - 2.75, 2.5, 0.75);
+    ctx.fillRect(headX - 0.75, guestY - 3.5, 1.5, 0.75);
   }
   
   // Arms (animated)
   const armSwing = walkCycle * 0.75;
-  ctx.fillStyle = guest.shirtColor;
-  ctx.fillRect(x - 1.5, guestY + armSwing, 0.5, 1.25);
-  ctx.fillRect(x + 1, guestY - armSwing, 0.5, 1.25);
+  ctx.fillStyle = shirtColor;
+  ctx.fillRect(x - torsoHalfWidth - 0.5, guestY + armSwing, 0.5, 1.25);
+  ctx.fillRect(x + torsoHalfWidth, guestY - armSwing, 0.5, 1.25);
 }
 
 // =============================================================================
 // GUEST AI / PATHFINDING
 // =============================================================================
 
+const PLAZA_LINE_TIE_BREAK = 0.001; // Weight of straying from the straight line in findPathAStar
+
 /**
- * Find path from guest position to target using simple BFS
+ * Find path from guest position to target using A* with a Manhattan heuristic.
+ * Guests only walk on path and queue tiles. Each tile is recorded once in a
+ * came-from table and the route is rebuilt only at the end, so long corridors
+ * on big parks don't copy a growing path at every step.
+ * Among equally short routes it keeps to the straight line between the ends,
+ * so guests cut through the middle of a plaza instead of hugging its rim.
  */
-export function findPath(
+export function findPathAStar(
   grid: Tile[][],
   startX: number,
   startY: number,
@@ -274,15 +495,57 @@ export function findPath(
   maxSteps: number = 100
 ): { x: number; y: number }[] {
   const gridSize = grid.length;
+  if (startX === targetX && startY === targetY) return [{ x: targetX, y: targetY }];
   
-  // BFS
-  const visited = new Set<string>();
-  const queue: { x: number; y: number; path: { x: number; y: number }[] }[] = [
-    { x: startX, y: startY, path: [] }
-  ];
+  const tileCount = gridSize * gridSize;
+  const startIdx = startY * gridSize + startX;
+  const targetIdx = targetY * gridSize + targetX;
+  const heuristic = (x: number, y: number) => Math.abs(x - targetX) + Math.abs(y - targetY);
+  // Distance from the start-target line, scaled well below one step so it only breaks ties
+  const lineX = startX - targetX;
+  const lineY = startY - targetY;
+  const lineScale = PLAZA_LINE_TIE_BREAK / (Math.abs(lineX) + Math.abs(lineY));
+  const lineOffset = (x: number, y: number) => Math.abs((x - targetX) * lineY - (y - targetY) * lineX) * lineScale;
+  
+  const gScore = new Int32Array(tileCount).fill(-1);
+  const cameFrom = new Int32Array(tileCount).fill(-1);
+  const closed = new Uint8Array(tileCount);
+  gScore[startIdx] = 0;
   
-  const key = (x: number, y: number) => `${x},${y}`;
-  visited.add(key(startX, startY));
+  // Binary min-heap of tile indices ordered by f = g + h (deeper nodes win ties)
+  const heap: number[] = [startIdx];
+  const fScore = new Float64Array(tileCount);
+  fScore[startIdx] = heuristic(startX, startY);
+  const before = (a: number, b: number) => fScore[a] < fScore[b] || (fScore[a] === fScore[b] && gScore[a] > gScore[b]);
+  const push = (idx: number) => {
+    heap.push(idx);
+    let i = heap.length - 1;
+    while (i > 0) {
+      const parent = (i - 1) >> 1;
+      if (!before(heap[i], heap[parent])) break;
+      [heap[i], heap[parent]] = [heap[parent], heap[i]];
+      i = parent;
+    }
+  };
+  const pop = (): number => {
+    const top = heap[0];
+    const last = heap.pop()!;
+    if (heap.length > 0) {
+      heap[0] = last;
+      let i = 0;
+      for (;;) {
+        const left = i * 2 + 1;
+        const right = left + 1;
+        let smallest = i;
+        if (left < heap.length && before(heap[left], heap[smallest])) smallest = left;
+        if (right < heap.length && before(heap[right], heap[smallest])) smallest = right;
+        if (smallest === i) break;
+        [heap[i], heap[smallest]] = [heap[smallest], heap[i]];
+        i = smallest;
+      }
+    }
+    return top;
+  };
   
   const directions = [
     { dx: 1, dy: 0 },
@@ -291,42 +554,66 @@ export function findPath(
     { dx: 0, dy: -1 },
   ];
   
-  while (queue.length > 0 && queue[0].path.length < maxSteps) {
-    const current = queue.shift()!;
+  while (heap.length > 0) {
+    const current = pop();
+    if (closed[current]) continue;
+    closed[current] = 1;
     
-    if (current.x === targetX && current.y === targetY) {
-      return [...current.path, { x: targetX, y: targetY }];
+    if (current === targetIdx) {
+      // Walk the came-from table back to the start
+      const path: { x: number; y: number }[] = [];
+      for (let idx = current; idx !== -1; idx = cameFrom[idx]) {
+        path.push({ x: idx % gridSize, y: Math.floor(idx / gridSize) });
+      }
+      return path.reverse();
     }
     
+    // Same limit as the BFS: stop extending routes that are already maxSteps long
+    const nextG = gScore[current] + 1;
+    if (nextG >= maxSteps) continue;
+    
+    const cx = current % gridSize;
+    const cy = Math.floor(current / gridSize);
     for (const dir of directions) {
-      const nx = current.x + dir.dx;
-      const ny = current.y + dir.dy;
+      const nx = cx + dir.dx;
+      const ny = cy + dir.dy;
       
       if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
-      if (visited.has(key(nx, ny))) continue;
+      const neighbor = ny * gridSize + nx;
+      if (closed[neighbor]) continue;
       
       const tile = grid[ny][nx];
       // Guests can only walk on paths
       if (!tile.path && !tile.queue) continue;
+      if (gScore[neighbor] !== -1 && gScore[neighbor] <= nextG) continue;
       
-      visited.add(key(nx, ny));
-      queue.push({
-        x: nx,
-        y: ny,
-        path: [...current.path, { x: current.x, y: current.y }],
-      });
+      gScore[neighbor] = nextG;
+      fScore[neighbor] = nextG + heuristic(nx, ny) + lineOffset(nx, ny);
+      cameFrom[neighbor] = current;
+      push(neighbor);
     }
   }
   
   return []; // No path found
 }
 
-function isRideBuilding(type: string): boolean {
-  return type.startsWith('ride_') || type.startsWith('show_') || type.startsWith('station_');
-}
-
-function isFoodBuilding(type: string): boolean {
-  return type.startsWith('food_') || type.startsWith('drink_') || type.startsWith('snack_') || type.startsWith('cart_');
+/**
+ * findPathAStar with the shared route cache in front of it
+ */
+export function findPathCached(
+  grid: Tile[][],
+  startX: number,
+  startY: number,
+  targetX: number,
+  targetY: number,
+  maxSteps: number = 100
+): { x: number; y: number }[] {
+  const cached = getCachedPath(grid, startX, startY, targetX, targetY, maxSteps);
+  if (cached) return cached;
+  
+  const path = findPathAStar(grid, startX, startY, targetX, targetY, maxSteps);
+  storeCachedPath(grid, startX, startY, targetX, targetY, path);
+  return path;
 }
 
 function isShopBuilding(type: string): boolean {
@@ -338,7 +625,6 @@ function isShopBuilding(type: string): boolean {
     type === 'photo_booth' ||
     type === 'caricature' ||
     type === 'face_paint' ||
-    type === 'restroom' ||
     type === 'first_aid' ||
     type === 'lockers' ||
     type === 'stroller_rental' ||
@@ -346,64 +632,415 @@ function isShopBuilding(type: string): boolean {
   );
 }
 
+/**
+ * Whether a building sells from a stock that can run out (food stalls and shops)
+ */
+export function isStockedBuilding(type: string | undefined): boolean {
+  return Boolean(type && (isFoodBuilding(type) || isShopBuilding(type)));
+}
+
+function isRestroomBuilding(type: string): boolean {
+  return type === 'restroom';
+}
+
+const DESTINATION_MAX_STEPS = 200; // Longest walk a guest will plan
+const NEAREST_AMENITY_CANDIDATES = 4; // Stalls, shops and restrooms: pick among the closest few
+const DESTINATION_DISTANCE_FALLOFF = 15; // Tiles of walking that halve a destination's pull
+
+/**
+ * Walking distance (in steps) from (startX, startY) to every path or queue tile
+ * reachable within maxSteps, row-major; -1 marks tiles that can't be reached.
+ * parents holds the tile each one was reached from, so routes can be read back.
+ */
+function getWalkDistances(
+  grid: Tile[][],
+  startX: number,
+  startY: number,
+  maxSteps: number
+): { distances: Int32Array; parents: Int32Array } {
+  const gridSize = grid.length;
+  const distances = new Int32Array(gridSize * gridSize).fill(-1);
+  const parents = new Int32Array(gridSize * gridSize).fill(-1);
+  if (startX < 0 || startY < 0 || startX >= gridSize || startY >= gridSize) return { distances, parents };
+  
+  const startIdx = startY * gridSize + startX;
+  distances[startIdx] = 0;
+  const queue = [startIdx];
+  for (let head = 0; head < queue.length; head++) {
+    const current = queue[head];
+    const nextDistance = distances[current] + 1;
+    if (nextDistance >= maxSteps) continue;
+    
+    const cx = current % gridSize;
+    const cy = Math.floor(current / gridSize);
+    for (const [dx, dy] of [[1, 0], [-1, 0], [0, 1], [0, -1]]) {
+      const nx = cx + dx;
+      const ny = cy + dy;
+      if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+      const neighbor = ny * gridSize + nx;
+      if (distances[neighbor] !== -1) continue;
+      const tile = grid[ny][nx];
+      if (!tile.path && !tile.queue) continue;
+      distances[neighbor] = nextDistance;
+      parents[neighbor] = current;
+      queue.push(neighbor);
+    }
+  }
+  return { distances, parents };
+}
+
+/**
+ * The shortest walk to (targetX, targetY) read back from getWalkDistances,
+ * excluding the start tile; empty if the target wasn't reached
+ */
+function traceWalk(parents: Int32Array, gridSize: number, targetX: number, targetY: number): { x: number; y: number }[] {
+  const path: { x: number; y: number }[] = [];
+  for (let idx = targetY * gridSize + targetX; parents[idx] !== -1; idx = parents[idx]) {
+    path.push({ x: idx % gridSize, y: Math.floor(idx / gridSize) });
+  }
+  return path.reverse();
+}
+
+/**
+ * Pick a building for a guest to head to. Only buildings connected to the
+ * guest's path network count; nearer ones are favored, and amenities are
+ * chosen among the closest few so guests use the stall down the path rather
+ * than one across the park.
+ */
 function findBuildingDestination(
   grid: Tile[][],
   guest: Guest,
-  predicate: (type: string) => boolean,
-  preferQueue: boolean
+  predicate: (type: string, buildingId: string) => boolean,
+  preferQueue: boolean,
+  weight?: (buildingId: string) => number
 ): { path: { x: number; y: number }[]; buildingId: string } | null {
   const gridSize = grid.length;
-  const buildingTiles: { x: number; y: number; id: string }[] = [];
+  const { distances, parents } = getWalkDistances(grid, guest.tileX, guest.tileY, DESTINATION_MAX_STEPS);
+  let candidates: { id: string; access: { x: number; y: number }; distance: number }[] = [];
   
   for (let y = 0; y < gridSize; y++) {
     for (let x = 0; x < gridSize; x++) {
       const buildingType = grid[y][x].building?.type;
-      if (buildingType && predicate(buildingType)) {
-        buildingTiles.push({ x, y, id: `${x},${y}` });
-      }
+      if (!buildingType || !predicate(buildingType, `${x},${y}`)) continue;
+      // Skip buildings no path or queue touches, or that sit on another path network
+      const access = getBuildingAccessTile(grid, x, y, preferQueue ? 'queue' : 'path');
+      if (!access) continue;
+      const distance = distances[access.y * gridSize + access.x];
+      if (distance < 0) continue;
+      candidates.push({ id: `${x},${y}`, access, distance });
     }
   }
   
-  if (buildingTiles.length === 0) return null;
+  if (candidates.length === 0) return null;
   
-  const attempts = Math.min(6, buildingTiles.length);
-  for (let i = 0; i < attempts; i++) {
-    const building = buildingTiles[Math.floor(Math.random() * buildingTiles.length)];
-    const neighbors = [
-      { x: building.x + 1, y: building.y },
-      { x: building.x - 1, y: building.y },
-      { x: building.x, y: building.y + 1 },
-      { x: building.x, y: building.y - 1 },
-    ].filter(tile => tile.x >= 0 && tile.y >= 0 && tile.x < gridSize && tile.y < gridSize);
-    
-    const queueTiles = neighbors.filter(tile => grid[tile.y][tile.x].queue);
-    const pathTiles = neighbors.filter(tile => grid[tile.y][tile.x].path);
-    const targetTile = preferQueue ? (queueTiles[0] || pathTiles[0]) : pathTiles[0] || queueTiles[0];
-    
-    if (!targetTile) continue;
-    
-    const path = findPath(grid, guest.tileX, guest.tileY, targetTile.x, targetTile.y, 200);
-    if (path.length > 0) {
-      const trimmedPath = path[0]?.x === guest.tileX && path[0]?.y === guest.tileY
-        ? path.slice(1)
-        : path;
-      return { path: trimmedPath, buildingId: building.id };
+  // Rides keep the whole park in play (guests cross it for a coaster they like);
+  // for everything else only the nearest handful are worth the walk
+  candidates.sort((a, b) => a.distance - b.distance);
+  if (!preferQueue) {
+    candidates = candidates.slice(0, NEAREST_AMENITY_CANDIDATES);
+  }
+  
+  // Optional per-building weights (e.g. ride appeal) scaled down with distance
+  const weights = candidates.map(candidate =>
+    (weight ? Math.max(0, weight(candidate.id)) : 1) / (1 + candidate.distance / DESTINATION_DISTANCE_FALLOFF)
+  );
+  const totalWeight = weights.reduce((sum, w) => sum + w, 0);
+  let chosen = candidates[0];
+  if (totalWeight > 0) {
+    let roll = simulationRandom() * totalWeight;
+    for (let i = 0; i < candidates.length; i++) {
+      roll -= weights[i];
+      if (roll <= 0) {
+        chosen = candidates[i];
+        break;
+      }
     }
   }
   
+  // The search that ranked the candidates already knows the way there
+  return { path: traceWalk(parents, gridSize, chosen.access.x, chosen.access.y), buildingId: chosen.id };
+}
+
+/**
+ * Whether a ride is accepting guests (closed, broken or stalled coasters are
+ * skipped, as are stations left without a coaster once its track is torn down)
+ */
+function isRideOpen(grid: Tile[][], rideId: string, coasters: Coaster[]): boolean {
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  if (!grid[rideY]?.[rideX]?.building?.type.startsWith('station_')) return true;
+  const coaster = findCoasterForRide(grid, rideId, coasters);
+  return Boolean(coaster && coaster.operating && !coaster.broken && !coaster.stalled);
+}
+
+function findRideDestination(grid: Tile[][], guest: Guest, coasters: Coaster[] = [], payPerRide: boolean = false) {
+  // With ticketed rides, guests skip any ride they think is a rip-off or can't afford
+  const isOpenRide = (type: string, buildingId: string) =>
+    isRideBuilding(type) && isRideOpen(grid, buildingId, coasters) &&
+    isRideWithinThrillRange(guest, getRideProfileForGuest(grid, buildingId, coasters)) &&
+    (!payPerRide || isRidePriceAcceptable(grid, guest, buildingId, coasters));
+  return findBuildingDestination(grid, guest, isOpenRide, true, buildingId => {
+    const profile = getRideProfileForGuest(grid, buildingId, coasters);
+    return profile ? getRideAppeal(guest, profile) : 0.5;
+  });
+}
+
+function findFoodDestination(grid: Tile[][], guest: Guest, soldOut?: Set<string>) {
+  // Thirsty guests lean toward drink stands, hungry ones toward meals
+  const isOpen = (type: string, id: string) => isFoodBuilding(type) && !soldOut?.has(id);
+  return findBuildingDestination(grid, guest, isOpen, false, buildingId => {
+    const [x, y] = buildingId.split(',').map(Number);
+    const profile = getFoodProfile(grid[y][x].building.type);
+    return 5 + (guest.hunger * profile.hunger + guest.thirst * profile.thirst) / 100;
+  });
+}
+
+function findShopDestination(grid: Tile[][], guest: Guest, soldOut?: Set<string>) {
+  return findBuildingDestination(grid, guest, (type, id) => isShopBuilding(type) && !soldOut?.has(id), false);
+}
+
+function findRestroomDestination(grid: Tile[][], guest: Guest) {
+  return findBuildingDestination(grid, guest, isRestroomBuilding, false);
+}
+
+function findBenchDestination(grid: Tile[][], guest: Guest) {
+  return findBuildingDestination(grid, guest, isBenchBuilding, false);
+}
+
+/**
+ * Head for whatever the group's leader is on their way to
+ */
+function findGroupDestination(grid: Tile[][], guest: Guest, leader: Guest, coasters: Coaster[]) {
+  const buildingId = leader.targetBuildingId;
+  const isRide = leader.targetBuildingKind === 'ride';
+  if (!buildingId || (isRide && !isRideOpen(grid, buildingId, coasters))) return null;
+  return findBuildingDestination(grid, guest, (_type, id) => id === buildingId, isRide);
+}
+
+/**
+ * Find the coaster served by a station building: the one whose track runs
+ * alongside it (the same edge-adjacent track that set the station's rotation).
+ * Returns null for anything that isn't a coaster station.
+ */
+export function findCoasterForRide(grid: Tile[][], rideId: string, coasters: Coaster[]): Coaster | null {
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  if (!grid[rideY]?.[rideX]?.building?.type.startsWith('station_')) return null;
+  for (const [dx, dy] of [[1, 0], [-1, 0], [0, 1], [0, -1]]) {
+    const coasterId = grid[rideY + dy]?.[rideX + dx]?.coasterTrackId;
+    const coaster = coasterId ? coasters.find(c => c.id === coasterId) : undefined;
+    if (coaster) return coaster;
+  }
   return null;
 }
 
-function findRideDestination(grid: Tile[][], guest: Guest) {
-  return findBuildingDestination(grid, guest, isRideBuilding, true);
+/**
+ * Origin tile of the building covering (x, y), following footprint tiles back
+ * to the tile that holds the building's price and settings
+ */
+export function findBuildingOrigin(grid: Tile[][], x: number, y: number): { x: number; y: number } | null {
+  const building = grid[y]?.[x]?.building;
+  if (!building) return null;
+  if (!building.type.endsWith('_footprint')) return { x, y };
+  
+  const originType = building.type.replace('_footprint', '');
+  for (let dy = 0; dy <= 3; dy++) {
+    for (let dx = 0; dx <= 3; dx++) {
+      if (grid[y - dy]?.[x - dx]?.building?.type === originType) return { x: x - dx, y: y - dy };
+    }
+  }
+  return null;
+}
+
+/**
+ * The ride building a ride id refers to (its origin tile when the id is a footprint tile)
+ */
+export function getRideBuilding(grid: Tile[][], rideId: string): Building | null {
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  const origin = findBuildingOrigin(grid, rideX, rideY);
+  return origin ? grid[origin.y][origin.x].building : null;
+}
+
+/**
+ * Look up the excitement/intensity/nausea profile of the ride a guest rode.
+ * Coaster stations use their coaster's ratings (or the coaster type's base stats
+ * until ratings are calculated); flat rides use their building profile.
+ */
+function getRideProfileForGuest(grid: Tile[][], rideId: string, coasters: Coaster[]): RideProfile | null {
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  const type = grid[rideY]?.[rideX]?.building?.type;
+  if (!type) return null;
+  
+  if (type.startsWith('station_')) {
+    const coaster = findCoasterForRide(grid, rideId, coasters);
+    if (!coaster) return null;
+    if (coaster.excitement > 0) {
+      return { excitement: coaster.excitement, intensity: coaster.intensity, nausea: coaster.nausea };
+    }
+    const typeStats = COASTER_TYPE_STATS[coaster.type];
+    return typeStats
+      ? { excitement: typeStats.excitement.base, intensity: typeStats.intensity.base, nausea: typeStats.nausea.base }
+      : null;
+  }
+  
+  if (!isRideBuilding(type)) return null;
+  // Multi-tile rides can be targeted through any footprint tile
+  return getRideProfile(type.replace('_footprint', '') as BuildingType);
+}
+
+// =============================================================================
+// GUEST PREFERENCES
+// =============================================================================
+
+/**
+ * Remember a thought, forgetting the oldest once MAX_GUEST_THOUGHTS are held
+ */
+export function addGuestThought(guest: Guest, thought: GuestThought) {
+  guest.thoughts = [...guest.thoughts.slice(-(MAX_GUEST_THOUGHTS - 1)), thought];
+}
+
+/**
+ * Whether a guest is too sick to ride anything (they look for food to settle their stomach)
+ */
+export function isGuestNauseous(guest: Guest): boolean {
+  return guest.nausea > NAUSEOUS_NAUSEA;
+}
+
+/**
+ * Guest thrill preference (0 = timid, 1 = thrill-seeker).
+ * Falls back to preferIntensity (2-8) for guests saved before preferences existed.
+ */
+function getGuestThrill(guest: Guest): number {
+  if (guest.thrillPreference !== undefined) return guest.thrillPreference;
+  return Math.max(0, Math.min(1, (guest.preferIntensity - 2) / 6));
 }
 
-function findFoodDestination(grid: Tile[][], guest: Guest) {
-  return findBuildingDestination(grid, guest, isFoodBuilding, false);
+const RIDE_THRILL_MISMATCH_LIMIT = 0.6; // Intensity gap (0-1) past which a guest won't consider a ride
+
+/**
+ * Whether a ride's intensity is anywhere near what the guest enjoys, so timid
+ * guests pass on the drop tower and thrill-seekers pass on the carousel
+ */
+function isRideWithinThrillRange(guest: Guest, profile: RideProfile | null): boolean {
+  if (!profile) return true;
+  return Math.abs(profile.intensity / 10 - getGuestThrill(guest)) <= RIDE_THRILL_MISMATCH_LIMIT;
 }
 
-function findShopDestination(grid: Tile[][], guest: Guest) {
-  return findBuildingDestination(grid, guest, isShopBuilding, false);
+/**
+ * How appealing a ride is to a guest (used to weight ride choice).
+ * Rides matching the guest's thrill preference score highest; already
+ * queasy guests steer away from nauseating rides unless they're thrill-seekers.
+ */
+function getRideAppeal(guest: Guest, profile: RideProfile): number {
+  const thrill = getGuestThrill(guest);
+  const intensityMatch = 1 - Math.min(1, Math.abs(profile.intensity / 10 - thrill));
+  const queasiness = (guest.nausea / 100) * (profile.nausea / 10) * (1 - thrill);
+  return 0.1 + intensityMatch * (0.5 + profile.excitement / 10) * (1 - Math.min(0.9, queasiness * 2));
+}
+
+/**
+ * Whether a guest can spare a price from their cash. Low-budget guests hold
+ * back most of their cash instead of spending it all.
+ */
+function canSpareCash(guest: Guest, price: number): boolean {
+  const tier = guest.budgetTier ?? 'medium';
+  const spendableShare = tier === 'low' ? 0.3 : tier === 'medium' ? 0.6 : 1;
+  return price <= guest.cash * spendableShare;
+}
+
+/**
+ * Whether a guest is willing to pay a price for something that normally costs defaultPrice
+ */
+export function isPriceAcceptable(guest: Guest, price: number, defaultPrice: number): boolean {
+  const tier = guest.budgetTier ?? 'medium';
+  if (price > defaultPrice * BUDGET_TIER_PRICE_TOLERANCE[tier]) return false;
+  return canSpareCash(guest, price);
+}
+
+/**
+ * Ticket price guests consider fair for a ride: the more exciting, the more it's worth
+ */
+export function getFairRidePrice(profile: RideProfile): number {
+  return Math.max(1, Math.round(DEFAULT_PRICES.rideTicket * (0.4 + profile.excitement / 5)));
+}
+
+/**
+ * Fair price for the ride with this id (coasters are judged on their track ratings)
+ */
+export function getRideFairPrice(grid: Tile[][], rideId: string, coasters: Coaster[]): number {
+  const profile = getRideProfileForGuest(grid, rideId, coasters);
+  return profile ? getFairRidePrice(profile) : DEFAULT_PRICES.rideTicket;
+}
+
+function isRidePriceAcceptable(grid: Tile[][], guest: Guest, rideId: string, coasters: Coaster[]): boolean {
+  const price = getRideBuilding(grid, rideId)?.price ?? 0;
+  if (price <= 0) return true;
+  return isPriceAcceptable(guest, price, getRideFairPrice(grid, rideId, coasters));
+}
+
+/**
+ * Whether an arriving guest pays the entrance fee or turns away at the gate.
+ * Above a guest's price tolerance, the chance of entering falls off quickly,
+ * so raising the fee drives away low-budget guests first.
+ */
+export function willPayEntranceFee(guest: Guest, entranceFee: number): boolean {
+  if (entranceFee <= 0) return true;
+  if (entranceFee > guest.cash) return false;
+  const tier = guest.budgetTier ?? 'medium';
+  const ratio = entranceFee / (DEFAULT_PRICES.parkEntrance * BUDGET_TIER_PRICE_TOLERANCE[tier]);
+  if (ratio <= 1) return true;
+  return simulationRandom() < 1 / (ratio * ratio);
+}
+
+/**
+ * Broke guests head home; low-budget guests give up much sooner (per tick roll)
+ */
+export function shouldGuestLeaveWhenBroke(guest: Guest): boolean {
+  if (guest.cash >= DEFAULT_PRICES.drinkItem) return false;
+  const tier = guest.budgetTier ?? 'medium';
+  const leaveChance = tier === 'low' ? 0.01 : guest.happiness < 60 ? 0.002 : 0.0005;
+  return simulationRandom() < leaveChance;
+}
+
+/**
+ * Count guests by thrill level and budget tier
+ */
+export function getGuestPreferenceStats(guests: Guest[]): GuestPreferenceStats {
+  const stats: GuestPreferenceStats = {
+    thrill: { timid: 0, moderate: 0, thrill_seeker: 0 },
+    budget: { low: 0, medium: 0, high: 0 },
+  };
+  for (const guest of guests) {
+    stats.thrill[getGuestThrillLevel(getGuestThrill(guest))]++;
+    stats.budget[guest.budgetTier ?? 'medium']++;
+  }
+  return stats;
+}
+
+/**
+ * Apply a finished ride's profile to the guest's mood.
+ * Thrill-seekers enjoy intense rides more;
+ * everyone else gets more nauseous from them.
+ */
+function applyRideProfile(guest: Guest, profile: RideProfile) {
+  const thrill = getGuestThrill(guest);
+  
+  const happinessGain = 2 + profile.excitement * 0.8 + profile.intensity * (thrill - 0.4) * 1.2;
+  guest.happiness = Math.max(0, Math.min(100, guest.happiness + happinessGain));
+  
+  // Low tolerance and low thrill preference both amplify nausea, and intense rides shake guests up more
+  const toleranceFactor = 5 / Math.max(1, guest.nauseaTolerance);
+  const intensityFactor = 1 + profile.intensity * RIDE_INTENSITY_NAUSEA;
+  const nauseaGain = profile.nausea * (2 + (1 - thrill) * 2) * toleranceFactor * intensityFactor * (0.8 + simulationRandom() * 0.4);
+  const wasQueasy = guest.nausea > QUEASY_NAUSEA;
+  guest.nausea = Math.min(100, guest.nausea + nauseaGain);
+  
+  // Say what stood out: feeling sick, a ride too intense for them, or a great ride
+  if (!wasQueasy && guest.nausea > QUEASY_NAUSEA) {
+    addGuestThought(guest, 'ride_made_sick');
+  } else if (happinessGain < 0) {
+    addGuestThought(guest, 'ride_was_scary');
+  } else if (happinessGain >= GREAT_RIDE_HAPPINESS) {
+    addGuestThought(guest, 'ride_was_great');
+  }
 }
 
 function assignPath(guest: Guest, path: { x: number; y: number }[]) {
@@ -416,15 +1053,270 @@ function assignPath(guest: Guest, path: { x: number; y: number }[]) {
   }
 }
 
+// =============================================================================
+// RIDE QUEUES
+// =============================================================================
+
+const QUEUE_BALK_HAPPINESS_PENALTY = 4;
+const SOLD_OUT_HAPPINESS_PENALTY = 5; // Walking to a stall only to find it empty
+const QUEUE_PATIENCE_MIN = 150;  // Ticks a guest will wait in line before giving up
+const QUEUE_PATIENCE_RANGE = 150;
+
+/**
+ * Per-tick queue bookkeeping shared by every guest update. Guests joining a
+ * line bump queueLengths so later arrivals in the same tick see it; guests who
+ * find the line full are tallied in balks.
+ */
+export interface RideQueueTracker {
+  queueLengths: Map<string, number>;
+  balks: Map<string, number>;
+}
+
+/**
+ * Turn a guest to face the neighboring tile they're about to step onto
+ */
+function faceToward(guest: Guest, next: { x: number; y: number }) {
+  const dx = next.x - guest.tileX;
+  const dy = next.y - guest.tileY;
+  if (dx > 0) guest.direction = 'south';
+  else if (dx < 0) guest.direction = 'north';
+  else if (dy > 0) guest.direction = 'west';
+  else if (dy < 0) guest.direction = 'east';
+}
+
+/**
+ * Whether a guest heading for a ride has just stepped into that ride's line
+ * (a queue linked to it), so they join the back instead of walking to the front
+ */
+function hasReachedRideLine(grid: Tile[][], guest: Guest): boolean {
+  if (guest.targetBuildingKind !== 'ride') return false;
+  const rideId = guest.queueRideId ?? guest.targetBuildingId;
+  return Boolean(rideId) && grid[guest.tileY]?.[guest.tileX]?.queueRideId === rideId;
+}
+
+/**
+ * Walk a queuing guest one tile at a time toward their place in line
+ * (queuePosition, set by the simulation from the ride's line order)
+ */
+function shuffleForwardInLine(guest: Guest, grid: Tile[][], rideId: string) {
+  if (guest.targetTileX !== guest.tileX || guest.targetTileY !== guest.tileY) {
+    guest.progress += GUEST_WALK_SPEED;
+    if (guest.progress >= 1) {
+      guest.tileX = guest.targetTileX;
+      guest.tileY = guest.targetTileY;
+      guest.progress = 0;
+    }
+    return;
+  }
+  
+  const distance = getQueueDistances(grid, rideId).get(`${guest.tileX},${guest.tileY}`);
+  if (distance === undefined || distance <= getQueueSlotDistance(guest.queuePosition)) return;
+  const next = getNextQueueTile(grid, rideId, guest.tileX, guest.tileY);
+  if (!next) return;
+  faceToward(guest, next);
+  guest.targetTileX = next.x;
+  guest.targetTileY = next.y;
+}
+
+/**
+ * Count the guests currently waiting in each ride's line
+ */
+export function createRideQueueTracker(guests: Guest[]): RideQueueTracker {
+  const queueLengths = new Map<string, number>();
+  for (const guest of guests) {
+    if (guest.state !== 'queuing' || !guest.queueRideId) continue;
+    queueLengths.set(guest.queueRideId, (queueLengths.get(guest.queueRideId) ?? 0) + 1);
+  }
+  return { queueLengths, balks: new Map() };
+}
+
+/**
+ * Each ride's line in arrival order. Guests still queuing keep their place
+ * from last tick; guests who joined since go to the back.
+ */
+export function collectRideLines(guests: Guest[], rideStats: Record<string, RideStats> | undefined): Map<string, string[]> {
+  const queuingRide = new Map<string, string>();
+  for (const guest of guests) {
+    if (guest.state === 'queuing' && guest.queueRideId) queuingRide.set(guest.id, guest.queueRideId);
+  }
+
+  const lines = new Map<string, string[]>();
+  const placed = new Set<string>();
+  const join = (rideId: string, guestId: string) => {
+    const line = lines.get(rideId);
+    if (line) line.push(guestId);
+    else lines.set(rideId, [guestId]);
+    placed.add(guestId);
+  };
+
+  if (rideStats) {
+    for (const rideId in rideStats) {
+      for (const guestId of rideStats[rideId].queue ?? []) {
+        if (queuingRide.get(guestId) === rideId) join(rideId, guestId);
+      }
+    }
+  }
+  for (const [guestId, rideId] of queuingRide) {
+    if (!placed.has(guestId)) join(rideId, guestId);
+  }
+  return lines;
+}
+
+/**
+ * Whether a queued-for ride is still standing and running
+ */
+function isRideStillOpen(grid: Tile[][], rideId: string, coasters: Coaster[]): boolean {
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  return isRideBuilding(grid[rideY]?.[rideX]?.building?.type) && isRideOpen(grid, rideId, coasters);
+}
+
+/**
+ * Whether a ride's line has room for one more guest
+ */
+function hasQueueRoom(grid: Tile[][], rideId: string, queues: RideQueueTracker): boolean {
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  const building = grid[rideY]?.[rideX]?.building;
+  if (!building) return true;
+  return (queues.queueLengths.get(rideId) ?? 0) < getMaxQueueLength(building);
+}
+
+// =============================================================================
+// BATHROOM NEED
+// =============================================================================
+
+const BATHROOM_SEEK_THRESHOLD = 70;   // Guests head for a restroom above this
+const BATHROOM_DESPERATE_PENALTY = 0.5; // Extra happiness loss per tick once the need maxes out
+const BATHROOM_RELIEF_HAPPINESS = 5;
+
+// =============================================================================
+// RESTING
+// =============================================================================
+
+const REST_TIME_MIN = 10;             // Ticks a guest sits on a bench
+const REST_TIME_RANGE = 15;
+const REST_ENERGY_PER_TICK = 2.5;
+const REST_HAPPINESS_PER_TICK = 0.1;
+const REST_NAUSEA_PER_TICK = 1.5;     // Sitting down settles a stomach faster than walking it off
+
+/**
+ * Guests who can't find a restroom and are miserable about it head home (per tick roll)
+ */
+export function shouldGuestLeaveForBathroom(guest: Guest): boolean {
+  if (guest.bathroom < 100 || guest.happiness > 30) return false;
+  return simulationRandom() < 0.01;
+}
+
+// =============================================================================
+// LEAVING THE PARK
+// =============================================================================
+
+const LEAVE_HAPPINESS_THRESHOLD = 15; // Guests this miserable head straight home
+
+/**
+ * Path tiles on the park border - guests enter and leave through these gates
+ */
+export function findParkEntranceTiles(grid: Tile[][]): { x: number; y: number }[] {
+  const entranceTiles: { x: number; y: number }[] = [];
+  const gridSize = grid.length;
+  
+  // Check all four edges for path tiles - these are the entrance gate locations
+  for (let i = 0; i < gridSize; i++) {
+    // North edge (x=0) - check grid[y][0] for all y
+    if (grid[i]?.[0]?.path) entranceTiles.push({ x: 0, y: i });
+    // South edge (x=gridSize-1) - check grid[y][gridSize-1] for all y
+    if (grid[i]?.[gridSize - 1]?.path) entranceTiles.push({ x: gridSize - 1, y: i });
+    // East edge (y=0) - check grid[0][x] for all x
+    if (grid[0]?.[i]?.path) entranceTiles.push({ x: i, y: 0 });
+    // West edge (y=gridSize-1) - check grid[gridSize-1][x] for all x
+    if (grid[gridSize - 1]?.[i]?.path) entranceTiles.push({ x: i, y: gridSize - 1 });
+  }
+  
+  // Remove duplicates (corner tiles appear twice)
+  return entranceTiles.filter((tile, index, self) =>
+    index === self.findIndex(t => t.x === tile.x && t.y === tile.y)
+  );
+}
+
+function isParkExitTile(grid: Tile[][], x: number, y: number): boolean {
+  const gridSize = grid.length;
+  const onEdge = x === 0 || y === 0 || x === gridSize - 1 || y === gridSize - 1;
+  return onEdge && Boolean(grid[y]?.[x]?.path);
+}
+
+/**
+ * Route from the guest to the nearest reachable park exit.
+ * Returns an empty path if they're already standing on one, null if no exit is reachable.
+ */
+function findExitPath(grid: Tile[][], guest: Guest): { x: number; y: number }[] | null {
+  const exits = findParkEntranceTiles(grid)
+    .map(tile => ({ ...tile, distance: Math.abs(tile.x - guest.tileX) + Math.abs(tile.y - guest.tileY) }))
+    .sort((a, b) => a.distance - b.distance)
+    .slice(0, 3);
+  
+  for (const exit of exits) {
+    const path = findPathCached(grid, guest.tileX, guest.tileY, exit.x, exit.y, 400);
+    if (path.length > 0) {
+      return path[0]?.x === guest.tileX && path[0]?.y === guest.tileY ? path.slice(1) : path;
+    }
+  }
+  return null;
+}
+
+/**
+ * Whether a guest is done with the park: too unhappy, worn out, or it's closing time
+ */
+function shouldGuestHeadHome(guest: Guest, parkClosed: boolean): boolean {
+  return parkClosed || guest.happiness < LEAVE_HAPPINESS_THRESHOLD || guest.energy <= 0;
+}
+
+/**
+ * Drop whatever the guest was doing and send them toward the nearest exit
+ */
+export function sendGuestHome(guest: Guest, grid: Tile[][]): Guest {
+  const leavingGuest: Guest = {
+    ...guest,
+    state: 'leaving',
+    queueRideId: null,
+    targetBuildingId: null,
+    targetBuildingKind: null,
+    path: [],
+    pathIndex: 0,
+  };
+  if (guest.state !== 'leaving') addGuestThought(leavingGuest, guest.energy <= 0 ? 'tired' : 'want_to_go_home');
+  const exitPath = findExitPath(grid, leavingGuest);
+  if (exitPath) {
+    assignPath(leavingGuest, exitPath);
+  } else {
+    leavingGuest.decisionCooldown = 30 + simulationRandom() * 30;
+  }
+  return leavingGuest;
+}
+
+/**
+ * Whether a leaving guest has reached a park exit and can be removed
+ */
+export function hasGuestLeftPark(guest: Guest, grid: Tile[][]): boolean {
+  return guest.state === 'leaving' &&
+    guest.pathIndex >= guest.path.length &&
+    isParkExitTile(grid, guest.tileX, guest.tileY);
+}
+
 /**
  * Update guest state and position
  */
 export function updateGuest(
   guest: Guest,
   grid: Tile[][],
-  deltaTime: number
+  deltaTime: number,
+  coasters: Coaster[] = [],
+  queues?: RideQueueTracker,
+  parkClosed: boolean = false,
+  payPerRide: boolean = false,
+  occupancy?: Uint16Array,
+  groupLeaders?: Map<string, Guest>,
+  soldOut?: Set<string>
 ): Guest {
-  const updatedGuest = { ...guest };
+  let updatedGuest = { ...guest };
   const previousState = updatedGuest.state;
   
   // Update time in park
@@ -438,10 +1330,17 @@ export function updateGuest(
   
   // Update happiness based on needs
   let happinessChange = 0;
-  if (updatedGuest.hunger > 70) happinessChange -= 0.1;
-  if (updatedGuest.thirst > 70) happinessChange -= 0.15;
-  if (updatedGuest.bathroom > 80) happinessChange -= 0.2;
-  if (updatedGuest.nausea > 50) happinessChange -= 0.1;
+  if (updatedGuest.hunger > HUNGRY_NEED) happinessChange -= 0.1;
+  if (updatedGuest.thirst > THIRSTY_NEED) happinessChange -= 0.15;
+  if (updatedGuest.bathroom > BATHROOM_NEED) happinessChange -= 0.2;
+  if (updatedGuest.bathroom >= 100) happinessChange -= BATHROOM_DESPERATE_PENALTY;
+  if (updatedGuest.nausea > QUEASY_NAUSEA) happinessChange -= 0.1;
+  
+  // Guests speak up as a need crosses the point where it starts costing them happiness
+  if (guest.hunger <= HUNGRY_NEED && updatedGuest.hunger > HUNGRY_NEED) addGuestThought(updatedGuest, 'hungry');
+  if (guest.thirst <= THIRSTY_NEED && updatedGuest.thirst > THIRSTY_NEED) addGuestThought(updatedGuest, 'thirsty');
+  if (guest.bathroom <= BATHROOM_NEED && updatedGuest.bathroom > BATHROOM_NEED) addGuestThought(updatedGuest, 'need_bathroom');
+  if (guest.energy >= TIRED_ENERGY && updatedGuest.energy < TIRED_ENERGY) addGuestThought(updatedGuest, 'tired');
   
   updatedGuest.happiness = Math.max(0, Math.min(100, updatedGuest.happiness + happinessChange * deltaTime));
   
@@ -451,25 +1350,63 @@ export function updateGuest(
   // Decision cooldown
   updatedGuest.decisionCooldown = Math.max(0, updatedGuest.decisionCooldown - deltaTime);
 
-  // Handle queuing/riding
-  if (updatedGuest.state === 'queuing' || updatedGuest.state === 'riding') {
+  // Queuing guests wait for the ride to dispatch them (see dispatch in the simulation tick);
+  // they give up if the ride goes away or their patience (queueTimer) runs out
+  if (updatedGuest.state === 'queuing') {
+    updatedGuest.queueTimer -= deltaTime;
+    const rideId = updatedGuest.queueRideId;
+    if (rideId) shuffleForwardInLine(updatedGuest, grid, rideId);
+    const rideGone = !rideId || !isRideStillOpen(grid, rideId, coasters);
+    if (rideGone || updatedGuest.queueTimer <= 0) {
+      if (!rideGone) {
+        updatedGuest.happiness = Math.max(0, updatedGuest.happiness - QUEUE_BALK_HAPPINESS_PENALTY);
+        addGuestThought(updatedGuest, 'queue_too_long');
+      }
+      updatedGuest.state = 'walking';
+      updatedGuest.queueRideId = null;
+      updatedGuest.targetBuildingId = null;
+      updatedGuest.targetBuildingKind = null;
+      updatedGuest.queueTimer = 0;
+      updatedGuest.decisionCooldown = 5 + simulationRandom() * 10;
+    }
+    updatedGuest.lastState = previousState;
+    return updatedGuest;
+  }
+
+  // Handle riding
+  if (updatedGuest.state === 'riding') {
     updatedGuest.queueTimer -= deltaTime;
     if (updatedGuest.queueTimer <= 0) {
-      if (updatedGuest.state === 'queuing') {
-        updatedGuest.state = 'riding';
-        updatedGuest.queueTimer = 10 + Math.random() * 20;
-        updatedGuest.happiness = Math.min(100, updatedGuest.happiness + 8);
-      } else {
-        updatedGuest.state = 'walking';
-        if (updatedGuest.queueRideId) {
-          updatedGuest.ridesRidden.push(updatedGuest.queueRideId);
+      updatedGuest.state = 'walking';
+      if (updatedGuest.queueRideId) {
+        updatedGuest.ridesRidden.push(updatedGuest.queueRideId);
+        const profile = getRideProfileForGuest(grid, updatedGuest.queueRideId, coasters);
+        if (profile) {
+          applyRideProfile(updatedGuest, profile);
         }
-        updatedGuest.queueRideId = null;
-        updatedGuest.targetBuildingId = null;
-        updatedGuest.targetBuildingKind = null;
-        updatedGuest.queueTimer = 0;
-        updatedGuest.nausea = Math.min(100, updatedGuest.nausea + 5 + Math.random() * 5);
       }
+      updatedGuest.queueRideId = null;
+      updatedGuest.targetBuildingId = null;
+      updatedGuest.targetBuildingKind = null;
+      updatedGuest.queueTimer = 0;
+    }
+    updatedGuest.lastState = previousState;
+    return updatedGuest;
+  }
+
+  // Resting on a bench: energy comes back (and nausea fades) until the guest moves on
+  if (updatedGuest.state === 'sitting') {
+    updatedGuest.queueTimer -= deltaTime;
+    updatedGuest.energy = Math.min(100, updatedGuest.energy + REST_ENERGY_PER_TICK * deltaTime);
+    updatedGuest.happiness = Math.min(100, updatedGuest.happiness + REST_HAPPINESS_PER_TICK * deltaTime);
+    updatedGuest.nausea = Math.max(0, updatedGuest.nausea - REST_NAUSEA_PER_TICK * deltaTime);
+    const [benchX, benchY] = (updatedGuest.targetBuildingId ?? '').split(',').map(Number);
+    const benchGone = !isBenchBuilding(grid[benchY]?.[benchX]?.building?.type);
+    if (updatedGuest.queueTimer <= 0 || benchGone) {
+      updatedGuest.state = 'walking';
+      updatedGuest.targetBuildingId = null;
+      updatedGuest.targetBuildingKind = null;
+      updatedGuest.queueTimer = 0;
     }
     updatedGuest.lastState = previousState;
     return updatedGuest;
@@ -486,63 +1423,65 @@ export function updateGuest(
       updatedGuest.initialActivityTime = updatedGuest.queueTimer + 1;
     }
     
-    // Proportional walk animation: first 15% of time walking in, last 15% walking out
-    const totalTime = updatedGuest.initialActivityTime;
-    const elapsed = totalTime - updatedGuest.queueTimer;
-    const remaining = updatedGuest.queueTimer;
-    const walkFraction = 0.15; // 15% of activity time for walk in/out
-    const walkTime = Math.max(3, totalTime * walkFraction); // At least 3 game minutes
-    
-    if (elapsed < walkTime) {
-      // Walking in (0 -> 1)
-      updatedGuest.approachProgress = elapsed / walkTime;
-    } else if (remaining < walkTime && remaining > 0) {
-      // Walking out (1 -> 0)
-      updatedGuest.approachProgress = remaining / walkTime;
-    } else if (remaining <= 0) {
-      // Done - back at path
-      updatedGuest.approachProgress = 0;
-    } else {
-      // Inside building
-      updatedGuest.approachProgress = 1;
-    }
+    // Walk in over the first STALL_WALK_TIME minutes, then stay at the stall
+    const elapsed = updatedGuest.initialActivityTime - updatedGuest.queueTimer;
+    updatedGuest.approachProgress = Math.min(1, elapsed / STALL_WALK_TIME);
     
     if (updatedGuest.queueTimer <= 0) {
       if (updatedGuest.state === 'eating') {
-        updatedGuest.hunger = Math.max(0, updatedGuest.hunger - 60);
-        updatedGuest.thirst = Math.max(0, updatedGuest.thirst - 40);
+        const [stallX, stallY] = (updatedGuest.targetBuildingId ?? '').split(',').map(Number);
+        const profile = getFoodProfile(grid[stallY]?.[stallX]?.building?.type ?? '');
+        updatedGuest.hunger = Math.max(0, updatedGuest.hunger - profile.hunger);
+        updatedGuest.thirst = Math.max(0, updatedGuest.thirst - profile.thirst);
+        updatedGuest.nausea = Math.max(0, updatedGuest.nausea - FOOD_NAUSEA_RELIEF);
         updatedGuest.happiness = Math.min(100, updatedGuest.happiness + 6);
+      } else if (updatedGuest.targetBuildingKind === 'restroom') {
+        updatedGuest.bathroom = simulationRandom() * 5;
+        updatedGuest.happiness = Math.min(100, updatedGuest.happiness + BATHROOM_RELIEF_HAPPINESS);
       } else {
         updatedGuest.happiness = Math.min(100, updatedGuest.happiness + 4);
       }
       // Transition to exiting_building for walk-out animation
       updatedGuest.state = 'exiting_building';
-      updatedGuest.activityStartTime = Date.now(); // Reset for exit animation
-      updatedGuest.queueTimer = 0;
+      updatedGuest.queueTimer = STALL_WALK_TIME;
       updatedGuest.approachProgress = 1; // Start at building
     }
     updatedGuest.lastState = previousState;
     return updatedGuest;
   }
   
-  // Handle exiting_building state (walk-out animation)
+  // Handle exiting_building state (walk back out to the path)
   if (updatedGuest.state === 'exiting_building') {
-    const exitDuration = 800; // 800ms to walk out
-    const elapsed = Date.now() - (updatedGuest.activityStartTime || Date.now());
+    updatedGuest.queueTimer -= deltaTime;
+    updatedGuest.approachProgress = Math.max(0, updatedGuest.queueTimer / STALL_WALK_TIME);
     
-    if (elapsed >= exitDuration) {
+    if (updatedGuest.queueTimer <= 0) {
       // Exit animation complete, now walking
       updatedGuest.state = 'walking';
       updatedGuest.targetBuildingId = null;
       updatedGuest.targetBuildingKind = null;
       updatedGuest.approachProgress = 0;
       updatedGuest.initialActivityTime = 0;
-      updatedGuest.activityStartTime = 0;
     }
     updatedGuest.lastState = previousState;
     return updatedGuest;
   }
 
+  // Head home when unhappy, exhausted, or the park is closing
+  if ((updatedGuest.state === 'walking' || updatedGuest.state === 'entering') && shouldGuestHeadHome(updatedGuest, parkClosed)) {
+    updatedGuest = sendGuestHome(updatedGuest, grid);
+  }
+  
+  // Leaving guests without a route (none reachable yet) keep looking for an exit
+  if (
+    updatedGuest.state === 'leaving' &&
+    updatedGuest.path.length === 0 &&
+    updatedGuest.decisionCooldown <= 0 &&
+    !isParkExitTile(grid, updatedGuest.tileX, updatedGuest.tileY)
+  ) {
+    updatedGuest = sendGuestHome(updatedGuest, grid);
+  }
+  
   // Seek rides if idle
   if ((updatedGuest.state === 'walking' || updatedGuest.state === 'entering') && updatedGuest.path.length === 0 && !updatedGuest.queueRideId && !updatedGuest.targetBuildingId) {
     if (updatedGuest.decisionCooldown <= 0) {
@@ -550,43 +1489,76 @@ export function updateGuest(
       let targetKind: Guest['targetBuildingKind'] = null;
       
       // Random activity selection with weighted probabilities
-      const roll = Math.random();
+      const roll = simulationRandom();
       const isHungry = updatedGuest.hunger > 50 || updatedGuest.thirst > 50;
+      // Badly nauseous guests stay off the rides and look for something to settle their stomach
+      const isNauseous = isGuestNauseous(updatedGuest);
+      // Shop prices are fixed, so guests only browse when they can spare the price of an item
+      const willShop = canSpareCash(updatedGuest, DEFAULT_PRICES.shopItem);
+      
+      // A pressing bathroom need comes before anything else (the need_bathroom
+      // thought comes from the needs update once it gets urgent)
+      if (updatedGuest.bathroom > BATHROOM_SEEK_THRESHOLD) {
+        destination = findRestroomDestination(grid, updatedGuest);
+        targetKind = 'restroom';
+      }
       
-      if (isHungry) {
+      // Worn-out or queasy guests take a seat; a snack settles the stomach if there's no bench
+      if (!destination && (updatedGuest.energy < TIRED_ENERGY || isNauseous)) {
+        destination = findBenchDestination(grid, updatedGuest);
+        targetKind = 'rest';
+      }
+      
+      // Group members mostly tag along with their leader rather than choosing for themselves
+      const leader = updatedGuest.groupId && updatedGuest.groupId !== updatedGuest.id
+        ? groupLeaders?.get(updatedGuest.groupId)
+        : undefined;
+      if (
+        !destination && leader?.targetBuildingKind &&
+        !(isNauseous && leader.targetBuildingKind === 'ride') &&
+        simulationRandom() < GROUP_FOLLOW_CHANCE
+      ) {
+        destination = findGroupDestination(grid, updatedGuest, leader, coasters);
+        targetKind = leader.targetBuildingKind;
+      }
+      
+      if (!destination && isNauseous) {
+        destination = findFoodDestination(grid, updatedGuest, soldOut);
+        targetKind = 'food';
+      } else if (!destination && isHungry) {
         // When hungry, 70% food, 30% shop (browsing while looking for food)
-        if (roll < 0.7) {
-          destination = findFoodDestination(grid, updatedGuest);
+        if (roll < 0.7 || !willShop) {
+          destination = findFoodDestination(grid, updatedGuest, soldOut);
           targetKind = 'food';
         } else {
-          destination = findShopDestination(grid, updatedGuest);
+          destination = findShopDestination(grid, updatedGuest, soldOut);
           targetKind = 'shop';
         }
-      } else {
+      } else if (!destination) {
         // When not hungry: 40% shop, 40% ride, 20% food (snack)
-        if (roll < 0.4) {
-          destination = findShopDestination(grid, updatedGuest);
+        if (roll < 0.4 && willShop) {
+          destination = findShopDestination(grid, updatedGuest, soldOut);
           targetKind = 'shop';
-        } else if (roll < 0.8) {
-          destination = findRideDestination(grid, updatedGuest);
+        } else if (roll < 0.8 && !isNauseous) {
+          destination = findRideDestination(grid, updatedGuest, coasters, payPerRide);
           targetKind = 'ride';
         } else {
-          destination = findFoodDestination(grid, updatedGuest);
+          destination = findFoodDestination(grid, updatedGuest, soldOut);
           targetKind = 'food';
         }
       }
       
       // If first choice not found, try alternatives
-      if (!destination && targetKind !== 'ride') {
-        destination = findRideDestination(grid, updatedGuest);
+      if (!destination && targetKind !== 'ride' && !isNauseous) {
+        destination = findRideDestination(grid, updatedGuest, coasters, payPerRide);
         targetKind = 'ride';
       }
-      if (!destination && targetKind !== 'shop') {
-        destination = findShopDestination(grid, updatedGuest);
+      if (!destination && targetKind !== 'shop' && willShop) {
+        destination = findShopDestination(grid, updatedGuest, soldOut);
         targetKind = 'shop';
       }
       if (!destination && targetKind !== 'food') {
-        destination = findFoodDestination(grid, updatedGuest);
+        destination = findFoodDestination(grid, updatedGuest, soldOut);
         targetKind = 'food';
       }
       
@@ -597,18 +1569,17 @@ export function updateGuest(
         updatedGuest.targetBuildingId = destination.buildingId;
         updatedGuest.targetBuildingKind = targetKind;
         updatedGuest.state = 'walking';
-        updatedGuest.decisionCooldown = 60 + Math.random() * 90;
+        updatedGuest.decisionCooldown = 60 + simulationRandom() * 90;
         assignPath(updatedGuest, destination.path);
       } else {
-        updatedGuest.decisionCooldown = 30 + Math.random() * 60;
+        updatedGuest.decisionCooldown = 30 + simulationRandom() * 60;
       }
     }
   }
   
   // Movement
-  if (updatedGuest.state === 'walking' || updatedGuest.state === 'entering') {
-    const speed = 0.02; // Progress per tick
-    updatedGuest.progress += speed;
+  if (updatedGuest.state === 'walking' || updatedGuest.state === 'entering' || updatedGuest.state === 'leaving') {
+    updatedGuest.progress += GUEST_WALK_SPEED;
     
     if (updatedGuest.progress >= 1) {
       // Reached target tile
@@ -616,27 +1587,69 @@ export function updateGuest(
       updatedGuest.tileY = updatedGuest.targetTileY;
       updatedGuest.progress = 0;
       
-      // Get next waypoint from path
-      if (updatedGuest.path.length > 0 && updatedGuest.pathIndex < updatedGuest.path.length) {
+      // Get next waypoint from path (guests bound for a ride stop once they're in its line)
+      const joinsLine = hasReachedRideLine(grid, updatedGuest);
+      if (!joinsLine && updatedGuest.path.length > 0 && updatedGuest.pathIndex < updatedGuest.path.length) {
         const next = updatedGuest.path[updatedGuest.pathIndex];
+        faceToward(updatedGuest, next);
         updatedGuest.targetTileX = next.x;
         updatedGuest.targetTileY = next.y;
         updatedGuest.pathIndex++;
-        
-        // Update direction
-        const dx = next.x - updatedGuest.tileX;
-        const dy = next.y - updatedGuest.tileY;
-        if (dx > 0) updatedGuest.direction = 'south';
-        else if (dx < 0) updatedGuest.direction = 'north';
-        else if (dy > 0) updatedGuest.direction = 'west';
-        else if (dy < 0) updatedGuest.direction = 'east';
       } else {
         // Path complete
+        if (updatedGuest.state === 'leaving') {
+          // At the gate the guest waits to be removed; anywhere else they re-route next tick
+          updatedGuest.path = [];
+          updatedGuest.pathIndex = 0;
+          updatedGuest.targetTileX = updatedGuest.tileX;
+          updatedGuest.targetTileY = updatedGuest.tileY;
+          updatedGuest.decisionCooldown = 0;
+          updatedGuest.lastState = previousState;
+          return updatedGuest;
+        }
         if (updatedGuest.targetBuildingKind) {
+          // The stall sold out while they were on the way - disappointed, they look elsewhere
+          const isStall = updatedGuest.targetBuildingKind === 'food' || updatedGuest.targetBuildingKind === 'shop';
+          if (isStall && updatedGuest.targetBuildingId && soldOut?.has(updatedGuest.targetBuildingId)) {
+            updatedGuest.happiness = Math.max(0, updatedGuest.happiness - SOLD_OUT_HAPPINESS_PENALTY);
+            addGuestThought(updatedGuest, 'sold_out');
+            updatedGuest.state = 'walking';
+            updatedGuest.targetBuildingId = null;
+            updatedGuest.targetBuildingKind = null;
+            updatedGuest.decisionCooldown = 5 + simulationRandom() * 10;
+            updatedGuest.path = [];
+            updatedGuest.pathIndex = 0;
+            updatedGuest.targetTileX = updatedGuest.tileX;
+            updatedGuest.targetTileY = updatedGuest.tileY;
+            updatedGuest.lastState = previousState;
+            return updatedGuest;
+          }
           if (updatedGuest.targetBuildingKind === 'ride') {
+            const rideId = updatedGuest.queueRideId ?? updatedGuest.targetBuildingId;
+            if (queues && rideId && !hasQueueRoom(grid, rideId, queues)) {
+              // Line is full - give up on this ride and pick something else
+              queues.balks.set(rideId, (queues.balks.get(rideId) ?? 0) + 1);
+              updatedGuest.happiness = Math.max(0, updatedGuest.happiness - QUEUE_BALK_HAPPINESS_PENALTY);
+              addGuestThought(updatedGuest, 'queue_too_long');
+              updatedGuest.state = 'walking';
+              updatedGuest.queueRideId = null;
+              updatedGuest.targetBuildingId = null;
+              updatedGuest.targetBuildingKind = null;
+              updatedGuest.decisionCooldown = 5 + simulationRandom() * 10;
+              updatedGuest.path = [];
+              updatedGuest.pathIndex = 0;
+              updatedGuest.targetTileX = updatedGuest.tileX;
+              updatedGuest.targetTileY = updatedGuest.tileY;
+              updatedGuest.lastState = previousState;
+              return updatedGuest;
+            }
+            if (queues && rideId) {
+              queues.queueLengths.set(rideId, (queues.queueLengths.get(rideId) ?? 0) + 1);
+            }
             updatedGuest.state = 'queuing';
-            updatedGuest.queueTimer = 30 + Math.random() * 60;
-            updatedGuest.queuePosition = 0;
+            updatedGuest.queueTimer = QUEUE_PATIENCE_MIN + simulationRandom() * QUEUE_PATIENCE_RANGE;
+            // Back of the line until the simulation confirms the order
+            updatedGuest.queuePosition = queues && rideId ? (queues.queueLengths.get(rideId) ?? 1) - 1 : 0;
             updatedGuest.path = [];
             updatedGuest.pathIndex = 0;
             updatedGuest.targetTileX = updatedGuest.tileX;
@@ -646,23 +1659,31 @@ export function updateGuest(
           }
           if (updatedGuest.targetBuildingKind === 'food') {
             updatedGuest.state = 'eating';
-            const activityTime = 8 + Math.random() * 12;
-            updatedGuest.queueTimer = activityTime;
-            updatedGuest.initialActivityTime = activityTime;
+            const activityTime = 8 + simulationRandom() * 12;
+            updatedGuest.queueTimer = activityTime + STALL_WALK_TIME;
+            updatedGuest.initialActivityTime = activityTime + STALL_WALK_TIME;
             updatedGuest.approachProgress = 0;
-            updatedGuest.activityStartTime = Date.now();
             updatedGuest.path = [];
             updatedGuest.pathIndex = 0;
             updatedGuest.lastState = previousState;
             return updatedGuest;
           }
-          if (updatedGuest.targetBuildingKind === 'shop') {
+          if (updatedGuest.targetBuildingKind === 'rest') {
+            updatedGuest.state = 'sitting';
+            updatedGuest.queueTimer = REST_TIME_MIN + simulationRandom() * REST_TIME_RANGE;
+            updatedGuest.path = [];
+            updatedGuest.pathIndex = 0;
+            updatedGuest.targetTileX = updatedGuest.tileX;
+            updatedGuest.targetTileY = updatedGuest.tileY;
+            updatedGuest.lastState = previousState;
+            return updatedGuest;
+          }
+          if (updatedGuest.targetBuildingKind === 'shop' || updatedGuest.targetBuildingKind === 'restroom') {
             updatedGuest.state = 'shopping';
-            const activityTime = 6 + Math.random() * 10;
-            updatedGuest.queueTimer = activityTime;
-            updatedGuest.initialActivityTime = activityTime;
+            const activityTime = 6 + simulationRandom() * 10;
+            updatedGuest.queueTimer = activityTime + STALL_WALK_TIME;
+            updatedGuest.initialActivityTime = activityTime + STALL_WALK_TIME;
             updatedGuest.approachProgress = 0;
-            updatedGuest.activityStartTime = Date.now();
             updatedGuest.path = [];
             updatedGuest.pathIndex = 0;
             updatedGuest.lastState = previousState;
@@ -690,8 +1711,16 @@ export function updateGuest(
           return grid[ny][nx].path || grid[ny][nx].queue;
         });
         
-        if (validDirs.length > 0) {
-          const dir = validDirs[Math.floor(Math.random() * validDirs.length)];
+        // Prefer stepping somewhere that isn't already packed (see getGuestOccupancy)
+        const openDirs = occupancy
+          ? validDirs.filter(dir =>
+            occupancy[(updatedGuest.tileY + dir.dy) * grid.length + updatedGuest.tileX + dir.dx] < CROWDED_TILE_GUESTS
+          )
+          : validDirs;
+        const choices = openDirs.length > 0 ? openDirs : validDirs;
+        
+        if (choices.length > 0) {
+          const dir = choices[Math.floor(simulationRandom() * choices.length)];
           updatedGuest.targetTileX = updatedGuest.tileX + dir.dx;
           updatedGuest.targetTileY = updatedGuest.tileY + dir.dy;
         }
@@ -714,52 +1743,153 @@ export function spawnGuests(
   grid: Tile[][],
   currentGuests: Guest[],
   parkRating: number,
-  hour: number
+  hour: number,
+  maxGuests: number = DEFAULT_MAX_GUESTS,
+  spawnRateMultiplier: number = DEFAULT_SPAWN_RATE_MULTIPLIER,
+  openHour: number = DEFAULT_OPEN_HOUR,
+  closeHour: number = DEFAULT_CLOSE_HOUR
 ): Guest[] {
-  // Don't spawn at night or if park is closed
-  if (hour < 9 || hour > 21) return [];
+  // Nobody arrives outside opening hours
+  if (!isParkOpenAt(hour, openHour, closeHour)) return [];
   
   // Calculate spawn rate based on park rating and time
   const baseRate = 0.02; // 2% chance per tick
   const ratingBonus = parkRating / 1000 * 0.03;
   const peakHourBonus = (hour >= 11 && hour <= 15) ? 0.02 : 0;
   
-  const spawnChance = baseRate + ratingBonus + peakHourBonus;
+  // Each arrival is a whole party, so scale by party size to keep guests per tick the same
+  const spawnChance = (baseRate + ratingBonus + peakHourBonus) * spawnRateMultiplier / EXPECTED_PARTY_SIZE;
   
   // Cap maximum guests
-  const maxGuests = 50000;
   if (currentGuests.length >= maxGuests) return [];
   
   const newGuests: Guest[] = [];
   
-  if (Math.random() < spawnChance) {
-    // Find entrance tiles (look for path tiles at grid edges - these have entrance gates)
-    const entranceTiles: { x: number; y: number }[] = [];
-    const gridSize = grid.length;
-    
-    // Check all four edges for path tiles - these are the entrance gate locations
-    for (let i = 0; i < gridSize; i++) {
-      // North edge (x=0) - check grid[y][0] for all y
-      if (grid[i]?.[0]?.path) entranceTiles.push({ x: 0, y: i });
-      // South edge (x=gridSize-1) - check grid[y][gridSize-1] for all y
-      if (grid[i]?.[gridSize - 1]?.path) entranceTiles.push({ x: gridSize - 1, y: i });
-      // East edge (y=0) - check grid[0][x] for all x
-      if (grid[0]?.[i]?.path) entranceTiles.push({ x: i, y: 0 });
-      // West edge (y=gridSize-1) - check grid[gridSize-1][x] for all x
-      if (grid[gridSize - 1]?.[i]?.path) entranceTiles.push({ x: i, y: gridSize - 1 });
-    }
-    
+  // Chances above 1 (high multipliers) let several guests arrive in one tick
+  let arrivals = Math.floor(spawnChance) + (simulationRandom() < spawnChance % 1 ? 1 : 0);
+  arrivals = Math.min(arrivals, maxGuests - currentGuests.length);
+  if (arrivals > 0) {
     // Only spawn at edge entrance tiles (with gates)
-    // Remove duplicates (corner tiles appear twice)
-    const uniqueEntrances = entranceTiles.filter((tile, index, self) =>
-      index === self.findIndex(t => t.x === tile.x && t.y === tile.y)
-    );
-    
-    if (uniqueEntrances.length > 0) {
-      const entrance = uniqueEntrances[Math.floor(Math.random() * uniqueEntrances.length)];
-      newGuests.push(createGuest(entrance.x, entrance.y, gridSize));
+    const entrances = findParkEntranceTiles(grid);
+    if (entrances.length > 0) {
+      for (let i = 0; i < arrivals; i++) {
+        const room = maxGuests - currentGuests.length - newGuests.length;
+        if (room <= 0) break;
+        const entrance = entrances[Math.floor(simulationRandom() * entrances.length)];
+        
+        // Families and groups come through the gate together, led by whoever arrives first
+        const partySize = simulationRandom() < GROUP_SPAWN_CHANCE
+          ? GROUP_MIN_SIZE + Math.floor(simulationRandom() * (GROUP_MAX_SIZE - GROUP_MIN_SIZE + 1))
+          : 1;
+        const party = Array.from({ length: Math.min(partySize, room) }, () => createGuest(entrance.x, entrance.y, grid.length));
+        if (party.length > 1) {
+          for (const member of party) member.groupId = party[0].id;
+        }
+        newGuests.push(...party);
+      }
     }
   }
   
   return newGuests;
 }
+
+// =============================================================================
+// GUEST SURVEY
+// =============================================================================
+
+const SURVEY_NEED_THRESHOLD = 50; // Need level at which a guest reports it
+const SURVEY_BATHROOM_THRESHOLD = 60;
+const SURVEY_HAPPY_THRESHOLD = 70;
+const SURVEY_BORED_RIDE_DISTANCE = 10; // Manhattan tiles to the nearest ride
+
+/**
+ * Randomly sample up to `sampleSize` guests and report the share reporting
+ * each need. Uses a partial Fisher-Yates shuffle so every guest is equally
+ * likely to be picked. With no guests every share is 0.
+ */
+export function sampleGuestSurvey(guests: Guest[], grid: Tile[][], sampleSize: number): GuestSurvey {
+  const count = Math.max(0, Math.min(Math.floor(sampleSize), guests.length));
+  const survey: GuestSurvey = {
+    sampleSize: count,
+    hungry: 0,
+    thirsty: 0,
+    needBathroom: 0,
+    nauseous: 0,
+    bored: 0,
+    happy: 0,
+  };
+  if (count === 0) return survey;
+  
+  const rideTiles: { x: number; y: number }[] = [];
+  for (let y = 0; y < grid.length; y++) {
+    for (let x = 0; x < grid.length; x++) {
+      const type = grid[y][x].building?.type;
+      if (type && isRideBuilding(type) && getBuildingAccessTile(grid, x, y, 'queue')) rideTiles.push({ x, y });
+    }
+  }
+  
+  const indices = guests.map((_, index) => index);
+  for (let i = 0; i < count; i++) {
+    const j = i + Math.floor(simulationRandom() * (indices.length - i));
+    [indices[i], indices[j]] = [indices[j], indices[i]];
+    const guest = guests[indices[i]];
+    
+    if (guest.hunger > SURVEY_NEED_THRESHOLD) survey.hungry++;
+    if (guest.thirst > SURVEY_NEED_THRESHOLD) survey.thirsty++;
+    if (guest.bathroom > SURVEY_BATHROOM_THRESHOLD) survey.needBathroom++;
+    if (guest.nausea > SURVEY_NEED_THRESHOLD) survey.nauseous++;
+    if (guest.happiness >= SURVEY_HAPPY_THRESHOLD) survey.happy++;
+    const nearRide = rideTiles.some(tile =>
+      Math.abs(tile.x - guest.tileX) + Math.abs(tile.y - guest.tileY) <= SURVEY_BORED_RIDE_DISTANCE
+    );
+    if (!nearRide) survey.bored++;
+  }
+  
+  survey.hungry /= count;
+  survey.thirsty /= count;
+  survey.needBathroom /= count;
+  survey.nauseous /= count;
+  survey.bored /= count;
+  survey.happy /= count;
+  return survey;
+}
+
+// =============================================================================
+// CROWD HOTSPOTS
+// =============================================================================
+
+export interface CrowdHotspot {
+  x: number;
+  y: number;
+  count: number;
+}
+
+/**
+ * Count guests standing on each tile (walking, entering or queuing).
+ * Indexed as y * gridSize + x.
+ */
+export function getGuestOccupancy(guests: Guest[], gridSize: number): Uint16Array {
+  const occupancy = new Uint16Array(gridSize * gridSize);
+  for (const guest of guests) {
+    if (guest.state !== 'walking' && guest.state !== 'entering' && guest.state !== 'queuing') continue;
+    if (guest.tileX < 0 || guest.tileY < 0 || guest.tileX >= gridSize || guest.tileY >= gridSize) continue;
+    const index = guest.tileY * gridSize + guest.tileX;
+    occupancy[index] = Math.min(0xffff, occupancy[index] + 1);
+  }
+  return occupancy;
+}
+
+/**
+ * Tiles where more than `threshold` guests are standing, busiest first.
+ * Empty when the park is sparse.
+ */
+export function findCrowdHotspots(guests: Guest[], gridSize: number, threshold: number): CrowdHotspot[] {
+  const occupancy = getGuestOccupancy(guests, gridSize);
+  const hotspots: CrowdHotspot[] = [];
+  for (let i = 0; i < occupancy.length; i++) {
+    if (occupancy[i] > threshold) {
+      hotspots.push({ x: i % gridSize, y: Math.floor(i / gridSize), count: occupancy[i] });
+    }
+  }
+  return hotspots.sort((a, b) => b.count - a.count);
+}
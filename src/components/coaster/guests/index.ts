@@ -3,3 +3,4 @@
  */
 
 export * from './guestSystem';
+export * from './pathCache';
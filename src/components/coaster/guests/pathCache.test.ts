@@ -0,0 +1,67 @@
+import { beforeEach, describe, it } from 'node:test';
+import assert from 'node:assert/strict';
+import { Tile, createEmptyTile } from '@/games/coaster/types';
+import { findPathAStar, findPathCached } from './guestSystem';
+import { clearPathCache, getCachedPath } from './pathCache';
+
+const GRID_SIZE = 5;
+const START = { x: 0, y: 2 };
+const TARGET = { x: 4, y: 2 };
+
+/**
+ * A path from (0, 2) to (4, 2) that bends around a gap at (2, 2)
+ */
+function buildDetourGrid(): Tile[][] {
+  const grid = Array.from({ length: GRID_SIZE }, (_, y) => Array.from({ length: GRID_SIZE }, (_, x) => createEmptyTile(x, y)));
+  for (const [x, y] of [[0, 2], [1, 2], [1, 1], [2, 1], [3, 1], [3, 2], [4, 2]]) {
+    grid[y][x].path = true;
+  }
+  return grid;
+}
+
+/**
+ * A copy of the grid (as an edit would produce) with one tile's path toggled
+ */
+function togglePath(grid: Tile[][], x: number, y: number): Tile[][] {
+  const next = grid.map(row => row.map(tile => ({ ...tile })));
+  next[y][x].path = !next[y][x].path;
+  return next;
+}
+
+function lookup(grid: Tile[][]) {
+  return getCachedPath(grid, START.x, START.y, TARGET.x, TARGET.y, 100);
+}
+
+describe('path cache', () => {
+  beforeEach(() => clearPathCache());
+
+  it('serves the same route as an uncached search', () => {
+    const grid = buildDetourGrid();
+    assert.equal(lookup(grid), null);
+
+    findPathCached(grid, START.x, START.y, TARGET.x, TARGET.y);
+
+    assert.deepEqual(lookup(grid), findPathAStar(grid, START.x, START.y, TARGET.x, TARGET.y));
+  });
+
+  it('drops the route when a path edit could shorten it', () => {
+    const grid = buildDetourGrid();
+    findPathCached(grid, START.x, START.y, TARGET.x, TARGET.y);
+
+    assert.equal(lookup(togglePath(grid, 2, 2)), null);
+  });
+
+  it('drops the route when one of its tiles is removed', () => {
+    const grid = buildDetourGrid();
+    findPathCached(grid, START.x, START.y, TARGET.x, TARGET.y);
+
+    assert.equal(lookup(togglePath(grid, 2, 1)), null);
+  });
+
+  it('keeps the route across edits too far away to affect it', () => {
+    const grid = buildDetourGrid();
+    const path = findPathCached(grid, START.x, START.y, TARGET.x, TARGET.y);
+
+    assert.deepEqual(lookup(togglePath(grid, 0, 4)), path);
+  });
+});
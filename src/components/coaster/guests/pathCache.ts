@@ -0,0 +1,174 @@
+/**
+ * Path Cache for IsoCoaster guests
+ * LRU cache of guest routes keyed by (start, target) so guests leaving the
+ * same tile for the same popular building don't repeat full searches.
+ * Edits that don't touch the walkable network keep every cached route; adding
+ * or removing a path/queue tile only drops the routes it could affect.
+ */
+
+import { Tile } from '@/games/coaster/types/game';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+const PATH_CACHE_CAPACITY = 512; // Max cached routes before evicting least recently used
+
+// =============================================================================
+// CACHE STATE
+// =============================================================================
+
+interface PathCacheEntry {
+  generation: number;
+  path: { x: number; y: number }[];
+}
+
+// One cache per page, not per park: it follows whichever grid it saw last.
+// Handing it another park's grid (e.g. a benchmark copy) invalidates around
+// every tile whose walkability differs, so a route is only ever served where
+// it is still shortest, but alternating parks throws most entries away.
+// Map iteration order doubles as LRU order (oldest first)
+const pathCache = new Map<string, PathCacheEntry>();
+let pathCacheGeneration = 0;
+let pathCacheGrid: Tile[][] | null = null;
+let pathCacheWalkable: Uint8Array | null = null; // Walkable tiles as of the last grid seen
+
+function cacheKey(startX: number, startY: number, targetX: number, targetY: number): string {
+  return `${startX},${startY}>${targetX},${targetY}`;
+}
+
+/**
+ * Invalidate every cached route (call after paths or buildings change).
+ * Entries from older generations are ignored and evicted lazily.
+ */
+export function invalidatePathCache(): void {
+  pathCacheGeneration++;
+}
+
+/**
+ * Current cache generation (bumped on every invalidation)
+ */
+export function getPathCacheGeneration(): number {
+  return pathCacheGeneration;
+}
+
+/**
+ * Drop every cached route and forget the last grid seen
+ */
+export function clearPathCache(): void {
+  pathCache.clear();
+  pathCacheGrid = null;
+  pathCacheWalkable = null;
+}
+
+/**
+ * Drop cached routes that a walkability change at (x, y) could affect.
+ * A route of L steps only uses tiles p with |s-p| + |p-t| <= L (Manhattan), and
+ * any new shortcut through p must be shorter than L, so routes where that sum
+ * exceeds L can neither lose a tile nor gain a shorter path from this edit.
+ */
+export function invalidatePathCacheAt(x: number, y: number): void {
+  for (const [key, entry] of pathCache) {
+    const start = entry.path[0];
+    const target = entry.path[entry.path.length - 1];
+    const steps = entry.path.length - 1;
+    const detour = Math.abs(start.x - x) + Math.abs(start.y - y) + Math.abs(target.x - x) + Math.abs(target.y - y);
+    if (detour <= steps) pathCache.delete(key);
+  }
+}
+
+function readWalkable(grid: Tile[][]): Uint8Array {
+  const gridSize = grid.length;
+  const walkable = new Uint8Array(gridSize * gridSize);
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      const tile = grid[y][x];
+      walkable[y * gridSize + x] = tile.path || tile.queue ? 1 : 0;
+    }
+  }
+  return walkable;
+}
+
+/**
+ * Any edit to the park produces a new grid. Compare its walkable tiles with the
+ * previous grid and invalidate only around the tiles that changed.
+ */
+function syncPathCacheGrid(grid: Tile[][]): void {
+  if (grid === pathCacheGrid) return;
+  pathCacheGrid = grid;
+
+  const walkable = readWalkable(grid);
+  const previous = pathCacheWalkable;
+  pathCacheWalkable = walkable;
+  if (!previous || previous.length !== walkable.length) {
+    invalidatePathCache();
+    return;
+  }
+
+  const gridSize = grid.length;
+  for (let i = 0; i < walkable.length; i++) {
+    if (walkable[i] !== previous[i]) {
+      invalidatePathCacheAt(i % gridSize, Math.floor(i / gridSize));
+    }
+  }
+}
+
+// =============================================================================
+// LOOKUP
+// =============================================================================
+
+/**
+ * Look up a cached route from (startX, startY) to the target.
+ * Only a route searched from the exact same start is served, so every hit is
+ * as short as a fresh search. Returns null on a miss.
+ */
+export function getCachedPath(
+  grid: Tile[][],
+  startX: number,
+  startY: number,
+  targetX: number,
+  targetY: number,
+  maxSteps: number
+): { x: number; y: number }[] | null {
+  syncPathCacheGrid(grid);
+
+  const key = cacheKey(startX, startY, targetX, targetY);
+  const entry = pathCache.get(key);
+  if (!entry) return null;
+
+  if (entry.generation !== pathCacheGeneration) {
+    pathCache.delete(key);
+    return null;
+  }
+
+  // Refresh LRU position
+  pathCache.delete(key);
+  pathCache.set(key, entry);
+
+  if (entry.path.length > maxSteps + 1) return null;
+  return entry.path.map(tile => ({ ...tile }));
+}
+
+/**
+ * Store a freshly searched route, evicting the least recently used entries
+ */
+export function storeCachedPath(
+  grid: Tile[][],
+  startX: number,
+  startY: number,
+  targetX: number,
+  targetY: number,
+  path: { x: number; y: number }[]
+): void {
+  if (path.length === 0) return;
+  syncPathCacheGrid(grid);
+
+  const key = cacheKey(startX, startY, targetX, targetY);
+  pathCache.delete(key);
+  pathCache.set(key, { generation: pathCacheGeneration, path: path.map(tile => ({ ...tile })) });
+  while (pathCache.size > PATH_CACHE_CAPACITY) {
+    const oldestKey = pathCache.keys().next().value;
+    if (oldestKey === undefined) break;
+    pathCache.delete(oldestKey);
+  }
+}
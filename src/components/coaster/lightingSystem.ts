@@ -4,7 +4,8 @@
  */
 
 import { useEffect } from 'react';
-import { Tile } from '@/games/coaster/types';
+import { Tile, WeatherType } from '@/games/coaster/types';
+import { getWeatherTint } from '@/games/coaster/lib/weather';
 
 // =============================================================================
 // CONSTANTS
@@ -59,6 +60,40 @@ export function getAmbientColor(hour: number): { r: number; g: number; b: number
   return { r: 10, g: 15, b: 40 };
 }
 
+const NIGHT_TINT = { r: 0, g: 5, b: 20 };
+const TWILIGHT_TINT = { r: 255, g: 140, b: 60 };
+
+/**
+ * Full-screen tint for a (fractional) hour: clear at midday, warm orange
+ * through dawn and dusk, deep blue at night
+ */
+export function getSkyTint(hour: number): { r: number; g: number; b: number; alpha: number } {
+  const darkness = getDarkness(hour);
+  // Twilight peaks halfway through the dawn/dusk transitions
+  const twilight = 1 - Math.abs(darkness * 2 - 1);
+  const mix = (night: number, warm: number) => Math.round(night + (warm - night) * twilight);
+  return {
+    r: mix(NIGHT_TINT.r, TWILIGHT_TINT.r),
+    g: mix(NIGHT_TINT.g, TWILIGHT_TINT.g),
+    b: mix(NIGHT_TINT.b, TWILIGHT_TINT.b),
+    alpha: darkness < 0.01 ? 0 : darkness * 0.25 + twilight * 0.1,
+  };
+}
+
+/**
+ * Fill the whole canvas with a translucent color in device space
+ * (ignores and resets any zoom/pan transform)
+ */
+export function fillScreenTint(
+  ctx: CanvasRenderingContext2D,
+  color: { r: number; g: number; b: number },
+  alpha: number
+): void {
+  ctx.setTransform(1, 0, 0, 1, 0, 0);
+  ctx.fillStyle = `rgba(${color.r}, ${color.g}, ${color.b}, ${alpha})`;
+  ctx.fillRect(0, 0, ctx.canvas.width, ctx.canvas.height);
+}
+
 /**
  * Deterministic pseudo-random function for consistent lighting patterns
  */
@@ -94,10 +129,12 @@ export interface CoasterLightingConfig {
   grid: Tile[][];
   gridSize: number;
   hour: number;
+  minute?: number; // Smooths dawn and dusk between whole hours
   offset: { x: number; y: number };
   zoom: number;
   canvasWidth: number;
   canvasHeight: number;
+  weather?: WeatherType; // Greys the scene out in rain and storms when set
 }
 
 // =============================================================================
@@ -385,10 +422,12 @@ export function useCoasterLightingSystem(config: CoasterLightingConfig): void {
     grid,
     gridSize,
     hour,
+    minute = 0,
     offset,
     zoom,
     canvasWidth,
     canvasHeight,
+    weather,
   } = config;
 
   useEffect(() => {
@@ -398,22 +437,24 @@ export function useCoasterLightingSystem(config: CoasterLightingConfig): void {
     if (!ctx) return;
     
     const dpr = window.devicePixelRatio || 1;
-    const darkness = getDarkness(hour);
+    const visualHour = hour + minute / 60;
+    const darkness = getDarkness(visualHour);
     
     // Clear canvas first
     ctx.setTransform(1, 0, 0, 1, 0, 0);
     ctx.clearRect(0, 0, canvas.width, canvas.height);
     
-    // If it's full daylight, just clear and return
-    if (darkness <= 0.01) return;
+    const weatherTint = weather ? getWeatherTint(weather) : null;
+    if (weatherTint) {
+      fillScreenTint(ctx, weatherTint, weatherTint.alpha);
+    }
     
-    const ambient = getAmbientColor(hour);
+    // If it's full daylight, the weather tint is all there is
+    if (darkness <= 0.01) return;
     
-    // Apply darkness overlay - use very low alpha to avoid washed out look
-    // Use pure dark blue with minimal opacity
-    const alpha = darkness * 0.25;
-    ctx.fillStyle = `rgba(0, 5, 20, ${alpha})`;
-    ctx.fillRect(0, 0, canvas.width, canvas.height);
+    // Apply the sky tint - low alpha to avoid a washed out look
+    const tint = getSkyTint(visualHour);
+    fillScreenTint(ctx, tint, tint.alpha);
     
     // Calculate viewport bounds
     const viewWidth = canvas.width / (dpr * zoom);
@@ -462,5 +503,5 @@ export function useCoasterLightingSystem(config: CoasterLightingConfig): void {
     ctx.restore();
     ctx.globalCompositeOperation = 'source-over';
     
-  }, [canvasRef, grid, gridSize, hour, offset, zoom, canvasWidth, canvasHeight]);
+  }, [canvasRef, grid, gridSize, hour, minute, offset, zoom, canvasWidth, canvasHeight, weather]);
 }
@@ -60,12 +60,12 @@ const SUBMENU_CATEGORIES: { key: string; label: string; tools: Tool[] }[] = [
   {
     key: 'paths',
     label: 'Paths',
-    tools: ['path', 'queue'],
+    tools: ['path', 'queue', 'auto_path'],
   },
   {
     key: 'terrain',
     label: 'Terrain',
-    tools: ['zone_water', 'zone_land'],
+    tools: ['zone_water', 'zone_land', 'terrain_raise', 'terrain_lower', 'terrain_sand', 'terrain_rock'],
   },
   {
     key: 'trees',
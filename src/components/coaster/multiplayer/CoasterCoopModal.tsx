@@ -14,7 +14,7 @@ import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { useMultiplayer } from '@/context/MultiplayerContext';
 import { GameState as CoasterGameState } from '@/games/coaster/types';
-import { createInitialCoasterGameState } from '@/context/CoasterContext';
+import { createInitialCoasterGameState } from '@/games/coaster/lib/simulation';
 import { useCopyRoomLink } from '@/hooks/useCopyRoomLink';
 import { Copy, Check, Loader2, AlertCircle, ArrowLeft } from 'lucide-react';
 import { T, useGT, Plural, Var } from 'gt-next';
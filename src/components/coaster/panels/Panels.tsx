@@ -1,7 +1,9 @@
 'use client';
 
-import React, { useState } from 'react';
+import React, { useMemo, useState } from 'react';
 import { useCoaster } from '@/context/CoasterContext';
+import { TOOL_INFO, getToolForBuilding } from '@/games/coaster/types';
+import { isRideBuilding } from '@/games/coaster/types/buildings';
 import { Card } from '@/components/ui/card';
 import { Button } from '@/components/ui/button';
 import { Switch } from '@/components/ui/switch';
@@ -33,6 +35,7 @@ function PanelWrapper({ title, children, onClose }: { title: string; children: R
 function FinancesPanel({ onClose }: { onClose: () => void }) {
   const { state } = useCoaster();
   const { finances } = state;
+  const lastReport = finances.reports[finances.reports.length - 1];
   
   return (
     <PanelWrapper title="Finances" onClose={onClose}>
@@ -69,6 +72,10 @@ function FinancesPanel({ onClose }: { onClose: () => void }) {
       </div>
       
       <div className="border-t border-slate-800 pt-3 space-y-2 text-sm">
+        <div className="flex items-center justify-between text-white/70">
+          <span>Construction</span>
+          <span className="text-red-300">{formatCurrency(finances.expenseConstruction)}</span>
+        </div>
         <div className="flex items-center justify-between text-white/70">
           <span>Upkeep</span>
           <span className="text-red-300">{formatCurrency(finances.expenseUpkeep)}</span>
@@ -79,6 +86,44 @@ function FinancesPanel({ onClose }: { onClose: () => void }) {
         </div>
       </div>
       
+      {lastReport && (
+        <div className="border-t border-slate-800 pt-3">
+          <div className="text-xs uppercase text-white/50 tracking-wide mb-2">
+            Last Month (Y{lastReport.year} M{lastReport.month})
+          </div>
+          <div className="space-y-1 text-xs text-white/70">
+            {[
+              ['Ride tickets', lastReport.income.rides],
+              ['Admissions', lastReport.income.admissions],
+              ['Food & drinks', lastReport.income.food],
+              ['Shops', lastReport.income.shops],
+            ].map(([label, value]) => (
+              <div key={label} className="flex justify-between">
+                <span>{label}</span>
+                <span className="text-green-300">{formatCurrency(Math.round(value as number))}</span>
+              </div>
+            ))}
+            {[
+              ['Construction', lastReport.expenses.construction],
+              ['Upkeep', lastReport.expenses.upkeep],
+              ['Wages', lastReport.expenses.wages],
+              ['Loan interest', lastReport.expenses.loanInterest],
+            ].map(([label, value]) => (
+              <div key={label} className="flex justify-between">
+                <span>{label}</span>
+                <span className="text-red-300">{formatCurrency(Math.round(value as number))}</span>
+              </div>
+            ))}
+            <div className="flex justify-between font-semibold pt-1">
+              <span>Profit</span>
+              <span className={lastReport.profit >= 0 ? 'text-green-300' : 'text-red-300'}>
+                {formatCurrency(Math.round(lastReport.profit))}
+              </span>
+            </div>
+          </div>
+        </div>
+      )}
+      
       {finances.history.length > 0 && (
         <div>
           <div className="text-xs uppercase text-white/50 tracking-wide mb-2">Recent Months</div>
@@ -98,6 +143,71 @@ function FinancesPanel({ onClose }: { onClose: () => void }) {
   );
 }
 
+function RidesPanel({ onClose }: { onClose: () => void }) {
+  const { state, getRideInfo, setRidePrice } = useCoaster();
+  
+  // Ride buildings only move when the grid changes
+  const rides = useMemo(() => {
+    const found: { x: number; y: number; name: string }[] = [];
+    state.grid.forEach((row, y) => row.forEach((tile, x) => {
+      if (!isRideBuilding(tile.building.type)) return;
+      const tool = getToolForBuilding(tile.building.type);
+      found.push({ x, y, name: tool ? TOOL_INFO[tool].name : tile.building.type });
+    }));
+    return found;
+  }, [state.grid]);
+  
+  return (
+    <PanelWrapper title="Rides" onClose={onClose}>
+      {rides.length === 0 ? (
+        <div className="text-sm text-white/50">No rides built yet.</div>
+      ) : (
+        <div className="space-y-2 text-sm">
+          {!state.settings.payPerRide && (
+            <div className="text-xs text-white/50">Ride prices apply when the park is set to pay-per-ride.</div>
+          )}
+          <div className="grid grid-cols-[1fr_auto_auto_auto] gap-x-4 text-xs uppercase text-white/50">
+            <span>Ride</span>
+            <span>Queue</span>
+            <span>Riders</span>
+            <span>Price</span>
+          </div>
+          {rides.map(ride => {
+            // Re-read every render so the numbers follow the simulation
+            const info = getRideInfo(ride.x, ride.y);
+            if (!info) return null;
+            const backedUp = info.queueLength >= info.maxQueueLength;
+            const overpriced = info.price > info.fairPrice;
+            return (
+              <div key={info.rideId} className="grid grid-cols-[1fr_auto_auto_auto] items-center gap-x-4 text-white/80">
+                <span className="truncate">{ride.name}</span>
+                <span className={backedUp ? 'text-red-300' : 'text-white/80'}>
+                  {info.queueLength}/{info.maxQueueLength}
+                </span>
+                <span className="text-green-300">{info.ridersServed.toLocaleString()}</span>
+                <span className="flex items-center gap-1">
+                  <Button variant="ghost" size="icon" className="h-5 w-5 text-white/60" onClick={() => setRidePrice(ride.x, ride.y, info.price - 1)}>
+                    −
+                  </Button>
+                  <span
+                    className={`min-w-[28px] text-center ${overpriced ? 'text-amber-300' : 'text-green-400'}`}
+                    title={`Guests think this ride is worth ${formatCurrency(info.fairPrice)}`}
+                  >
+                    {formatCurrency(info.price)}
+                  </span>
+                  <Button variant="ghost" size="icon" className="h-5 w-5 text-white/60" onClick={() => setRidePrice(ride.x, ride.y, info.price + 1)}>
+                    +
+                  </Button>
+                </span>
+              </div>
+            );
+          })}
+        </div>
+      )}
+    </PanelWrapper>
+  );
+}
+
 // Helper function to load example state with proper error handling
 async function loadExampleState(
   filename: string,
@@ -364,6 +474,10 @@ export function Panels() {
     return <FinancesPanel onClose={() => setActivePanel('none')} />;
   }
   
+  if (state.activePanel === 'rides') {
+    return <RidesPanel onClose={() => setActivePanel('none')} />;
+  }
+  
   if (state.activePanel === 'settings') {
     return <SettingsPanel onClose={() => setActivePanel('none')} />;
   }
@@ -0,0 +1,5 @@
+/**
+ * Staff System Exports
+ */
+
+export * from './staffSystem';
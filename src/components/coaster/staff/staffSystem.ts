@@ -0,0 +1,421 @@
+/**
+ * Staff System for IsoCoaster
+ * Handles janitors, the litter they sweep up, and their rendering
+ */
+
+import { Guest, Staff, DEFAULT_PRICES, generateGuestName } from '@/games/coaster/types/economy';
+import { Tile } from '@/games/coaster/types/game';
+import { findPathCached } from '@/components/coaster/guests';
+import { getBuildingAccessTile } from '@/games/coaster/lib/buildingAccess';
+import { simulationRandom } from '@/games/coaster/lib/random';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+const TILE_WIDTH = 64;
+const HEIGHT_RATIO = 0.60;
+const TILE_HEIGHT = TILE_WIDTH * HEIGHT_RATIO;
+
+export const JANITORS_PER_STAFF_BUILDING = 2;
+const STAFF_BUILDING_SIZE = 2; // Staff buildings are 2x2
+
+const MAX_LITTER = 100;
+const LITTER_PER_DROP = 8;
+const LITTER_DROP_CHANCE = 0.5;      // Chance a guest leaving a stall drops their wrapper
+const LITTER_STRAY_CHANCE = 0.0004;  // Per-tick chance any walking guest drops something
+const VOMIT_NAUSEA = 90;             // Nausea at which a walking guest may be sick on the path
+const VOMIT_CHANCE = 0.002;          // Per-tick chance for a guest that nauseous
+const LITTER_PER_VOMIT = 30;         // One mess is enough to make a path dirty
+export const DIRTY_LITTER_THRESHOLD = 20; // Litter level at which a path counts as dirty
+const DIRTY_TILE_RATING_PENALTY = 4; // Park rating lost per dirty path tile
+const MAX_LITTER_RATING_PENALTY = 250;
+
+const JANITOR_SPEED = 0.025;         // Progress per tick (a little brisker than guests)
+const JANITOR_SWEEP_RATE = 4;        // Litter removed per tick while sweeping
+const JANITOR_SEARCH_RADIUS = 12;    // Tiles (Manhattan) a janitor looks for litter
+const JANITOR_PATH_STEPS = 120;
+const JANITOR_IDLE_COOLDOWN = 20;    // Ticks between litter searches when the area is clean
+
+const JANITOR_COLORS = {
+  uniform: '#16a34a',
+  pants: '#1e293b',
+  skin: '#e5b898',
+  broom: '#a16207',
+};
+
+const DIRECTIONS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+// =============================================================================
+// STAFF CREATION
+// =============================================================================
+
+function generateStaffId(): string {
+  return `staff-${Date.now()}-${Math.random().toString(36).slice(2, 8)}`;
+}
+
+/**
+ * Hire a janitor working out of the staff building at (homeX, homeY)
+ */
+export function createJanitor(homeX: number, homeY: number): Staff {
+  return {
+    id: generateStaffId(),
+    type: 'handyman',
+    name: generateGuestName(),
+    tileX: homeX,
+    tileY: homeY,
+    patrol: [],
+    wage: DEFAULT_PRICES.handymanWage,
+    energy: 100,
+    working: false,
+    homeX,
+    homeY,
+    targetTileX: homeX,
+    targetTileY: homeY,
+    progress: 0,
+    path: [],
+    pathIndex: 0,
+    direction: 'south',
+    decisionCooldown: 0,
+  };
+}
+
+function isWalkable(grid: Tile[][], x: number, y: number): boolean {
+  const tile = grid[y]?.[x];
+  return !!tile && (tile.path || tile.queue);
+}
+
+function isStaffBuildingAt(grid: Tile[][], x: number, y: number): boolean {
+  return grid[y]?.[x]?.building.type === 'infra_office';
+}
+
+/**
+ * Path tile next to any part of a staff building's footprint
+ */
+function findStaffBuildingAccess(grid: Tile[][], homeX: number, homeY: number): { x: number; y: number } | null {
+  for (let dy = 0; dy < STAFF_BUILDING_SIZE; dy++) {
+    for (let dx = 0; dx < STAFF_BUILDING_SIZE; dx++) {
+      const access = getBuildingAccessTile(grid, homeX + dx, homeY + dy);
+      if (access) return access;
+    }
+  }
+  return null;
+}
+
+// =============================================================================
+// LITTER
+// =============================================================================
+
+function litterKey(x: number, y: number): string {
+  return `${x},${y}`;
+}
+
+export function getLitterAt(litter: Record<string, number> | undefined, x: number, y: number): number {
+  return litter?.[litterKey(x, y)] ?? 0;
+}
+
+/**
+ * Guests finishing a meal or a shop visit often leave their wrapper behind;
+ * anyone walking occasionally drops something too
+ */
+function shouldGuestDropLitter(guest: Guest): boolean {
+  if (
+    guest.state === 'exiting_building' &&
+    (guest.lastState === 'eating' || guest.lastState === 'shopping') &&
+    guest.targetBuildingKind !== 'restroom'
+  ) {
+    return simulationRandom() < LITTER_DROP_CHANCE;
+  }
+  return guest.state === 'walking' && simulationRandom() < LITTER_STRAY_CHANCE;
+}
+
+/**
+ * Litter a guest leaves on their tile this tick: a wrapper, or a mess if
+ * they're sick enough to throw up
+ */
+function getGuestLitterDrop(guest: Guest): number {
+  if (guest.state === 'walking' && guest.nausea >= VOMIT_NAUSEA && simulationRandom() < VOMIT_CHANCE) {
+    return LITTER_PER_VOMIT;
+  }
+  return shouldGuestDropLitter(guest) ? LITTER_PER_DROP : 0;
+}
+
+/**
+ * Park rating lost to dirty paths
+ */
+export function getLitterRatingPenalty(litter: Record<string, number> | undefined): number {
+  if (!litter) return 0;
+  let dirtyTiles = 0;
+  for (const key in litter) {
+    if (litter[key] >= DIRTY_LITTER_THRESHOLD) dirtyTiles++;
+  }
+  return Math.min(MAX_LITTER_RATING_PENALTY, dirtyTiles * DIRTY_TILE_RATING_PENALTY);
+}
+
+/**
+ * Dirtiest tile within the janitor's search radius (nearest wins ties)
+ */
+function findDirtiestTile(litter: Record<string, number>, x: number, y: number): { x: number; y: number } | null {
+  let best: { x: number; y: number } | null = null;
+  let bestLitter = 0;
+  let bestDistance = Infinity;
+
+  for (const key in litter) {
+    const amount = litter[key];
+    if (amount <= 0) continue;
+    const [lx, ly] = key.split(',').map(Number);
+    const distance = Math.abs(lx - x) + Math.abs(ly - y);
+    if (distance > JANITOR_SEARCH_RADIUS) continue;
+    if (amount > bestLitter || (amount === bestLitter && distance < bestDistance)) {
+      best = { x: lx, y: ly };
+      bestLitter = amount;
+      bestDistance = distance;
+    }
+  }
+
+  return best;
+}
+
+// =============================================================================
+// JANITOR AI
+// =============================================================================
+
+function stepToward(janitor: Staff, next: { x: number; y: number }) {
+  const dx = next.x - janitor.tileX;
+  const dy = next.y - janitor.tileY;
+  if (dx > 0) janitor.direction = 'south';
+  else if (dx < 0) janitor.direction = 'north';
+  else if (dy > 0) janitor.direction = 'west';
+  else if (dy < 0) janitor.direction = 'east';
+  janitor.targetTileX = next.x;
+  janitor.targetTileY = next.y;
+}
+
+/**
+ * Advance one janitor by a tick. Janitors sweep whatever litter is on their
+ * tile, otherwise walk to the dirtiest path nearby, or wander when it's clean.
+ * Returns how much litter was swept from the janitor's tile.
+ */
+export function updateJanitor(
+  janitor: Staff,
+  grid: Tile[][],
+  litter: Record<string, number>
+): { janitor: Staff; swept: number } {
+  const updated: Staff = { ...janitor };
+
+  // Fresh hires (or janitors whose path was removed) step out onto the nearest path
+  if (!isWalkable(grid, updated.tileX, updated.tileY)) {
+    const access = findStaffBuildingAccess(grid, updated.homeX, updated.homeY);
+    if (!access) return { janitor: updated, swept: 0 };
+    updated.tileX = access.x;
+    updated.tileY = access.y;
+    updated.targetTileX = access.x;
+    updated.targetTileY = access.y;
+    updated.progress = 0;
+    updated.path = [];
+    updated.pathIndex = 0;
+  }
+
+  // Walk toward the current target tile
+  if (updated.targetTileX !== updated.tileX || updated.targetTileY !== updated.tileY) {
+    if (!isWalkable(grid, updated.targetTileX, updated.targetTileY)) {
+      updated.targetTileX = updated.tileX;
+      updated.targetTileY = updated.tileY;
+      updated.progress = 0;
+      updated.path = [];
+      updated.pathIndex = 0;
+    } else {
+      updated.progress += JANITOR_SPEED;
+      if (updated.progress < 1) return { janitor: updated, swept: 0 };
+      updated.tileX = updated.targetTileX;
+      updated.tileY = updated.targetTileY;
+      updated.progress = 0;
+    }
+  }
+
+  // Sweep up anything underfoot
+  const here = getLitterAt(litter, updated.tileX, updated.tileY);
+  if (here > 0) {
+    updated.working = true;
+    return { janitor: updated, swept: Math.min(here, JANITOR_SWEEP_RATE) };
+  }
+  updated.working = false;
+
+  // Carry on along the current route
+  if (updated.pathIndex < updated.path.length) {
+    stepToward(updated, updated.path[updated.pathIndex]);
+    updated.pathIndex++;
+    return { janitor: updated, swept: 0 };
+  }
+  updated.path = [];
+  updated.pathIndex = 0;
+
+  updated.decisionCooldown = Math.max(0, updated.decisionCooldown - 1);
+  if (updated.decisionCooldown > 0) return { janitor: updated, swept: 0 };
+
+  const target = findDirtiestTile(litter, updated.tileX, updated.tileY);
+  if (target) {
+    const path = findPathCached(grid, updated.tileX, updated.tileY, target.x, target.y, JANITOR_PATH_STEPS);
+    if (path.length > 1) {
+      updated.path = path;
+      stepToward(updated, path[1]);
+      updated.pathIndex = 2;
+      return { janitor: updated, swept: 0 };
+    }
+  }
+
+  // Nothing to clean (or none reachable) - wander a tile and look again later
+  const validDirs = DIRECTIONS.filter(dir => isWalkable(grid, updated.tileX + dir.dx, updated.tileY + dir.dy));
+  if (validDirs.length > 0) {
+    const dir = validDirs[Math.floor(simulationRandom() * validDirs.length)];
+    stepToward(updated, { x: updated.tileX + dir.dx, y: updated.tileY + dir.dy });
+  }
+  updated.decisionCooldown = JANITOR_IDLE_COOLDOWN;
+  return { janitor: updated, swept: 0 };
+}
+
+// =============================================================================
+// SIMULATION
+// =============================================================================
+
+/**
+ * One simulation tick of staff and litter: guests drop litter on the paths
+ * they stand on, janitors whose staff building was demolished are let go,
+ * and the rest sweep. Returns the input litter object when nothing changed.
+ */
+export function simulateStaff(
+  staff: Staff[],
+  guests: Guest[],
+  grid: Tile[][],
+  litter: Record<string, number>
+): { staff: Staff[]; litter: Record<string, number> } {
+  let nextLitter = litter;
+  const editLitter = () => {
+    if (nextLitter === litter) nextLitter = { ...litter };
+    return nextLitter;
+  };
+
+  for (const guest of guests) {
+    if (!grid[guest.tileY]?.[guest.tileX]?.path) continue;
+    const drop = getGuestLitterDrop(guest);
+    if (drop <= 0) continue;
+    const key = litterKey(guest.tileX, guest.tileY);
+    const edited = editLitter();
+    edited[key] = Math.min(MAX_LITTER, (edited[key] ?? 0) + drop);
+  }
+
+  // Litter left on tiles that are no longer paths goes with them
+  for (const key in nextLitter) {
+    const [x, y] = key.split(',').map(Number);
+    if (!grid[y]?.[x]?.path) delete editLitter()[key];
+  }
+
+  const nextStaff: Staff[] = [];
+  for (const member of staff) {
+    if (member.type !== 'handyman') {
+      nextStaff.push(member);
+      continue;
+    }
+    if (!isStaffBuildingAt(grid, member.homeX, member.homeY)) continue;
+
+    const { janitor, swept } = updateJanitor(member, grid, nextLitter);
+    if (swept > 0) {
+      const key = litterKey(janitor.tileX, janitor.tileY);
+      const edited = editLitter();
+      const remaining = (edited[key] ?? 0) - swept;
+      if (remaining > 0) edited[key] = remaining;
+      else delete edited[key];
+    }
+    nextStaff.push(janitor);
+  }
+
+  return { staff: nextStaff, litter: nextLitter };
+}
+
+// =============================================================================
+// RENDERING
+// =============================================================================
+
+function gridToScreen(gridX: number, gridY: number): { x: number; y: number } {
+  const x = (gridX - gridY) * (TILE_WIDTH / 2);
+  const y = (gridX + gridY) * (TILE_HEIGHT / 2);
+  return { x, y };
+}
+
+export function drawJanitor(
+  ctx: CanvasRenderingContext2D,
+  janitor: Staff,
+  tick: number
+) {
+  const { x: startX, y: startY } = gridToScreen(janitor.tileX, janitor.tileY);
+  const { x: endX, y: endY } = gridToScreen(janitor.targetTileX, janitor.targetTileY);
+  const x = startX + (endX - startX) * janitor.progress + TILE_WIDTH / 2;
+  const y = startY + (endY - startY) * janitor.progress + TILE_HEIGHT / 2;
+
+  const isMoving = janitor.targetTileX !== janitor.tileX || janitor.targetTileY !== janitor.tileY;
+  const walkCycle = isMoving ? Math.sin(tick * 0.4) : 0;
+  const bodyY = y - 3 - Math.abs(walkCycle) * 0.5;
+
+  // Shadow
+  ctx.fillStyle = 'rgba(0, 0, 0, 0.2)';
+  ctx.beginPath();
+  ctx.ellipse(x, y + 0.5, 1.25, 0.75, 0, 0, Math.PI * 2);
+  ctx.fill();
+
+  // Broom, swishing back and forth while sweeping
+  const sweep = janitor.working ? Math.sin(tick * 0.5) * 1.2 : 0;
+  ctx.strokeStyle = JANITOR_COLORS.broom;
+  ctx.lineWidth = 0.3;
+  ctx.beginPath();
+  ctx.moveTo(x + 1.2, bodyY);
+  ctx.lineTo(x + 1.8 + sweep, y + 0.5);
+  ctx.stroke();
+
+  // Legs
+  ctx.fillStyle = JANITOR_COLORS.pants;
+  ctx.fillRect(x - 0.75 + walkCycle * 0.3, bodyY + 1.5, 0.5, 1.5);
+  ctx.fillRect(x + 0.25 - walkCycle * 0.3, bodyY + 1.5, 0.5, 1.5);
+
+  // Uniform
+  ctx.fillStyle = JANITOR_COLORS.uniform;
+  ctx.fillRect(x - 1, bodyY - 0.5, 2, 2);
+
+  // Head and cap
+  ctx.fillStyle = JANITOR_COLORS.skin;
+  ctx.beginPath();
+  ctx.arc(x, bodyY - 1.5, 1, 0, Math.PI * 2);
+  ctx.fill();
+  ctx.fillStyle = JANITOR_COLORS.uniform;
+  ctx.fillRect(x - 1, bodyY - 2.75, 2, 0.75);
+}
+
+/**
+ * Scatter of wrappers on a path tile; more litter draws more specks
+ */
+export function drawLitter(
+  ctx: CanvasRenderingContext2D,
+  screenX: number,
+  screenY: number,
+  gridX: number,
+  gridY: number,
+  amount: number
+) {
+  const specks = Math.min(8, Math.ceil(amount / 12));
+  const cx = screenX + TILE_WIDTH / 2;
+  const cy = screenY + TILE_HEIGHT / 2;
+
+  for (let i = 0; i < specks; i++) {
+    // Stable pseudo-random placement per tile so specks don't jump around
+    const seed = (gridX * 73856093) ^ (gridY * 19349663) ^ (i * 83492791);
+    const u = ((seed >>> 0) % 1000) / 1000 - 0.5;
+    const v = (((seed >>> 10) >>> 0) % 1000) / 1000 - 0.5;
+    ctx.fillStyle = i % 3 === 0 ? '#f8fafc' : i % 3 === 1 ? '#ef4444' : '#facc15';
+    // Keep specks inside the diamond by mixing along both tile axes
+    ctx.fillRect(cx + (u - v) * TILE_WIDTH * 0.35, cy + (u + v) * TILE_HEIGHT * 0.35, 1.5, 1);
+  }
+}
@@ -453,9 +453,69 @@ export function drawStraightTrack(
   ctx.stroke();
 }
 
+// Edge midpoints in tile-local ground coordinates (0-1 along each grid axis)
+const EDGE_GROUND: Record<TrackDirection, Point> = {
+  north: { x: 0, y: 0.5 },
+  east: { x: 0.5, y: 0 },
+  south: { x: 1, y: 0.5 },
+  west: { x: 0.5, y: 1 },
+};
+
+/** Which tile edges a turn connects; startDir is the edge the track comes FROM */
+function getTurnEdges(startDir: TrackDirection, turnRight: boolean): { from: TrackDirection; to: TrackDirection } {
+  if (startDir === 'north') return { from: 'north', to: turnRight ? 'east' : 'west' };
+  if (startDir === 'south') return { from: 'south', to: turnRight ? 'west' : 'east' };
+  if (startDir === 'east') return { from: 'east', to: turnRight ? 'south' : 'north' };
+  return { from: 'west', to: turnRight ? 'north' : 'south' };
+}
+
+/**
+ * Point along a turn, t from 0 (entry edge) to 1 (exit edge), with the unit
+ * screen-space direction across the track at that point.
+ * The turn is a quarter circle on the ground around the tile corner the two
+ * edges share, projected isometrically, so it leaves each edge along the same
+ * line (and with the same rail spacing) as the straight piece beside it.
+ */
+export function getCurvedTrackPoint(
+  startX: number,
+  startY: number,
+  startDir: TrackDirection,
+  turnRight: boolean,
+  height: number,
+  t: number
+): { x: number; y: number; perpX: number; perpY: number } {
+  const { from, to } = getTurnEdges(startDir, turnRight);
+  const fromGround = EDGE_GROUND[from];
+  const toGround = EDGE_GROUND[to];
+  // Shared corner: both edge midpoints sit half a tile from it
+  const cornerX = fromGround.x + toGround.x - 0.5;
+  const cornerY = fromGround.y + toGround.y - 0.5;
+  const fromAngle = Math.atan2(fromGround.y - cornerY, fromGround.x - cornerX);
+  let sweep = Math.atan2(toGround.y - cornerY, toGround.x - cornerX) - fromAngle;
+  if (sweep > Math.PI) sweep -= Math.PI * 2;
+  if (sweep < -Math.PI) sweep += Math.PI * 2;
+  
+  const angle = fromAngle + sweep * t;
+  const radialX = Math.cos(angle);
+  const radialY = Math.sin(angle);
+  const groundX = cornerX + radialX * 0.5;
+  const groundY = cornerY + radialY * 0.5;
+  
+  // Project ground coordinates; the tile's top corner is at (startX + w/2, startY)
+  const perpX = (radialX - radialY) * (TILE_WIDTH / 2);
+  const perpY = (radialX + radialY) * (TILE_HEIGHT / 2);
+  const perpLength = Math.hypot(perpX, perpY);
+  return {
+    x: startX + TILE_WIDTH / 2 + (groundX - groundY) * (TILE_WIDTH / 2),
+    y: startY + (groundX + groundY) * (TILE_HEIGHT / 2) - height * HEIGHT_UNIT,
+    perpX: perpX / perpLength,
+    perpY: perpY / perpLength,
+  };
+}
+
 /**
  * Draw a curved track segment (turn)
- * Uses quadratic bezier like the city game's rail system for proper alignment
+ * Follows getCurvedTrackPoint so the rails meet neighboring straights end to end
  */
 export function drawCurvedTrack(
   ctx: CanvasRenderingContext2D,
@@ -469,54 +529,29 @@ export function drawCurvedTrack(
   coasterCategory?: CoasterCategory,
   _tick: number = 0
 ) {
-  const w = TILE_WIDTH;
-  const h = TILE_HEIGHT;
   const heightOffset = height * HEIGHT_UNIT;
-  
-  // Edge midpoints - MUST match where straight tracks end (like city game's rail system)
-  const northEdge = { x: startX + w * 0.25, y: startY + h * 0.25 - heightOffset };
-  const eastEdge = { x: startX + w * 0.75, y: startY + h * 0.25 - heightOffset };
-  const southEdge = { x: startX + w * 0.75, y: startY + h * 0.75 - heightOffset };
-  const westEdge = { x: startX + w * 0.25, y: startY + h * 0.75 - heightOffset };
-  const center = { x: startX + w / 2, y: startY + h / 2 - heightOffset };
-  
-  // Determine which edges to connect based on direction and turn
-  // startDir is the direction the track is coming FROM
-  let fromEdge: Point;
-  let toEdge: Point;
-  
-  if (startDir === 'north') {
-    fromEdge = northEdge;
-    toEdge = turnRight ? eastEdge : westEdge;
-  } else if (startDir === 'south') {
-    fromEdge = southEdge;
-    toEdge = turnRight ? westEdge : eastEdge;
-  } else if (startDir === 'east') {
-    fromEdge = eastEdge;
-    toEdge = turnRight ? southEdge : northEdge;
-  } else { // west
-    fromEdge = westEdge;
-    toEdge = turnRight ? northEdge : southEdge;
-  }
+  const segments = 16;
+  const points = Array.from({ length: segments + 1 }, (_, i) =>
+    getCurvedTrackPoint(startX, startY, startDir, turnRight, height, i / segments)
+  );
   
   // Draw support if elevated - place under the curve midpoint
   if (height > 0) {
-    const midT = 0.5;
-    const u = 1 - midT;
-    const curveMid = {
-      x: u * u * fromEdge.x + 2 * u * midT * center.x + midT * midT * toEdge.x,
-      y: u * u * fromEdge.y + 2 * u * midT * center.y + midT * midT * toEdge.y,
-    };
+    const curveMid = points[segments / 2];
     // Use enhanced wooden cross-bracing for wooden coasters
     if (coasterCategory === 'wooden' || strutStyle === 'wood') {
-      drawWoodenCrossBracing(ctx, curveMid.x, curveMid.y + heightOffset, height, 0, 0);
+      drawWoodenCrossBracing(ctx, curveMid.x, curveMid.y + heightOffset, height, curveMid.perpX, curveMid.perpY);
     } else {
-      drawSupport(ctx, curveMid.x, curveMid.y + heightOffset, height, undefined, strutStyle);
+      drawSupport(ctx, curveMid.x, curveMid.y + heightOffset, height, { x: curveMid.perpX, y: curveMid.perpY }, strutStyle);
     }
   }
   
-  // Draw crossties along the quadratic curve (fewer ties - 4 is enough)
-  const numTies = 4;
+  // Draw crossties spaced along the arc like straights space them along their length
+  let length = 0;
+  for (let i = 1; i <= segments; i++) {
+    length += Math.hypot(points[i].x - points[i - 1].x, points[i].y - points[i - 1].y);
+  }
+  const numTies = Math.max(3, Math.floor(length / TIE_SPACING));
   // Wooden coasters get wooden ties
   if (coasterCategory === 'wooden' || strutStyle === 'wood') {
     ctx.strokeStyle = COLORS.woodAccent;
@@ -524,71 +559,34 @@ export function drawCurvedTrack(
     ctx.strokeStyle = COLORS.tie;
   }
   ctx.lineWidth = 1.5;
+  ctx.lineCap = 'butt';
   
   for (let i = 0; i <= numTies; i++) {
-    const t = i / numTies;
-    // Quadratic bezier point
-    const u = 1 - t;
-    const pt = {
-      x: u * u * fromEdge.x + 2 * u * t * center.x + t * t * toEdge.x,
-      y: u * u * fromEdge.y + 2 * u * t * center.y + t * t * toEdge.y,
-    };
-    // Quadratic bezier tangent
-    const tangent = {
-      x: 2 * (1 - t) * (center.x - fromEdge.x) + 2 * t * (toEdge.x - center.x),
-      y: 2 * (1 - t) * (center.y - fromEdge.y) + 2 * t * (toEdge.y - center.y),
-    };
-    const len = Math.sqrt(tangent.x * tangent.x + tangent.y * tangent.y);
-    const perpX = -tangent.y / len;
-    const perpY = tangent.x / len;
-    
+    const tie = getCurvedTrackPoint(startX, startY, startDir, turnRight, height, i / numTies);
     ctx.beginPath();
-    ctx.moveTo(pt.x - perpX * TIE_LENGTH / 2, pt.y - perpY * TIE_LENGTH / 2);
-    ctx.lineTo(pt.x + perpX * TIE_LENGTH / 2, pt.y + perpY * TIE_LENGTH / 2);
+    ctx.moveTo(tie.x - tie.perpX * TIE_LENGTH / 2, tie.y - tie.perpY * TIE_LENGTH / 2);
+    ctx.lineTo(tie.x + tie.perpX * TIE_LENGTH / 2, tie.y + tie.perpY * TIE_LENGTH / 2);
     ctx.stroke();
   }
   
-  // Draw rails using quadratic bezier
+  // Draw rails offset across the track at each point
   const railOffset = TRACK_WIDTH / 2;
-  const segments = 16;
   
   ctx.strokeStyle = trackColor;
   ctx.lineWidth = RAIL_WIDTH;
   ctx.lineCap = 'round';
   
-  // Left and right rail paths
   for (const side of [-1, 1]) {
     ctx.beginPath();
-    
-    for (let i = 0; i <= segments; i++) {
-      const t = i / segments;
-      const u = 1 - t;
-      
-      // Quadratic bezier point
-      const pt = {
-        x: u * u * fromEdge.x + 2 * u * t * center.x + t * t * toEdge.x,
-        y: u * u * fromEdge.y + 2 * u * t * center.y + t * t * toEdge.y,
-      };
-      
-      // Quadratic bezier tangent
-      const tangent = {
-        x: 2 * (1 - t) * (center.x - fromEdge.x) + 2 * t * (toEdge.x - center.x),
-        y: 2 * (1 - t) * (center.y - fromEdge.y) + 2 * t * (toEdge.y - center.y),
-      };
-      const len = Math.sqrt(tangent.x * tangent.x + tangent.y * tangent.y);
-      const perpX = -tangent.y / len;
-      const perpY = tangent.x / len;
-      
-      const rx = pt.x + perpX * railOffset * side;
-      const ry = pt.y + perpY * railOffset * side;
-      
+    points.forEach((pt, i) => {
+      const rx = pt.x + pt.perpX * railOffset * side;
+      const ry = pt.y + pt.perpY * railOffset * side;
       if (i === 0) {
         ctx.moveTo(rx, ry);
       } else {
         ctx.lineTo(rx, ry);
       }
-    }
-    
+    });
     ctx.stroke();
   }
 }
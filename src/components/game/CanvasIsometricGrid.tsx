@@ -55,10 +55,8 @@ import {
 import {
   getOverlayFillStyle,
   OVERLAY_TO_BUILDING_TYPES,
-  OVERLAY_CIRCLE_COLORS,
-  OVERLAY_CIRCLE_FILL_COLORS,
-  OVERLAY_HIGHLIGHT_COLORS,
 } from '@/components/game/overlays';
+import { getColorPalette } from '@/lib/colorPalettes';
 import { SERVICE_CONFIG, SERVICE_RANGE_INCREASE_PER_LEVEL } from '@/lib/simulation';
 import { drawPlaceholderBuilding } from '@/components/game/placeholders';
 import { loadImage, loadSpriteImage, onImageLoaded, getCachedImage } from '@/components/game/imageLoader';
@@ -125,7 +123,7 @@ export interface CanvasIsometricGridProps {
 
 // Canvas-based Isometric Grid - HIGH PERFORMANCE
 export function CanvasIsometricGrid({ overlayMode, selectedTile, setSelectedTile, isMobile = false, navigationTarget, onNavigationComplete, onViewportChange, onBargeDelivery }: CanvasIsometricGridProps) {
-  const { state, latestStateRef, placeAtTile, finishTrackDrag, connectToCity, checkAndDiscoverCities, currentSpritePack, visualHour } = useGame();
+  const { state, latestStateRef, placeAtTile, finishTrackDrag, connectToCity, checkAndDiscoverCities, currentSpritePack, visualHour, colorblindMode } = useGame();
   const { grid, gridSize, selectedTool, speed, adjacentCities, waterBodies, gameVersion } = state;
   
   // PERF: Use latestStateRef for real-time grid access in animation loops
@@ -2019,6 +2017,7 @@ export function CanvasIsometricGrid({ overlayMode, selectedTile, setSelectedTile
         
         // Draw overlays on the buildings canvas so they appear ON TOP of buildings
         // (The buildings canvas is layered above the main canvas, so overlays must be drawn here)
+        const overlayPalette = getColorPalette(colorblindMode);
         // PERF: Use for loop instead of forEach
         for (let i = 0; i < overlayQueue.length; i++) {
           const { tile, screenX, screenY } = overlayQueue[i];
@@ -2030,7 +2029,7 @@ export function CanvasIsometricGrid({ overlayMode, selectedTile, setSelectedTile
             education: state.services.education[tile.y][tile.x],
           };
           
-          const fillStyle = getOverlayFillStyle(overlayMode, tile, coverage);
+          const fillStyle = getOverlayFillStyle(overlayMode, tile, coverage, overlayPalette);
           // Only draw if there's actually a color to show
           if (fillStyle !== 'rgba(0, 0, 0, 0)') {
             buildingsCtx.fillStyle = fillStyle;
@@ -2047,9 +2046,9 @@ export function CanvasIsometricGrid({ overlayMode, selectedTile, setSelectedTile
         // Draw service radius circles and building highlights for the active overlay
         if (overlayMode !== 'none' && overlayMode !== 'subway') {
           const serviceBuildingTypes = OVERLAY_TO_BUILDING_TYPES[overlayMode];
-          const circleColor = OVERLAY_CIRCLE_COLORS[overlayMode];
-          const circleFillColor = OVERLAY_CIRCLE_FILL_COLORS[overlayMode];
-          const highlightColor = OVERLAY_HIGHLIGHT_COLORS[overlayMode];
+          const circleColor = overlayPalette.circleColors[overlayMode];
+          const circleFillColor = overlayPalette.circleFillColors[overlayMode];
+          const highlightColor = overlayPalette.highlightColors[overlayMode];
           
           // Find all service buildings of this type and draw their radii
           for (let y = 0; y < gridSize; y++) {
@@ -2168,7 +2167,7 @@ export function CanvasIsometricGrid({ overlayMode, selectedTile, setSelectedTile
       }
     };
   // PERF: hoveredTile and selectedTile removed from deps - now rendered on separate hover canvas layer
-  }, [grid, gridSize, offset, zoom, overlayMode, imagesLoaded, imageLoadVersion, canvasSize, dragStartTile, dragEndTile, state.services, currentSpritePack, waterBodies, getTileMetadata, showsDragGrid, isMobile]);
+  }, [grid, gridSize, offset, zoom, overlayMode, imagesLoaded, imageLoadVersion, canvasSize, dragStartTile, dragEndTile, state.services, currentSpritePack, colorblindMode, waterBodies, getTileMetadata, showsDragGrid, isMobile]);
   
   // PERF: Lightweight hover/selection overlay - renders ONLY tile highlights
   // This runs frequently (on mouse move) but is extremely fast since it only draws simple shapes
@@ -5,6 +5,7 @@
 
 import { Tile } from '@/types/game';
 import { OverlayMode } from './types';
+import { ColorPalette, getColorPalette } from '@/lib/colorPalettes';
 
 // ============================================================================
 // Types
@@ -120,9 +121,6 @@ function tileNeedsCoverage(tile: Tile): boolean {
   return !NON_BUILDING_TYPES.has(tile.building.type);
 }
 
-/** Warning color for uncovered buildings */
-const UNCOVERED_WARNING = 'rgba(239, 68, 68, 0.45)'; // Red tint
-
 /** No overlay needed (transparent) */
 const NO_OVERLAY = 'rgba(0, 0, 0, 0)';
 
@@ -130,22 +128,25 @@ const NO_OVERLAY = 'rgba(0, 0, 0, 0)';
  * Calculate the fill style color for an overlay tile.
  * 
  * New simplified logic:
- * - Buildings without coverage get a red warning tint
+ * - Buildings without coverage get a warning tint (red, or a colorblind-safe color)
  * - Covered buildings and non-building tiles get no tint
  * - Radius circles are drawn separately to show coverage areas
  * 
  * @param mode - The current overlay mode
  * @param tile - The tile being rendered
  * @param coverage - Service coverage values for the tile
+ * @param palette - Color palette to use (defaults to the active colorblind mode)
  * @returns CSS color string for the overlay fill
  */
 export function getOverlayFillStyle(
   mode: OverlayMode,
   tile: Tile,
-  coverage: ServiceCoverage
+  coverage: ServiceCoverage,
+  palette: ColorPalette = getColorPalette()
 ): string {
   // Only show warning on tiles that have buildings needing coverage
   const needsCoverage = tileNeedsCoverage(tile);
+  const UNCOVERED_WARNING = palette.uncoveredWarning;
   
   switch (mode) {
     case 'power':
@@ -181,8 +182,8 @@ export function getOverlayFillStyle(
     case 'subway':
       // Underground view overlay - keep existing behavior
       return tile.hasSubway
-        ? 'rgba(245, 158, 11, 0.7)'  // Bright amber for existing subway
-        : 'rgba(40, 30, 20, 0.4)';   // Dark brown tint for "underground" view
+        ? palette.subwayPresent  // Bright tint for existing subway
+        : palette.subwayAbsent;  // Dark tint for "underground" view
 
     case 'none':
     default:
@@ -219,38 +220,11 @@ export const OVERLAY_TO_BUILDING_TYPES: Record<OverlayMode, string[]> = {
   subway: ['subway_station'],
 };
 
-/** Overlay circle stroke colors (light/visible colors) */
-export const OVERLAY_CIRCLE_COLORS: Record<OverlayMode, string> = {
-  none: 'transparent',
-  power: 'rgba(251, 191, 36, 0.8)',    // Amber
-  water: 'rgba(96, 165, 250, 0.8)',    // Blue
-  fire: 'rgba(248, 113, 113, 0.8)',    // Light red
-  police: 'rgba(147, 197, 253, 0.8)',  // Light blue
-  health: 'rgba(134, 239, 172, 0.8)',  // Light green
-  education: 'rgba(196, 181, 253, 0.8)', // Light purple
-  subway: 'rgba(253, 224, 71, 0.8)',   // Yellow
-};
+/** Overlay circle stroke colors (light/visible colors, default palette) */
+export const OVERLAY_CIRCLE_COLORS: Record<OverlayMode, string> = getColorPalette('none').circleColors;
 
-/** Building highlight glow colors */
-export const OVERLAY_HIGHLIGHT_COLORS: Record<OverlayMode, string> = {
-  none: 'transparent',
-  power: 'rgba(251, 191, 36, 1)',      // Amber
-  water: 'rgba(96, 165, 250, 1)',      // Blue  
-  fire: 'rgba(239, 68, 68, 1)',        // Red
-  police: 'rgba(59, 130, 246, 1)',     // Blue
-  health: 'rgba(34, 197, 94, 1)',      // Green
-  education: 'rgba(168, 85, 247, 1)',  // Purple
-  subway: 'rgba(234, 179, 8, 1)',      // Yellow
-};
+/** Building highlight glow colors (default palette) */
+export const OVERLAY_HIGHLIGHT_COLORS: Record<OverlayMode, string> = getColorPalette('none').highlightColors;
 
-/** Overlay circle fill colors (subtle, for area visibility) */
-export const OVERLAY_CIRCLE_FILL_COLORS: Record<OverlayMode, string> = {
-  none: 'transparent',
-  power: 'rgba(251, 191, 36, 0.12)',
-  water: 'rgba(96, 165, 250, 0.12)',
-  fire: 'rgba(248, 113, 113, 0.12)',
-  police: 'rgba(147, 197, 253, 0.12)',
-  health: 'rgba(134, 239, 172, 0.12)',
-  education: 'rgba(196, 181, 253, 0.12)',
-  subway: 'rgba(253, 224, 71, 0.12)',
-};
+/** Overlay circle fill colors (subtle, for area visibility, default palette) */
+export const OVERLAY_CIRCLE_FILL_COLORS: Record<OverlayMode, string> = getColorPalette('none').circleFillColors;
@@ -13,6 +13,7 @@ import { Label } from '@/components/ui/label';
 import { Separator } from '@/components/ui/separator';
 import { SpriteTestPanel } from './SpriteTestPanel';
 import { SavedCityMeta } from '@/types/game';
+import { COLORBLIND_MODES } from '@/lib/colorPalettes';
 import { LocaleSelector } from 'gt-next';
 
 // Translatable UI labels
@@ -71,6 +72,11 @@ const UI_LABELS = {
   auto: msg('Auto'),
   day: msg('Day'),
   night: msg('Night'),
+  colorblindMode: msg('Colorblind Mode'),
+  colorblindModeDesc: msg('Use colorblind-friendly colors for overlays and pedestrians'),
+  colorblindNone: msg('Off'),
+  deuteranopia: msg('Deuteranopia'),
+  protanopia: msg('Protanopia'),
   cannotShrink: msg('Cannot shrink city further - minimum size reached.'),
 };
 
@@ -128,7 +134,7 @@ function formatMoney(money: number): string {
 }
 
 export function SettingsPanel() {
-  const { state, setActivePanel, setDisastersEnabled, newGame, loadState, exportState, expandCity, shrinkCity, currentSpritePack, availableSpritePacks, setSpritePack, dayNightMode, setDayNightMode, getSavedCityInfo, restoreSavedCity, clearSavedCity, savedCities, saveCity, loadSavedCity, deleteSavedCity, renameSavedCity } = useGame();
+  const { state, setActivePanel, setDisastersEnabled, newGame, loadState, exportState, expandCity, shrinkCity, currentSpritePack, availableSpritePacks, setSpritePack, dayNightMode, setDayNightMode, colorblindMode, setColorblindMode, getSavedCityInfo, restoreSavedCity, clearSavedCity, savedCities, saveCity, loadSavedCity, deleteSavedCity, renameSavedCity } = useGame();
   const { disastersEnabled, cityName, gridSize, id: currentCityId } = state;
   const m = useMessages();
   const searchParams = useSearchParams();
@@ -655,6 +661,27 @@ export function SettingsPanel() {
                 ))}
               </div>
             </div>
+            <div className="mt-4 pt-4 border-t border-border">
+              <Label>{m(UI_LABELS.colorblindMode)}</Label>
+              <p className="text-muted-foreground text-xs mb-2">{m(UI_LABELS.colorblindModeDesc)}</p>
+              <div className="flex rounded-md border border-border overflow-hidden">
+                {COLORBLIND_MODES.map((mode) => (
+                  <button
+                    key={mode}
+                    onClick={() => setColorblindMode(mode)}
+                    className={`flex-1 px-3 py-2 text-sm font-medium transition-colors ${
+                      colorblindMode === mode
+                        ? 'bg-primary text-primary-foreground'
+                        : 'bg-background hover:bg-muted text-muted-foreground hover:text-foreground'
+                    }`}
+                  >
+                    {mode === 'none' && m(UI_LABELS.colorblindNone)}
+                    {mode === 'deuteranopia' && m(UI_LABELS.deuteranopia)}
+                    {mode === 'protanopia' && m(UI_LABELS.protanopia)}
+                  </button>
+                ))}
+              </div>
+            </div>
           </div>
         </div>
       </DialogContent>
@@ -19,6 +19,7 @@ import {
   TILE_WIDTH,
   TILE_HEIGHT,
 } from './types';
+import { getColorPalette } from '@/lib/colorPalettes';
 import {
   PEDESTRIAN_SKIN_COLORS,
   PEDESTRIAN_SHIRT_COLORS,
@@ -187,6 +188,7 @@ export function createPedestrian(
 ): Pedestrian {
   const hasDog = destType === 'park' && Math.random() < PEDESTRIAN_DOG_CHANCE;
   const hasBag = (destType === 'commercial' || destType === 'industrial') && Math.random() < PEDESTRIAN_BAG_CHANCE;
+  const shirtColors = getColorPalette().shirtColors ?? PEDESTRIAN_SHIRT_COLORS;
   const hasHat = Math.random() < PEDESTRIAN_HAT_CHANCE;
   
   const startTile = path[startIndex];
@@ -201,7 +203,7 @@ export function createPedestrian(
     age: 0,
     maxAge: 120 + Math.random() * 180, // 2-5 minutes lifespan
     skinColor: PEDESTRIAN_SKIN_COLORS[Math.floor(Math.random() * PEDESTRIAN_SKIN_COLORS.length)],
-    shirtColor: PEDESTRIAN_SHIRT_COLORS[Math.floor(Math.random() * PEDESTRIAN_SHIRT_COLORS.length)],
+    shirtColor: shirtColors[Math.floor(Math.random() * shirtColors.length)],
     pantsColor: PEDESTRIAN_PANTS_COLORS[Math.floor(Math.random() * PEDESTRIAN_PANTS_COLORS.length)],
     hasHat,
     hatColor: hasHat ? PEDESTRIAN_HAT_COLORS[Math.floor(Math.random() * PEDESTRIAN_HAT_COLORS.length)] : '#000000',
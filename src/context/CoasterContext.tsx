@@ -6,23 +6,54 @@ import {
   Tool,
   Tile,
   Notification,
+  GameEvent,
+  TickBreakdown,
+  TileInspection,
+  TrackPlacementPreview,
+  CameraViewpoint,
+  VIEWPOINT_SLOTS,
+  RideStats,
+  RideInfo,
+  createRideStats,
   createEmptyTile,
   createEmptyBuilding,
   TOOL_INFO,
+  OverlayMode,
+  SELL_REFUND_RATE,
+  getBuildingCost,
+  getTrackPieceCost,
 } from '@/games/coaster/types';
-import { ParkFinances, ParkStats, ParkSettings, Guest, Staff, DEFAULT_PRICES, WeatherState, WeatherType, WEATHER_EFFECTS, WEATHER_TRANSITIONS, getSeasonalWeatherBias, GuestThought } from '@/games/coaster/types/economy';
-import { Coaster, CoasterTrain, CoasterCar, TrackDirection, TrackHeight, TrackPiece, TrackPieceType, CoasterType, COASTER_TYPE_STATS, getStrutStyleForCoasterType, getCoasterCategory, areCoasterTypesCompatible } from '@/games/coaster/types/tracks';
-import { Building, BuildingType } from '@/games/coaster/types/buildings';
-import { spawnGuests, updateGuest } from '@/components/coaster/guests';
-import { perlinNoise } from '@/lib/simulation';
+import { ParkFinances, ParkStats, ParkSettings, GuestSurvey, StaffType, DEFAULT_PRICES, DEFAULT_BANKRUPTCY_GRACE_DAYS, DEFAULT_AUTOSAVE_INTERVAL_HOURS, DEFAULT_MAX_GUESTS, DEFAULT_SPAWN_RATE_MULTIPLIER, MAX_SPAWN_RATE_MULTIPLIER, isParkOpenAt, LOAN_CEILING, LoanSummary, calculateLoanPayment, MonthlyReport, RecentThought, RatingBreakdown, GuestInfo, GuestState, ParkStatsSnapshot, WeatherType, STALL_LOW_STOCK, LowStockStall } from '@/games/coaster/types/economy';
+import { Coaster, CoasterTrain, CoasterTrainConfig, CoasterDispatchPolicy, CoasterOperatingState, CoasterSummary, MAX_COASTER_NAME_LENGTH, isHexColor, normalizeHexColor, DEFAULT_CAR_SPACING, validateTrainConfig, TrackDirection, TrackHeight, TrackPiece, TrackPieceType, CoasterType, getStrutStyleForCoasterType, getStationStyleForCoasterType, isCoasterType, getCoasterCategory, areCoasterTypesCompatible } from '@/games/coaster/types/tracks';
+import { Building, BuildingType, ThemeZone, BuildingOrientation, rotateOrientation, getRideCapacity, getMaxQueueLength, isRideBuilding, isFoodBuilding, getRideProfile } from '@/games/coaster/types/buildings';
+import { getRideBuilding, findBuildingOrigin, getRideFairPrice, getFairRidePrice, findCoasterForRide, sampleGuestSurvey, findCrowdHotspots, getGuestOccupancy, isGuestNauseous, isStockedBuilding, CrowdHotspot, pickGuestAt as findGuestAt, clearPathCache as clearGuestPathCache } from '@/components/coaster/guests';
+import { findPathPlacementRoute, getStraightRunTiles } from '@/games/coaster/lib/pathPlacement';
+import { relinkQueues } from '@/games/coaster/lib/queueLines';
+import { isColorblindMode } from '@/lib/colorPalettes';
+import { applySpriteOverrides, exportSpriteLayout, SpriteLayoutResult } from '@/games/coaster/lib/coasterRenderConfig';
+import { simulationRandom } from '@/games/coaster/lib/random';
+import { BrokenQueue, findBrokenQueues } from '@/games/coaster/lib/queueValidation';
+import { findThemeZones } from '@/games/coaster/lib/themeZones';
+import { CoasterRatings } from '@/games/coaster/lib/coasterRatings';
+import { planElevationChange } from '@/games/coaster/lib/terrain';
+import { getLightCoverage, isTileLit } from '@/games/coaster/lib/lightCoverage';
+import { getBuildingAccessTile, isReachableFromEntrance } from '@/games/coaster/lib/buildingAccess';
+import { createInitialWeather, generateUUID, pushNotification, pushEvents, calculateMonthlyUpkeep, findStationTile, collectConnectedTrack, rateCoasterTrack, collectCoasterTrack, createTrainsForCoaster, createTrainsAtStation, createInitialCoasterGameState, simulateTick, getLastTickBreakdown, runSimulationTicks, DEFAULT_GRID_SIZE, RIDE_CYCLE_TIME, GAME_VERSION } from '@/games/coaster/lib/simulation';
+import { TRACK_TOOLS, findEntranceEdgeTile, getPlacementRejection, getTrackPlacementRejection } from '@/games/coaster/lib/placementPreview';
+import { getExitDirection, getTrackValidationErrors, isTrackComplete } from '@/games/coaster/lib/trackValidation';
+import { clampHeight, createDefaultCoaster, splitCoasterTrack } from '@/games/coaster/lib/trackFragments';
+import { getSellRefund } from '@/games/coaster/lib/sellRefund';
+import { createJanitor, JANITORS_PER_STAFF_BUILDING } from '@/components/coaster/staff';
 import {
   COASTER_AUTOSAVE_KEY,
   COASTER_SAVED_PARK_PREFIX,
   buildSavedParkMeta,
   loadCoasterStateFromStorage,
+  parseCoasterState,
   readSavedParksIndex,
   saveCoasterStateToStorage,
   saveCoasterStateToStorageAsync,
+  serializeCoasterState,
   upsertSavedParkMeta,
   writeSavedParksIndex,
 } from '@/games/coaster/saveUtils';
@@ -31,336 +62,15 @@ import {
 // CONSTANTS
 // =============================================================================
 
-const DEFAULT_GRID_SIZE = 60;
-
-// Weather change interval in ticks (roughly every 2-4 in-game hours)
-const WEATHER_CHANGE_MIN_TICKS = 120; // ~2 hours at normal speed
-const WEATHER_CHANGE_MAX_TICKS = 240; // ~4 hours at normal speed
-
 const SPEED_TICK_INTERVALS = [0, 50, 25, 16] as const; // ms per tick for 0x-3x
-const SPEED_TRAIN_BOOSTS = [1, 1.5, 2.0, 2.5] as const; // visual velocity boost by speed
-
-// =============================================================================
-// WEATHER SIMULATION
-// =============================================================================
-
-function createInitialWeather(month: number): WeatherState {
-  const initialWeather = pickNextWeather('sunny', month);
-  return {
-    current: initialWeather,
-    temperature: getTemperatureForWeather(initialWeather, month),
-    nextChange: Math.floor(Math.random() * (WEATHER_CHANGE_MAX_TICKS - WEATHER_CHANGE_MIN_TICKS)) + WEATHER_CHANGE_MIN_TICKS,
-    forecast: [
-      pickNextWeather(initialWeather, month),
-      pickNextWeather(initialWeather, month),
-      pickNextWeather(initialWeather, month),
-    ],
-  };
-}
-
-function pickNextWeather(current: WeatherType, month: number): WeatherType {
-  const transitions = WEATHER_TRANSITIONS[current];
-  const seasonBias = getSeasonalWeatherBias(month);
-  
-  // Build weighted probability list
-  const options: { weather: WeatherType; weight: number }[] = [];
-  for (const [weather, baseWeight] of Object.entries(transitions)) {
-    const bias = seasonBias[weather as WeatherType] ?? 1.0;
-    options.push({ weather: weather as WeatherType, weight: (baseWeight ?? 0) * bias });
-  }
-  
-  // Normalize weights
-  const totalWeight = options.reduce((sum, o) => sum + o.weight, 0);
-  const random = Math.random() * totalWeight;
-  
-  let cumulative = 0;
-  for (const option of options) {
-    cumulative += option.weight;
-    if (random <= cumulative) {
-      return option.weather;
-    }
-  }
-  
-  return options[options.length - 1]?.weather ?? 'sunny';
-}
-
-function getTemperatureForWeather(weather: WeatherType, month: number): number {
-  // Base temperature by season (Celsius)
-  let baseTemp: number;
-  if (month >= 6 && month <= 8) baseTemp = 28; // Summer
-  else if (month >= 3 && month <= 5) baseTemp = 18; // Spring
-  else if (month >= 9 && month <= 11) baseTemp = 14; // Fall
-  else baseTemp = 5; // Winter
-  
-  // Adjust by weather type
-  switch (weather) {
-    case 'hot': return baseTemp + 8 + Math.random() * 5;
-    case 'sunny': return baseTemp + 3 + Math.random() * 3;
-    case 'partly_cloudy': return baseTemp + Math.random() * 2;
-    case 'cloudy': return baseTemp - 2 + Math.random() * 2;
-    case 'rain': return baseTemp - 4 + Math.random() * 2;
-    case 'storm': return baseTemp - 5 + Math.random() * 3;
-    case 'cold': return baseTemp - 10 + Math.random() * 3;
-    default: return baseTemp;
-  }
-}
-
-function simulateWeather(weather: WeatherState, tick: number, month: number): WeatherState {
-  if (tick < weather.nextChange) {
-    return weather;
-  }
-  
-  // Time for weather change!
-  const newWeather = weather.forecast[0];
-  const nextChangeIn = Math.floor(Math.random() * (WEATHER_CHANGE_MAX_TICKS - WEATHER_CHANGE_MIN_TICKS)) + WEATHER_CHANGE_MIN_TICKS;
-  
-  // Shift forecast and add new prediction
-  const newForecast = [
-    weather.forecast[1],
-    weather.forecast[2],
-    pickNextWeather(weather.forecast[2], month),
-  ];
-  
-  return {
-    current: newWeather,
-    temperature: getTemperatureForWeather(newWeather, month),
-    nextChange: tick + nextChangeIn,
-    forecast: newForecast,
-  };
-}
-
-// Apply weather effects to a guest
-function applyWeatherEffectsToGuest(guest: Guest, weather: WeatherType): Guest {
-  const effects = WEATHER_EFFECTS[weather];
-  
-  // Clone guest to avoid mutation
-  const updatedGuest = { ...guest };
-  
-  // Apply thirst modifier
-  updatedGuest.thirst = Math.min(100, Math.max(0, updatedGuest.thirst + effects.thirstModifier));
-  
-  // Apply energy modifier (weather affects tiredness)
-  updatedGuest.energy = Math.max(0, updatedGuest.energy - effects.energyModifier);
-  
-  // Apply happiness modifier
-  updatedGuest.happiness = Math.min(100, Math.max(0, updatedGuest.happiness + effects.happinessModifier));
-  
-  // Add weather-related thoughts occasionally
-  if (Math.random() < 0.01) { // 1% chance per tick
-    const newThoughts = [...updatedGuest.thoughts];
-    let weatherThought: GuestThought | null = null;
-    
-    switch (weather) {
-      case 'sunny':
-      case 'partly_cloudy':
-        if (Math.random() < 0.5) weatherThought = 'weather_great';
-        else weatherThought = 'perfect_day';
-        break;
-      case 'rain':
-        weatherThought = 'getting_wet';
-        break;
-      case 'storm':
-        weatherThought = 'need_shelter';
-        break;
-      case 'hot':
-        weatherThought = 'too_hot';
-        break;
-      case 'cold':
-        weatherThought = 'too_cold';
-        break;
-    }
-    
-    if (weatherThought && !newThoughts.includes(weatherThought)) {
-      newThoughts.push(weatherThought);
-      // Keep only last 5 thoughts
-      if (newThoughts.length > 5) {
-        newThoughts.shift();
-      }
-      updatedGuest.thoughts = newThoughts;
-    }
-  }
-  
-  return updatedGuest;
-}
-
-// Check if a guest decides to leave due to weather
-// This is called every tick, so the chance must be VERY low
-function shouldGuestLeaveForWeather(guest: Guest, weather: WeatherType): boolean {
-  const effects = WEATHER_EFFECTS[weather];
-
-  // Base leave chance (extremely low - this is per tick!)
-  // With 50ms ticks at normal speed, this runs ~20 times per second
-  const baseChance = 0.0001;
+const MAX_TICK_CATCHUP_MS = 250; // Real time a single timer callback may catch up on (e.g. after a background tab)
+const AUTOSAVE_CHECK_MS = 10000; // Real time between autosave checks, so saves never come faster than this
+const AUTOSAVE_MAX_WAIT_MS = 30000; // Unsaved changes are saved after this long even if no game hours pass (e.g. paused)
+const MAX_RIDE_PRICE = 50;
 
-  // Only check in bad weather (storms)
-  if (weather !== 'storm' && weather !== 'rain') {
-    return false;
-  }
-
-  // Apply weather multiplier
-  let leaveChance = baseChance * effects.leaveChanceMultiplier;
-
-  // Only very unhappy guests consider leaving due to weather
-  if (guest.happiness >= 40) {
-    return false;
-  }
-  
-  // Even then, give them a further reduction
-  leaveChance *= 0.5;
-
-  // Guests who just arrived are much less likely to leave
-  if (guest.timeInPark < 600) { // Less than 10 minutes
-    leaveChance *= 0.1;
-  }
-  
-  return Math.random() < leaveChance;
-}
-
-// =============================================================================
-// TERRAIN GENERATION
-// =============================================================================
-
-// Generate 2-3 large, round lakes for the park terrain
-// Uses perlinNoise imported from @/lib/simulation
-function generateLakes(grid: Tile[][], size: number, seed: number): void {
-  // Use noise to find potential lake centers - look for low points
-  const lakeNoise = (x: number, y: number) => perlinNoise(x, y, seed + 1000, 3);
-  
-  // Find lake seed points (local minimums in noise)
-  const lakeCenters: { x: number; y: number; noise: number }[] = [];
-  const minDistFromEdge = Math.max(8, Math.floor(size * 0.15)); // Keep lakes away from edges
-  const minDistBetweenLakes = Math.max(size * 0.2, 10); // Adaptive but ensure minimum separation
-  
-  // Collect all potential lake centers with adaptive threshold
-  let threshold = 0.5;
-  let attempts = 0;
-  const maxAttempts = 3;
-  
-  while (lakeCenters.length < 2 && attempts < maxAttempts) {
-    lakeCenters.length = 0; // Reset for this attempt
-    
-    for (let y = minDistFromEdge; y < size - minDistFromEdge; y++) {
-      for (let x = minDistFromEdge; x < size - minDistFromEdge; x++) {
-        const noiseVal = lakeNoise(x, y);
-        
-        // Check if this is a good lake center (low noise value)
-        if (noiseVal < threshold) {
-          // Check distance from other lake centers
-          let tooClose = false;
-          for (const center of lakeCenters) {
-            const dist = Math.sqrt((x - center.x) ** 2 + (y - center.y) ** 2);
-            if (dist < minDistBetweenLakes) {
-              tooClose = true;
-              break;
-            }
-          }
-          
-          if (!tooClose) {
-            lakeCenters.push({ x, y, noise: noiseVal });
-          }
-        }
-      }
-    }
-    
-    // If we found enough centers, break
-    if (lakeCenters.length >= 2) break;
-    
-    // Otherwise, relax the threshold for next attempt
-    threshold += 0.1;
-    attempts++;
-  }
-  
-  // If still no centers found, force create at least 2 lakes at strategic positions
-  if (lakeCenters.length === 0) {
-    const safeZone = minDistFromEdge + 5;
-    const quarterSize = Math.max(safeZone, Math.floor(size / 4));
-    const threeQuarterSize = Math.min(size - safeZone, Math.floor(size * 3 / 4));
-    lakeCenters.push(
-      { x: quarterSize, y: quarterSize, noise: 0 },
-      { x: threeQuarterSize, y: threeQuarterSize, noise: 0 }
-    );
-  } else if (lakeCenters.length === 1) {
-    // If only one center found, add another at a safe distance
-    const existing = lakeCenters[0];
-    const safeZone = minDistFromEdge + 5;
-    const quarterSize = Math.max(safeZone, Math.floor(size / 4));
-    const threeQuarterSize = Math.min(size - safeZone, Math.floor(size * 3 / 4));
-    const newX = existing.x > size / 2 ? quarterSize : threeQuarterSize;
-    const newY = existing.y > size / 2 ? quarterSize : threeQuarterSize;
-    lakeCenters.push({ x: newX, y: newY, noise: 0 });
-  }
-  
-  // Sort by noise value (lowest first) and pick 2-3 best candidates
-  lakeCenters.sort((a, b) => a.noise - b.noise);
-  const numLakes = 2 + Math.floor(Math.random() * 2); // 2 or 3 lakes
-  const selectedCenters = lakeCenters.slice(0, Math.min(numLakes, lakeCenters.length));
-  
-  // Grow lakes from each center using radial expansion for rounder shapes
-  for (const center of selectedCenters) {
-    // Target size: 40-80 tiles for bigger lakes
-    const targetSize = 40 + Math.floor(Math.random() * 41);
-    const lakeTiles: { x: number; y: number }[] = [{ x: center.x, y: center.y }];
-    const candidates: { x: number; y: number; dist: number; noise: number }[] = [];
-    
-    // Add initial neighbors as candidates
-    const directions = [[-1, 0], [1, 0], [0, -1], [0, 1], [-1, -1], [-1, 1], [1, -1], [1, 1]];
-    for (const [dx, dy] of directions) {
-      const nx = center.x + dx;
-      const ny = center.y + dy;
-      if (nx >= minDistFromEdge && nx < size - minDistFromEdge && 
-          ny >= minDistFromEdge && ny < size - minDistFromEdge) {
-        const dist = Math.sqrt(dx * dx + dy * dy);
-        const noise = lakeNoise(nx, ny);
-        candidates.push({ x: nx, y: ny, dist, noise });
-      }
-    }
-    
-    // Grow lake by adding adjacent tiles, prioritizing:
-    // 1. Closer to center (for rounder shape)
-    // 2. Lower noise values (for organic shape)
-    while (lakeTiles.length < targetSize && candidates.length > 0) {
-      // Sort by distance from center first, then noise
-      candidates.sort((a, b) => {
-        if (Math.abs(a.dist - b.dist) < 0.5) {
-          return a.noise - b.noise;
-        }
-        return a.dist - b.dist;
-      });
-      
-      // Pick from top candidates (closest/lowest noise)
-      const pickIndex = Math.floor(Math.random() * Math.min(5, candidates.length));
-      const picked = candidates.splice(pickIndex, 1)[0];
-      
-      // Check if already in lake
-      if (lakeTiles.some(t => t.x === picked.x && t.y === picked.y)) continue;
-      
-      // Check if tile is valid (not already water from another lake)
-      if (grid[picked.y][picked.x].building.type === 'water') continue;
-      
-      lakeTiles.push({ x: picked.x, y: picked.y });
-      
-      // Add new neighbors as candidates
-      for (const [dx, dy] of directions) {
-        const nx = picked.x + dx;
-        const ny = picked.y + dy;
-        if (nx >= minDistFromEdge && nx < size - minDistFromEdge && 
-            ny >= minDistFromEdge && ny < size - minDistFromEdge &&
-            !lakeTiles.some(t => t.x === nx && t.y === ny) &&
-            !candidates.some(c => c.x === nx && c.y === ny)) {
-          const dist = Math.sqrt((nx - center.x) ** 2 + (ny - center.y) ** 2);
-          const noise = lakeNoise(nx, ny);
-          candidates.push({ x: nx, y: ny, dist, noise });
-        }
-      }
-    }
-    
-    // Apply lake tiles to grid
-    for (const tile of lakeTiles) {
-      grid[tile.y][tile.x].terrain = 'water';
-      grid[tile.y][tile.x].building = { ...createEmptyBuilding(), type: 'water' };
-    }
-  }
-}
+// Tools that can be laid as a straight run between two clicked tiles
+const TRACK_LINE_TOOLS: Tool[] = ['coaster_build', 'coaster_track', 'coaster_slope_up', 'coaster_slope_down'];
+const LINE_TOOLS: Tool[] = ['path', 'queue', ...TRACK_LINE_TOOLS];
 
 // =============================================================================
 // CONTEXT TYPE
@@ -374,12 +84,23 @@ interface CoasterContextValue {
   setTool: (tool: Tool) => void;
   setSpeed: (speed: 0 | 1 | 2 | 3, isRemote?: boolean) => void;
   setActivePanel: (panel: GameState['activePanel']) => void;
+  setOverlayMode: (mode: OverlayMode) => void;
+  setOverlayPalette: (palette: string) => boolean;
+  setSpriteLayout: (sheetId: string, layout: unknown) => SpriteLayoutResult;
+  getSpriteLayout: (sheetId: string) => string | null;
+  setDebugGrid: (on: boolean) => void;
+  getGuestDensityOverlay: () => number[][];
   
   // Placement
   placeAtTile: (x: number, y: number, isRemote?: boolean) => void;
   bulldozeTile: (x: number, y: number, isRemote?: boolean) => void;
+  bulldozeArea: (start: { x: number; y: number }, end: { x: number; y: number }, isRemote?: boolean) => number;
+  getBulldozeAreaCost: (start: { x: number; y: number }, end: { x: number; y: number }) => number;
   setPlaceCallback: (callback: ((args: { x: number; y: number; tool: Tool }) => void) | null) => void;
   setBulldozeCallback: (callback: ((args: { x: number; y: number }) => void) | null) => void;
+  setBulldozeAreaCallback: (callback: ((args: { start: { x: number; y: number }; end: { x: number; y: number } }) => void) | null) => void;
+  placePathRoute: (start: { x: number; y: number }, end: { x: number; y: number }) => void;
+  placeLine: (start: { x: number; y: number }, end: { x: number; y: number }) => boolean;
   
   // Coaster building
   startCoasterBuild: (coasterType: string, options?: { coasterId?: string; isRemote?: boolean }) => void;
@@ -393,18 +114,73 @@ interface CoasterContextValue {
   // Track line placement (for drag-to-draw)
   placeTrackLine: (tiles: { x: number; y: number }[]) => void;
   
+  // Train configuration
+  setCoasterTrains: (coasterId: string, numTrains: number, carsPerTrain: number, carSpacing?: number) => boolean;
+  setDispatchPolicy: (coasterId: string, minWait: number, maxWait: number) => boolean;
+  setCoasterOperating: (coasterId: string, operating: boolean) => boolean;
+  getCoasterState: (coasterId: string) => CoasterOperatingState | null;
+  getActiveCoasterErrors: () => string[];
+  getCoasterList: () => CoasterSummary[];
+  setActiveCoaster: (coasterId: string) => boolean;
+  setCoasterType: (coasterType: string) => boolean;
+  renameCoaster: (coasterId: string, name: string) => boolean;
+  demolishCoaster: (coasterId: string) => boolean;
+  setCoasterColors: (coasterId: string, primary: string, secondary: string, supports: string) => boolean;
+  getCoasterStats: (coasterId: string) => CoasterRatings | null;
+  
   // Park management
   setParkSettings: (settings: Partial<ParkSettings>, isRemote?: boolean) => void;
   addMoney: (amount: number) => void;
   clearGuests: () => void;
   addNotification: (title: string, description: string, icon: Notification['icon']) => void;
+  sampleGuestSurvey: (sampleSize: number) => GuestSurvey;
+  getBrokenQueues: () => BrokenQueue[];
+  getCrowdHotspots: (threshold: number) => CrowdHotspot[];
+  getThemeZones: () => ThemeZone[];
+  getLightCoverage: (x: number, y: number) => boolean;
+  getLightCoverageOverlay: () => Uint8Array;
+  getRideStats: (rideId: string) => RideStats;
+  getRideInfo: (x: number, y: number) => RideInfo | null;
+  setRidePrice: (x: number, y: number, price: number) => void;
+  getStaffCount: (type?: StaffType) => number;
+  isBankrupt: () => boolean;
+  takeLoan: (amount: number) => boolean;
+  repayLoan: (amount: number) => boolean;
+  getDebt: () => LoanSummary;
+  getMonthlyReport: () => MonthlyReport | null;
+  getMonthlyUpkeep: () => number;
+  getLowStockStalls: () => LowStockStall[];
+  benchmarkTicks: (ticks: number) => number;
+  getTickBreakdown: () => TickBreakdown;
+  getRecentThoughts: (limit?: number) => RecentThought[];
+  getWeather: () => WeatherType;
+  getRatingBreakdown: () => RatingBreakdown;
+  getGuestIds: () => string[];
+  getGuestInfo: (id: string) => GuestInfo | null;
+  pickGuestAt: (screenX: number, screenY: number) => string | null;
+  inspectTile: (x: number, y: number) => TileInspection | null;
+  previewTrackPiece: (x: number, y: number, tool: Tool) => TrackPlacementPreview;
+  getStatsSnapshot: () => ParkStatsSnapshot;
+  drainEvents: () => GameEvent[];
+  setAutosaveInterval: (hours: number) => void;
+  setMaxGuests: (maxGuests: number) => void;
+  setParkHours: (openHour: number, closeHour: number) => boolean;
+  isParkOpen: () => boolean;
+  setSpawnMultiplier: (multiplier: number) => void;
+  saveViewpoint: (slot: number, camera: { offset: { x: number; y: number }; zoom: number }) => boolean;
+  getViewpoint: (slot: number) => CameraViewpoint | null;
+  listViewpoints: () => CameraViewpoint[];
+  rotatePlacement: () => BuildingOrientation;
+  clearPathCache: () => void;
   setParkSettingsCallback: (callback: ((settings: Partial<ParkSettings>) => void) | null) => void;
   setSpeedCallback: (callback: ((speed: 0 | 1 | 2 | 3) => void) | null) => void;
   
   // Save/Load
   saveGame: () => void;
   loadGame: () => boolean;
-  newGame: (name?: string) => void;
+  newGame: (name?: string, seed?: number) => void;
+  resetPark: (gridSize?: number) => void;
+  clearAllBuildings: () => void;
   hasSavedGame: boolean;
   
   // Export/Import (for settings panel)
@@ -413,6 +189,8 @@ interface CoasterContextValue {
   
   // State flags
   isStateReady: boolean;
+  spriteLayoutVersion: number; // Bumped when sprite alignment overrides change so the park redraws
+  debugGrid: boolean; // Tile outlines and coordinates drawn over the park
 }
 
 const CoasterContext = createContext<CoasterContextValue | null>(null);
@@ -421,12 +199,26 @@ const CoasterContext = createContext<CoasterContextValue | null>(null);
 // HELPER FUNCTIONS
 // =============================================================================
 
-function generateUUID(): string {
-  return 'xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx'.replace(/[xy]/g, function(c) {
-    const r = Math.random() * 16 | 0;
-    const v = c === 'x' ? r : (r & 0x3 | 0x8);
-    return v.toString(16);
-  });
+/**
+ * Turn down a placement the player should hear about (wrong terrain, an
+ * entrance away from the edge): nothing is built or charged, and the player
+ * (and any event listener) is told why
+ */
+function rejectPlacement(state: GameState, tool: Tool, x: number, y: number, title: string, reason: string): GameState {
+  const notified = pushNotification(state, title, reason, 'warning', { x, y });
+  return pushEvents(notified, [{ type: 'placement_rejected', tick: state.tick, tool, x, y, reason }]);
+}
+
+/**
+ * Pay for construction now and book it in this month's ledger.
+ * Negative amounts (sell refunds) credit it back.
+ */
+function chargeConstruction(finances: ParkFinances, amount: number): ParkFinances {
+  return {
+    ...finances,
+    cash: finances.cash - amount,
+    expenseConstruction: finances.expenseConstruction + amount,
+  };
 }
 
 const DIRECTION_ORDER: TrackDirection[] = ['north', 'east', 'south', 'west'];
@@ -451,22 +243,30 @@ function directionFromDelta(dx: number, dy: number): TrackDirection | null {
   return null;
 }
 
-function clampHeight(height: number): TrackHeight {
-  if (height <= 0) return 0;
-  if (height >= 10) return 10;
-  return height as TrackHeight;
+interface TrackPiecePlan {
+  pieceType: TrackPieceType;
+  startDirection: TrackDirection;
+  endDirection: TrackDirection;
+  startHeight: TrackHeight;
+  endHeight: TrackHeight;
+  chainLift: boolean;
 }
 
 /**
- * Find the best station tile for a coaster - prioritizes tiles with adjacent queue lines
- * Falls back to first track tile if no queue-adjacent tile is found
+ * Work out the piece a track tool would lay at (x, y): its type, direction and
+ * heights, continuing from adjacent track or the piece last built
  */
-function findStationTile(
-  grid: Tile[][],
-  trackTiles: { x: number; y: number }[],
-  gridSize: number
-): { x: number; y: number } | null {
-  if (trackTiles.length === 0) return null;
+function planTrackPiece(state: GameState, x: number, y: number, tool: Tool): TrackPiecePlan {
+  const buildPath = state.buildingCoasterPath;
+  const lastTile = buildPath.length > 0 ? buildPath[buildPath.length - 1] : null;
+  const deltaDir = lastTile ? directionFromDelta(x - lastTile.x, y - lastTile.y) : null;
+  
+  // ALWAYS check for adjacent existing track to inherit direction and height
+  // When multiple adjacent tracks exist, prefer the one that matches the build path
+  let adjacentDirection: TrackDirection | null = null;
+  let adjacentHeight = state.buildingCoasterHeight;
+  let connectingToEntry = false; // True if we're feeding INTO adjacent track's entry
+  let targetEntryHeight = 0; // The height we need our exit to be at when connecting to entry
   
   const adjacentOffsets = [
     { dx: -1, dy: 0 },
@@ -475,137 +275,235 @@ function findStationTile(
     { dx: 0, dy: 1 },
   ];
   
-  // First, look for a track tile with an adjacent queue
-  for (const trackTile of trackTiles) {
-    for (const { dx, dy } of adjacentOffsets) {
-      const adjX = trackTile.x + dx;
-      const adjY = trackTile.y + dy;
-      if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
-        const adjTile = grid[adjY]?.[adjX];
-        if (adjTile?.queue) {
-          return trackTile;
+  type AdjacentCandidate = {
+    adjX: number;
+    adjY: number;
+    baseDirection: TrackDirection;
+    baseHeight: number;
+    connectingToEntry: boolean;
+    targetEntryHeight: number;
+    isExitConnection: boolean;
+  };
+  
+  const candidates: AdjacentCandidate[] = [];
+  
+  for (const { dx, dy } of adjacentOffsets) {
+    const adjX = x + dx;
+    const adjY = y + dy;
+    if (adjX >= 0 && adjY >= 0 && adjX < state.gridSize && adjY < state.gridSize) {
+      const adjTile = state.grid[adjY]?.[adjX];
+      if (adjTile?.trackPiece) {
+        const adjPiece = adjTile.trackPiece;
+        
+        // Calculate entry and exit directions for the adjacent piece
+        // Turns store entry direction; straights/slopes store exit direction.
+        const isFlatTurn =
+          adjPiece.type === 'turn_left_flat' ||
+          adjPiece.type === 'turn_right_flat' ||
+          adjPiece.type === 'turn_left_large_flat' ||
+          adjPiece.type === 'turn_right_large_flat';
+        const entryDir = isFlatTurn ? adjPiece.direction : OPPOSITE_DIRECTION[adjPiece.direction];
+        
+        // Exit direction depends on track type
+        let exitDir = adjPiece.direction;
+        if (adjPiece.type === 'turn_left_flat' || adjPiece.type === 'turn_left_large_flat') {
+          exitDir = rotateDirection(adjPiece.direction, 'left');
+        } else if (adjPiece.type === 'turn_right_flat' || adjPiece.type === 'turn_right_large_flat') {
+          exitDir = rotateDirection(adjPiece.direction, 'right');
+        }
+        
+        // Check if adjacent track's EXIT points toward us (we connect to receive from it)
+        // Adjacent is at (x + dx, y + dy) relative to our new tile at (x, y)
+        const exitPointsToUs = (
+          (exitDir === 'south' && dx === -1) ||
+          (exitDir === 'north' && dx === 1) ||
+          (exitDir === 'west' && dy === -1) ||
+          (exitDir === 'east' && dy === 1)
+        );
+        
+        // Check if adjacent track's ENTRY points toward us (we connect to feed into it)
+        const entryPointsToUs = (
+          (entryDir === 'south' && dx === -1) ||
+          (entryDir === 'north' && dx === 1) ||
+          (entryDir === 'west' && dy === -1) ||
+          (entryDir === 'east' && dy === 1)
+        );
+        
+        if (exitPointsToUs) {
+          candidates.push({
+            adjX,
+            adjY,
+            baseDirection: exitDir,
+            baseHeight: adjPiece.endHeight,
+            connectingToEntry: false,
+            targetEntryHeight: adjPiece.startHeight,
+            isExitConnection: true,
+          });
+        } else if (entryPointsToUs) {
+          candidates.push({
+            adjX,
+            adjY,
+            baseDirection: OPPOSITE_DIRECTION[entryDir],
+            baseHeight: adjPiece.startHeight,
+            connectingToEntry: true,
+            targetEntryHeight: adjPiece.startHeight,
+            isExitConnection: false,
+          });
         }
       }
     }
   }
   
-  // Second, look for a track tile with an adjacent station building
-  for (const trackTile of trackTiles) {
-    for (const { dx, dy } of adjacentOffsets) {
-      const adjX = trackTile.x + dx;
-      const adjY = trackTile.y + dy;
-      if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
-        const adjTile = grid[adjY]?.[adjX];
-        if (adjTile?.building?.type?.startsWith('station_')) {
-          return trackTile;
-        }
-      }
-    }
+  if (candidates.length > 0) {
+    const lastTileMatch = lastTile
+      ? candidates.find(candidate =>
+          candidate.adjX === lastTile.x &&
+          candidate.adjY === lastTile.y &&
+          candidate.isExitConnection
+        )
+      : null;
+    
+    const directionMatch = deltaDir
+      ? candidates.find(candidate =>
+          candidate.baseDirection === deltaDir && candidate.isExitConnection
+        )
+      : null;
+    
+    const exitCandidates = candidates.filter(candidate => candidate.isExitConnection);
+    const heightSorted = (list: AdjacentCandidate[]) =>
+      list.slice().sort((a, b) =>
+        Math.abs(a.baseHeight - state.buildingCoasterHeight) -
+        Math.abs(b.baseHeight - state.buildingCoasterHeight)
+      );
+    
+    const chosen = lastTileMatch
+      ?? directionMatch
+      ?? heightSorted(exitCandidates)[0]
+      ?? heightSorted(candidates)[0];
+    
+    adjacentDirection = chosen.baseDirection;
+    adjacentHeight = chosen.baseHeight;
+    connectingToEntry = chosen.connectingToEntry;
+    targetEntryHeight = chosen.targetEntryHeight;
   }
   
-  // Fall back to first track tile
-  return trackTiles[0];
-}
-
-export function createInitialCoasterGameState(parkName: string = 'My Theme Park', gridSize: number = DEFAULT_GRID_SIZE): GameState {
-  // Create empty grid
-  const grid: Tile[][] = [];
-  for (let y = 0; y < gridSize; y++) {
-    const row: Tile[] = [];
-    for (let x = 0; x < gridSize; x++) {
-      row.push(createEmptyTile(x, y));
+  // Determine track directions
+  // Priority: adjacentDirection (from existing track) > deltaDir (from drag) > lastDirection > default
+  const baseDirection = adjacentDirection ?? deltaDir ?? state.buildingCoasterLastDirection ?? 'south';
+  let startDirection: TrackDirection = baseDirection;
+  let endDirection: TrackDirection = baseDirection;
+  let pieceType: TrackPieceType = 'straight_flat';
+  let startHeight = adjacentHeight;
+  let endHeight = adjacentHeight;
+  let chainLift = false;
+  
+  if (tool === 'coaster_turn_left') {
+    pieceType = 'turn_left_flat';
+    // For turns, the drawing code interprets direction as "entering FROM" (not traveling TO)
+    if (adjacentDirection) {
+      if (connectingToEntry) {
+        // Feeding into adjacent's entry - our EXIT must go toward adjacent
+        // adjacentDirection is where we need to exit TO
+        // For turn_left: exit = rotateDirection(entry, 'left')
+        // So: entry = rotateDirection(exit, 'right')
+        startDirection = rotateDirection(adjacentDirection, 'right');
+      } else {
+        // Receiving from adjacent's exit - we enter FROM the opposite of where they're going
+        startDirection = OPPOSITE_DIRECTION[adjacentDirection];
+      }
+    }
+    endDirection = rotateDirection(startDirection, 'left');
+  } else if (tool === 'coaster_turn_right') {
+    pieceType = 'turn_right_flat';
+    if (adjacentDirection) {
+      if (connectingToEntry) {
+        // For turn_right: exit = rotateDirection(entry, 'right')
+        // So: entry = rotateDirection(exit, 'left')
+        startDirection = rotateDirection(adjacentDirection, 'left');
+      } else {
+        startDirection = OPPOSITE_DIRECTION[adjacentDirection];
+      }
+    }
+    endDirection = rotateDirection(startDirection, 'right');
+  } else if (tool === 'coaster_slope_up') {
+    pieceType = 'slope_up_small';
+    // For slopes, drawSlopeTrack interprets direction as the EXIT direction:
+    // direction='south' → enter from north (at startHeight), exit to south (at endHeight)
+    //
+    // For slope_up: startHeight < endHeight, so the slope rises toward the exit.
+    //
+    // When connecting to existing track:
+    // - Exit connection: adjacent exits toward us, we receive at our entry
+    // - Entry connection: we feed into adjacent's entry
+    // adjacentDirection already matches travel direction in both cases.
+    if (adjacentDirection) {
+      // For both entry/exit connections, adjacentDirection already matches travel direction.
+      startDirection = adjacentDirection;
+    }
+    if (connectingToEntry && targetEntryHeight > 0) {
+      endHeight = targetEntryHeight;
+      startHeight = clampHeight(targetEntryHeight - 1);
+    } else {
+      endHeight = clampHeight(startHeight + 1);
     }
-    grid.push(row);
+    chainLift = true;
+  } else if (tool === 'coaster_slope_down') {
+    pieceType = 'slope_down_small';
+    // For slope_down, direction logic is the SAME as slope_up:
+    // adjacentDirection already matches travel direction for entry/exit connections.
+    if (adjacentDirection) {
+      // For both entry/exit connections, adjacentDirection already matches travel direction.
+      startDirection = adjacentDirection;
+    }
+    if (connectingToEntry && targetEntryHeight < 10) {
+      endHeight = targetEntryHeight;
+      startHeight = clampHeight(targetEntryHeight + 1);
+    } else {
+      // Going down: start high, end low
+      endHeight = clampHeight(startHeight - 1);
+    }
+    chainLift = false;
+  } else if (tool === 'coaster_loop') {
+    pieceType = 'loop_vertical';
+  } else if (tool === 'coaster_build') {
+    if (deltaDir) {
+      startDirection = deltaDir;
+      endDirection = deltaDir;
+    }
+    pieceType = 'straight_flat';
+  } else {
+    pieceType = 'straight_flat';
   }
   
-  // Generate random lakes using procedural terrain generation (2-3 lakes)
-  const seed = Math.random() * 1000;
-  generateLakes(grid, gridSize, seed);
   
   return {
-    id: generateUUID(),
-    
-    grid,
-    gridSize,
-    
-    year: 1,
-    month: 3, // March - spring opening
-    day: 1,
-    hour: 8,
-    minute: 0,
-    tick: 0,
-    speed: 1,
-    
-    settings: {
-      name: parkName,
-      entranceFee: DEFAULT_PRICES.parkEntrance,
-      payPerRide: false,
-      openHour: 9,
-      closeHour: 22,
-      loanInterest: 0.1,
-      landCost: 100,
-      objectives: [],
-    },
-    
-    stats: {
-      guestsInPark: 0,
-      guestsTotal: 0,
-      guestsSatisfied: 0,
-      guestsUnsatisfied: 0,
-      averageHappiness: 0,
-      totalRides: 0,
-      totalRidesRidden: 0,
-      averageQueueTime: 0,
-      parkValue: 0,
-      companyValue: 10000,
-      parkRating: 0,
-    },
-    
-    finances: {
-      cash: 10000,
-      incomeAdmissions: 0,
-      incomeRides: 0,
-      incomeFood: 0,
-      incomeShops: 0,
-      incomeTotal: 0,
-      expenseWages: 0,
-      expenseUpkeep: 0,
-      expenseMarketing: 0,
-      expenseResearch: 0,
-      expenseTotal: 0,
-      profit: 0,
-      history: [],
-    },
-    
-    guests: [],
-    staff: [],
-    coasters: [],
-    
-    // Weather
-    weather: createInitialWeather(3), // March - spring opening
-    
-    selectedTool: 'select',
-    activePanel: 'none',
-    notifications: [],
-    
-    buildingCoasterId: null,
-    buildingCoasterPath: [],
-    buildingCoasterHeight: 0,
-    buildingCoasterLastDirection: null,
-    buildingCoasterType: null,
-    
-    gameVersion: 1,
+    pieceType,
+    startDirection,
+    endDirection,
+    startHeight: clampHeight(startHeight),
+    endHeight: clampHeight(endHeight),
+    chainLift,
   };
 }
 
 function normalizeLoadedState(state: GameState): GameState {
+  // Saves from before per-ride pricing have every ride at $0; start them at a fair price
+  const needsRidePrices = (state.gameVersion ?? 1) < 2;
   const normalizedGrid = state.grid.map(row =>
     row.map(tile => ({
       ...tile,
+      building: needsRidePrices && isRideBuilding(tile.building.type) && !tile.building.type.endsWith('_footprint')
+        ? { ...tile.building, price: getDefaultRidePrice(tile.building.type) }
+        : tile.building,
       trackPiece: tile.trackPiece ?? null,
-      hasCoasterTrack: tile.hasCoasterTrack || Boolean(tile.trackPiece),
+      elevation: tile.elevation ?? 0,
+      // Track flags follow the track pieces so tiles never claim a coaster they don't hold
+      hasCoasterTrack: Boolean(tile.trackPiece),
+      coasterTrackId: tile.trackPiece ? tile.coasterTrackId ?? null : null,
     }))
   );
+  // Older saves never linked queues to their rides
+  relinkQueues(normalizedGrid);
 
   // Recollect track tiles and pieces from the grid to fix any incorrect track order
   // This ensures cars travel in the correct direction through all track pieces
@@ -631,18 +529,8 @@ function normalizeLoadedState(state: GameState): GameState {
       stationTileX: stationTile.x,
       stationTileY: stationTile.y,
       // Regenerate trains with proper multi-train configuration and station position
-      trains: createTrainsForCoaster(collectedPieces.length, coaster.type).map((train, trainIndex) => {
-        // Position trains evenly around the track, starting from station
-        const trainOffset = (trainIndex * collectedPieces.length) / Math.max(1, createTrainsForCoaster(collectedPieces.length, coaster.type).length);
-        const baseProgress = (effectiveStationIdx + trainOffset) % collectedPieces.length;
-        return {
-          ...train,
-          cars: train.cars.map((car, carIndex) => ({
-            ...car,
-            trackProgress: (baseProgress + carIndex * 0.18) % collectedPieces.length,
-          })),
-        };
-      }),
+      trains: createTrainsAtStation(collectedPieces.length, effectiveStationIdx, coaster.type, coaster.trainConfig),
+      ...rateCoasterTrack(collectedTiles, collectedPieces, coaster.type),
     };
   }).filter((coaster): coaster is NonNullable<typeof coaster> => coaster !== null);
 
@@ -660,435 +548,46 @@ function normalizeLoadedState(state: GameState): GameState {
       decisionCooldown: guest.decisionCooldown ?? 0,
       targetBuildingId: guest.targetBuildingId ?? null,
       targetBuildingKind: guest.targetBuildingKind ?? null,
+      thrillPreference: guest.thrillPreference ?? Math.max(0, Math.min(1, (guest.preferIntensity - 2) / 6)),
+      budgetTier: guest.budgetTier ?? 'medium',
     })),
     weather: normalizedWeather,
+    settings: {
+      ...state.settings,
+      bankruptcyGraceDays: state.settings.bankruptcyGraceDays ?? DEFAULT_BANKRUPTCY_GRACE_DAYS,
+      autosaveIntervalHours: state.settings.autosaveIntervalHours ?? DEFAULT_AUTOSAVE_INTERVAL_HOURS,
+      maxGuests: state.settings.maxGuests ?? DEFAULT_MAX_GUESTS,
+      spawnRateMultiplier: state.settings.spawnRateMultiplier ?? DEFAULT_SPAWN_RATE_MULTIPLIER,
+    },
+    stats: {
+      ...state.stats,
+      guestsDeparted: state.stats.guestsDeparted ?? 0,
+      departedGuestSpending: state.stats.departedGuestSpending ?? 0,
+    },
+    finances: {
+      ...state.finances,
+      expenseConstruction: state.finances.expenseConstruction ?? 0,
+      reports: state.finances.reports ?? [],
+      daysInDebt: state.finances.daysInDebt ?? 0,
+      bankrupt: state.finances.bankrupt ?? false,
+      loan: state.finances.loan ?? 0,
+      loanMonthlyPayment: state.finances.loanMonthlyPayment ?? 0,
+    },
     buildingCoasterId: state.buildingCoasterId ?? null,
     buildingCoasterPath: state.buildingCoasterPath ?? [],
     buildingCoasterHeight: state.buildingCoasterHeight ?? 0,
     buildingCoasterLastDirection: state.buildingCoasterLastDirection ?? null,
     buildingCoasterType: state.buildingCoasterType ?? null,
+    gameVersion: Math.max(state.gameVersion ?? 1, GAME_VERSION),
   };
 }
 
-function isRideBuilding(type: string) {
-  return type.startsWith('ride_') || type.startsWith('show_') || type.startsWith('station_');
-}
-
-function calculateMonthlyUpkeep(grid: Tile[][]): { upkeep: number; buildingCount: number; rideCount: number; trackCount: number } {
-  let buildingCount = 0;
-  let rideCount = 0;
-  let trackCount = 0;
-  
-  for (const row of grid) {
-    for (const tile of row) {
-      if (tile.trackPiece) trackCount += 1;
-      const type = tile.building?.type;
-      if (!type || type === 'empty' || type === 'grass' || type === 'water' || type === 'path' || type === 'queue') {
-        continue;
-      }
-      buildingCount += 1;
-      if (isRideBuilding(type)) rideCount += 1;
-    }
-  }
-  
-  const upkeep = buildingCount * 5 + rideCount * 20 + trackCount * 2;
-  return { upkeep, buildingCount, rideCount, trackCount };
-}
-
-function calculateStaffWages(staff: Staff[]): number {
-  const wageMap: Record<Staff['type'], number> = {
-    handyman: DEFAULT_PRICES.handymanWage,
-    mechanic: DEFAULT_PRICES.mechanicWage,
-    security: DEFAULT_PRICES.securityWage,
-    entertainer: DEFAULT_PRICES.entertainerWage,
-  };
-  return staff.reduce((sum, member) => sum + (wageMap[member.type] ?? 0), 0);
-}
-
-/**
- * Get the exit direction for a track piece.
- * For straight pieces, exit direction = entry direction.
- * For curves, exit direction is rotated based on turn type.
- */
-function getExitDirection(piece: TrackPiece): TrackDirection {
-  const { type, direction } = piece;
-  
-  if (type === 'turn_right_flat' || type === 'turn_right_large_flat') {
-    // Right turn: north->east, east->south, south->west, west->north
-    const rightTurn: Record<TrackDirection, TrackDirection> = {
-      north: 'east', east: 'south', south: 'west', west: 'north'
-    };
-    return rightTurn[direction];
-  }
-  
-  if (type === 'turn_left_flat' || type === 'turn_left_large_flat') {
-    // Left turn: north->west, west->south, south->east, east->north
-    const leftTurn: Record<TrackDirection, TrackDirection> = {
-      north: 'west', west: 'south', south: 'east', east: 'north'
-    };
-    return leftTurn[direction];
-  }
-  
-  // Straight pieces, slopes, loops - exit in same direction
-  return direction;
-}
-
-/**
- * Get the grid offset to the next tile based on direction.
- * In our isometric grid:
- * - North: x-1, y unchanged (moving up-left visually)
- * - South: x+1, y unchanged (moving down-right visually)  
- * - East: y-1, x unchanged (moving up-right visually)
- * - West: y+1, x unchanged (moving down-left visually)
- */
-function getDirectionOffset(dir: TrackDirection): { dx: number; dy: number } {
-  const offsets: Record<TrackDirection, { dx: number; dy: number }> = {
-    north: { dx: -1, dy: 0 },
-    south: { dx: 1, dy: 0 },
-    east: { dx: 0, dy: -1 },
-    west: { dx: 0, dy: 1 },
-  };
-  return offsets[dir];
-}
-
-/**
- * Calculate the correct direction for a straight track piece based on the actual tile flow.
- * This fixes pieces that have incorrect stored directions.
- */
-function calculateCorrectDirection(
-  prevTile: { x: number; y: number } | null,
-  currTile: { x: number; y: number },
-  nextTile: { x: number; y: number } | null,
-  piece: TrackPiece
-): TrackPiece {
-  const { type } = piece;
-  
-  // For turns, we need to calculate based on entry direction
-  if (type === 'turn_left_flat' || type === 'turn_right_flat' || type === 'turn_left_large_flat' || type === 'turn_right_large_flat') {
-    if (prevTile) {
-      // Calculate entry direction (where we came FROM)
-      const dx = currTile.x - prevTile.x;
-      const dy = currTile.y - prevTile.y;
-      
-      let entryDir: TrackDirection;
-      if (dx === 1 && dy === 0) entryDir = 'north';      // came from north (lower x)
-      else if (dx === -1 && dy === 0) entryDir = 'south'; // came from south (higher x)
-      else if (dx === 0 && dy === 1) entryDir = 'east';   // came from east (lower y)
-      else if (dx === 0 && dy === -1) entryDir = 'west';  // came from west (higher y)
-      else return piece; // Can't determine, keep original
-      
-      // For turns, direction field = entry direction
-      if (piece.direction !== entryDir) {
-        return { ...piece, direction: entryDir };
-      }
-    }
-    return piece;
-  }
-  
-  // For straights, slopes, and loops, calculate based on exit direction
-  if (nextTile) {
-    const dx = nextTile.x - currTile.x;
-    const dy = nextTile.y - currTile.y;
-    
-    let exitDir: TrackDirection;
-    if (dx === 1 && dy === 0) exitDir = 'south';      // going to south (higher x)
-    else if (dx === -1 && dy === 0) exitDir = 'north'; // going to north (lower x)
-    else if (dx === 0 && dy === 1) exitDir = 'west';   // going to west (higher y)
-    else if (dx === 0 && dy === -1) exitDir = 'east';  // going to east (lower y)
-    else return piece; // Can't determine, keep original
-    
-    // For straights and slopes, direction field = exit/travel direction
-    if (piece.direction !== exitDir) {
-      // Check if direction is being flipped 180 degrees (not rotated 90 degrees)
-      const isDirectionFlipped = 
-        (piece.direction === 'north' && exitDir === 'south') ||
-        (piece.direction === 'south' && exitDir === 'north') ||
-        (piece.direction === 'east' && exitDir === 'west') ||
-        (piece.direction === 'west' && exitDir === 'east');
-      
-      // For slopes, if direction is flipped, swap startHeight and endHeight
-      // This ensures the train goes from the correct height to the correct height
-      // based on which edge it enters/exits
-      if (isDirectionFlipped && (type === 'slope_up_small' || type === 'slope_down_small')) {
-        return { 
-          ...piece, 
-          direction: exitDir,
-          startHeight: piece.endHeight,
-          endHeight: piece.startHeight,
-        };
-      }
-      
-      return { ...piece, direction: exitDir };
-    }
-  }
-  
-  return piece;
-}
-
-/**
- * Collect a single connected component of track tiles starting from a given tile.
- * Returns tiles in connected order following track connections.
- * Also fixes track piece directions to match the actual flow.
- */
-function collectConnectedTrack(
-  grid: Tile[][],
-  startX: number,
-  startY: number,
-  visited: Set<string>
-): { tiles: { x: number; y: number }[]; pieces: TrackPiece[] } {
-  const gridSize = grid.length;
-  const orderedTiles: { x: number; y: number }[] = [];
-  const collectedPieces: TrackPiece[] = [];
-  
-  const startTile = grid[startY]?.[startX];
-  if (!startTile?.trackPiece) {
-    return { tiles: [], pieces: [] };
-  }
-  
-  let current: { x: number; y: number; piece: TrackPiece } = {
-    x: startX,
-    y: startY,
-    piece: startTile.trackPiece,
-  };
-  
-  while (current && !visited.has(`${current.x},${current.y}`)) {
-    visited.add(`${current.x},${current.y}`);
-    orderedTiles.push({ x: current.x, y: current.y });
-    collectedPieces.push(current.piece);
-    
-    // Find the next tile based on exit direction
-    const exitDir = getExitDirection(current.piece);
-    const offset = getDirectionOffset(exitDir);
-    const nx = current.x + offset.dx;
-    const ny = current.y + offset.dy;
-    const key = `${nx},${ny}`;
-    
-    let found = false;
-    
-    // First priority: tile in our exit direction
-    if (!visited.has(key) && nx >= 0 && ny >= 0 && nx < gridSize && ny < gridSize) {
-      const nextTile = grid[ny]?.[nx];
-      if (nextTile?.trackPiece) {
-        current = { x: nx, y: ny, piece: nextTile.trackPiece };
-        found = true;
-      }
-    }
-    
-    // Fallback: try all adjacent unvisited tiles (for legacy tracks with imperfect directions)
-    if (!found) {
-      const adjacentOffsets = [
-        { dx: 1, dy: 0 }, { dx: -1, dy: 0 },
-        { dx: 0, dy: 1 }, { dx: 0, dy: -1 },
-      ];
-      
-      for (const { dx, dy } of adjacentOffsets) {
-        const adjX = current.x + dx;
-        const adjY = current.y + dy;
-        const adjKey = `${adjX},${adjY}`;
-        
-        if (!visited.has(adjKey) && adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
-          const adjTile = grid[adjY]?.[adjX];
-          if (adjTile?.trackPiece) {
-            current = { x: adjX, y: adjY, piece: adjTile.trackPiece };
-            found = true;
-            break;
-          }
-        }
-      }
-    }
-    
-    if (!found) break;
-  }
-  
-  // Now fix the directions of all collected pieces to match the actual flow
-  const orderedPieces: TrackPiece[] = collectedPieces.map((piece, i) => {
-    const prevTile = i > 0 ? orderedTiles[i - 1] : 
-                     (orderedTiles.length > 1 ? orderedTiles[orderedTiles.length - 1] : null);
-    const currTile = orderedTiles[i];
-    const nextTile = i < orderedTiles.length - 1 ? orderedTiles[i + 1] :
-                     (orderedTiles.length > 1 ? orderedTiles[0] : null);
-    
-    return calculateCorrectDirection(prevTile, currTile, nextTile, piece);
-  });
-  
-  return { tiles: orderedTiles, pieces: orderedPieces };
-}
-
 /**
- * Check if a track forms a complete loop (closed circuit).
- * A track is complete if the last piece's exit connects back to the first piece's entry.
+ * Starting ticket price for a newly built ride (coaster stations use the flat default
+ * until their track is rated)
  */
-function isTrackComplete(
-  tiles: { x: number; y: number }[],
-  pieces: TrackPiece[]
-): boolean {
-  if (tiles.length < 4 || pieces.length < 4) {
-    // Need at least 4 pieces to form a loop
-    return false;
-  }
-  
-  const firstTile = tiles[0];
-  const lastTile = tiles[tiles.length - 1];
-  const lastPiece = pieces[pieces.length - 1];
-  
-  // Get where the last piece exits to
-  const exitDir = getExitDirection(lastPiece);
-  const offset = getDirectionOffset(exitDir);
-  const exitX = lastTile.x + offset.dx;
-  const exitY = lastTile.y + offset.dy;
-  
-  // Check if the exit leads back to the first tile
-  return exitX === firstTile.x && exitY === firstTile.y;
-}
-
-/**
- * Collect all track tiles for a coaster from the grid.
- * Returns tiles in connected order following the track direction.
- */
-function collectCoasterTrack(grid: Tile[][], coasterId: string): { tiles: { x: number; y: number }[]; pieces: TrackPiece[] } {
-  const gridSize = grid.length;
-
-  // First, find all tiles with this coaster ID
-  const coasterTiles: { x: number; y: number }[] = [];
-  for (let y = 0; y < gridSize; y++) {
-    for (let x = 0; x < gridSize; x++) {
-      const tile = grid[y][x];
-      if (tile.coasterTrackId === coasterId && tile.trackPiece) {
-        coasterTiles.push({ x, y });
-      }
-    }
-  }
-  
-  if (coasterTiles.length === 0) {
-    return { tiles: [], pieces: [] };
-  }
-  
-  // Try to find the best starting tile:
-  // 1. Prefer a tile with an adjacent queue (station area)
-  // 2. Prefer a tile with an adjacent station building
-  // 3. Fall back to first tile found
-  const adjacentOffsets = [
-    { dx: -1, dy: 0 },
-    { dx: 1, dy: 0 },
-    { dx: 0, dy: -1 },
-    { dx: 0, dy: 1 },
-  ];
-  
-  let startTile = coasterTiles[0];
-  
-  // Look for a tile with adjacent queue
-  for (const { x, y } of coasterTiles) {
-    let hasAdjacentQueue = false;
-    for (const { dx, dy } of adjacentOffsets) {
-      const adjX = x + dx;
-      const adjY = y + dy;
-      if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
-        const adjTile = grid[adjY]?.[adjX];
-        if (adjTile?.queue) {
-          hasAdjacentQueue = true;
-          break;
-        }
-      }
-    }
-    if (hasAdjacentQueue) {
-      startTile = { x, y };
-      break;
-    }
-  }
-  
-  // If no queue found, look for adjacent station building
-  if (startTile === coasterTiles[0]) {
-    for (const { x, y } of coasterTiles) {
-      let hasAdjacentStation = false;
-      for (const { dx, dy } of adjacentOffsets) {
-        const adjX = x + dx;
-        const adjY = y + dy;
-        if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
-          const adjTile = grid[adjY]?.[adjX];
-          if (adjTile?.building?.type?.startsWith('station_')) {
-            hasAdjacentStation = true;
-            break;
-          }
-        }
-      }
-      if (hasAdjacentStation) {
-        startTile = { x, y };
-        break;
-      }
-    }
-  }
-
-  const visited = new Set<string>();
-  const result = collectConnectedTrack(grid, startTile.x, startTile.y, visited);
-  
-  // Check if track needs to be reversed based on slope directions
-  // For slopes, the original piece's direction and heights define "correct" travel direction
-  // If we're traveling through most slopes in the wrong direction, reverse the whole track
-  if (result.tiles.length >= 4) {
-    let slopesCorrect = 0;  // We travel in the direction that matches original piece
-    let slopesReversed = 0; // We travel opposite to original piece direction
-    
-    for (let i = 0; i < result.tiles.length; i++) {
-      const tile = result.tiles[i];
-      const originalPiece = grid[tile.y]?.[tile.x]?.trackPiece;
-      
-      // Only check slope pieces - they have clear directionality
-      if (originalPiece && (originalPiece.type === 'slope_up_small' || originalPiece.type === 'slope_down_small')) {
-        // Get the next tile to determine our travel direction through this piece
-        const nextIdx = (i + 1) % result.tiles.length;
-        if (nextIdx === 0 && i === result.tiles.length - 1) {
-          // Last tile wrapping to first - only valid for closed loops
-          // Skip this check if track might not be a closed loop
-          continue;
-        }
-        const nextTile = result.tiles[nextIdx];
-        const travelDx = nextTile.x - tile.x;
-        const travelDy = nextTile.y - tile.y;
-        
-        // Get the original piece's exit direction (where train SHOULD exit for correct travel)
-        const originalExitDir = getExitDirection(originalPiece);
-        const originalExitOffset = getDirectionOffset(originalExitDir);
-        
-        // Check if we're traveling in the original's intended direction
-        if (travelDx === originalExitOffset.dx && travelDy === originalExitOffset.dy) {
-          slopesCorrect++;
-        } else {
-          slopesReversed++;
-        }
-      }
-    }
-    
-    // If more slopes are reversed than correct, we need to reverse the whole track
-    const needsReverse = slopesReversed > slopesCorrect;
-    
-    if (needsReverse) {
-      // Reverse the track so slopes are traversed correctly
-      // Use the ORIGINAL pieces from the grid and re-correct them for the new direction
-      const reversedTiles = [...result.tiles].reverse();
-      
-      // Get original pieces from grid in reversed order
-      const originalPiecesReversed = reversedTiles.map(tile => {
-        const origPiece = grid[tile.y]?.[tile.x]?.trackPiece;
-        if (!origPiece) throw new Error('Missing track piece');
-        return { ...origPiece }; // Clone to avoid mutation
-      });
-      
-      // Now correct each piece's direction for the new (reversed) travel order
-      const reversedPieces = originalPiecesReversed.map((piece, i) => {
-        const prevTile = i > 0 ? reversedTiles[i - 1] : 
-                         (reversedTiles.length > 1 ? reversedTiles[reversedTiles.length - 1] : null);
-        const currTile = reversedTiles[i];
-        const nextTile = i < reversedTiles.length - 1 ? reversedTiles[i + 1] :
-                         (reversedTiles.length > 1 ? reversedTiles[0] : null);
-        
-        return calculateCorrectDirection(prevTile, currTile, nextTile, piece);
-      });
-      
-      return { tiles: reversedTiles, pieces: reversedPieces };
-    }
-  }
-  
-  return result;
+function getDefaultRidePrice(type: BuildingType): number {
+  return type.startsWith('station_') ? DEFAULT_PRICES.rideTicket : getFairRidePrice(getRideProfile(type));
 }
 
 /**
@@ -1186,7 +685,9 @@ function ensureAllTracksHaveCoasters(
           newCoaster.trackTiles = componentTiles;
           newCoaster.stationTileX = stationTile.x;
           newCoaster.stationTileY = stationTile.y;
-          newCoaster.trains = createTrainsForCoaster(componentPieces.length, newCoaster.type);
+          newCoaster.trainConfig = originalCoaster?.trainConfig;
+          newCoaster.dispatchPolicy = originalCoaster?.dispatchPolicy;
+          newCoaster.trains = createTrainsForCoaster(componentPieces.length, newCoaster.type, newCoaster.trainConfig);
           
           newCoasters.push(newCoaster);
         }
@@ -1214,14 +715,6 @@ function ensureAllTracksHaveCoasters(
   return { grid: newGrid, coasters: newCoasters, changed };
 }
 
-// Configuration for train creation
-interface TrainConfig {
-  numCars?: number;
-  carSpacing?: number;
-  startProgress?: number;
-  guestsPerCar?: number;
-}
-
 /**
  * Normalize train positions when track length changes.
  * Ensures cars maintain proper spacing and positions are valid for the new track length.
@@ -1231,12 +724,11 @@ function normalizeTrainsForTrackChange(
   trains: CoasterTrain[],
   oldTrackLength: number,
   newTrackLength: number,
-  stationIndex: number = 0
+  stationIndex: number = 0,
+  carSpacing: number = DEFAULT_CAR_SPACING
 ): CoasterTrain[] {
   if (newTrackLength === 0) return trains;
   
-  const carSpacing = 0.18;
-  
   return trains.map((train, trainIndex) => {
     // If track length changed, reset train to station to prevent weird positioning
     // This keeps behavior predictable when editing tracks
@@ -1259,162 +751,213 @@ function normalizeTrainsForTrackChange(
       ...train,
       cars: normalizedCars,
       state: 'loading' as const, // Reset to loading state at station
-      stateTimer: 3 + Math.random() * 2, // Short loading time after reset
+      stateTimer: 3 + simulationRandom() * 2, // Short loading time after reset
     };
   });
 }
 
-function createDefaultTrain(config: TrainConfig = {}): CoasterTrain {
-  const numCars = config.numCars ?? 6;
-  const carSpacing = config.carSpacing ?? 0.18; // Spacing between cars
-  const startProgress = config.startProgress ?? 0;
-  const guestsPerCar = config.guestsPerCar ?? 4;
-  const baseVelocity = 0.06;
-  
-  const cars: CoasterCar[] = [];
-  for (let i = 0; i < numCars; i++) {
-    cars.push({
-      trackProgress: startProgress + i * carSpacing,
-      velocity: baseVelocity,
-      rotation: { pitch: 0, yaw: 0, roll: 0 },
-      screenX: 0,
-      screenY: 0,
-      screenZ: 0,
-      guests: [], // Will be filled during loading
-    });
-  }
-
-  return {
-    id: generateUUID(),
-    cars,
-    state: 'loading', // Start in loading state at station
-    stateTimer: 5 + Math.random() * 3, // 5-8 seconds loading time
-  };
-}
+// =============================================================================
+// TILE DEMOLITION
+// =============================================================================
 
 /**
- * Create multiple trains for a coaster based on track length and coaster type
- * Uses the coaster type stats to determine min/max trains and scales by track length
+ * Remove whatever occupies a tile (building, footprint, path, queue or track).
+ * Returns the updated state; shared by the bulldoze and sell tools.
  */
-function createTrainsForCoaster(trackLength: number, coasterType: string = 'steel_sit_down'): CoasterTrain[] {
-  if (trackLength === 0) return [createDefaultTrain()];
-  
-  // Get coaster type stats for train limits
-  const typeStats = COASTER_TYPE_STATS[coasterType as CoasterType];
-  const minTrains = typeStats?.trainsPerTrack?.min ?? 1;
-  const maxTrains = typeStats?.trainsPerTrack?.max ?? 3;
-  const minCars = typeStats?.trainLength?.min ?? 4;
-  const maxCars = typeStats?.trainLength?.max ?? 8;
-  
-  // Calculate number of trains based on track length
-  // Scale linearly from min at 8 tiles to max at 30+ tiles
-  let numTrains: number;
-  if (trackLength <= 8) {
-    numTrains = minTrains;
-  } else if (trackLength >= 30) {
-    numTrains = maxTrains;
-  } else {
-    // Linear interpolation between min and max
-    const t = (trackLength - 8) / (30 - 8);
-    numTrains = Math.round(minTrains + t * (maxTrains - minTrains));
+function demolishTile(prev: GameState, x: number, y: number): GameState {
+  const newGrid = prev.grid.map(row => row.map(tile => ({ ...tile })));
+  const tile = newGrid[y][x];
+  
+  // Bulldozing water fills it back in with grass, priced like the land terraform
+  if (tile.terrain === 'water') {
+    const fillCost = TOOL_INFO.zone_land.cost;
+    if (prev.finances.cash < fillCost || prev.finances.bankrupt) return prev;
+    tile.terrain = 'grass';
+    tile.building = { ...createEmptyBuilding(), type: 'grass' };
+    return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, fillCost) };
   }
   
-  // Clamp to coaster type limits
-  numTrains = Math.max(minTrains, Math.min(maxTrains, numTrains));
-  
-  // Ensure minimum spacing between trains (at least 12 tiles per train for safety)
-  const minSpacingPerTrain = 12;
-  const maxTrainsForSpacing = Math.max(1, Math.floor(trackLength / minSpacingPerTrain));
-  numTrains = Math.min(numTrains, maxTrainsForSpacing);
-  
-  // Calculate cars per train based on track length (longer tracks = longer trains)
-  // Use smaller trains for better visual appearance
-  let numCars: number;
-  if (trackLength <= 15) {
-    numCars = Math.max(2, minCars - 2); // Smaller trains for short tracks
-  } else if (trackLength >= 50) {
-    numCars = Math.min(6, maxCars); // Cap at 6 cars even for long tracks
-  } else {
-    const t = (trackLength - 15) / (50 - 15);
-    numCars = Math.round(2 + t * 4); // Scale from 2 to 6 cars
+  // Check if we're bulldozing a multi-tile building (origin or footprint tile)
+  const buildingType = tile.building?.type;
+  if (buildingType && (buildingType.endsWith('_footprint') || TOOL_INFO[buildingType as Tool]?.size)) {
+    // Find the origin tile and clear all footprint tiles
+    const originType = buildingType.endsWith('_footprint') 
+      ? buildingType.replace('_footprint', '') 
+      : buildingType;
+    const toolInfo = TOOL_INFO[originType as Tool];
+    const buildingSize = toolInfo?.size ?? { width: 1, height: 1 };
+    
+    // Find the origin by searching nearby tiles
+    for (let searchY = Math.max(0, y - buildingSize.height + 1); searchY <= y; searchY++) {
+      for (let searchX = Math.max(0, x - buildingSize.width + 1); searchX <= x; searchX++) {
+        const searchTile = newGrid[searchY]?.[searchX];
+        if (searchTile?.building?.type === originType) {
+          // Found the origin - clear all footprint tiles
+          for (let dy = 0; dy < buildingSize.height; dy++) {
+            for (let dx = 0; dx < buildingSize.width; dx++) {
+              const clearX = searchX + dx;
+              const clearY = searchY + dy;
+              if (clearX < prev.gridSize && clearY < prev.gridSize) {
+                const clearTile = newGrid[clearY][clearX];
+                clearTile.building = createEmptyBuilding();
+              }
+            }
+          }
+          relinkQueues(newGrid, { x: searchX, y: searchY, ...buildingSize });
+          return { ...prev, grid: newGrid };
+        }
+      }
+    }
   }
-  numCars = Math.max(2, Math.min(6, numCars));
-  
-  const trains: CoasterTrain[] = [];
-  for (let i = 0; i < numTrains; i++) {
-    // Space trains evenly around the track
-    const startProgress = (i * trackLength) / numTrains;
-    const train = createDefaultTrain({ 
-      startProgress,
-      numCars,
-    });
-    // First train starts loading, others running
-    train.state = i === 0 ? 'loading' : 'running';
-    train.stateTimer = i === 0 ? (5 + Math.random() * 3) : 0; // 5-8 second stop at station for loading
-    trains.push(train);
+  
+  // Check if we're bulldozing track and get the coaster ID
+  const hadTrack = tile.hasCoasterTrack || tile.trackPiece;
+  const coasterId = tile.coasterTrackId;
+  
+  // Reset tile
+  tile.building = createEmptyBuilding();
+  tile.path = false;
+  tile.queue = false;
+  tile.queueRideId = null;
+  tile.hasCoasterTrack = false;
+  tile.coasterTrackId = null;
+  tile.trackPiece = null;
+  
+  // Queues that led to this tile (or were cut in two by it) find their ride again
+  relinkQueues(newGrid, { x, y, width: 1, height: 1 });
+  
+  // If track was demolished, shorten or split the coaster around the gap
+  // (a coaster left with no track is removed entirely)
+  let updatedCoasters = prev.coasters;
+  if (hadTrack && coasterId) {
+    updatedCoasters = splitCoasterTrack(newGrid, prev.coasters, coasterId);
+  }
+  
+  // If track was demolished, reset the coaster building state
+  if (hadTrack) {
+    return { 
+      ...prev, 
+      grid: newGrid,
+      coasters: updatedCoasters,
+      buildingCoasterHeight: 0,
+      buildingCoasterLastDirection: null,
+      buildingCoasterPath: [],
+    };
   }
   
-  return trains;
+  return { ...prev, grid: newGrid };
 }
 
-/** Unique colors for each coaster type - gives each coaster its own distinct look */
-const COASTER_TYPE_COLORS: Record<CoasterType, { primary: string; secondary: string; supports: string }> = {
-  // Wooden coasters - natural wood tones
-  wooden_classic: { primary: '#8B4513', secondary: '#D2691E', supports: '#5C3317' },    // Classic brown wood
-  wooden_twister: { primary: '#A0522D', secondary: '#CD853F', supports: '#654321' },    // Sienna/tan wood
-  
-  // Steel coasters - vibrant modern colors
-  steel_sit_down: { primary: '#dc2626', secondary: '#fbbf24', supports: '#374151' },    // Classic red/yellow
-  steel_standup: { primary: '#7c3aed', secondary: '#c084fc', supports: '#4c1d95' },     // Purple/violet
-  steel_inverted: { primary: '#2563eb', secondary: '#60a5fa', supports: '#1e3a8a' },    // Blue scheme
-  steel_floorless: { primary: '#059669', secondary: '#34d399', supports: '#064e3b' },   // Emerald green
-  steel_wing: { primary: '#ea580c', secondary: '#fb923c', supports: '#7c2d12' },        // Orange/flame
-  steel_flying: { primary: '#0891b2', secondary: '#22d3ee', supports: '#164e63' },      // Cyan/sky
-  steel_4d: { primary: '#be123c', secondary: '#fb7185', supports: '#881337' },          // Rose/magenta
-  steel_spinning: { primary: '#65a30d', secondary: '#a3e635', supports: '#365314' },    // Lime green
-  launch_coaster: { primary: '#e11d48', secondary: '#fda4af', supports: '#9f1239' },    // Hot pink/red
-  hyper_coaster: { primary: '#0d9488', secondary: '#5eead4', supports: '#134e4a' },     // Teal
-  giga_coaster: { primary: '#4f46e5', secondary: '#a5b4fc', supports: '#312e81' },      // Indigo
-  
-  // Water coaster - aquatic blues
-  water_coaster: { primary: '#0ea5e9', secondary: '#38bdf8', supports: '#0c4a6e' },     // Sky blue
-  
-  // Specialty coasters - themed colors
-  mine_train: { primary: '#92400e', secondary: '#fcd34d', supports: '#451a03' },        // Rust/gold (mining theme)
-  bobsled: { primary: '#1d4ed8', secondary: '#93c5fd', supports: '#1e3a8a' },           // Ice blue
-  suspended: { primary: '#b45309', secondary: '#fcd34d', supports: '#78350f' },         // Amber/bronze
-};
+/**
+ * Tear down a whole coaster in one go: every tile carrying its track is
+ * cleared, the coaster is dropped, and part of the track's cost comes back.
+ * Station buildings stay put so new track can reuse them; guests treat them
+ * as closed until it does. Returns the input state for unknown ids.
+ */
+function removeCoaster(prev: GameState, coasterId: string): GameState {
+  if (!prev.coasters.some(c => c.id === coasterId)) return prev;
+  
+  // Scan the whole grid rather than trusting trackTiles, so no stray flags survive
+  let trackValue = 0;
+  const newGrid = prev.grid.map(row => row.map(tile => {
+    if (tile.coasterTrackId !== coasterId) return tile;
+    if (tile.trackPiece) trackValue += getTrackPieceCost(tile.trackPiece.type);
+    return { ...tile, hasCoasterTrack: false, coasterTrackId: null, trackPiece: null };
+  }));
+  const refund = Math.floor(trackValue * Math.min(1, Math.max(0, SELL_REFUND_RATE)));
+  
+  const wasBuilding = prev.buildingCoasterId === coasterId;
+  return {
+    ...prev,
+    grid: newGrid,
+    coasters: prev.coasters.filter(c => c.id !== coasterId),
+    finances: chargeConstruction(prev.finances, -refund),
+    ...(wasBuilding ? {
+      buildingCoasterId: null,
+      buildingCoasterPath: [],
+      buildingCoasterHeight: 0,
+      buildingCoasterLastDirection: null,
+      buildingCoasterType: null,
+    } : {}),
+  };
+}
 
-function createDefaultCoaster(
-  id: string, 
-  startTile: { x: number; y: number }, 
-  trackLength: number = 0,
-  coasterType: CoasterType = 'steel_sit_down'
-): Coaster {
-  const colors = COASTER_TYPE_COLORS[coasterType] ?? COASTER_TYPE_COLORS.steel_sit_down;
-  const typeStats = COASTER_TYPE_STATS[coasterType];
+/**
+ * Sell whatever occupies a tile, refunding part of its cost.
+ * Unlike bulldozing there is no demolition fee.
+ */
+function sellTile(prev: GameState, x: number, y: number): GameState {
+  const tile = prev.grid[y]?.[x];
+  if (!tile) return prev;
   
+  const hasSellable = tile.path || tile.queue || tile.trackPiece ||
+    (tile.building.type !== 'empty' && tile.building.type !== 'grass' && tile.building.type !== 'water');
+  if (!hasSellable) return prev;
+  
+  const refund = getSellRefund(tile);
+  const next = demolishTile(prev, x, y);
   return {
-    id,
-    name: typeStats?.name ?? 'Custom Coaster',
-    type: coasterType,
-    color: colors,
-    track: [],
-    trackTiles: [],
-    stationTileX: startTile.x,
-    stationTileY: startTile.y,
-    trains: createTrainsForCoaster(trackLength, coasterType),
-    operating: true,
-    broken: false,
-    excitement: 0,
-    intensity: 0,
-    nausea: 0,
-    ridersTotal: 0,
-    income: 0,
-    upkeep: 0,
+    ...next,
+    finances: chargeConstruction(next.finances, -refund),
   };
 }
 
+/**
+ * Tiles inside a rectangle (corners in any order) that a bulldozer would
+ * actually clear. Open water is left alone: clearing an area isn't landfill.
+ */
+function getBulldozeRectTiles(
+  grid: Tile[][],
+  start: { x: number; y: number },
+  end: { x: number; y: number }
+): { x: number; y: number }[] {
+  const gridSize = grid.length;
+  const minX = Math.max(0, Math.min(start.x, end.x));
+  const maxX = Math.min(gridSize - 1, Math.max(start.x, end.x));
+  const minY = Math.max(0, Math.min(start.y, end.y));
+  const maxY = Math.min(gridSize - 1, Math.max(start.y, end.y));
+  
+  const tiles: { x: number; y: number }[] = [];
+  for (let y = minY; y <= maxY; y++) {
+    for (let x = minX; x <= maxX; x++) {
+      const tile = grid[y][x];
+      if (tile.terrain === 'water') continue;
+      const type = tile.building.type;
+      if (tile.path || tile.queue || tile.trackPiece || tile.hasCoasterTrack || (type !== 'empty' && type !== 'grass')) {
+        tiles.push({ x, y });
+      }
+    }
+  }
+  return tiles;
+}
+
+/**
+ * Demolition fee for clearing a rectangle: one fee per thing torn down, so a
+ * multi-tile building counts once (by its origin) however many of its tiles are inside
+ */
+function getBulldozeRectCost(grid: Tile[][], start: { x: number; y: number }, end: { x: number; y: number }): number {
+  const cleared = new Set<string>();
+  for (const { x, y } of getBulldozeRectTiles(grid, start, end)) {
+    const origin = grid[y][x].building.type.endsWith('_footprint') ? findBuildingOrigin(grid, x, y) : null;
+    cleared.add(origin ? `${origin.x},${origin.y}` : `${x},${y}`);
+  }
+  return cleared.size * TOOL_INFO.bulldoze.cost;
+}
+
+/**
+ * Clear everything in a rectangle tile by tile, so multi-tile buildings,
+ * queues and coaster track are torn down exactly as the bulldozer would,
+ * then charge the demolition fee in one go.
+ */
+function bulldozeRect(prev: GameState, start: { x: number; y: number }, end: { x: number; y: number }): GameState {
+  const tiles = getBulldozeRectTiles(prev.grid, start, end);
+  if (tiles.length === 0) return prev;
+  
+  const cost = getBulldozeRectCost(prev.grid, start, end);
+  const next = tiles.reduce((state, { x, y }) => demolishTile(state, x, y), prev);
+  return { ...next, finances: chargeConstruction(next.finances, cost) };
+}
+
 // =============================================================================
 // PROVIDER COMPONENT
 // =============================================================================
@@ -1431,14 +974,20 @@ export function CoasterProvider({
   const [state, setState] = useState<GameState>(() => createInitialCoasterGameState());
   const [isStateReady, setIsStateReady] = useState(false);
   const [hasSavedGame, setHasSavedGame] = useState(false);
+  const [spriteLayoutVersion, setSpriteLayoutVersion] = useState(0);
+  const [debugGrid, setDebugGridState] = useState(false);
   const latestStateRef = useRef<GameState>(state);
   const placeCallbackRef = useRef<((args: { x: number; y: number; tool: Tool }) => void) | null>(null);
   const bulldozeCallbackRef = useRef<((args: { x: number; y: number }) => void) | null>(null);
+  const bulldozeAreaCallbackRef = useRef<((args: { start: { x: number; y: number }; end: { x: number; y: number } }) => void) | null>(null);
   const coasterBuildCallbackRef = useRef<((args: { coasterType: CoasterType; coasterId: string }) => void) | null>(null);
   const coasterBuildFinishCallbackRef = useRef<(() => void) | null>(null);
   const coasterBuildCancelCallbackRef = useRef<(() => void) | null>(null);
   const parkSettingsCallbackRef = useRef<((settings: Partial<ParkSettings>) => void) | null>(null);
   const speedCallbackRef = useRef<((speed: 0 | 1 | 2 | 3) => void) | null>(null);
+  const lastAutosaveHourRef = useRef<number | null>(null);
+  const lastAutosaveTimeRef = useRef(0);
+  const lastAutosavedStateRef = useRef<GameState | null>(null);
   
   // Keep ref in sync
   useEffect(() => {
@@ -1546,19 +1095,39 @@ export function CoasterProvider({
     });
   }, [isStateReady]);
   
-  // Auto-save periodically using async worker-based save (no stuttering!)
+  // Auto-save every N game hours using async worker-based save (no stuttering!).
+  // Checked on a real-time timer so fast nights don't save every second, and
+  // changes made while paused (no game hours passing) still get saved.
+  const autosaveIntervalHours = state.settings.autosaveIntervalHours;
   useEffect(() => {
-    if (!isStateReady) return;
+    if (!isStateReady || autosaveIntervalHours <= 0) return;
     
-    const saveInterval = setInterval(() => {
-      // Use async save to avoid blocking main thread during compression
-      persistCoasterSaveAsync(latestStateRef.current).catch((e) => {
+    const interval = setInterval(() => {
+      const current = latestStateRef.current;
+      const totalHours = (((current.year * 12 + current.month) * 30) + current.day) * 24 + current.hour;
+      const now = performance.now();
+      if (lastAutosaveHourRef.current === null) {
+        lastAutosaveHourRef.current = totalHours;
+        lastAutosaveTimeRef.current = now;
+        lastAutosavedStateRef.current = current;
+        return;
+      }
+      if (current === lastAutosavedStateRef.current) return; // Nothing changed since the last save
+      const hoursDue = totalHours - lastAutosaveHourRef.current >= autosaveIntervalHours;
+      const waitedTooLong = now - lastAutosaveTimeRef.current >= AUTOSAVE_MAX_WAIT_MS;
+      if (!hoursDue && !waitedTooLong) return;
+      
+      lastAutosaveHourRef.current = totalHours;
+      lastAutosaveTimeRef.current = now;
+      lastAutosavedStateRef.current = current;
+      // Same serialization as a manual save; compression runs in a worker
+      persistCoasterSaveAsync(current).catch((e) => {
         console.error('Failed to auto-save:', e);
       });
-    }, 30000); // Every 30 seconds
+    }, AUTOSAVE_CHECK_MS);
     
-    return () => clearInterval(saveInterval);
-  }, [isStateReady, persistCoasterSaveAsync]);
+    return () => clearInterval(interval);
+  }, [isStateReady, autosaveIntervalHours, persistCoasterSaveAsync]);
   
   // Simulation tick
   useEffect(() => {
@@ -1566,488 +1135,19 @@ export function CoasterProvider({
     
     const tickInterval = SPEED_TICK_INTERVALS[state.speed];
     
+    // Timers drift and get throttled, so run however many fixed steps the elapsed
+    // real time calls for instead of assuming one step per callback
+    let lastTime = performance.now();
+    let accumulator = 0;
+    
     const interval = setInterval(() => {
-      setState(prev => {
-        const newTick = prev.tick + 1;
-        let { minute, hour, day, month, year } = prev;
-        
-        // Time progression - slower during day to make daytime last longer
-        // During day (7-18): advance 0.25 minutes per tick (longer days)
-        // During night/dawn/dusk: advance 3 minutes per tick (faster to get through night)
-        const isDaytime = hour >= 7 && hour < 18;
-        const minuteIncrement = isDaytime ? 0.25 : 3; // Much slower during day, faster at night
-        
-        minute += minuteIncrement;
-        if (minute >= 60) {
-          minute = minute - 60;
-          hour += 1;
-          if (hour >= 24) {
-            hour = 0;
-            day += 1;
-            if (day > 30) {
-              day = 1;
-              month += 1;
-              if (month > 12) {
-                month = 1;
-                year += 1;
-              }
-            }
-          }
-        }
-        
-        // Update weather
-        const newWeather = simulateWeather(prev.weather, newTick, month);
-        const weatherEffects = WEATHER_EFFECTS[newWeather.current];
-        
-        // Update guests with weather effects
-        const deltaTime = 1; // 1 game minute per tick
-        const updatedGuestsBase = prev.guests.map(guest => updateGuest(guest, prev.grid, deltaTime));
-        
-        // Apply weather effects to guests and check if they want to leave
-        const updatedGuests = updatedGuestsBase.map(guest => {
-          const weatheredGuest = applyWeatherEffectsToGuest(guest, newWeather.current);
-          
-          // Check if guest decides to leave due to weather (rare)
-          // Only affects walking guests who are already unhappy
-          if (weatheredGuest.state === 'walking' && shouldGuestLeaveForWeather(weatheredGuest, newWeather.current)) {
-            return {
-              ...weatheredGuest,
-              state: 'leaving' as const,
-              thoughts: [...weatheredGuest.thoughts.slice(-4), 'want_to_go_home' as const],
-            };
-          }
-          
-          return weatheredGuest;
-        }); // Don't filter out guests here - let them leave naturally through the exit
-        
-        // Spawn guests (affected by weather)
-        const baseSpawnedGuests = spawnGuests(prev.grid, updatedGuests, prev.stats.parkRating, hour);
-
-        // Apply weather spawn multiplier probabilistically
-        // Since spawns are typically 0-1 guests, we need to treat the multiplier as a probability
-        // e.g., multiplier of 0.85 means 85% chance to keep each spawned guest
-        const spawnedGuestsRaw = baseSpawnedGuests.filter(() => 
-          Math.random() < weatherEffects.guestSpawnMultiplier
-        );
-        const entranceFee = prev.settings.payPerRide ? 0 : prev.settings.entranceFee;
-        const admissionRevenue = spawnedGuestsRaw.reduce((sum, guest) => sum + Math.min(guest.cash, entranceFee), 0);
-        const spawnedGuests = spawnedGuestsRaw.map(guest => {
-          const fee = Math.min(guest.cash, entranceFee);
-          return {
-            ...guest,
-            cash: guest.cash - fee,
-            totalSpent: guest.totalSpent + fee,
-          };
-        });
-
-        const rideTicket = prev.settings.payPerRide ? DEFAULT_PRICES.rideTicket : 0;
-        let rideRevenue = 0;
-        let foodRevenue = 0;
-        let shopRevenue = 0;
-        let rideCompletions = 0;
-        const guests = updatedGuests.map(guest => {
-          let nextGuest = guest;
-
-          if (guest.state === 'riding' && guest.lastState === 'queuing' && rideTicket > 0) {
-            const fee = Math.min(guest.cash, rideTicket);
-            if (fee > 0) {
-              rideRevenue += fee;
-              nextGuest = { ...nextGuest, cash: nextGuest.cash - fee, totalSpent: nextGuest.totalSpent + fee };
-            }
-          }
-
-          if (guest.state === 'eating' && guest.lastState !== 'eating') {
-            const price = guest.thirst > guest.hunger ? DEFAULT_PRICES.drinkItem : DEFAULT_PRICES.foodItem;
-            const fee = Math.min(nextGuest.cash, price);
-            if (fee > 0) {
-              foodRevenue += fee;
-              nextGuest = { ...nextGuest, cash: nextGuest.cash - fee, totalSpent: nextGuest.totalSpent + fee };
-            }
-          }
-
-          if (guest.state === 'shopping' && guest.lastState !== 'shopping') {
-            const fee = Math.min(nextGuest.cash, DEFAULT_PRICES.shopItem);
-            if (fee > 0) {
-              shopRevenue += fee;
-              nextGuest = { ...nextGuest, cash: nextGuest.cash - fee, totalSpent: nextGuest.totalSpent + fee };
-            }
-          }
-
-          if (guest.state === 'walking' && guest.lastState === 'riding') {
-            rideCompletions += 1;
-          }
-
-          return nextGuest;
-        }).concat(spawnedGuests);
-
-        
-        const guestsInPark = guests.length;
-        const guestsSatisfied = guests.filter(guest => guest.happiness >= 70).length;
-        const guestsUnsatisfied = guests.filter(guest => guest.happiness <= 40).length;
-        const avgHappiness = guestsInPark > 0
-          ? guests.reduce((sum, guest) => sum + guest.happiness, 0) / guestsInPark
-          : 0;
-        
-        const parkRating = Math.min(1000, Math.round(avgHappiness * 10));
-
-        // Update coaster trains with state machine and station logic
-        // First, aggressively clean up coasters - recollect track from grid to get current state
-        const cleanedCoasters: Coaster[] = [];
-        for (const coaster of prev.coasters) {
-          // Always recollect from grid to ensure we have current data
-          const { tiles: currentTiles, pieces: currentPieces } = collectCoasterTrack(prev.grid, coaster.id);
-          
-          // If no valid track exists, skip this coaster entirely
-          if (currentTiles.length === 0) continue;
-          
-          // Check if track changed - if so, regenerate trains
-          const trackChanged = currentTiles.length !== coaster.trackTiles.length ||
-            currentTiles.some((t, i) => t.x !== coaster.trackTiles[i]?.x || t.y !== coaster.trackTiles[i]?.y);
-          
-          if (trackChanged) {
-            // Track changed - regenerate everything
-            const stationTile = findStationTile(prev.grid, currentTiles, prev.gridSize) || currentTiles[0];
-            const stationIdx = currentTiles.findIndex(t => t.x === stationTile.x && t.y === stationTile.y);
-            const effectiveStationIdx = stationIdx >= 0 ? stationIdx : 0;
-            
-            const newTrains = createTrainsForCoaster(currentPieces.length, coaster.type).map((train, trainIndex) => {
-              const trainOffset = (trainIndex * currentPieces.length) / Math.max(1, createTrainsForCoaster(currentPieces.length, coaster.type).length);
-              const baseProgress = (effectiveStationIdx + trainOffset) % currentPieces.length;
-              return {
-                ...train,
-                cars: train.cars.map((car, carIndex) => ({
-                  ...car,
-                  trackProgress: (baseProgress + carIndex * 0.18) % currentPieces.length,
-                })),
-              };
-            });
-            
-            cleanedCoasters.push({
-              ...coaster,
-              track: currentPieces,
-              trackTiles: currentTiles,
-              stationTileX: stationTile.x,
-              stationTileY: stationTile.y,
-              trains: newTrains,
-            });
-          } else {
-            cleanedCoasters.push(coaster);
-          }
-        }
-        
-        const updatedCoasters = cleanedCoasters.map(coaster => {
-          if (coaster.track.length === 0 || coaster.trains.length === 0) return coaster;
-          const trackLength = coaster.track.length;
-          
-          // Only run trains if the track forms a complete loop
-          const trackComplete = isTrackComplete(coaster.trackTiles, coaster.track);
-          if (!trackComplete) {
-            // Track is incomplete - reset all trains to proper positions at start of track
-            // This prevents "stuck" cars from appearing in random positions
-            const carSpacing = 0.18;
-            const resetTrains = coaster.trains.map((train, trainIndex) => {
-              const trainOffset = (trainIndex * trackLength) / Math.max(1, coaster.trains.length);
-              return {
-                ...train,
-                state: 'loading' as const,
-                stateTimer: 8,
-                cars: train.cars.map((car, carIndex) => ({
-                  ...car,
-                  trackProgress: (trainOffset + carIndex * carSpacing) % trackLength,
-                  velocity: 0,
-                })),
-              };
-            });
-            return { ...coaster, trains: resetTrains };
-          }
-          
-          // Find station position - the tile with an adjacent queue
-          const stationIndex = coaster.trackTiles.findIndex(
-            t => t.x === coaster.stationTileX && t.y === coaster.stationTileY
-          );
-          const effectiveStationIndex = stationIndex >= 0 ? stationIndex : 0;
-          const stationRange = { min: effectiveStationIndex, max: effectiveStationIndex + 1.5 }; // Train is "at station" if lead car is in this range
-          
-          // Helper function to check if a position is within the station range (handles wrap-around)
-          const isPositionAtStation = (progress: number): boolean => {
-            const normalizedProgress = ((progress % trackLength) + trackLength) % trackLength;
-            // Check if within range directly
-            if (normalizedProgress >= stationRange.min && normalizedProgress <= stationRange.max) {
-              return true;
-            }
-            // Handle wrap-around case: station spans across track boundary (e.g., station at index 0)
-            if (stationRange.min < 2) {
-              // Check if we're at the end of the track, close enough to wrap to station
-              const distanceToStationViaWrap = trackLength - normalizedProgress + stationRange.min;
-              if (distanceToStationViaWrap <= 0.5 && distanceToStationViaWrap >= 0) {
-                return true;
-              }
-            }
-            return false;
-          };
-          
-          const updatedTrains = coaster.trains.map((train, trainIndex) => {
-            let { state, stateTimer, cars } = train;
-            stateTimer -= deltaTime;
-            
-            const carSpacing = 0.18;
-            
-            // Validate all car positions - fix any invalid values
-            let hasInvalidCar = false;
-            for (const car of cars) {
-              if (!Number.isFinite(car.trackProgress) || car.trackProgress < 0 || car.trackProgress > trackLength * 10) {
-                hasInvalidCar = true;
-                break;
-              }
-            }
-            
-            if (hasInvalidCar) {
-              // Reset all cars to proper positions at station
-              cars = cars.map((car, idx) => ({
-                ...car,
-                trackProgress: (effectiveStationIndex + idx * carSpacing) % trackLength,
-                velocity: 0,
-              }));
-              state = 'loading';
-              stateTimer = 5 + Math.random() * 3; // 5-8 second stop at station
-            }
-            
-            // Get lead car's position
-            const leadCar = cars[0];
-            const leadProgress = leadCar.trackProgress % trackLength;
-            const isAtStation = isPositionAtStation(leadProgress);
-            
-            // Check for other trains ahead (collision avoidance)
-            const hasTrainAhead = coaster.trains.some((otherTrain, idx) => {
-              if (idx === trainIndex) return false;
-              const otherLead = otherTrain.cars[0].trackProgress % trackLength;
-              const distance = (otherLead - leadProgress + trackLength) % trackLength;
-              return distance < 4 && distance > 0; // Within 4 tiles ahead
-            });
-            
-            // Validate car spacing - if cars have drifted apart, reset them
-            const maxCarDrift = carSpacing * 1.5; // Allow 50% variance before resetting
-            let needsSpacingReset = false;
-            for (let i = 1; i < cars.length; i++) {
-              const prevCar = cars[i - 1];
-              const currCar = cars[i];
-              const expectedDiff = carSpacing;
-              const actualDiff = (currCar.trackProgress - prevCar.trackProgress + trackLength) % trackLength;
-              // If difference is more than half the track, the car wrapped around
-              const normalizedDiff = actualDiff > trackLength / 2 ? trackLength - actualDiff : actualDiff;
-              if (Math.abs(normalizedDiff - expectedDiff) > maxCarDrift) {
-                needsSpacingReset = true;
-                break;
-              }
-            }
-            
-            if (needsSpacingReset) {
-              // Reset cars to proper spacing from lead car
-              const leadProgress = cars[0].trackProgress;
-              cars = cars.map((car, idx) => ({
-                ...car,
-                trackProgress: (leadProgress + idx * carSpacing) % trackLength,
-                velocity: car.velocity,
-              }));
-            }
-            
-            // State machine for train operation
-            switch (state) {
-              case 'loading':
-                // Stay at station loading guests
-                if (stateTimer <= 0) {
-                  state = 'dispatching';
-                  stateTimer = 2; // 2 second dispatch
-                }
-                // Keep train stationary at station - maintain proper car positions
-                cars = cars.map((car, idx) => ({
-                  ...car,
-                  trackProgress: (effectiveStationIndex + idx * carSpacing) % trackLength,
-                  velocity: 0,
-                }));
-                break;
-                
-              case 'dispatching':
-                // Accelerating from station
-                if (stateTimer <= 0) {
-                  state = 'running';
-                  stateTimer = 0;
-                }
-                // Slow acceleration - boost velocity at higher game speeds for visual feedback
-                const speedBoostDispatch = SPEED_TRAIN_BOOSTS[prev.speed];
-                const baseDispatchVelocity = (0.02 + (1 - stateTimer / 2) * 0.04) * speedBoostDispatch;
-                cars = cars.map(car => {
-                  // Check if car is on a loop - slow down on loops
-                  const carTrackIdx = Math.floor(car.trackProgress % trackLength);
-                  const trackPiece = coaster.track[carTrackIdx];
-                  const isOnLoop = trackPiece?.type === 'loop_vertical';
-                  const velocityMultiplier = isOnLoop ? 0.5 : 1.0;
-                  const dispatchVelocity = baseDispatchVelocity * velocityMultiplier;
-                  
-                  let nextProgress = car.trackProgress + dispatchVelocity * deltaTime;
-                  nextProgress = nextProgress % trackLength;
-                  if (nextProgress < 0) nextProgress += trackLength;
-                  return { ...car, trackProgress: nextProgress, velocity: dispatchVelocity };
-                });
-                break;
-                
-              case 'running':
-                // Check if approaching station and should brake
-                const distanceToStation = (effectiveStationIndex - leadProgress + trackLength) % trackLength;
-                const shouldBrake = distanceToStation < 3 && distanceToStation > 0.5;
-                
-                if (shouldBrake || hasTrainAhead) {
-                  state = 'braking';
-                  stateTimer = 0;
-                } else {
-                  // Normal running speed - boost velocity at higher game speeds for visual feedback
-                  const speedBoostRun = SPEED_TRAIN_BOOSTS[prev.speed];
-                  const baseRunVelocity = (hasTrainAhead ? 0.02 : 0.06) * speedBoostRun;
-                  cars = cars.map(car => {
-                    // Check if car is on a loop - loops are much longer so slow down
-                    const carTrackIdx = Math.floor(car.trackProgress % trackLength);
-                    const trackPiece = coaster.track[carTrackIdx];
-                    const isOnLoop = trackPiece?.type === 'loop_vertical';
-                    // Loops are ~3x longer than straight, so reduce speed
-                    const velocityMultiplier = isOnLoop ? 0.5 : 1.0;
-                    const runVelocity = baseRunVelocity * velocityMultiplier;
-                    
-                    let nextProgress = car.trackProgress + runVelocity * deltaTime;
-                    nextProgress = nextProgress % trackLength;
-                    if (nextProgress < 0) nextProgress += trackLength;
-                    return { ...car, trackProgress: nextProgress, velocity: runVelocity };
-                  });
-                }
-                break;
-                
-              case 'braking':
-                // Slow down approaching station - boost velocity at higher game speeds for visual feedback
-                const speedBoostBrake = SPEED_TRAIN_BOOSTS[prev.speed];
-                const baseBrakeVelocity = (hasTrainAhead ? 0.01 : 0.03) * speedBoostBrake;
-                const leadProgressNow = cars[0].trackProgress % trackLength;
-                const atStation = isPositionAtStation(leadProgressNow);
-                
-                if (atStation && !hasTrainAhead) {
-                  state = 'loading';
-                  stateTimer = 5 + Math.random() * 3; // 5-8 seconds stop at station for loading
-                  // Snap to station position (use actual station index, not always 0)
-                  cars = cars.map((car, idx) => ({
-                    ...car,
-                    trackProgress: (effectiveStationIndex + idx * 0.18) % trackLength,
-                    velocity: 0,
-                  }));
-                } else if (hasTrainAhead) {
-                  // Wait for train ahead to clear
-                  cars = cars.map(car => ({ ...car, velocity: 0 }));
-                } else {
-                  cars = cars.map(car => {
-                    // Check if car is on a loop - slow down on loops
-                    const carTrackIdx = Math.floor(car.trackProgress % trackLength);
-                    const trackPiece = coaster.track[carTrackIdx];
-                    const isOnLoop = trackPiece?.type === 'loop_vertical';
-                    const velocityMultiplier = isOnLoop ? 0.5 : 1.0;
-                    const brakeVelocity = baseBrakeVelocity * velocityMultiplier;
-                    
-                    let nextProgress = car.trackProgress + brakeVelocity * deltaTime;
-                    nextProgress = nextProgress % trackLength;
-                    if (nextProgress < 0) nextProgress += trackLength;
-                    return { ...car, trackProgress: nextProgress, velocity: brakeVelocity };
-                  });
-                }
-                break;
-                
-              case 'returning':
-                // Legacy state - treat as running
-                state = 'running';
-                break;
-            }
-            
-            return { ...train, state, stateTimer, cars };
-          });
-          
-          return { ...coaster, trains: updatedTrains };
-        });
-        
-        const incomeAdmissions = prev.finances.incomeAdmissions + admissionRevenue;
-        const incomeRides = prev.finances.incomeRides + rideRevenue;
-        const incomeFood = prev.finances.incomeFood + foodRevenue;
-        const incomeShops = prev.finances.incomeShops + shopRevenue;
-        const incomeTotal = incomeAdmissions + incomeRides + incomeFood + incomeShops;
-        const expenseTotal = prev.finances.expenseWages + prev.finances.expenseUpkeep + prev.finances.expenseMarketing + prev.finances.expenseResearch;
-        const profit = incomeTotal - expenseTotal;
-
-        const monthChanged = month !== prev.month || year !== prev.year;
-        let finances = {
-          ...prev.finances,
-          cash: prev.finances.cash + admissionRevenue + rideRevenue + foodRevenue + shopRevenue,
-          incomeAdmissions,
-          incomeRides,
-          incomeFood,
-          incomeShops,
-          incomeTotal,
-          expenseTotal,
-          profit,
-        };
-
-        if (monthChanged) {
-          const { upkeep } = calculateMonthlyUpkeep(prev.grid);
-          const wages = calculateStaffWages(prev.staff);
-          const monthlyExpenses = upkeep + wages + prev.finances.expenseMarketing + prev.finances.expenseResearch;
-          const monthlyProfit = incomeTotal - monthlyExpenses;
-
-          finances = {
-            ...prev.finances,
-            cash: prev.finances.cash + admissionRevenue + rideRevenue + foodRevenue + shopRevenue - monthlyExpenses,
-            incomeAdmissions: 0,
-            incomeRides: 0,
-            incomeFood: 0,
-            incomeShops: 0,
-            incomeTotal: 0,
-            expenseWages: 0,
-            expenseUpkeep: 0,
-            expenseMarketing: 0,
-            expenseResearch: 0,
-            expenseTotal: 0,
-            profit: 0,
-            history: [
-              ...prev.finances.history,
-              {
-                month: prev.month,
-                year: prev.year,
-                income: incomeTotal,
-                expenses: monthlyExpenses,
-                profit: monthlyProfit,
-                guests: guestsInPark,
-                parkValue: prev.stats.parkValue,
-              },
-            ].slice(-24),
-          };
-        }
-
-        return {
-          ...prev,
-          tick: newTick,
-          minute,
-          hour,
-          day,
-          month,
-          year,
-          weather: newWeather,
-          guests,
-          coasters: updatedCoasters,
-          stats: {
-            ...prev.stats,
-            guestsInPark,
-            guestsTotal: prev.stats.guestsTotal + spawnedGuests.length,
-            guestsSatisfied,
-            guestsUnsatisfied,
-            averageHappiness: avgHappiness,
-            parkRating,
-            totalRidesRidden: prev.stats.totalRidesRidden + rideCompletions,
-          },
-          finances,
-        };
-      });
+      const now = performance.now();
+      accumulator += Math.min(now - lastTime, MAX_TICK_CATCHUP_MS);
+      lastTime = now;
+      let steps = Math.floor(accumulator / tickInterval);
+      accumulator -= steps * tickInterval;
+      
+      while (steps-- > 0) setState(simulateTick);
     }, tickInterval);
     
     return () => clearInterval(interval);
@@ -2068,6 +1168,43 @@ export function CoasterProvider({
     }
   }, []);
   
+  const setOverlayMode = useCallback((mode: OverlayMode) => {
+    setState(prev => ({ ...prev, overlayMode: mode }));
+  }, []);
+  
+  // Switch overlay tints to a colorblind-friendly palette; unknown names are rejected
+  const setOverlayPalette = useCallback((palette: string): boolean => {
+    if (!isColorblindMode(palette)) return false;
+    setState(prev => ({ ...prev, overlayPalette: palette }));
+    return true;
+  }, []);
+  
+  // Tune sprite offsets, scales and crops live; the park redraws with the new layout
+  const setSpriteLayout = useCallback((sheetId: string, layout: unknown): SpriteLayoutResult => {
+    const result = applySpriteOverrides(sheetId, layout);
+    if (result.applied.length > 0) {
+      setSpriteLayoutVersion(version => version + 1);
+    }
+    return result;
+  }, []);
+  
+  // A sheet's current layout as JSON, ready to save or hand back to setSpriteLayout
+  const getSpriteLayout = useCallback((sheetId: string): string | null => {
+    const layout = exportSpriteLayout(sheetId);
+    return layout ? JSON.stringify(layout, null, 2) : null;
+  }, []);
+  
+  const setDebugGrid = useCallback((on: boolean) => {
+    setDebugGridState(on);
+  }, []);
+  
+  // Guests out on each tile, as rows indexed [y][x]
+  const getGuestDensityOverlay = useCallback((): number[][] => {
+    const { guests, gridSize } = latestStateRef.current;
+    const occupancy = getGuestOccupancy(guests, gridSize);
+    return Array.from({ length: gridSize }, (_, y) => Array.from(occupancy.subarray(y * gridSize, (y + 1) * gridSize)));
+  }, []);
+  
   const setActivePanel = useCallback((panel: GameState['activePanel']) => {
     setState(prev => ({ ...prev, activePanel: panel }));
   }, []);
@@ -2077,6 +1214,11 @@ export function CoasterProvider({
     setState(prev => {
       const tool = prev.selectedTool;
       if (tool === 'select' || tool === 'bulldoze') return prev;
+      // Auto-path places a whole route at once via placePathRoute
+      if (tool === 'auto_path') return prev;
+      
+      // Selling removes the tile contents for a partial refund
+      if (tool === 'sell') return sellTile(prev, x, y);
       
       // Clone grid
       const newGrid = prev.grid.map(row => row.map(tile => ({ ...tile })));
@@ -2086,84 +1228,64 @@ export function CoasterProvider({
       const toolInfo = TOOL_INFO[tool];
       if (!toolInfo) return prev;
       
-      // Check if we can afford it
-      if (prev.finances.cash < toolInfo.cost) return prev;
+      // The same rules colour the hover preview (cash, bankruptcy, terrain, occupied
+      // tiles); only terrain and entrance problems are worth a notification
+      const rejection = getPlacementRejection(prev, tool, x, y);
+      if (rejection) return rejection.title ? rejectPlacement(prev, tool, x, y, rejection.title, rejection.reason) : prev;
       
       // Handle water terraform - turn land into water
       if (tool === 'zone_water') {
-        // Already water - do nothing
-        if (tile.terrain === 'water') return prev;
-        // Don't terraform if there's a building/path/track
-        if (tile.building.type !== 'empty' && tile.building.type !== 'grass') return prev;
-        if (tile.path || tile.queue || tile.hasCoasterTrack) return prev;
-        
         tile.terrain = 'water';
         tile.building = { ...createEmptyBuilding(), type: 'water' };
-        return { ...prev, grid: newGrid, finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost } };
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, toolInfo.cost) };
       }
       
       // Handle land terraform - turn water into land
       if (tool === 'zone_land') {
-        // Only works on water
-        if (tile.terrain !== 'water') return prev;
-        
         tile.terrain = 'grass';
         tile.building = { ...createEmptyBuilding(), type: 'grass' };
-        return { ...prev, grid: newGrid, finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost } };
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, toolInfo.cost) };
+      }
+      
+      // Resurface open ground; water has to be filled in first
+      if (tool === 'terrain_sand' || tool === 'terrain_rock') {
+        tile.terrain = tool === 'terrain_sand' ? 'sand' : 'rock';
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, toolInfo.cost) };
       }
       
-      // Don't build on water (except for some specific things)
-      if (tile.terrain === 'water') return prev;
+      // Raise or lower bare land; steep neighbors are smoothed and each reshaped tile is charged
+      if (tool === 'terrain_raise' || tool === 'terrain_lower') {
+        const changes = planElevationChange(newGrid, x, y, tool === 'terrain_raise' ? 1 : -1);
+        if (!changes) return prev;
+        const cost = toolInfo.cost * changes.size;
+        changes.forEach(change => {
+          newGrid[change.y][change.x].elevation = change.elevation;
+        });
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, cost) };
+      }
       
       // Handle path placement
       if (tool === 'path') {
-        // Don't place path on existing buildings, tracks, or footprints
-        const existingType = tile.building?.type;
-        if (existingType && existingType !== 'empty' && existingType !== 'grass' && existingType !== 'path') return prev;
-        if (tile.trackPiece || tile.hasCoasterTrack) return prev;
-        
         tile.path = true;
         tile.building = { ...createEmptyBuilding(), type: 'path' };
-        return { ...prev, grid: newGrid, finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost } };
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, toolInfo.cost) };
       }
       
       // Handle queue placement
       if (tool === 'queue') {
-        // Don't place queue on existing buildings, tracks, or footprints
-        const existingType = tile.building?.type;
-        if (existingType && existingType !== 'empty' && existingType !== 'grass' && existingType !== 'queue') return prev;
-        if (tile.trackPiece || tile.hasCoasterTrack) return prev;
-        
         tile.queue = true;
         tile.building = { ...createEmptyBuilding(), type: 'queue' };
-        return { ...prev, grid: newGrid, finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost } };
+        // A queue touching a ride (or joining a line that does) leads guests to it
+        relinkQueues(newGrid, { x, y, width: 1, height: 1 });
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, toolInfo.cost) };
       }
       
-      const trackTools: Tool[] = [
-        'coaster_build',
-        'coaster_track',
-        'coaster_turn_left',
-        'coaster_turn_right',
-        'coaster_slope_up',
-        'coaster_slope_down',
-        'coaster_loop',
-      ];
-      
-      if (trackTools.includes(tool)) {
+      if (TRACK_TOOLS.includes(tool)) {
         const buildPath = prev.buildingCoasterPath;
         const lastTile = buildPath.length > 0 ? buildPath[buildPath.length - 1] : null;
         const deltaDir = lastTile ? directionFromDelta(x - lastTile.x, y - lastTile.y) : null;
         
-        // For auto-build mode, require adjacency
-        if (tool === 'coaster_build' && lastTile && !deltaDir) return prev;
-        
-        // ALWAYS check for adjacent existing track to inherit direction and height
-        // When multiple adjacent tracks exist, prefer the one that matches the build path
-        let adjacentDirection: TrackDirection | null = null;
-        let adjacentHeight = prev.buildingCoasterHeight;
-        let connectingToEntry = false; // True if we're feeding INTO adjacent track's entry
-        let targetEntryHeight = 0; // The height we need our exit to be at when connecting to entry
-        
+        const { pieceType, startDirection, endDirection, startHeight, endHeight, chainLift } = planTrackPiece(prev, x, y, tool);
         const adjacentOffsets = [
           { dx: -1, dy: 0 },
           { dx: 1, dy: 0 },
@@ -2171,206 +1293,6 @@ export function CoasterProvider({
           { dx: 0, dy: 1 },
         ];
         
-        type AdjacentCandidate = {
-          adjX: number;
-          adjY: number;
-          baseDirection: TrackDirection;
-          baseHeight: number;
-          connectingToEntry: boolean;
-          targetEntryHeight: number;
-          isExitConnection: boolean;
-        };
-        
-        const candidates: AdjacentCandidate[] = [];
-        
-        for (const { dx, dy } of adjacentOffsets) {
-          const adjX = x + dx;
-          const adjY = y + dy;
-          if (adjX >= 0 && adjY >= 0 && adjX < prev.gridSize && adjY < prev.gridSize) {
-            const adjTile = prev.grid[adjY]?.[adjX];
-            if (adjTile?.trackPiece) {
-              const adjPiece = adjTile.trackPiece;
-              
-              // Calculate entry and exit directions for the adjacent piece
-              // Turns store entry direction; straights/slopes store exit direction.
-              const isFlatTurn =
-                adjPiece.type === 'turn_left_flat' ||
-                adjPiece.type === 'turn_right_flat' ||
-                adjPiece.type === 'turn_left_large_flat' ||
-                adjPiece.type === 'turn_right_large_flat';
-              const entryDir = isFlatTurn ? adjPiece.direction : OPPOSITE_DIRECTION[adjPiece.direction];
-              
-              // Exit direction depends on track type
-              let exitDir = adjPiece.direction;
-              if (adjPiece.type === 'turn_left_flat' || adjPiece.type === 'turn_left_large_flat') {
-                exitDir = rotateDirection(adjPiece.direction, 'left');
-              } else if (adjPiece.type === 'turn_right_flat' || adjPiece.type === 'turn_right_large_flat') {
-                exitDir = rotateDirection(adjPiece.direction, 'right');
-              }
-              
-              // Check if adjacent track's EXIT points toward us (we connect to receive from it)
-              // Adjacent is at (x + dx, y + dy) relative to our new tile at (x, y)
-              const exitPointsToUs = (
-                (exitDir === 'south' && dx === -1) ||
-                (exitDir === 'north' && dx === 1) ||
-                (exitDir === 'west' && dy === -1) ||
-                (exitDir === 'east' && dy === 1)
-              );
-              
-              // Check if adjacent track's ENTRY points toward us (we connect to feed into it)
-              const entryPointsToUs = (
-                (entryDir === 'south' && dx === -1) ||
-                (entryDir === 'north' && dx === 1) ||
-                (entryDir === 'west' && dy === -1) ||
-                (entryDir === 'east' && dy === 1)
-              );
-              
-              if (exitPointsToUs) {
-                candidates.push({
-                  adjX,
-                  adjY,
-                  baseDirection: exitDir,
-                  baseHeight: adjPiece.endHeight,
-                  connectingToEntry: false,
-                  targetEntryHeight: adjPiece.startHeight,
-                  isExitConnection: true,
-                });
-              } else if (entryPointsToUs) {
-                candidates.push({
-                  adjX,
-                  adjY,
-                  baseDirection: OPPOSITE_DIRECTION[entryDir],
-                  baseHeight: adjPiece.startHeight,
-                  connectingToEntry: true,
-                  targetEntryHeight: adjPiece.startHeight,
-                  isExitConnection: false,
-                });
-              }
-            }
-          }
-        }
-        
-        if (candidates.length > 0) {
-          const lastTileMatch = lastTile
-            ? candidates.find(candidate =>
-                candidate.adjX === lastTile.x &&
-                candidate.adjY === lastTile.y &&
-                candidate.isExitConnection
-              )
-            : null;
-          
-          const directionMatch = deltaDir
-            ? candidates.find(candidate =>
-                candidate.baseDirection === deltaDir && candidate.isExitConnection
-              )
-            : null;
-          
-          const exitCandidates = candidates.filter(candidate => candidate.isExitConnection);
-          const heightSorted = (list: AdjacentCandidate[]) =>
-            list.slice().sort((a, b) =>
-              Math.abs(a.baseHeight - prev.buildingCoasterHeight) -
-              Math.abs(b.baseHeight - prev.buildingCoasterHeight)
-            );
-          
-          const chosen = lastTileMatch
-            ?? directionMatch
-            ?? heightSorted(exitCandidates)[0]
-            ?? heightSorted(candidates)[0];
-          
-          adjacentDirection = chosen.baseDirection;
-          adjacentHeight = chosen.baseHeight;
-          connectingToEntry = chosen.connectingToEntry;
-          targetEntryHeight = chosen.targetEntryHeight;
-        }
-        
-        // Determine track directions
-        // Priority: adjacentDirection (from existing track) > deltaDir (from drag) > lastDirection > default
-        const baseDirection = adjacentDirection ?? deltaDir ?? prev.buildingCoasterLastDirection ?? 'south';
-        let startDirection: TrackDirection = baseDirection;
-        let endDirection: TrackDirection = baseDirection;
-        let pieceType: TrackPieceType = 'straight_flat';
-        let startHeight = adjacentHeight;
-        let endHeight = adjacentHeight;
-        let chainLift = false;
-        
-        if (tool === 'coaster_turn_left') {
-          pieceType = 'turn_left_flat';
-          // For turns, the drawing code interprets direction as "entering FROM" (not traveling TO)
-          if (adjacentDirection) {
-            if (connectingToEntry) {
-              // Feeding into adjacent's entry - our EXIT must go toward adjacent
-              // adjacentDirection is where we need to exit TO
-              // For turn_left: exit = rotateDirection(entry, 'left')
-              // So: entry = rotateDirection(exit, 'right')
-              startDirection = rotateDirection(adjacentDirection, 'right');
-            } else {
-              // Receiving from adjacent's exit - we enter FROM the opposite of where they're going
-              startDirection = OPPOSITE_DIRECTION[adjacentDirection];
-            }
-          }
-          endDirection = rotateDirection(startDirection, 'left');
-        } else if (tool === 'coaster_turn_right') {
-          pieceType = 'turn_right_flat';
-          if (adjacentDirection) {
-            if (connectingToEntry) {
-              // For turn_right: exit = rotateDirection(entry, 'right')
-              // So: entry = rotateDirection(exit, 'left')
-              startDirection = rotateDirection(adjacentDirection, 'left');
-            } else {
-              startDirection = OPPOSITE_DIRECTION[adjacentDirection];
-            }
-          }
-          endDirection = rotateDirection(startDirection, 'right');
-        } else if (tool === 'coaster_slope_up') {
-          pieceType = 'slope_up_small';
-          // For slopes, drawSlopeTrack interprets direction as the EXIT direction:
-          // direction='south' → enter from north (at startHeight), exit to south (at endHeight)
-          //
-          // For slope_up: startHeight < endHeight, so the slope rises toward the exit.
-          //
-          // When connecting to existing track:
-          // - Exit connection: adjacent exits toward us, we receive at our entry
-          // - Entry connection: we feed into adjacent's entry
-          // adjacentDirection already matches travel direction in both cases.
-          if (adjacentDirection) {
-            // For both entry/exit connections, adjacentDirection already matches travel direction.
-            startDirection = adjacentDirection;
-          }
-          if (connectingToEntry && targetEntryHeight > 0) {
-            endHeight = targetEntryHeight;
-            startHeight = clampHeight(targetEntryHeight - 1);
-          } else {
-            endHeight = clampHeight(startHeight + 1);
-          }
-          chainLift = true;
-        } else if (tool === 'coaster_slope_down') {
-          pieceType = 'slope_down_small';
-          // For slope_down, direction logic is the SAME as slope_up:
-          // adjacentDirection already matches travel direction for entry/exit connections.
-          if (adjacentDirection) {
-            // For both entry/exit connections, adjacentDirection already matches travel direction.
-            startDirection = adjacentDirection;
-          }
-          if (connectingToEntry && targetEntryHeight < 10) {
-            endHeight = targetEntryHeight;
-            startHeight = clampHeight(targetEntryHeight + 1);
-          } else {
-            // Going down: start high, end low
-            endHeight = clampHeight(startHeight - 1);
-          }
-          chainLift = false;
-        } else if (tool === 'coaster_loop') {
-          pieceType = 'loop_vertical';
-        } else if (tool === 'coaster_build') {
-          if (deltaDir) {
-            startDirection = deltaDir;
-            endDirection = deltaDir;
-          }
-          pieceType = 'straight_flat';
-        } else {
-          pieceType = 'straight_flat';
-        }
-        
         // Update previous tile for auto-build turns
         if (tool === 'coaster_build' && lastTile && deltaDir && buildPath.length > 1) {
           const prevPathTile = buildPath[buildPath.length - 2];
@@ -2502,14 +1424,15 @@ export function CoasterProvider({
         // Always normalize train positions when track changes to prevent cars from separating
         let trains: CoasterTrain[];
         if (needsNewTrains) {
-          trains = createTrainsForCoaster(trackPieces.length, coasterBase.type);
+          trains = createTrainsForCoaster(trackPieces.length, coasterBase.type, coasterBase.trainConfig);
         } else if (oldTrackLength !== trackPieces.length) {
           // Track changed but not enough for new trains - normalize positions
           trains = normalizeTrainsForTrackChange(
             coasterBase.trains,
             oldTrackLength,
             trackPieces.length,
-            effectiveStationIdx
+            effectiveStationIdx,
+            coasterBase.trainConfig?.carSpacing
           );
         } else {
           trains = coasterBase.trains;
@@ -2523,6 +1446,7 @@ export function CoasterProvider({
           stationTileX: stationTile.x,
           stationTileY: stationTile.y,
           trains,
+          ...rateCoasterTrack(trackTiles, trackPieces, coasterBase.type),
         };
         
         // Remove absorbed coasters (those whose tracks were merged into this one)
@@ -2538,7 +1462,7 @@ export function CoasterProvider({
         return {
           ...prev,
           grid: newGrid,
-          finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost },
+          finances: chargeConstruction(prev.finances, toolInfo.cost),
           buildingCoasterId: coasterId,
           buildingCoasterPath: updatedPath,
           buildingCoasterHeight: endHeight,
@@ -2547,31 +1471,6 @@ export function CoasterProvider({
         };
       }
       
-      // Check if the tool is a scenery tool (flowers, bushes, trees)
-      const sceneryTools = [
-        // Trees
-        'tree_oak', 'tree_maple', 'tree_birch', 'tree_elm', 'tree_willow',
-        'tree_pine', 'tree_spruce', 'tree_fir', 'tree_cedar', 'tree_redwood',
-        'tree_palm', 'tree_banana', 'tree_bamboo', 'tree_coconut', 'tree_tropical',
-        'tree_cherry', 'tree_magnolia', 'tree_dogwood', 'tree_jacaranda', 'tree_wisteria',
-        // Bushes
-        'bush_hedge', 'bush_flowering',
-        // Topiaries
-        'topiary_ball', 'topiary_spiral', 'topiary_animal',
-        // Flowers
-        'flowers_bed', 'flowers_planter', 'flowers_hanging', 'flowers_wild', 'ground_cover',
-      ];
-      
-      // For scenery tools, check if tile already has a structure
-      if (sceneryTools.includes(tool)) {
-        const existingBuildingType = tile.building?.type;
-        // Allow placement only on empty/grass tiles (not on paths, queues, other buildings, or track)
-        const canPlace = !existingBuildingType || existingBuildingType === 'empty' || existingBuildingType === 'grass';
-        if (!canPlace || tile.trackPiece || tile.hasCoasterTrack) {
-          return prev; // Can't place scenery on top of existing structures
-        }
-      }
-      
       // Map tools to building types (tool name is often the building type)
       const toolToBuildingType: Record<string, BuildingType | BuildingType[]> = {
         // Trees
@@ -2803,21 +1702,6 @@ export function CoasterProvider({
       
       // Special handling for coaster_station - select correct rotation based on adjacent track
       if (tool === 'coaster_station') {
-        // Validate tile is buildable (coaster_station is 2x1)
-        const stationSize = toolInfo.size ?? { width: 2, height: 1 };
-        for (let dy = 0; dy < stationSize.height; dy++) {
-          for (let dx = 0; dx < stationSize.width; dx++) {
-            const checkX = x + dx;
-            const checkY = y + dy;
-            if (checkX >= prev.gridSize || checkY >= prev.gridSize) return prev;
-            const checkTile = prev.grid[checkY]?.[checkX];
-            if (!checkTile) return prev;
-            if (checkTile.terrain === 'water') return prev;
-            if (checkTile.building?.type && checkTile.building.type !== 'empty' && checkTile.building.type !== 'grass') return prev;
-            if (checkTile.path || checkTile.queue || checkTile.hasCoasterTrack || checkTile.trackPiece) return prev;
-          }
-        }
-        
         // Check adjacent tiles for track to determine station orientation
         const adjacentOffsets = [
           { dx: -1, dy: 0 }, { dx: 1, dy: 0 },
@@ -2837,9 +1721,8 @@ export function CoasterProvider({
           }
         }
         
-        // Select station type randomly
-        const stationTypes = ['wooden', 'steel', 'inverted', 'water'];
-        const stationType = stationTypes[Math.floor(Math.random() * stationTypes.length)];
+        // Station style follows the coaster type being built
+        const stationType = getStationStyleForCoasterType(prev.buildingCoasterType ?? 'steel_sit_down');
         
         // Select rotation based on track direction:
         // - _1, _2: For north/south track direction (NE-SW orientation on screen)
@@ -2857,8 +1740,21 @@ export function CoasterProvider({
           ...createEmptyBuilding(), 
           type: stationBuildingType as BuildingType,
           constructionProgress: 100,
+          price: DEFAULT_PRICES.rideTicket,
         };
-        return { ...prev, grid: newGrid, finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost } };
+        return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, toolInfo.cost) };
+      }
+      
+      // Park entrances connect to the edge path guests arrive on (checked above)
+      if (tool === 'park_entrance') {
+        const entranceSize = toolInfo.size ?? { width: 1, height: 1 };
+        const edgeTile = findEntranceEdgeTile(newGrid, prev.gridSize, x, y, entranceSize);
+        if (edgeTile) {
+          // Auto-create the edge path (included in the entrance cost)
+          const entranceTile = newGrid[edgeTile.y][edgeTile.x];
+          entranceTile.path = true;
+          entranceTile.building = { ...createEmptyBuilding(), type: 'path' };
+        }
       }
       
       const buildingEntry = toolToBuildingType[tool];
@@ -2867,28 +1763,11 @@ export function CoasterProvider({
         : buildingEntry;
       
       if (buildingType) {
-        // Check if this is a multi-tile building
+        // Check if this is a multi-tile building (its footprint was checked above)
         const buildingSize = toolInfo.size ?? { width: 1, height: 1 };
         
-        // Validate all tiles in the footprint
-        for (let dy = 0; dy < buildingSize.height; dy++) {
-          for (let dx = 0; dx < buildingSize.width; dx++) {
-            const checkX = x + dx;
-            const checkY = y + dy;
-            
-            // Check bounds
-            if (checkX >= prev.gridSize || checkY >= prev.gridSize) {
-              return prev; // Can't place here
-            }
-            
-            const checkTile = newGrid[checkY][checkX];
-            
-            // Check if tile is buildable (not water, not already built on, no tracks)
-            if (checkTile.terrain === 'water') return prev;
-            if (checkTile.building.type !== 'empty' && checkTile.building.type !== 'grass') return prev;
-            if (checkTile.path || checkTile.queue || checkTile.hasCoasterTrack || checkTile.trackPiece) return prev;
-          }
-        }
+        // Only square footprints can turn; mirroring a long building would no longer match its tiles
+        const orientation = buildingSize.width === buildingSize.height ? prev.placementOrientation ?? 0 : 0;
         
         // Place the building on all footprint tiles
         for (let dy = 0; dy < buildingSize.height; dy++) {
@@ -2903,6 +1782,8 @@ export function CoasterProvider({
                 ...createEmptyBuilding(), 
                 type: buildingType,
                 constructionProgress: 100,
+                orientation,
+                price: isRideBuilding(buildingType) ? getDefaultRidePrice(buildingType) : 0,
               };
             } else {
               // Non-origin tile - mark as part of building footprint
@@ -2916,134 +1797,141 @@ export function CoasterProvider({
           }
         }
         
-        return { ...prev, grid: newGrid, finances: { ...prev.finances, cash: prev.finances.cash - toolInfo.cost } };
+        // Queues already waiting beside a new ride start feeding it
+        relinkQueues(newGrid, { x, y, ...buildingSize });
+        
+        // Each staff building comes with its own janitors
+        const staff = tool === 'staff_building'
+          ? [...prev.staff, ...Array.from({ length: JANITORS_PER_STAFF_BUILDING }, () => createJanitor(x, y))]
+          : prev.staff;
+        
+        return { ...prev, grid: newGrid, staff, finances: chargeConstruction(prev.finances, toolInfo.cost) };
       }
       
       return prev;
     });
-    if (!isRemote && currentTool !== 'select' && currentTool !== 'bulldoze' && placeCallbackRef.current) {
+    if (!isRemote && currentTool !== 'select' && currentTool !== 'bulldoze' && currentTool !== 'auto_path' && placeCallbackRef.current) {
       placeCallbackRef.current({ x, y, tool: currentTool });
     }
   }, []);
   
-  const bulldozeTile = useCallback((x: number, y: number, isRemote: boolean = false) => {
+  const placePathRoute = useCallback((start: { x: number; y: number }, end: { x: number; y: number }) => {
+    const current = latestStateRef.current;
+    const route = findPathPlacementRoute(current.grid, current.gridSize, start, end);
+    const routeCost = route ? route.newTiles.length * TOOL_INFO.path.cost : 0;
+    const canAfford = current.finances.cash >= routeCost && !current.finances.bankrupt;
+    
     setState(prev => {
-      const newGrid = prev.grid.map(row => row.map(tile => ({ ...tile })));
-      const tile = newGrid[y][x];
+      // Recompute against the latest grid so the route is placed all-or-nothing
+      const latestRoute = findPathPlacementRoute(prev.grid, prev.gridSize, start, end);
+      const cost = latestRoute ? latestRoute.newTiles.length * TOOL_INFO.path.cost : 0;
       
-      // Check if we're bulldozing a multi-tile building (origin or footprint tile)
-      const buildingType = tile.building?.type;
-      if (buildingType && (buildingType.endsWith('_footprint') || TOOL_INFO[buildingType as Tool]?.size)) {
-        // Find the origin tile and clear all footprint tiles
-        const originType = buildingType.endsWith('_footprint') 
-          ? buildingType.replace('_footprint', '') 
-          : buildingType;
-        const toolInfo = TOOL_INFO[originType as Tool];
-        const buildingSize = toolInfo?.size ?? { width: 1, height: 1 };
-        
-        // Find the origin by searching nearby tiles
-        for (let searchY = Math.max(0, y - buildingSize.height + 1); searchY <= y; searchY++) {
-          for (let searchX = Math.max(0, x - buildingSize.width + 1); searchX <= x; searchX++) {
-            const searchTile = newGrid[searchY]?.[searchX];
-            if (searchTile?.building?.type === originType) {
-              // Found the origin - clear all footprint tiles
-              for (let dy = 0; dy < buildingSize.height; dy++) {
-                for (let dx = 0; dx < buildingSize.width; dx++) {
-                  const clearX = searchX + dx;
-                  const clearY = searchY + dy;
-                  if (clearX < prev.gridSize && clearY < prev.gridSize) {
-                    const clearTile = newGrid[clearY][clearX];
-                    clearTile.building = createEmptyBuilding();
-                  }
-                }
-              }
-              return { ...prev, grid: newGrid };
-            }
-          }
-        }
+      if (!latestRoute) {
+        return pushNotification(prev, 'No Route Found', 'There is no buildable route between those tiles.', 'warning', end);
       }
-      
-      // Check if we're bulldozing track and get the coaster ID
-      const hadTrack = tile.hasCoasterTrack || tile.trackPiece;
-      const coasterId = tile.coasterTrackId;
-      
-      // Reset tile
-      tile.building = createEmptyBuilding();
-      tile.path = false;
-      tile.queue = false;
-      tile.queueRideId = null;
-      tile.hasCoasterTrack = false;
-      tile.coasterTrackId = null;
-      tile.trackPiece = null;
-      
-      // If track was demolished, update the coaster's track arrays and normalize trains
-      let updatedCoasters = prev.coasters;
-      if (hadTrack && coasterId) {
-        // Recollect all track for this coaster from the updated grid
-        const { tiles: trackTiles, pieces: trackPieces } = collectCoasterTrack(newGrid, coasterId);
-
-        // If no track tiles remain, remove the coaster entirely
-        if (trackTiles.length === 0) {
-          updatedCoasters = prev.coasters.filter(c => c.id !== coasterId);
-        } else {
-          updatedCoasters = prev.coasters.map(coaster => {
-            if (coaster.id === coasterId) {
-              const oldTrackLength = coaster.track.length;
-              const newTrackLength = trackPieces.length;
-
-              // Find the best station tile for the updated track
-              const stationTile = findStationTile(newGrid, trackTiles, prev.gridSize) || trackTiles[0];
-              const stationIdx = stationTile
-                ? trackTiles.findIndex(t => t.x === stationTile.x && t.y === stationTile.y)
-                : 0;
-              const effectiveStationIdx = stationIdx >= 0 ? stationIdx : 0;
-
-              // Always regenerate trains when track changes to prevent orphaned cars
-              const trains = createTrainsForCoaster(newTrackLength, coaster.type).map((train, trainIndex) => {
-                const trainOffset = (trainIndex * newTrackLength) / Math.max(1, createTrainsForCoaster(newTrackLength, coaster.type).length);
-                const baseProgress = (effectiveStationIdx + trainOffset) % newTrackLength;
-                return {
-                  ...train,
-                  cars: train.cars.map((car, carIndex) => ({
-                    ...car,
-                    trackProgress: (baseProgress + carIndex * 0.18) % newTrackLength,
-                  })),
-                };
-              });
-
-              return {
-                ...coaster,
-                track: trackPieces,
-                trackTiles,
-                stationTileX: stationTile?.x ?? coaster.stationTileX,
-                stationTileY: stationTile?.y ?? coaster.stationTileY,
-                trains,
-              };
-            }
-            return coaster;
-          });
-        }
+      if (prev.finances.bankrupt) {
+        return pushNotification(prev, 'Park Bankrupt', 'Construction is halted until cash recovers.', 'error', end);
       }
-      
-      // If track was demolished, reset the coaster building state
-      if (hadTrack) {
-        return { 
-          ...prev, 
-          grid: newGrid,
-          coasters: updatedCoasters,
-          buildingCoasterHeight: 0,
-          buildingCoasterLastDirection: null,
-          buildingCoasterPath: [],
-        };
+      if (prev.finances.cash < cost) {
+        return pushNotification(prev, 'Not Enough Money', `This path needs $${cost.toLocaleString()} to build.`, 'money', end);
       }
       
-      return { ...prev, grid: newGrid };
+      if (latestRoute.newTiles.length === 0) return prev;
+      
+      const newGrid = prev.grid.map(row => row.map(tile => ({ ...tile })));
+      for (const { x, y } of latestRoute.newTiles) {
+        const tile = newGrid[y][x];
+        tile.path = true;
+        tile.building = { ...createEmptyBuilding(), type: 'path' };
+      }
+      return { ...prev, grid: newGrid, finances: chargeConstruction(prev.finances, cost) };
     });
+    
+    // Sync the placed tiles to other players as ordinary path placements
+    if (route && canAfford && placeCallbackRef.current) {
+      for (const { x, y } of route.newTiles) {
+        placeCallbackRef.current({ x, y, tool: 'path' });
+      }
+    }
+  }, []);
+  
+  // Lay a straight run of path, queue or track with the selected tool, one tile at a time
+  const placeLine = useCallback((start: { x: number; y: number }, end: { x: number; y: number }) => {
+    const current = latestStateRef.current;
+    if (!LINE_TOOLS.includes(current.selectedTool)) return false;
+    let tiles = getStraightRunTiles(start, end);
+    
+    // Track has to carry on from the piece last laid, so the run must start (or end) beside it
+    if (TRACK_LINE_TOOLS.includes(current.selectedTool)) {
+      const lastTile = current.buildingCoasterPath[current.buildingCoasterPath.length - 1];
+      if (lastTile) {
+        const touches = (tile: { x: number; y: number }) =>
+          Math.abs(tile.x - lastTile.x) + Math.abs(tile.y - lastTile.y) <= 1;
+        if (!touches(tiles[0]) && touches(tiles[tiles.length - 1])) {
+          tiles = [...tiles].reverse();
+        } else if (!touches(tiles[0])) {
+          setState(prev => pushNotification(
+            prev,
+            'Track Must Connect',
+            'Start the line next to the last piece of track you built.',
+            'warning',
+            start
+          ));
+          return false;
+        }
+        tiles = tiles.filter(tile => tile.x !== lastTile.x || tile.y !== lastTile.y);
+      }
+    }
+    
+    for (const tile of tiles) {
+      placeAtTile(tile.x, tile.y);
+    }
+    return true;
+  }, [placeAtTile]);
+  
+  const bulldozeTile = useCallback((x: number, y: number, isRemote: boolean = false) => {
+    setState(prev => demolishTile(prev, x, y));
     if (!isRemote && bulldozeCallbackRef.current) {
       bulldozeCallbackRef.current({ x, y });
     }
   }, []);
   
+  // Price of clearing a rectangle, so the UI can show it before the player commits
+  const getBulldozeAreaCost = useCallback((start: { x: number; y: number }, end: { x: number; y: number }): number => {
+    return getBulldozeRectCost(latestStateRef.current.grid, start, end);
+  }, []);
+  
+  // Clear a whole rectangle at once; returns what it cost (0 if nothing was cleared)
+  const bulldozeArea = useCallback((start: { x: number; y: number }, end: { x: number; y: number }, isRemote: boolean = false): number => {
+    const current = latestStateRef.current;
+    const tiles = getBulldozeRectTiles(current.grid, start, end);
+    const cost = getBulldozeRectCost(current.grid, start, end);
+    if (tiles.length === 0) return 0;
+    
+    // The clearing player already paid the checks; replay it (and its fee) as-is
+    if (isRemote) {
+      setState(prev => bulldozeRect(prev, start, end));
+      return cost;
+    }
+    
+    if (current.finances.bankrupt) {
+      setState(prev => pushNotification(prev, 'Park Bankrupt', 'Construction is halted until cash recovers.', 'error', end));
+      return 0;
+    }
+    if (current.finances.cash < cost) {
+      setState(prev => pushNotification(prev, 'Not Enough Money', `Clearing this area needs $${cost.toLocaleString()}.`, 'money', end));
+      return 0;
+    }
+    
+    setState(prev => bulldozeRect(prev, start, end));
+    
+    // Sync as one area clear so other players charge the same fee
+    if (bulldozeAreaCallbackRef.current) {
+      bulldozeAreaCallbackRef.current({ start, end });
+    }
+    return cost;
+  }, []);
+  
   const startCoasterBuild = useCallback((coasterType: string, options?: { coasterId?: string; isRemote?: boolean }) => {
     const nextCoasterId = options?.coasterId ?? generateUUID();
     setState(prev => ({
@@ -3091,11 +1979,211 @@ export function CoasterProvider({
     }
   }, []);
   
+  // Configure a coaster's trains and re-seed them around the circuit from the station.
+  // Returns false (and posts a notification) if the layout doesn't fit the track.
+  const setCoasterTrains = useCallback((
+    coasterId: string,
+    numTrains: number,
+    carsPerTrain: number,
+    carSpacing: number = DEFAULT_CAR_SPACING
+  ): boolean => {
+    const coaster = latestStateRef.current.coasters.find(c => c.id === coasterId);
+    if (!coaster) return false;
+    
+    const trainConfig: CoasterTrainConfig = { numTrains, carsPerTrain, carSpacing };
+    const error = validateTrainConfig(coaster.track.length, trainConfig);
+    if (error) {
+      setState(prev => pushNotification(prev, 'Trains Would Overlap', error, 'warning', { x: coaster.stationTileX, y: coaster.stationTileY }));
+      return false;
+    }
+    
+    setState(prev => ({
+      ...prev,
+      coasters: prev.coasters.map(c => {
+        if (c.id !== coasterId) return c;
+        const stationIdx = c.trackTiles.findIndex(t => t.x === c.stationTileX && t.y === c.stationTileY);
+        return {
+          ...c,
+          trainConfig,
+          // Replace every train so none are left mid-circuit with the old layout
+          trains: createTrainsAtStation(c.track.length, Math.max(0, stationIdx), c.type, trainConfig),
+        };
+      }),
+    }));
+    return true;
+  }, []);
+  
+  // Set how long trains wait at the station before leaving
+  const setDispatchPolicy = useCallback((coasterId: string, minWait: number, maxWait: number): boolean => {
+    if (!(minWait >= 0) || !(maxWait >= minWait)) return false;
+    if (!latestStateRef.current.coasters.some(c => c.id === coasterId)) return false;
+    
+    const dispatchPolicy: CoasterDispatchPolicy = { minWait, maxWait };
+    setState(prev => ({
+      ...prev,
+      coasters: prev.coasters.map(c => c.id === coasterId ? { ...c, dispatchPolicy } : c),
+    }));
+    return true;
+  }, []);
+  
+  // Open or close a coaster without demolishing it. Closing parks every train
+  // at the station and sends guests waiting for it off to find another ride.
+  const setCoasterOperating = useCallback((coasterId: string, operating: boolean): boolean => {
+    if (!latestStateRef.current.coasters.some(c => c.id === coasterId)) return false;
+    
+    setState(prev => {
+      const coaster = prev.coasters.find(c => c.id === coasterId);
+      if (!coaster || coaster.operating === operating) return prev;
+      
+      const stationIdx = coaster.trackTiles.findIndex(t => t.x === coaster.stationTileX && t.y === coaster.stationTileY);
+      const coasters = prev.coasters.map(c => c.id !== coasterId ? c : {
+        ...c,
+        operating,
+        stalled: false,
+        trains: createTrainsAtStation(c.track.length, Math.max(0, stationIdx), c.type, c.trainConfig),
+      });
+      
+      if (operating) return { ...prev, coasters };
+      
+      const guests = prev.guests.map(guest => {
+        const headingToRide = guest.state === 'queuing' || (guest.state === 'walking' && guest.targetBuildingKind === 'ride');
+        if (!headingToRide || !guest.queueRideId) return guest;
+        if (findCoasterForRide(prev.grid, guest.queueRideId, prev.coasters)?.id !== coasterId) return guest;
+        return {
+          ...guest,
+          state: 'walking' as const,
+          queueRideId: null,
+          targetBuildingId: null,
+          targetBuildingKind: null,
+          queueTimer: 0,
+          decisionCooldown: 0,
+          path: [],
+          pathIndex: 0,
+          targetTileX: guest.tileX,
+          targetTileY: guest.tileY,
+        };
+      });
+      
+      return pushNotification(
+        { ...prev, coasters, guests },
+        'Ride Closed',
+        `${coaster.name} is closed. Waiting guests have moved on.`,
+        'ride',
+        { x: coaster.stationTileX, y: coaster.stationTileY }
+      );
+    });
+    return true;
+  }, []);
+  
+  // Why the coaster being built can't run yet (empty once its circuit is rideable)
+  const getActiveCoasterErrors = useCallback((): string[] => {
+    const { grid, buildingCoasterId } = latestStateRef.current;
+    if (!buildingCoasterId) return [];
+    const { tiles, pieces } = collectCoasterTrack(grid, buildingCoasterId);
+    return getTrackValidationErrors(tiles, pieces);
+  }, []);
+  
+  const getCoasterList = useCallback((): CoasterSummary[] => {
+    const { coasters, buildingCoasterId } = latestStateRef.current;
+    return coasters.map(c => ({
+      id: c.id,
+      name: c.name,
+      type: c.type,
+      stationTileX: c.stationTileX,
+      stationTileY: c.stationTileY,
+      active: c.id === buildingCoasterId,
+    }));
+  }, []);
+  
+  // Pick which existing coaster new track pieces extend
+  const setActiveCoaster = useCallback((coasterId: string): boolean => {
+    const coaster = latestStateRef.current.coasters.find(c => c.id === coasterId);
+    if (!coaster) return false;
+    
+    setState(prev => {
+      const active = prev.coasters.find(c => c.id === coasterId) ?? coaster;
+      // Pick up building from the open end of the track (the last piece in ride order)
+      const lastPiece = active.track[active.track.length - 1];
+      return {
+        ...prev,
+        buildingCoasterId: active.id,
+        buildingCoasterPath: [...active.trackTiles],
+        buildingCoasterHeight: lastPiece?.endHeight ?? 0,
+        buildingCoasterLastDirection: lastPiece ? getExitDirection(lastPiece) : null,
+        buildingCoasterType: active.type,
+      };
+    });
+    return true;
+  }, []);
+  
+  // Set the type for the next station and track without starting a new coaster.
+  // Unknown names are rejected and the current type is kept.
+  const setCoasterType = useCallback((coasterType: string): boolean => {
+    if (!isCoasterType(coasterType)) return false;
+    setState(prev => ({ ...prev, buildingCoasterType: coasterType }));
+    return true;
+  }, []);
+  
+  const renameCoaster = useCallback((coasterId: string, name: string): boolean => {
+    const trimmed = name.trim().slice(0, MAX_COASTER_NAME_LENGTH);
+    if (!trimmed) return false;
+    if (!latestStateRef.current.coasters.some(c => c.id === coasterId)) return false;
+    
+    setState(prev => ({
+      ...prev,
+      coasters: prev.coasters.map(c => c.id === coasterId ? { ...c, name: trimmed } : c),
+    }));
+    return true;
+  }, []);
+  
+  // Remove a coaster's entire track at once, refunding part of what it cost
+  const demolishCoaster = useCallback((coasterId: string): boolean => {
+    if (!latestStateRef.current.coasters.some(c => c.id === coasterId)) return false;
+    setState(prev => removeCoaster(prev, coasterId));
+    return true;
+  }, []);
+  
+  // Repaint a coaster's track, trains and supports; malformed colors are rejected outright
+  const setCoasterColors = useCallback((coasterId: string, primary: string, secondary: string, supports: string): boolean => {
+    if (![primary, secondary, supports].every(isHexColor)) return false;
+    if (!latestStateRef.current.coasters.some(c => c.id === coasterId)) return false;
+    
+    const color = { primary: normalizeHexColor(primary), secondary: normalizeHexColor(secondary), supports: normalizeHexColor(supports) };
+    setState(prev => ({
+      ...prev,
+      coasters: prev.coasters.map(c => c.id === coasterId ? { ...c, color } : c),
+    }));
+    return true;
+  }, []);
+  
+  const getCoasterState = useCallback((coasterId: string): CoasterOperatingState | null => {
+    const coaster = latestStateRef.current.coasters.find(c => c.id === coasterId);
+    if (!coaster) return null;
+    return {
+      operating: coaster.operating,
+      underTest: coaster.operating && !isTrackComplete(coaster.trackTiles, coaster.track),
+      broken: coaster.broken,
+      trainCount: coaster.trains.length,
+    };
+  }, []);
+  
+  const getCoasterStats = useCallback((coasterId: string): CoasterRatings | null => {
+    const coaster = latestStateRef.current.coasters.find(c => c.id === coasterId);
+    if (!coaster) return null;
+    return {
+      excitement: coaster.excitement,
+      intensity: coaster.intensity,
+      nausea: coaster.nausea,
+    };
+  }, []);
+  
   // Place a line of track tiles (for drag-to-draw functionality)
   const placeTrackLine = useCallback((tiles: { x: number; y: number }[]) => {
     if (tiles.length === 0) return;
     
     setState(prev => {
+      if (prev.finances.bankrupt) return prev;
+      
       const newGrid = prev.grid.map(row => row.map(tile => ({ ...tile })));
       
       // Determine if the first tile is connected to existing track with buildingCoasterId
@@ -3341,14 +2429,15 @@ export function CoasterProvider({
       // Always normalize train positions when track changes to prevent cars from separating
       let trains: CoasterTrain[];
       if (needsNewTrains) {
-        trains = createTrainsForCoaster(trackPieces.length, coasterBase.type);
+        trains = createTrainsForCoaster(trackPieces.length, coasterBase.type, coasterBase.trainConfig);
       } else if (oldTrackLength !== trackPieces.length) {
         // Track changed but not enough for new trains - normalize positions
         trains = normalizeTrainsForTrackChange(
           coasterBase.trains,
           oldTrackLength,
           trackPieces.length,
-          effectiveStationIdx
+          effectiveStationIdx,
+          coasterBase.trainConfig?.carSpacing
         );
       } else {
         trains = coasterBase.trains;
@@ -3362,6 +2451,7 @@ export function CoasterProvider({
         stationTileX: stationTile.x,
         stationTileY: stationTile.y,
         trains,
+        ...rateCoasterTrack(trackTiles, trackPieces, coasterBase.type),
       };
       
       // Remove absorbed coasters (those whose tracks were merged into this one)
@@ -3394,6 +2484,10 @@ export function CoasterProvider({
     bulldozeCallbackRef.current = callback;
   }, []);
 
+  const setBulldozeAreaCallback = useCallback((callback: ((args: { start: { x: number; y: number }; end: { x: number; y: number } }) => void) | null) => {
+    bulldozeAreaCallbackRef.current = callback;
+  }, []);
+
   const setCoasterBuildCallback = useCallback((callback: ((args: { coasterType: CoasterType; coasterId: string }) => void) | null) => {
     coasterBuildCallbackRef.current = callback;
   }, []);
@@ -3424,6 +2518,43 @@ export function CoasterProvider({
     }
   }, []);
   
+  // Local-only: each player autosaves to their own storage, so this isn't synced
+  const setAutosaveInterval = useCallback((hours: number) => {
+    setState(prev => ({
+      ...prev,
+      settings: { ...prev.settings, autosaveIntervalHours: Math.max(0, Math.floor(hours)) },
+    }));
+  }, []);
+  
+  // Opening hours as whole hours, 0-24; the park must open before it closes
+  const setParkHours = useCallback((openHour: number, closeHour: number): boolean => {
+    if (!Number.isInteger(openHour) || !Number.isInteger(closeHour)) return false;
+    if (openHour < 0 || closeHour > 24 || openHour >= closeHour) return false;
+    setState(prev => ({ ...prev, settings: { ...prev.settings, openHour, closeHour } }));
+    return true;
+  }, []);
+  
+  const isParkOpen = useCallback((): boolean => {
+    const { hour, settings } = latestStateRef.current;
+    return isParkOpenAt(hour, settings.openHour, settings.closeHour);
+  }, []);
+  
+  // Cap on guests in the park; guests already inside stay when it's lowered
+  const setMaxGuests = useCallback((maxGuests: number) => {
+    setState(prev => ({
+      ...prev,
+      settings: { ...prev.settings, maxGuests: Math.max(0, Math.floor(maxGuests)) },
+    }));
+  }, []);
+  
+  const setSpawnMultiplier = useCallback((multiplier: number) => {
+    if (!Number.isFinite(multiplier)) return;
+    setState(prev => ({
+      ...prev,
+      settings: { ...prev.settings, spawnRateMultiplier: Math.max(0, Math.min(MAX_SPAWN_RATE_MULTIPLIER, multiplier)) },
+    }));
+  }, []);
+  
   const addMoney = useCallback((amount: number) => {
     setState(prev => ({
       ...prev,
@@ -3453,6 +2584,388 @@ export function CoasterProvider({
     }));
   }, []);
   
+  const sampleGuestSurveyAction = useCallback((sampleSize: number): GuestSurvey => {
+    const current = latestStateRef.current;
+    return sampleGuestSurvey(current.guests, current.grid, sampleSize);
+  }, []);
+  
+  // Queue runs that don't connect a path to a ride (guests can't use them)
+  const getBrokenQueues = useCallback((): BrokenQueue[] => {
+    const current = latestStateRef.current;
+    return findBrokenQueues(current.grid, current.gridSize);
+  }, []);
+  
+  // Tiles where guests are bunching up (more than `threshold` guests on one tile)
+  const getCrowdHotspots = useCallback((threshold: number): CrowdHotspot[] => {
+    const current = latestStateRef.current;
+    return findCrowdHotspots(current.guests, current.gridSize, threshold);
+  }, []);
+
+  const getThemeZones = useCallback((): ThemeZone[] => {
+    const current = latestStateRef.current;
+    return current.themeZones ?? findThemeZones(current.grid, current.gridSize);
+  }, []);
+
+  // Whether a lamp lights the tile (coverage is cached until the park is edited)
+  const getLightCoverageAt = useCallback((x: number, y: number): boolean => {
+    return isTileLit(latestStateRef.current.grid, x, y);
+  }, []);
+
+  // Row-major byte grid of lit tiles (1 = lit) for an overlay
+  const getLightCoverageOverlay = useCallback((): Uint8Array => {
+    return getLightCoverage(latestStateRef.current.grid).slice();
+  }, []);
+
+  const getRideStats = useCallback((rideId: string): RideStats => {
+    return { ...createRideStats(), ...latestStateRef.current.rideStats?.[rideId] };
+  }, []);
+
+  // Line length and riders served for the ride at (x, y); null if there's no ride there
+  const getRideInfo = useCallback((x: number, y: number): RideInfo | null => {
+    const current = latestStateRef.current;
+    const rideId = `${x},${y}`;
+    const building = getRideBuilding(current.grid, rideId);
+    if (!building || !isRideBuilding(building.type)) return null;
+    const stats = { ...createRideStats(), ...current.rideStats?.[rideId] };
+    const capacity = getRideCapacity(building);
+    // Flat rides board a full batch every cycle, so the back of the line waits for
+    // the current cycle plus one more for each batch ahead of it
+    const isStation = building.type.startsWith('station_');
+    const batchesAhead = Math.floor(stats.queue.length / capacity);
+    return {
+      rideId,
+      queueLength: stats.queue.length,
+      maxQueueLength: getMaxQueueLength(building),
+      ridersServed: stats.ridersServed,
+      balks: stats.balks,
+      capacity,
+      waitTime: isStation ? null : Math.max(0, stats.dispatchTimer) + batchesAhead * RIDE_CYCLE_TIME,
+      price: building.price,
+      fairPrice: getRideFairPrice(current.grid, rideId, current.coasters),
+    };
+  }, []);
+
+  // Set the ticket price of the ride covering (x, y); charged when pay-per-ride is on
+  const setRidePrice = useCallback((x: number, y: number, price: number) => {
+    setState(prev => {
+      const origin = findBuildingOrigin(prev.grid, x, y);
+      if (!origin) return prev;
+      const building = prev.grid[origin.y][origin.x].building;
+      if (!isRideBuilding(building.type)) return prev;
+      const nextPrice = Math.max(0, Math.min(MAX_RIDE_PRICE, Math.round(price)));
+      if (nextPrice === building.price) return prev;
+      
+      const newGrid = prev.grid.map((row, rowY) => rowY !== origin.y ? row : row.map((tile, tileX) =>
+        tileX !== origin.x ? tile : { ...tile, building: { ...tile.building, price: nextPrice } }
+      ));
+      return { ...prev, grid: newGrid };
+    });
+  }, []);
+
+  // Hired staff, optionally of one type (janitors are 'handyman')
+  const getStaffCount = useCallback((type?: StaffType): number => {
+    const { staff } = latestStateRef.current;
+    return type ? staff.filter(member => member.type === type).length : staff.length;
+  }, []);
+
+  const isBankrupt = useCallback((): boolean => latestStateRef.current.finances.bankrupt, []);
+
+  // Borrow cash up to the loan ceiling; the monthly payment is re-amortized over a fresh term
+  const takeLoan = useCallback((amount: number): boolean => {
+    const current = latestStateRef.current;
+    if (amount <= 0) return false;
+    if (current.finances.loan + amount > LOAN_CEILING) {
+      setState(prev => pushNotification(
+        prev,
+        'Loan Limit Reached',
+        `The bank won't lend more than $${LOAN_CEILING.toLocaleString()} in total.`,
+        'money'
+      ));
+      return false;
+    }
+    
+    setState(prev => {
+      const loan = prev.finances.loan + amount;
+      // Another loan may have landed since the check above
+      if (loan > LOAN_CEILING) return prev;
+      return {
+        ...prev,
+        finances: {
+          ...prev.finances,
+          cash: prev.finances.cash + amount,
+          loan,
+          loanMonthlyPayment: calculateLoanPayment(loan, prev.settings.loanInterest),
+        },
+      };
+    });
+    return true;
+  }, []);
+
+  // Pay down the loan early from available cash
+  const repayLoan = useCallback((amount: number): boolean => {
+    const current = latestStateRef.current;
+    const payment = Math.min(amount, current.finances.loan, Math.max(0, current.finances.cash));
+    if (payment <= 0) return false;
+    
+    setState(prev => {
+      const paid = Math.min(payment, prev.finances.loan, Math.max(0, prev.finances.cash));
+      const loan = prev.finances.loan - paid;
+      return {
+        ...prev,
+        finances: {
+          ...prev.finances,
+          cash: prev.finances.cash - paid,
+          loan,
+          // Keep the same payment so early repayment shortens the remaining term
+          loanMonthlyPayment: loan > 0 ? prev.finances.loanMonthlyPayment : 0,
+        },
+      };
+    });
+    return true;
+  }, []);
+
+  const getDebt = useCallback((): LoanSummary => {
+    const { finances, settings } = latestStateRef.current;
+    return {
+      principal: finances.loan,
+      interestRate: settings.loanInterest,
+      monthlyPayment: finances.loanMonthlyPayment,
+      ceiling: LOAN_CEILING,
+    };
+  }, []);
+
+  // Newest guest thoughts first
+  const getRecentThoughts = useCallback((limit: number = 10): RecentThought[] => {
+    const thoughts = latestStateRef.current.recentThoughts ?? [];
+    return limit > 0 ? thoughts.slice(-limit).reverse() : [];
+  }, []);
+
+  // What the park rating is made of, so players can see what's holding it back
+  const getRatingBreakdown = useCallback((): RatingBreakdown => {
+    const { stats } = latestStateRef.current;
+    return stats.ratingBreakdown ?? {
+      happiness: 0, guests: 0, variety: 0, connectivity: 0, cleanliness: 0, total: stats.parkRating,
+    };
+  }, []);
+  
+  const getGuestIds = useCallback((): string[] => {
+    return latestStateRef.current.guests.map(guest => guest.id);
+  }, []);
+
+  // One call describing everything on a tile (null off the map)
+  const inspectTile = useCallback((x: number, y: number): TileInspection | null => {
+    const { grid, coasters, litter } = latestStateRef.current;
+    const tile = grid[y]?.[x];
+    if (!tile) return null;
+    
+    const origin = findBuildingOrigin(grid, x, y);
+    const buildingType = origin ? grid[origin.y][origin.x].building.type : tile.building.type;
+    const coaster = tile.coasterTrackId ? coasters.find(c => c.id === tile.coasterTrackId) : undefined;
+    // Walkable tiles are checked directly; buildings through the path or queue guests use them from
+    const access = tile.path || tile.queue ? { x, y } : getBuildingAccessTile(grid, origin?.x ?? x, origin?.y ?? y);
+    return {
+      x,
+      y,
+      terrain: tile.terrain,
+      elevation: tile.elevation ?? 0,
+      path: tile.path,
+      queue: tile.queue,
+      queueRideId: tile.queueRideId,
+      buildingType,
+      buildingOrigin: origin,
+      buildingCost: getBuildingCost(buildingType),
+      hasCoasterTrack: tile.hasCoasterTrack,
+      coasterId: tile.coasterTrackId,
+      coasterName: coaster?.name ?? null,
+      trackPieceType: tile.trackPiece?.type ?? null,
+      litter: litter?.[`${x},${y}`] ?? 0,
+      reachableFromEntrance: access !== null && isReachableFromEntrance(grid, access.x, access.y),
+    };
+  }, []);
+  
+  // What a track tool would lay at (x, y) - heights and validity - without building it
+  const previewTrackPiece = useCallback((x: number, y: number, tool: Tool): TrackPlacementPreview => {
+    const state = latestStateRef.current;
+    const reason = TRACK_TOOLS.includes(tool)
+      ? getTrackPlacementRejection(state, x, y, tool)
+      : 'Not a track tool';
+    if (reason) {
+      return { valid: false, reason, pieceType: null, direction: null, startHeight: null, endHeight: null };
+    }
+    const plan = planTrackPiece(state, x, y, tool);
+    return {
+      valid: true,
+      reason: null,
+      pieceType: plan.pieceType,
+      direction: plan.startDirection,
+      startHeight: plan.startHeight,
+      endHeight: plan.endHeight,
+    };
+  }, []);
+  
+  // Needs, money and destination of one guest (null once they've left the park)
+  const getGuestInfo = useCallback((id: string): GuestInfo | null => {
+    const guest = latestStateRef.current.guests.find(g => g.id === id);
+    if (!guest) return null;
+    const hasTarget = guest.targetBuildingKind !== null || guest.targetBuildingId !== null;
+    return {
+      id: guest.id,
+      name: guest.name,
+      tileX: guest.tileX,
+      tileY: guest.tileY,
+      state: guest.state,
+      hunger: guest.hunger,
+      thirst: guest.thirst,
+      energy: guest.energy,
+      happiness: guest.happiness,
+      nausea: guest.nausea,
+      cash: guest.cash,
+      totalSpent: guest.totalSpent,
+      target: hasTarget ? {
+        kind: guest.targetBuildingKind,
+        buildingId: guest.targetBuildingId,
+        x: guest.targetTileX,
+        y: guest.targetTileY,
+      } : null,
+    };
+  }, []);
+  
+  // Id of the guest drawn nearest a point in unzoomed screen space (camera offset removed)
+  const pickGuestAt = useCallback((screenX: number, screenY: number): string | null => {
+    const { guests, grid } = latestStateRef.current;
+    return findGuestAt(guests, grid, screenX, screenY)?.id ?? null;
+  }, []);
+  
+  // Everything an analytics panel needs in one pass over the guests and one over the grid
+  const getStatsSnapshot = useCallback((): ParkStatsSnapshot => {
+    const { guests, grid, coasters, finances, stats } = latestStateRef.current;
+    const guestsByState: Record<GuestState, number> = {
+      entering: 0, walking: 0, queuing: 0, riding: 0, exiting_ride: 0, shopping: 0,
+      eating: 0, exiting_building: 0, sitting: 0, watching: 0, leaving: 0, lost: 0,
+    };
+    let happiness = 0;
+    let hunger = 0;
+    let thirst = 0;
+    let nauseousGuests = 0;
+    const groups = new Set<string>();
+    let soloGuests = 0;
+    for (const guest of guests) {
+      if (guest.groupId) groups.add(guest.groupId);
+      else soloGuests++;
+      guestsByState[guest.state]++;
+      happiness += guest.happiness;
+      hunger += guest.hunger;
+      thirst += guest.thirst;
+      if (isGuestNauseous(guest)) nauseousGuests++;
+    }
+    
+    // Coasters are counted once each rather than by their station buildings
+    let rides = coasters.length;
+    let foodStalls = 0;
+    for (const row of grid) {
+      for (const tile of row) {
+        const type = tile.building.type;
+        if (type.endsWith('_footprint')) continue;
+        if (isRideBuilding(type) && !type.startsWith('station_')) rides++;
+        else if (isFoodBuilding(type)) foodStalls++;
+      }
+    }
+    
+    const count = guests.length;
+    return {
+      guests: count,
+      guestsByState,
+      averageHappiness: count > 0 ? happiness / count : 0,
+      averageHunger: count > 0 ? hunger / count : 0,
+      averageThirst: count > 0 ? thirst / count : 0,
+      nauseousGuests,
+      averageGroupSize: count > 0 ? count / (soloGuests + groups.size) : 0,
+      rides,
+      foodStalls,
+      cash: finances.cash,
+      debt: finances.loan,
+      rating: stats.parkRating,
+    };
+  }, []);
+  
+  // Hand over everything that happened since the last call and clear the queue
+  const drainEvents = useCallback((): GameEvent[] => {
+    const drained = latestStateRef.current.events ?? [];
+    if (drained.length === 0) return [];
+    // Drop exactly what was handed over, in case another tick queued more in the meantime
+    const handedOver = new Set(drained);
+    setState(prev => ({ ...prev, events: (prev.events ?? []).filter(event => !handedOver.has(event)) }));
+    return drained;
+  }, []);
+  
+  const getWeather = useCallback((): WeatherType => {
+    return latestStateRef.current.weather.current;
+  }, []);
+
+  // Breakdown of the last completed month (the full year is in finances.reports)
+  const getMonthlyReport = useCallback((): MonthlyReport | null => {
+    const { reports } = latestStateRef.current.finances;
+    return reports[reports.length - 1] ?? null;
+  }, []);
+  
+  // What the park currently costs to run each month, charged when the month rolls over
+  const getMonthlyUpkeep = useCallback((): number => {
+    return calculateMonthlyUpkeep(latestStateRef.current.grid).upkeep;
+  }, []);
+  
+  // Stalls at or below the low-stock mark, emptiest first
+  const getLowStockStalls = useCallback((): LowStockStall[] => {
+    const { stallStock, grid } = latestStateRef.current;
+    return Object.entries(stallStock ?? {})
+      .filter(([, stock]) => stock <= STALL_LOW_STOCK)
+      .map(([id, stock]) => {
+        const [x, y] = id.split(',').map(Number);
+        return { id, x, y, type: grid[y]?.[x]?.building?.type ?? 'empty', stock };
+      })
+      .filter(stall => isStockedBuilding(stall.type))
+      .sort((a, b) => a.stock - b.stock);
+  }, []);
+  
+  // Profiling: run ticks on a throwaway copy of the park (nothing is drawn or
+  // committed) and report how many milliseconds they took in total
+  const benchmarkTicks = useCallback((ticks: number): number => {
+    const count = Math.max(0, Math.floor(ticks));
+    const start = performance.now();
+    runSimulationTicks(latestStateRef.current, count);
+    return performance.now() - start;
+  }, []);
+  
+  const getTickBreakdown = useCallback((): TickBreakdown => getLastTickBreakdown(), []);
+
+  // Camera bookmarks (the viewport lives in the grid component, so callers pass it in)
+  const saveViewpoint = useCallback((slot: number, camera: { offset: { x: number; y: number }; zoom: number }): boolean => {
+    if (!Number.isInteger(slot) || slot < 1 || slot > VIEWPOINT_SLOTS) return false;
+    const viewpoint: CameraViewpoint = { slot, offsetX: camera.offset.x, offsetY: camera.offset.y, zoom: camera.zoom };
+    setState(prev => ({
+      ...prev,
+      viewpoints: [...(prev.viewpoints ?? []).filter(v => v.slot !== slot), viewpoint].sort((a, b) => a.slot - b.slot),
+    }));
+    return true;
+  }, []);
+
+  const getViewpoint = useCallback((slot: number): CameraViewpoint | null => {
+    return latestStateRef.current.viewpoints?.find(v => v.slot === slot) ?? null;
+  }, []);
+
+  const listViewpoints = useCallback((): CameraViewpoint[] => latestStateRef.current.viewpoints ?? [], []);
+
+  // Cycle the facing used for the next building placed
+  const rotatePlacement = useCallback((): BuildingOrientation => {
+    const orientation = rotateOrientation(latestStateRef.current.placementOrientation ?? 0);
+    setState(prev => ({ ...prev, placementOrientation: orientation }));
+    return orientation;
+  }, []);
+
+  // Forget every cached guest route (routes are otherwise kept until nearby paths change)
+  const clearPathCache = useCallback(() => {
+    clearGuestPathCache();
+  }, []);
+  
   const saveGame = useCallback(() => {
     // Use async worker-based save to avoid blocking main thread
     persistCoasterSaveAsync(latestStateRef.current)
@@ -3491,34 +3004,88 @@ export function CoasterProvider({
     return false;
   }, [persistCoasterSave]);
   
-  const newGame = useCallback((name?: string) => {
-    setState(createInitialCoasterGameState(name));
+  // Pass a seed to start a park others can reproduce
+  const newGame = useCallback((name?: string, seed?: number) => {
+    setState(createInitialCoasterGameState(name, DEFAULT_GRID_SIZE, seed));
     setHasSavedGame(false);
   }, []);
   
+  // Rebuild the park in place (fresh terrain, lakes, cash and clock) keeping the park name.
+  // Sprites live in the canvas, so nothing needs to be reloaded.
+  const resetPark = useCallback((gridSize?: number) => {
+    setState(prev => ({
+      ...createInitialCoasterGameState(prev.settings.name, gridSize ?? prev.gridSize),
+      speed: prev.speed,
+    }));
+  }, []);
+  
+  // Remove every building, path, queue and coaster but keep the terrain
+  const clearAllBuildings = useCallback(() => {
+    setState(prev => ({
+      ...prev,
+      grid: prev.grid.map(row => row.map(tile => {
+        const cleared = createEmptyTile(tile.x, tile.y);
+        cleared.terrain = tile.terrain;
+        cleared.elevation = tile.elevation;
+        if (tile.terrain === 'water') {
+          cleared.building = { ...createEmptyBuilding(), type: 'water' };
+        }
+        // Keep the way in: edge paths are where guests arrive, and the entrance gate stays with them
+        const onEdge = tile.x === 0 || tile.y === 0 || tile.x === prev.gridSize - 1 || tile.y === prev.gridSize - 1;
+        if (onEdge && tile.path) {
+          cleared.path = true;
+          cleared.building = { ...createEmptyBuilding(), type: 'path' };
+        } else if (tile.building.type.startsWith('infra_main_entrance')) {
+          cleared.building = { ...tile.building };
+        }
+        return cleared;
+      })),
+      // Back to opening time, as in a new park
+      hour: 8,
+      minute: 0,
+      guests: [],
+      staff: [],
+      coasters: [],
+      themeZones: [],
+      rideStats: {},
+      litter: {},
+      recentThoughts: [],
+      stats: { ...prev.stats, guestsInPark: 0, totalRides: 0 },
+      buildingCoasterId: null,
+      buildingCoasterPath: [],
+      buildingCoasterHeight: 0,
+      buildingCoasterLastDirection: null,
+      buildingCoasterType: null,
+    }));
+  }, []);
+  
   const exportState = useCallback((): string => {
-    return JSON.stringify(latestStateRef.current);
+    return serializeCoasterState(latestStateRef.current);
   }, []);
   
   const loadState = useCallback((stateString: string): boolean => {
+    const result = parseCoasterState(stateString);
+    if (!result.ok) {
+      console.error('Failed to load state from string:', result.error);
+      setState(prev => pushNotification(prev, 'Could Not Load Park', result.error, 'error'));
+      return false;
+    }
+    
     try {
-      const parsed = JSON.parse(stateString);
-      if (parsed && parsed.grid && parsed.gridSize) {
-        const normalizedState = normalizeLoadedState(parsed);
-        // Fix any disconnected tracks that share the same coasterTrackId
-        const { grid: fixedGrid, coasters: fixedCoasters } = ensureAllTracksHaveCoasters(
-          normalizedState.grid,
-          normalizedState.coasters
-        );
-        const finalState = {
-          ...normalizedState,
-          grid: fixedGrid,
-          coasters: fixedCoasters,
-        };
-        setState(finalState);
-        persistCoasterSave(finalState);
-        return true;
-      }
+      const normalizedState = normalizeLoadedState(result.state);
+      // Fix any disconnected tracks that share the same coasterTrackId
+      const { grid: fixedGrid, coasters: fixedCoasters } = ensureAllTracksHaveCoasters(
+        normalizedState.grid,
+        normalizedState.coasters
+      );
+      const finalState = {
+        ...normalizedState,
+        grid: fixedGrid,
+        coasters: fixedCoasters,
+      };
+      setState(finalState);
+      persistCoasterSave(finalState);
+      return true;
     } catch (e) {
       console.error('Failed to load state from string:', e);
     }
@@ -3536,11 +3103,22 @@ export function CoasterProvider({
     setTool,
     setSpeed,
     setActivePanel,
+    setOverlayMode,
+    setOverlayPalette,
+    setSpriteLayout,
+    getSpriteLayout,
+    setDebugGrid,
+    getGuestDensityOverlay,
     
     placeAtTile,
     bulldozeTile,
+    bulldozeArea,
+    getBulldozeAreaCost,
     setPlaceCallback,
     setBulldozeCallback,
+    setBulldozeAreaCallback,
+    placePathRoute,
+    placeLine,
     
     startCoasterBuild,
     addCoasterTrack,
@@ -3550,23 +3128,78 @@ export function CoasterProvider({
     setCoasterBuildFinishCallback,
     setCoasterBuildCancelCallback,
     placeTrackLine,
+    setCoasterTrains,
+    setDispatchPolicy,
+    setCoasterOperating,
+    getCoasterState,
+    getActiveCoasterErrors,
+    getCoasterList,
+    setActiveCoaster,
+    setCoasterType,
+    renameCoaster,
+    demolishCoaster,
+    setCoasterColors,
+    getCoasterStats,
 
     setParkSettings,
     addMoney,
     clearGuests,
     addNotification,
+    sampleGuestSurvey: sampleGuestSurveyAction,
+    getBrokenQueues,
+    getCrowdHotspots,
+    getThemeZones,
+    getLightCoverage: getLightCoverageAt,
+    getLightCoverageOverlay,
+    getRideStats,
+    getRideInfo,
+    setRidePrice,
+    getStaffCount,
+    isBankrupt,
+    takeLoan,
+    repayLoan,
+    getMonthlyReport,
+    getMonthlyUpkeep,
+    getLowStockStalls,
+    benchmarkTicks,
+    getTickBreakdown,
+    getRecentThoughts,
+    getWeather,
+    getRatingBreakdown,
+    getGuestIds,
+    getGuestInfo,
+    pickGuestAt,
+    inspectTile,
+    previewTrackPiece,
+    getStatsSnapshot,
+    drainEvents,
+    getDebt,
+    setAutosaveInterval,
+    setMaxGuests,
+    setParkHours,
+    isParkOpen,
+    setSpawnMultiplier,
+    saveViewpoint,
+    getViewpoint,
+    listViewpoints,
+    rotatePlacement,
+    clearPathCache,
     setParkSettingsCallback,
     setSpeedCallback,
     
     saveGame,
     loadGame,
     newGame,
+    resetPark,
+    clearAllBuildings,
     hasSavedGame,
     
     exportState,
     loadState,
     
     isStateReady,
+    spriteLayoutVersion,
+    debugGrid,
   };
   
   return (
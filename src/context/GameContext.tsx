@@ -4,6 +4,7 @@
 import React, { createContext, useCallback, useContext, useEffect, useState, useRef } from 'react';
 import { compressToUTF16, decompressFromUTF16 } from 'lz-string';
 import { serializeAndCompressAsync } from '@/lib/saveWorkerManager';
+import { ColorblindMode, isColorblindMode, setActiveColorblindMode } from '@/lib/colorPalettes';
 import { simulateTick } from '@/lib/simulation';
 import {
   Budget,
@@ -43,6 +44,7 @@ const SAVED_CITIES_INDEX_KEY = 'isocity-saved-cities-index'; // Index of all sav
 const SAVED_CITY_PREFIX = 'isocity-city-'; // Prefix for individual saved city states
 const SPRITE_PACK_STORAGE_KEY = 'isocity-sprite-pack';
 const DAY_NIGHT_MODE_STORAGE_KEY = 'isocity-day-night-mode';
+const COLORBLIND_MODE_STORAGE_KEY = 'isocity-colorblind-mode';
 
 export type DayNightMode = 'auto' | 'day' | 'night';
 
@@ -92,6 +94,9 @@ type GameContextValue = {
   dayNightMode: DayNightMode;
   setDayNightMode: (mode: DayNightMode) => void;
   visualHour: number; // The hour to use for rendering (respects day/night mode override)
+  // Colorblind-friendly palette for overlays and pedestrians
+  colorblindMode: ColorblindMode;
+  setColorblindMode: (mode: ColorblindMode) => void;
   // Save/restore city for shared links
   saveCurrentCityForRestore: () => void;
   restoreSavedCity: () => boolean;
@@ -430,6 +435,30 @@ function saveSpritePackId(packId: string): void {
   }
 }
 
+// Load colorblind mode from localStorage
+function loadColorblindMode(): ColorblindMode {
+  if (typeof window === 'undefined') return 'none';
+  try {
+    const saved = localStorage.getItem(COLORBLIND_MODE_STORAGE_KEY);
+    if (isColorblindMode(saved)) {
+      return saved;
+    }
+  } catch (e) {
+    console.error('Failed to load colorblind mode preference:', e);
+  }
+  return 'none';
+}
+
+// Save colorblind mode to localStorage
+function saveColorblindMode(mode: ColorblindMode): void {
+  if (typeof window === 'undefined') return;
+  try {
+    localStorage.setItem(COLORBLIND_MODE_STORAGE_KEY, mode);
+  } catch (e) {
+    console.error('Failed to save colorblind mode preference:', e);
+  }
+}
+
 // Load day/night mode from localStorage
 function loadDayNightMode(): DayNightMode {
   if (typeof window === 'undefined') return 'auto';
@@ -666,6 +695,9 @@ export function GameProvider({ children, startFresh = false }: { children: React
   // Day/night mode state
   const [dayNightMode, setDayNightModeState] = useState<DayNightMode>('auto');
   
+  // Colorblind mode state
+  const [colorblindMode, setColorblindModeState] = useState<ColorblindMode>('none');
+  
   // Saved cities state for multi-city save system
   const [savedCities, setSavedCities] = useState<SavedCityMeta[]>([]);
   
@@ -681,6 +713,11 @@ export function GameProvider({ children, startFresh = false }: { children: React
     const savedDayNightMode = loadDayNightMode();
     setDayNightModeState(savedDayNightMode);
     
+    // Load colorblind mode preference
+    const savedColorblindMode = loadColorblindMode();
+    setColorblindModeState(savedColorblindMode);
+    setActiveColorblindMode(savedColorblindMode);
+    
     // Load saved cities index
     const cities = loadSavedCitiesIndex();
     setSavedCities(cities);
@@ -1117,6 +1154,12 @@ export function GameProvider({ children, startFresh = false }: { children: React
     saveDayNightMode(mode);
   }, []);
 
+  const setColorblindMode = useCallback((mode: ColorblindMode) => {
+    setColorblindModeState(mode);
+    setActiveColorblindMode(mode);
+    saveColorblindMode(mode);
+  }, []);
+
   // Compute the visual hour based on the day/night mode override
   // This doesn't affect time progression, just the rendering
   const visualHour = dayNightMode === 'auto' 
@@ -1664,6 +1707,9 @@ export function GameProvider({ children, startFresh = false }: { children: React
     dayNightMode,
     setDayNightMode,
     visualHour,
+    // Colorblind palette
+    colorblindMode,
+    setColorblindMode,
     // Save/restore city for shared links
     saveCurrentCityForRestore,
     restoreSavedCity,
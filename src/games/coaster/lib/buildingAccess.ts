@@ -0,0 +1,112 @@
+/**
+ * IsoCoaster Building Access
+ * Which walkable tile guests use to reach a building
+ */
+
+import { Tile } from '../types';
+
+const ACCESS_NEIGHBORS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+/**
+ * Find the walkable tile a guest should stand on to use the building at (x, y).
+ * Only edge-adjacent tiles count (a path touching a corner diagonally gives no access).
+ * Paths are preferred over queues by default; rides pass 'queue' so guests line up.
+ * Returns null if no path or queue touches the building tile.
+ */
+export function getBuildingAccessTile(
+  grid: Tile[][],
+  x: number,
+  y: number,
+  prefer: 'path' | 'queue' = 'path'
+): { x: number; y: number } | null {
+  const gridSize = grid.length;
+  let pathTile: { x: number; y: number } | null = null;
+  let queueTile: { x: number; y: number } | null = null;
+
+  for (const { dx, dy } of ACCESS_NEIGHBORS) {
+    const nx = x + dx;
+    const ny = y + dy;
+    if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+    const tile = grid[ny][nx];
+    if (tile.queue && !queueTile) queueTile = { x: nx, y: ny };
+    if (tile.path && !pathTile) pathTile = { x: nx, y: ny };
+  }
+
+  return prefer === 'queue' ? (queueTile ?? pathTile) : (pathTile ?? queueTile);
+}
+
+/**
+ * Whether guests can reach the building at (x, y) at all
+ */
+export function isBuildingAccessible(grid: Tile[][], x: number, y: number): boolean {
+  return getBuildingAccessTile(grid, x, y) !== null;
+}
+
+// Walkable tiles reachable from the park entrance, cached per grid. Every park
+// edit produces a new grid, so path changes invalidate the cache on their own.
+let entranceReachGrid: Tile[][] | null = null;
+let entranceReach: Uint8Array | null = null;
+
+/**
+ * Flood fill over path and queue tiles from every entrance (a path on the map edge)
+ */
+function getEntranceReach(grid: Tile[][]): Uint8Array {
+  if (grid === entranceReachGrid && entranceReach) return entranceReach;
+
+  const gridSize = grid.length;
+  const reached = new Uint8Array(gridSize * gridSize);
+  const queue: number[] = [];
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      const onEdge = x === 0 || y === 0 || x === gridSize - 1 || y === gridSize - 1;
+      if (onEdge && grid[y][x].path) {
+        reached[y * gridSize + x] = 1;
+        queue.push(y * gridSize + x);
+      }
+    }
+  }
+
+  for (let head = 0; head < queue.length; head++) {
+    const cx = queue[head] % gridSize;
+    const cy = Math.floor(queue[head] / gridSize);
+    for (const { dx, dy } of ACCESS_NEIGHBORS) {
+      const nx = cx + dx;
+      const ny = cy + dy;
+      if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+      const neighbor = ny * gridSize + nx;
+      if (reached[neighbor]) continue;
+      const tile = grid[ny][nx];
+      if (!tile.path && !tile.queue) continue;
+      reached[neighbor] = 1;
+      queue.push(neighbor);
+    }
+  }
+
+  entranceReachGrid = grid;
+  entranceReach = reached;
+  return reached;
+}
+
+/**
+ * Whether guests entering the park can walk to (x, y). Walkable tiles must be
+ * joined to an entrance; buildings need an access tile that is.
+ */
+export function isReachableFromEntrance(grid: Tile[][], x: number, y: number): boolean {
+  const gridSize = grid.length;
+  const tile = grid[y]?.[x];
+  if (!tile) return false;
+  const reached = getEntranceReach(grid);
+  if (tile.path || tile.queue) return reached[y * gridSize + x] === 1;
+
+  return ACCESS_NEIGHBORS.some(({ dx, dy }) => {
+    const nx = x + dx;
+    const ny = y + dy;
+    if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) return false;
+    return reached[ny * gridSize + nx] === 1;
+  });
+}
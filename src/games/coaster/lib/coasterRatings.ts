@@ -0,0 +1,135 @@
+/**
+ * IsoCoaster Coaster Ratings
+ * Derives excitement, intensity and nausea from a coaster's track layout
+ */
+
+import { TrackPiece, TrackPieceType, CoasterType, COASTER_TYPE_STATS } from '../types';
+
+// =============================================================================
+// TYPES
+// =============================================================================
+
+export interface CoasterRatings {
+  excitement: number;
+  intensity: number;
+  nausea: number;
+}
+
+/** Layout measurements the ratings are built from (useful for breakdowns) */
+export interface CoasterTrackAnalysis {
+  length: number;
+  inversions: number;
+  drops: number;
+  maxDrop: number;      // Largest continuous height loss
+  maxHeight: number;
+  turns: number;
+  unbankedTurns: number;
+  helixes: number;
+}
+
+const MAX_RATING = 10;
+const NAUSEA_INVERSION_LIMIT = 4; // Inversions beyond this are extra sickening
+
+// =============================================================================
+// ANALYSIS
+// =============================================================================
+
+function isInversion(type: TrackPieceType): boolean {
+  return type.startsWith('loop_') ||
+    type.startsWith('corkscrew_') ||
+    type.startsWith('barrel_roll_') ||
+    type === 'zero_g_roll' ||
+    type === 'cobra_roll' ||
+    type === 'immelmann' ||
+    type === 'dive_loop' ||
+    type === 'heartline_roll';
+}
+
+function isTurn(type: TrackPieceType): boolean {
+  return type.startsWith('turn_');
+}
+
+/**
+ * Walk the track once and measure the features that drive its ratings
+ */
+export function analyzeCoasterTrack(track: TrackPiece[]): CoasterTrackAnalysis {
+  const analysis: CoasterTrackAnalysis = {
+    length: track.length,
+    inversions: 0,
+    drops: 0,
+    maxDrop: 0,
+    maxHeight: 0,
+    turns: 0,
+    unbankedTurns: 0,
+    helixes: 0,
+  };
+
+  let currentDrop = 0;
+  for (const piece of track) {
+    analysis.maxHeight = Math.max(analysis.maxHeight, piece.startHeight, piece.endHeight);
+    if (isInversion(piece.type)) analysis.inversions++;
+    if (piece.type.startsWith('helix_')) analysis.helixes++;
+    if (isTurn(piece.type)) {
+      analysis.turns++;
+      if (piece.bankAngle === 0 && !piece.type.startsWith('turn_banked')) analysis.unbankedTurns++;
+    }
+
+    // A drop is a continuous run of descending pieces
+    const heightChange = piece.endHeight - piece.startHeight;
+    if (heightChange < 0) {
+      if (currentDrop === 0) analysis.drops++;
+      currentDrop -= heightChange;
+      analysis.maxDrop = Math.max(analysis.maxDrop, currentDrop);
+    } else {
+      currentDrop = 0;
+    }
+  }
+
+  return analysis;
+}
+
+// =============================================================================
+// RATINGS
+// =============================================================================
+
+function clampRating(value: number): number {
+  return Math.round(Math.max(0, Math.min(MAX_RATING, value)) * 100) / 100;
+}
+
+/**
+ * Rate a completed coaster. Starts from the coaster type's base stats, then:
+ * - excitement rewards inversions, drops, big drops, helixes and a decent length
+ * - intensity follows inversions, drop height and tight unbanked turns
+ * - nausea follows inversions (extra past a few), unbanked turns and helixes
+ */
+export function calculateCoasterRatings(track: TrackPiece[], coasterType: CoasterType): CoasterRatings {
+  const base = COASTER_TYPE_STATS[coasterType];
+  const a = analyzeCoasterTrack(track);
+
+  const excitement = base.excitement.base +
+    base.excitement.perInversion * a.inversions +
+    base.excitement.perDrop * a.drops +
+    a.maxDrop * 0.15 +
+    a.helixes * 0.2 +
+    Math.min(1, a.length / 40);
+
+  const intensity = base.intensity.base +
+    base.intensity.perInversion * a.inversions +
+    base.intensity.perDrop * a.drops +
+    a.maxDrop * 0.3 +
+    a.unbankedTurns * 0.05;
+
+  const extraInversions = Math.max(0, a.inversions - NAUSEA_INVERSION_LIMIT);
+  const nausea = base.nausea.base +
+    base.nausea.perInversion * a.inversions +
+    base.nausea.perDrop * a.drops * 0.5 +
+    extraInversions * 0.5 +
+    a.unbankedTurns * 0.08 +
+    a.helixes * 0.15;
+
+  return {
+    excitement: clampRating(excitement),
+    intensity: clampRating(intensity),
+    nausea: clampRating(nausea),
+  };
+}
@@ -32,8 +32,13 @@ export interface SpriteMapping {
   cropBottom?: number; // Pixels to crop from bottom of sprite cell
   cropLeft?: number; // Pixels to crop from left of sprite cell
   cropRight?: number; // Pixels to crop from right of sprite cell
+  frameCount?: number; // Animation frames laid out along the row, starting at col
+  frameStride?: number; // Columns between consecutive frames (default 1)
 }
 
+// Game ticks each animation frame stays on screen
+export const SPRITE_FRAME_TICKS = 3;
+
 // =============================================================================
 // SPRITE SHEETS CONFIGURATION
 // =============================================================================
@@ -607,6 +612,106 @@ export const COASTER_SPRITE_PACK: CoasterSpritePack = {
   ],
 };
 
+// =============================================================================
+// SPRITE LAYOUT OVERRIDES
+// =============================================================================
+
+/** Alignment fields that can be tuned at runtime without touching the sheet layout */
+export type SpriteOverride = Pick<SpriteMapping, 'offsetX' | 'offsetY' | 'scale' | 'cropTop' | 'cropBottom' | 'cropLeft' | 'cropRight'>;
+
+const OVERRIDE_FIELDS: (keyof SpriteOverride)[] = ['offsetX', 'offsetY', 'scale', 'cropTop', 'cropBottom', 'cropLeft', 'cropRight'];
+
+export interface SpriteLayoutResult {
+  ok: boolean;
+  error: string | null; // Why the layout was rejected (nothing is applied then)
+  applied: string[]; // Sprites whose overrides were merged
+  ignored: string[]; // Sprite names the sheet doesn't have
+}
+
+// Sheet id -> sprite name -> override, merged onto the defaults by getSpriteInfo
+const spriteOverrides = new Map<string, Map<string, SpriteOverride>>();
+
+/**
+ * Merge per-sprite alignment overrides onto a sheet's defaults. The layout is a
+ * JSON object (or its string form) of sprite name -> { offsetX, offsetY, scale,
+ * cropTop, cropBottom, cropLeft, cropRight }, every field optional. Unknown
+ * sprite names are skipped; a malformed layout is rejected as a whole.
+ */
+export function applySpriteOverrides(
+  sheetId: string,
+  layout: unknown,
+  pack: CoasterSpritePack = COASTER_SPRITE_PACK
+): SpriteLayoutResult {
+  const reject = (error: string): SpriteLayoutResult => ({ ok: false, error, applied: [], ignored: [] });
+  const sheet = pack.sheets.find(s => s.id === sheetId);
+  if (!sheet) return reject(`Unknown sprite sheet "${sheetId}"`);
+
+  let parsed = layout;
+  if (typeof layout === 'string') {
+    try {
+      parsed = JSON.parse(layout);
+    } catch {
+      return reject('Layout is not valid JSON');
+    }
+  }
+  if (!parsed || typeof parsed !== 'object' || Array.isArray(parsed)) {
+    return reject('Layout must be an object of sprite name -> overrides');
+  }
+
+  // Validate everything before applying anything
+  const pending: [string, SpriteOverride][] = [];
+  const ignored: string[] = [];
+  for (const [name, entry] of Object.entries(parsed as Record<string, unknown>)) {
+    if (!entry || typeof entry !== 'object' || Array.isArray(entry)) {
+      return reject(`Overrides for "${name}" must be an object`);
+    }
+    const override: SpriteOverride = {};
+    for (const field of OVERRIDE_FIELDS) {
+      const value = (entry as Record<string, unknown>)[field];
+      if (value === undefined) continue;
+      if (typeof value !== 'number' || !Number.isFinite(value)) {
+        return reject(`"${name}.${field}" must be a number`);
+      }
+      if (field === 'scale' && value <= 0) return reject(`"${name}.scale" must be positive`);
+      override[field] = value;
+    }
+    if (!sheet.sprites.some(sprite => sprite.name === name)) {
+      ignored.push(name);
+      continue;
+    }
+    pending.push([name, override]);
+  }
+
+  const sheetOverrides = spriteOverrides.get(sheetId) ?? new Map<string, SpriteOverride>();
+  for (const [name, override] of pending) {
+    sheetOverrides.set(name, { ...sheetOverrides.get(name), ...override });
+  }
+  spriteOverrides.set(sheetId, sheetOverrides);
+  return { ok: true, error: null, applied: pending.map(([name]) => name), ignored };
+}
+
+/**
+ * A sheet's current alignment for every sprite (defaults with overrides merged),
+ * in the shape applySpriteOverrides accepts; null for an unknown sheet
+ */
+export function exportSpriteLayout(
+  sheetId: string,
+  pack: CoasterSpritePack = COASTER_SPRITE_PACK
+): Record<string, SpriteOverride> | null {
+  const sheet = pack.sheets.find(s => s.id === sheetId);
+  if (!sheet) return null;
+  const layout: Record<string, SpriteOverride> = {};
+  for (const sprite of sheet.sprites) {
+    const merged = { ...sprite, ...spriteOverrides.get(sheetId)?.get(sprite.name) };
+    const entry: SpriteOverride = {};
+    for (const field of OVERRIDE_FIELDS) {
+      if (merged[field] !== undefined) entry[field] = merged[field];
+    }
+    layout[sprite.name] = entry;
+  }
+  return layout;
+}
+
 // =============================================================================
 // SPRITE LOOKUP HELPER
 // =============================================================================
@@ -618,20 +723,33 @@ export function getSpriteInfo(
   for (const sheet of pack.sheets) {
     const sprite = sheet.sprites.find(s => s.name === buildingType);
     if (sprite) {
-      return { sheet, sprite };
+      const override = spriteOverrides.get(sheet.id)?.get(sprite.name);
+      return { sheet, sprite: override ? { ...sprite, ...override } : sprite };
     }
   }
   return null;
 }
 
 /**
- * Get the source rectangle for a sprite in its sheet
+ * Sheet column to draw at `tick`: cycles through the frames of animated
+ * sprites, and is just the sprite's column for everything else
+ */
+export function getSpriteFrameCol(sprite: SpriteMapping, tick: number = 0): number {
+  const frameCount = sprite.frameCount ?? 1;
+  if (frameCount <= 1) return sprite.col;
+  const frame = Math.floor(tick / SPRITE_FRAME_TICKS) % frameCount;
+  return sprite.col + frame * (sprite.frameStride ?? 1);
+}
+
+/**
+ * Get the source rectangle for a sprite in its sheet (at `tick` for animated sprites)
  */
 export function getSpriteRect(
   sheet: SpriteSheet,
   sprite: SpriteMapping,
   sheetWidth: number,
-  sheetHeight: number
+  sheetHeight: number,
+  tick: number = 0
 ): { sx: number; sy: number; sw: number; sh: number } {
   const cellWidth = sheetWidth / sheet.cols;
   const cellHeight = sheetHeight / sheet.rows;
@@ -643,7 +761,7 @@ export function getSpriteRect(
   const cropRight = sprite.cropRight || 0;
 
   return {
-    sx: sprite.col * cellWidth + cropLeft,
+    sx: getSpriteFrameCol(sprite, tick) * cellWidth + cropLeft,
     sy: sprite.row * cellHeight + cropTop,
     sw: cellWidth - cropLeft - cropRight,
     sh: cellHeight - cropTop - cropBottom,
@@ -0,0 +1,67 @@
+/**
+ * IsoCoaster Light Coverage
+ * Which tiles are lit by lamps after dark
+ */
+
+import { Tile, isLampBuilding } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+export const LAMP_LIGHT_RADIUS = 3; // Tiles (Euclidean) a lamp lights around itself
+
+// =============================================================================
+// COVERAGE
+// =============================================================================
+
+// Coverage only changes when the grid does, so keep the last result around
+let coverageGrid: Tile[][] | null = null;
+let coverage: Uint8Array = new Uint8Array(0);
+
+/**
+ * Full night (no daylight at all), matching the lighting overlay's darkest hours
+ */
+export function isNightHour(hour: number): boolean {
+  return hour >= 20 || hour < 5;
+}
+
+/**
+ * Byte grid (row-major, 1 = lit) of tiles within LAMP_LIGHT_RADIUS of a lamp.
+ * Rebuilt only when a new grid comes in (i.e. after the park is edited).
+ */
+export function getLightCoverage(grid: Tile[][]): Uint8Array {
+  if (grid === coverageGrid) return coverage;
+
+  const gridSize = grid.length;
+  const lit = new Uint8Array(gridSize * gridSize);
+  const radiusSq = LAMP_LIGHT_RADIUS * LAMP_LIGHT_RADIUS;
+
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      if (!isLampBuilding(grid[y][x].building?.type)) continue;
+      for (let dy = -LAMP_LIGHT_RADIUS; dy <= LAMP_LIGHT_RADIUS; dy++) {
+        for (let dx = -LAMP_LIGHT_RADIUS; dx <= LAMP_LIGHT_RADIUS; dx++) {
+          if (dx * dx + dy * dy > radiusSq) continue;
+          const lx = x + dx;
+          const ly = y + dy;
+          if (lx < 0 || ly < 0 || lx >= gridSize || ly >= gridSize) continue;
+          lit[ly * gridSize + lx] = 1;
+        }
+      }
+    }
+  }
+
+  coverageGrid = grid;
+  coverage = lit;
+  return lit;
+}
+
+/**
+ * Whether a lamp lights the tile at (x, y)
+ */
+export function isTileLit(grid: Tile[][], x: number, y: number): boolean {
+  const gridSize = grid.length;
+  if (x < 0 || y < 0 || x >= gridSize || y >= gridSize) return false;
+  return getLightCoverage(grid)[y * gridSize + x] === 1;
+}
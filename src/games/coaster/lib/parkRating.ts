@@ -0,0 +1,128 @@
+/**
+ * IsoCoaster Park Rating
+ * Combines guest happiness, crowd size, ride variety, path access and scenery into a 0-1000 score
+ */
+
+import { Coaster, RatingBreakdown, Tile, isPlantBuilding, isRideBuilding } from '../types';
+import { getBuildingAccessTile } from './buildingAccess';
+
+// =============================================================================
+// WEIGHTS
+// =============================================================================
+
+// Maximum points per component; they add up to the 1000-point ceiling
+const HAPPINESS_POINTS = 450;    // Happy guests are still what matters most
+const GUEST_POINTS = 200;
+const VARIETY_POINTS = 150;
+const CONNECTIVITY_POINTS = 150;
+const SCENERY_POINTS = 50;
+
+const FULL_RATING_GUESTS = 400;  // Crowd size that earns every guest point
+const FULL_RATING_RIDE_KINDS = 10; // Distinct rides that earn every variety point
+
+// Scenery is scored per area with diminishing returns, so a carpet of flowers in
+// one corner is worth far less than the same flowers spread around the park
+const SCENERY_AREA_SIZE = 8;       // Decorations are bucketed into 8x8-tile areas
+const SCENERY_AREA_SATURATION = 4; // Decorations that take an area about two-thirds of the way to full credit
+const FULL_RATING_SCENERY_AREAS = 12; // Fully decorated areas that earn every scenery point
+
+// =============================================================================
+// PARK SCAN
+// =============================================================================
+
+interface AttractionStats {
+  rideKinds: Set<string>;
+  attractions: number;
+  reachable: number;
+  decoratedAreas: number; // Sum of each area's saturating scenery credit (0-1 apiece)
+}
+
+// Attractions only change with the grid, so keep the last scan around
+let statsGrid: Tile[][] | null = null;
+let stats: AttractionStats = { rideKinds: new Set(), attractions: 0, reachable: 0, decoratedAreas: 0 };
+
+function isAttraction(type: string): boolean {
+  return isRideBuilding(type) ||
+    type.startsWith('food_') || type.startsWith('drink_') || type.startsWith('snack_') ||
+    type.startsWith('cart_') || type.startsWith('shop_') || type === 'restroom';
+}
+
+function isDecoration(type: string): boolean {
+  return isPlantBuilding(type) || type.startsWith('theme_') || type.startsWith('planter_') ||
+    type.startsWith('fountain_') || type.startsWith('pond_') || type.startsWith('waterfall_');
+}
+
+function getAttractionStats(grid: Tile[][]): AttractionStats {
+  if (grid === statsGrid) return stats;
+
+  const rideKinds = new Set<string>();
+  let attractions = 0;
+  let reachable = 0;
+  const areasPerSide = Math.ceil(grid.length / SCENERY_AREA_SIZE);
+  const decorationsPerArea = new Uint16Array(areasPerSide * areasPerSide);
+  for (let y = 0; y < grid.length; y++) {
+    for (let x = 0; x < grid.length; x++) {
+      const type = grid[y][x].building?.type;
+      if (!type || type.endsWith('_footprint')) continue;
+      if (isDecoration(type)) {
+        const area = Math.floor(y / SCENERY_AREA_SIZE) * areasPerSide + Math.floor(x / SCENERY_AREA_SIZE);
+        decorationsPerArea[area]++;
+        continue;
+      }
+      if (!isAttraction(type)) continue;
+      attractions++;
+      if (getBuildingAccessTile(grid, x, y)) reachable++;
+      // Stations are counted through their coasters' types instead
+      if (isRideBuilding(type) && !type.startsWith('station_')) rideKinds.add(type);
+    }
+  }
+
+  // Each decoration adds less to its area than the last
+  let decoratedAreas = 0;
+  for (const count of decorationsPerArea) {
+    decoratedAreas += 1 - Math.exp(-count / SCENERY_AREA_SATURATION);
+  }
+
+  statsGrid = grid;
+  stats = { rideKinds, attractions, reachable, decoratedAreas };
+  return stats;
+}
+
+// =============================================================================
+// RATING
+// =============================================================================
+
+/**
+ * Score the park. An empty but happy park no longer rates like a busy one:
+ * crowd size, ride variety, how much of the park guests can walk to and how
+ * widely it's landscaped all count, and dirty paths (litterPenalty) take points away.
+ */
+export function calculateParkRating(
+  grid: Tile[][],
+  coasters: Coaster[],
+  guestsInPark: number,
+  averageHappiness: number,
+  litterPenalty: number
+): RatingBreakdown {
+  const { rideKinds, attractions, reachable, decoratedAreas } = getAttractionStats(grid);
+  const kinds = new Set(rideKinds);
+  for (const coaster of coasters) kinds.add(coaster.type);
+
+  const happiness = Math.round((Math.max(0, Math.min(100, averageHappiness)) / 100) * HAPPINESS_POINTS);
+  // Square root so the first few hundred guests count for more than the next few hundred
+  const guests = Math.round(Math.sqrt(Math.min(1, guestsInPark / FULL_RATING_GUESTS)) * GUEST_POINTS);
+  const variety = Math.round(Math.min(1, kinds.size / FULL_RATING_RIDE_KINDS) * VARIETY_POINTS);
+  const connectivity = attractions > 0 ? Math.round((reachable / attractions) * CONNECTIVITY_POINTS) : 0;
+  const scenery = Math.round(Math.min(1, decoratedAreas / FULL_RATING_SCENERY_AREAS) * SCENERY_POINTS);
+  const cleanliness = -Math.max(0, litterPenalty);
+
+  return {
+    happiness,
+    guests,
+    variety,
+    connectivity,
+    scenery,
+    cleanliness,
+    total: Math.max(0, Math.min(1000, happiness + guests + variety + connectivity + scenery + cleanliness)),
+  };
+}
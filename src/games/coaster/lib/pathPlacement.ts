@@ -0,0 +1,147 @@
+/**
+ * IsoCoaster Path Placement
+ * Route finding for laying new paths between two tiles (auto-path tool),
+ * plus the tile runs swept by paint strokes and straight-line placement
+ */
+
+import { Tile } from '../types';
+
+// =============================================================================
+// PLACEMENT CHECKS
+// =============================================================================
+
+/**
+ * Whether a path can be laid on (or already exists on) this tile.
+ * Mirrors the checks used by the single-tile path tool.
+ */
+export function canPlacePathOnTile(tile: Tile): boolean {
+  if (tile.terrain === 'water') return false;
+  if (tile.queue || tile.trackPiece || tile.hasCoasterTrack) return false;
+  const type = tile.building?.type;
+  return !type || type === 'empty' || type === 'grass' || type === 'path';
+}
+
+// =============================================================================
+// ROUTE FINDING
+// =============================================================================
+
+export interface PathPlacementRoute {
+  /** Every tile on the route, start to end (inclusive) */
+  tiles: { x: number; y: number }[];
+  /** Tiles on the route that don't have a path yet and need to be paid for */
+  newTiles: { x: number; y: number }[];
+}
+
+const ROUTE_NEIGHBORS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+/**
+ * Find the cheapest route for a new path between two tiles.
+ * Existing path tiles are free to reuse, every other buildable tile costs one.
+ * Uses a 0-1 BFS so the result is the route with the fewest new path tiles.
+ * Returns null if either endpoint is blocked or no route exists.
+ */
+export function findPathPlacementRoute(
+  grid: Tile[][],
+  gridSize: number,
+  start: { x: number; y: number },
+  end: { x: number; y: number }
+): PathPlacementRoute | null {
+  const inBounds = (x: number, y: number) => x >= 0 && y >= 0 && x < gridSize && y < gridSize;
+  if (!inBounds(start.x, start.y) || !inBounds(end.x, end.y)) return null;
+  if (!canPlacePathOnTile(grid[start.y][start.x]) || !canPlacePathOnTile(grid[end.y][end.x])) return null;
+
+  const tileCost = (x: number, y: number) => (grid[y][x].path ? 0 : 1);
+  const index = (x: number, y: number) => y * gridSize + x;
+
+  const dist = new Array<number>(gridSize * gridSize).fill(Infinity);
+  const cameFrom = new Array<number>(gridSize * gridSize).fill(-1);
+  const startIdx = index(start.x, start.y);
+  const endIdx = index(end.x, end.y);
+  dist[startIdx] = tileCost(start.x, start.y);
+
+  // Process the frontier one cost level at a time: free moves extend the
+  // current level, paid moves are deferred to the next one.
+  let frontier = [startIdx];
+  while (frontier.length > 0 && dist[endIdx] === Infinity) {
+    const next: number[] = [];
+    for (let i = 0; i < frontier.length; i++) {
+      const current = frontier[i];
+      const cx = current % gridSize;
+      const cy = Math.floor(current / gridSize);
+      for (const { dx, dy } of ROUTE_NEIGHBORS) {
+        const nx = cx + dx;
+        const ny = cy + dy;
+        if (!inBounds(nx, ny)) continue;
+        if (!canPlacePathOnTile(grid[ny][nx])) continue;
+        const step = tileCost(nx, ny);
+        const nIdx = index(nx, ny);
+        const nextDist = dist[current] + step;
+        if (nextDist >= dist[nIdx]) continue;
+        dist[nIdx] = nextDist;
+        cameFrom[nIdx] = current;
+        if (step === 0) {
+          frontier.push(nIdx);
+        } else {
+          next.push(nIdx);
+        }
+      }
+    }
+    frontier = next;
+  }
+
+  if (dist[endIdx] === Infinity) return null;
+
+  const tiles: { x: number; y: number }[] = [];
+  for (let idx = endIdx; idx !== -1; idx = cameFrom[idx]) {
+    tiles.push({ x: idx % gridSize, y: Math.floor(idx / gridSize) });
+  }
+  tiles.reverse();
+
+  return {
+    tiles,
+    newTiles: tiles.filter(t => !grid[t.y][t.x].path),
+  };
+}
+
+/**
+ * Tiles crossed when a paint stroke jumps from one tile to another between
+ * mouse events, excluding `from` itself. Steps one edge at a time (never
+ * diagonally) so the painted path stays connected for guests.
+ */
+export function getStrokeTiles(
+  from: { x: number; y: number },
+  to: { x: number; y: number }
+): { x: number; y: number }[] {
+  const tiles: { x: number; y: number }[] = [];
+  let x = from.x;
+  let y = from.y;
+  while (x !== to.x || y !== to.y) {
+    // Walk along whichever axis has further to go so the stroke hugs the cursor's line
+    if (Math.abs(to.x - x) >= Math.abs(to.y - y)) {
+      x += Math.sign(to.x - x);
+    } else {
+      y += Math.sign(to.y - y);
+    }
+    tiles.push({ x, y });
+  }
+  return tiles;
+}
+
+/**
+ * Tiles on a straight run from `start` toward `end`, inclusive of both ends.
+ * Runs follow the grid axis the drag moved furthest along, so a slightly
+ * diagonal drag still lays one connected line instead of a staircase.
+ */
+export function getStraightRunTiles(
+  start: { x: number; y: number },
+  end: { x: number; y: number }
+): { x: number; y: number }[] {
+  const alongX = Math.abs(end.x - start.x) >= Math.abs(end.y - start.y);
+  const target = alongX ? { x: end.x, y: start.y } : { x: start.x, y: end.y };
+  return [start, ...getStrokeTiles(start, target)];
+}
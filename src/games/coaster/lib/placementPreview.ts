@@ -0,0 +1,218 @@
+/**
+ * IsoCoaster Placement Rules
+ * Whether the selected tool can be used on a tile: checked by placeAtTile
+ * before building and by the hover ghost to colour the cursor
+ */
+
+import { GameState, Tile, Tool, TOOL_INFO, getStationStyleForCoasterType } from '../types';
+import { getTerrainRejection, planElevationChange, MIN_ELEVATION } from './terrain';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+// Tools whose hover cursor is a plain highlight rather than a build preview
+const NEUTRAL_TOOLS = new Set<Tool>(['select', 'bulldoze', 'sell', 'auto_path']);
+
+export const TRACK_TOOLS: Tool[] = [
+  'coaster_build',
+  'coaster_track',
+  'coaster_turn_left',
+  'coaster_turn_right',
+  'coaster_slope_up',
+  'coaster_slope_down',
+  'coaster_loop',
+];
+
+/** The parts of the park placement checks look at */
+export type PlacementState = Pick<GameState, 'grid' | 'finances' | 'buildingCoasterPath' | 'buildingCoasterType'>;
+
+export interface PlacementRejection {
+  reason: string;
+  title?: string; // Set when the player should be told (not for the usual misses while dragging)
+}
+
+// =============================================================================
+// TILE CHECKS
+// =============================================================================
+
+function isOpenGround(tile: Tile): boolean {
+  const type = tile.building.type;
+  return type === 'empty' || type === 'grass';
+}
+
+function hasTrack(tile: Tile): boolean {
+  return tile.hasCoasterTrack || !!tile.trackPiece;
+}
+
+// =============================================================================
+// PARK ENTRANCE
+// =============================================================================
+
+/**
+ * Find the edge tile a park entrance at (x, y) would connect to: a tile on the
+ * park border adjacent to (or under the edge side of) the footprint that
+ * already has a path or can take one. Returns null if the spot is interior.
+ */
+export function findEntranceEdgeTile(
+  grid: Tile[][],
+  gridSize: number,
+  x: number,
+  y: number,
+  size: { width: number; height: number }
+): { x: number; y: number } | null {
+  const isEdge = (tx: number, ty: number) => tx === 0 || ty === 0 || tx === gridSize - 1 || ty === gridSize - 1;
+  const inFootprint = (tx: number, ty: number) => tx >= x && tx < x + size.width && ty >= y && ty < y + size.height;
+  const centerX = x + (size.width - 1) / 2;
+  const centerY = y + (size.height - 1) / 2;
+  
+  const candidates: { x: number; y: number }[] = [];
+  for (let fy = y; fy < y + size.height; fy++) {
+    for (let fx = x; fx < x + size.width; fx++) {
+      for (const [dx, dy] of [[1, 0], [-1, 0], [0, 1], [0, -1]]) {
+        const nx = fx + dx;
+        const ny = fy + dy;
+        if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+        if (!isEdge(nx, ny) || inFootprint(nx, ny)) continue;
+        const tile = grid[ny][nx];
+        const type = tile.building?.type;
+        const canHavePath = tile.path || (
+          tile.terrain !== 'water' && !tile.queue && !tile.trackPiece && !tile.hasCoasterTrack &&
+          (!type || type === 'empty' || type === 'grass')
+        );
+        if (canHavePath) candidates.push({ x: nx, y: ny });
+      }
+    }
+  }
+  if (candidates.length === 0) return null;
+  
+  // Prefer an existing path, then the tile closest to the middle of the entrance
+  candidates.sort((a, b) => {
+    const pathDiff = Number(grid[b.y][b.x].path) - Number(grid[a.y][a.x].path);
+    if (pathDiff !== 0) return pathDiff;
+    return (Math.abs(a.x - centerX) + Math.abs(a.y - centerY)) - (Math.abs(b.x - centerX) + Math.abs(b.y - centerY));
+  });
+  return candidates[0];
+}
+
+// =============================================================================
+// TRACK
+// =============================================================================
+
+/**
+ * Why a track tool can't lay a piece at (x, y), or null if it can. Track may
+ * go on any land tile (replacing a piece already there); auto-build lays one
+ * continuous run, so each tile must touch the last.
+ */
+export function getTrackPlacementRejection(state: PlacementState, x: number, y: number, tool: Tool): string | null {
+  const tile = state.grid[y]?.[x];
+  if (!tile) return 'Outside the park';
+  if (tile.terrain === 'water') return 'Track can\'t be built on water';
+  
+  const buildPath = state.buildingCoasterPath;
+  const lastTile = buildPath.length > 0 ? buildPath[buildPath.length - 1] : null;
+  if (tool === 'coaster_build' && lastTile && Math.abs(x - lastTile.x) + Math.abs(y - lastTile.y) !== 1) {
+    return 'Not adjacent to the track being built';
+  }
+  
+  const cost = TOOL_INFO[tool]?.cost ?? 0;
+  if (state.finances.bankrupt && cost > 0) return 'The park is bankrupt';
+  if (state.finances.cash < cost) return 'Not enough cash';
+  return null;
+}
+
+// =============================================================================
+// PREVIEW
+// =============================================================================
+
+/**
+ * Whether the tool gets a valid/invalid build preview at all
+ */
+export function isPreviewTool(tool: Tool): boolean {
+  return !NEUTRAL_TOOLS.has(tool) && !!TOOL_INFO[tool];
+}
+
+/**
+ * Why placing the tool with its origin at (x, y) would fail, or null if it
+ * would succeed. This is the one set of placement rules: placeAtTile checks
+ * it before building anything and the hover preview colours the cursor by it.
+ */
+export function getPlacementRejection(state: PlacementState, tool: Tool, x: number, y: number): PlacementRejection | null {
+  const toolInfo = TOOL_INFO[tool];
+  if (!toolInfo || NEUTRAL_TOOLS.has(tool)) return { reason: 'Not a build tool' };
+  if (TRACK_TOOLS.includes(tool)) {
+    const reason = getTrackPlacementRejection(state, x, y, tool);
+    return reason ? { reason } : null;
+  }
+
+  const { grid, finances } = state;
+  // Bankrupt parks can't build anything new
+  if (finances.bankrupt && toolInfo.cost > 0) return { reason: 'The park is bankrupt' };
+  if (finances.cash < toolInfo.cost) return { reason: 'Not enough cash' };
+
+  const gridSize = grid.length;
+  if (x < 0 || y < 0 || x >= gridSize || y >= gridSize) return { reason: 'Outside the park' };
+  const tile = grid[y][x];
+  const isClear = (t: Tile) => isOpenGround(t) && !t.path && !t.queue && !hasTrack(t);
+
+  if (tool === 'zone_water') {
+    // Only open grass or sand can be dug out, and water sits at ground level
+    if (tile.terrain !== 'grass' && tile.terrain !== 'sand') return { reason: 'Only grass or sand can be dug out' };
+    if (tile.elevation > MIN_ELEVATION) return { reason: 'Raised land has to be lowered first' };
+    return isClear(tile) ? null : { reason: 'Tile is occupied' };
+  }
+  if (tool === 'zone_land') return tile.terrain === 'water' ? null : { reason: 'Only water can be filled in' };
+  if (tool === 'terrain_sand' || tool === 'terrain_rock') {
+    const terrain = tool === 'terrain_sand' ? 'sand' : 'rock';
+    if (tile.terrain === 'water') return { reason: 'Water has to be filled in first' };
+    if (tile.terrain === terrain) return { reason: `Already ${terrain}` };
+    return isClear(tile) ? null : { reason: 'Tile is occupied' };
+  }
+  if (tool === 'terrain_raise' || tool === 'terrain_lower') {
+    // Each tile the slope reshapes is charged
+    const changes = planElevationChange(grid, x, y, tool === 'terrain_raise' ? 1 : -1);
+    if (!changes) return { reason: 'This land can\'t be reshaped' };
+    return finances.cash < toolInfo.cost * changes.size ? { reason: 'Not enough cash' } : null;
+  }
+
+  if (tile.terrain === 'water') return { reason: 'This can\'t be built on water' };
+
+  // Paths and queues can be laid again over their own kind
+  if (tool === 'path' || tool === 'queue') {
+    if (hasTrack(tile)) return { reason: 'Tile is occupied' };
+    return isOpenGround(tile) || tile.building.type === tool ? null : { reason: 'Tile is occupied' };
+  }
+
+  const size = toolInfo.size ?? { width: 1, height: 1 };
+  for (let dy = 0; dy < size.height; dy++) {
+    for (let dx = 0; dx < size.width; dx++) {
+      const checkTile = grid[y + dy]?.[x + dx];
+      if (!checkTile) return { reason: 'Doesn\'t fit in the park' };
+      if (checkTile.terrain === 'water') return { reason: 'This can\'t be built on water' };
+      if (!isClear(checkTile)) return { reason: 'Tile is occupied' };
+    }
+  }
+
+  // Park entrances must sit at the park edge, next to an edge path that guests arrive on
+  if (tool === 'park_entrance' && !findEntranceEdgeTile(grid, gridSize, x, y, size)) {
+    return {
+      title: 'Entrance Must Be at the Edge',
+      reason: 'Park entrances need to be placed on or next to the edge of the park.',
+    };
+  }
+
+  // Stations take the style of the coaster being built; scenery and ride tools
+  // share their building's name, so terrain rules apply directly
+  const buildingType = tool === 'coaster_station'
+    ? `station_${getStationStyleForCoasterType(state.buildingCoasterType ?? 'steel_sit_down')}`
+    : tool;
+  const terrainRejection = getTerrainRejection(grid, buildingType, x, y, size);
+  return terrainRejection ? { title: 'Wrong Terrain', reason: terrainRejection } : null;
+}
+
+/**
+ * Whether placing the tool with its origin at (x, y) would succeed
+ */
+export function canPlaceTool(state: PlacementState, tool: Tool, x: number, y: number): boolean {
+  return isPreviewTool(tool) && getPlacementRejection(state, tool, x, y) === null;
+}
@@ -0,0 +1,176 @@
+/**
+ * IsoCoaster Queue Lines
+ * Which ride each queue run feeds, and how far each queue tile is from the front of its line
+ */
+
+import { Tile, isRideBuilding } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+export const GUESTS_PER_QUEUE_TILE = 2; // Guests standing on each queue tile before the line backs up
+
+const QUEUE_NEIGHBORS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+// =============================================================================
+// LINKING
+// =============================================================================
+
+/**
+ * Id ("x,y" of the ride's origin tile) of a ride this queue tile feeds into.
+ * Only the origin tile counts, matching where guests board (see buildingAccess).
+ */
+function getAdjacentRideId(grid: Tile[][], x: number, y: number): string | null {
+  const gridSize = grid.length;
+  for (const { dx, dy } of QUEUE_NEIGHBORS) {
+    const nx = x + dx;
+    const ny = y + dy;
+    if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+    const type = grid[ny][nx].building?.type;
+    if (type && isRideBuilding(type) && !type.endsWith('_footprint')) return `${nx},${ny}`;
+  }
+  return null;
+}
+
+/**
+ * Every tile in the connected queue run containing (x, y)
+ */
+function collectQueueRun(grid: Tile[][], x: number, y: number, visited: Uint8Array): { x: number; y: number }[] {
+  const gridSize = grid.length;
+  const run: { x: number; y: number }[] = [];
+  const stack = [{ x, y }];
+  visited[y * gridSize + x] = 1;
+  while (stack.length > 0) {
+    const current = stack.pop()!;
+    run.push(current);
+    for (const { dx, dy } of QUEUE_NEIGHBORS) {
+      const nx = current.x + dx;
+      const ny = current.y + dy;
+      if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+      if (!grid[ny][nx].queue || visited[ny * gridSize + nx]) continue;
+      visited[ny * gridSize + nx] = 1;
+      stack.push({ x: nx, y: ny });
+    }
+  }
+  return run;
+}
+
+/**
+ * Point every queue run touching the given area (and one tile around it) at
+ * the ride it leads to, or at nothing if it no longer reaches one. Call on a
+ * freshly cloned grid after placing or removing queues or rides; omit the
+ * area to relink the whole park (e.g. for older saves).
+ */
+export function relinkQueues(
+  grid: Tile[][],
+  area?: { x: number; y: number; width: number; height: number }
+): void {
+  const gridSize = grid.length;
+  const visited = new Uint8Array(gridSize * gridSize);
+  const minX = area ? Math.max(0, area.x - 1) : 0;
+  const minY = area ? Math.max(0, area.y - 1) : 0;
+  const maxX = area ? Math.min(gridSize - 1, area.x + area.width) : gridSize - 1;
+  const maxY = area ? Math.min(gridSize - 1, area.y + area.height) : gridSize - 1;
+
+  for (let y = minY; y <= maxY; y++) {
+    for (let x = minX; x <= maxX; x++) {
+      const tile = grid[y][x];
+      if (!tile.queue) {
+        tile.queueRideId = null;
+        continue;
+      }
+      if (visited[y * gridSize + x]) continue;
+
+      const run = collectQueueRun(grid, x, y, visited);
+      let rideId: string | null = null;
+      for (const queueTile of run) {
+        rideId = getAdjacentRideId(grid, queueTile.x, queueTile.y);
+        if (rideId) break;
+      }
+      for (const queueTile of run) {
+        grid[queueTile.y][queueTile.x].queueRideId = rideId;
+      }
+    }
+  }
+}
+
+// =============================================================================
+// LINE POSITIONS
+// =============================================================================
+
+// Distances only change when the grid does, so keep them per grid
+let distanceGrid: Tile[][] | null = null;
+let distanceCache = new Map<string, Map<string, number>>();
+
+/**
+ * Steps from each of a ride's queue tiles to the front of its line (the
+ * queue tile touching the ride is 0), keyed by "x,y". Empty if the ride has
+ * no linked queue.
+ */
+export function getQueueDistances(grid: Tile[][], rideId: string): Map<string, number> {
+  if (grid !== distanceGrid) {
+    distanceGrid = grid;
+    distanceCache = new Map();
+  }
+  const cached = distanceCache.get(rideId);
+  if (cached) return cached;
+
+  const gridSize = grid.length;
+  const [rideX, rideY] = rideId.split(',').map(Number);
+  const distances = new Map<string, number>();
+  const frontier: { x: number; y: number }[] = [];
+
+  for (const { dx, dy } of QUEUE_NEIGHBORS) {
+    const nx = rideX + dx;
+    const ny = rideY + dy;
+    if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+    if (grid[ny][nx].queue && grid[ny][nx].queueRideId === rideId) {
+      distances.set(`${nx},${ny}`, 0);
+      frontier.push({ x: nx, y: ny });
+    }
+  }
+
+  for (let head = 0; head < frontier.length; head++) {
+    const current = frontier[head];
+    const distance = distances.get(`${current.x},${current.y}`)!;
+    for (const { dx, dy } of QUEUE_NEIGHBORS) {
+      const nx = current.x + dx;
+      const ny = current.y + dy;
+      if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+      const key = `${nx},${ny}`;
+      if (distances.has(key) || grid[ny][nx].queueRideId !== rideId) continue;
+      distances.set(key, distance + 1);
+      frontier.push({ x: nx, y: ny });
+    }
+  }
+
+  distanceCache.set(rideId, distances);
+  return distances;
+}
+
+/**
+ * The queue tile one step closer to the front of the line, or null if the
+ * guest is already at the front (or isn't standing in this ride's line)
+ */
+export function getNextQueueTile(grid: Tile[][], rideId: string, x: number, y: number): { x: number; y: number } | null {
+  const distances = getQueueDistances(grid, rideId);
+  const distance = distances.get(`${x},${y}`);
+  if (distance === undefined || distance === 0) return null;
+  for (const { dx, dy } of QUEUE_NEIGHBORS) {
+    if (distances.get(`${x + dx},${y + dy}`) === distance - 1) return { x: x + dx, y: y + dy };
+  }
+  return null;
+}
+
+/**
+ * How far back from the front a guest at this place in line should stand
+ */
+export function getQueueSlotDistance(queuePosition: number): number {
+  return Math.floor(queuePosition / GUESTS_PER_QUEUE_TILE);
+}
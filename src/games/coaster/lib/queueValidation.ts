@@ -0,0 +1,108 @@
+/**
+ * IsoCoaster Queue Validation
+ * Finds queue lines that can't take guests from a path to a ride
+ */
+
+import { Tile } from '../types';
+
+// =============================================================================
+// TYPES
+// =============================================================================
+
+export type BrokenQueueReason =
+  | 'no_entry'  // No queue tile touches a path, so guests can't join
+  | 'no_exit'   // No queue tile touches a ride or station, so guests can't board
+  | 'isolated'; // Touches neither
+
+export interface BrokenQueue {
+  /** Every tile in the connected queue run */
+  tiles: { x: number; y: number }[];
+  reason: BrokenQueueReason;
+}
+
+const QUEUE_NEIGHBORS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+// =============================================================================
+// CHECKS
+// =============================================================================
+
+/**
+ * Whether a tile is something a queue can feed into (ride, show, coaster station or track)
+ */
+function isQueueExitTile(tile: Tile): boolean {
+  const type = tile.building?.type;
+  if (type && (type.startsWith('ride_') || type.startsWith('show_') || type.startsWith('station_'))) {
+    return true;
+  }
+  return tile.hasCoasterTrack || Boolean(tile.trackPiece);
+}
+
+/**
+ * Group queue tiles into connected runs and report the runs that either have
+ * no path entry or don't reach a ride. A queue that only connects to other
+ * queues is checked as one run, so a gap anywhere in the middle splits it
+ * into two runs that each report broken.
+ */
+export function findBrokenQueues(grid: Tile[][], gridSize: number): BrokenQueue[] {
+  const visited = new Uint8Array(gridSize * gridSize);
+  const broken: BrokenQueue[] = [];
+
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      if (!grid[y][x].queue || visited[y * gridSize + x]) continue;
+
+      // Flood fill this queue run
+      const tiles: { x: number; y: number }[] = [];
+      let hasEntry = false;
+      let hasExit = false;
+      const stack = [{ x, y }];
+      visited[y * gridSize + x] = 1;
+
+      while (stack.length > 0) {
+        const current = stack.pop()!;
+        tiles.push(current);
+        for (const { dx, dy } of QUEUE_NEIGHBORS) {
+          const nx = current.x + dx;
+          const ny = current.y + dy;
+          if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+          const neighbor = grid[ny][nx];
+          if (neighbor.queue) {
+            if (!visited[ny * gridSize + nx]) {
+              visited[ny * gridSize + nx] = 1;
+              stack.push({ x: nx, y: ny });
+            }
+            continue;
+          }
+          if (neighbor.path) hasEntry = true;
+          if (isQueueExitTile(neighbor)) hasExit = true;
+        }
+      }
+
+      if (hasEntry && hasExit) continue;
+      broken.push({
+        tiles,
+        reason: !hasEntry && !hasExit ? 'isolated' : !hasEntry ? 'no_entry' : 'no_exit',
+      });
+    }
+  }
+
+  return broken;
+}
+
+/**
+ * Set of "x,y" keys for every tile in a broken queue (for rendering lookups)
+ */
+export function getBrokenQueueTileKeys(brokenQueues: BrokenQueue[]): Set<string> {
+  const keys = new Set<string>();
+  for (const queue of brokenQueues) {
+    for (const tile of queue.tiles) {
+      keys.add(`${tile.x},${tile.y}`);
+    }
+  }
+  return keys;
+}
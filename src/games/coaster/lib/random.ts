@@ -0,0 +1,74 @@
+/**
+ * IsoCoaster Random
+ * Seeded xorshift random numbers, so terrain and the simulation replay exactly for a given seed
+ */
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+const UINT32_RANGE = 0x100000000;
+const FALLBACK_STATE = 0x9e3779b9; // xorshift gets stuck on zero, so zero seeds start here
+
+// =============================================================================
+// GENERATORS
+// =============================================================================
+
+/**
+ * A fresh random seed for parks started without one
+ */
+export function randomSeed(): number {
+  return Math.floor(Math.random() * UINT32_RANGE);
+}
+
+/**
+ * Fold any number into a valid (non-zero, 32-bit) generator state
+ */
+export function normalizeSeed(seed: number): number {
+  const state = Math.floor(Math.abs(seed)) >>> 0;
+  return state === 0 ? FALLBACK_STATE : state;
+}
+
+function xorshift(state: number): number {
+  let x = state;
+  x ^= x << 13;
+  x ^= x >>> 17;
+  x ^= x << 5;
+  return x >>> 0;
+}
+
+/**
+ * A standalone generator for one-off seeded work like terrain generation
+ */
+export function createSeededRandom(seed: number): () => number {
+  let state = normalizeSeed(seed);
+  return () => {
+    state = xorshift(state);
+    return state / UINT32_RANGE;
+  };
+}
+
+// =============================================================================
+// SIMULATION STREAM
+// =============================================================================
+
+// Shared by the tick and the guest/staff systems it calls. The tick loads the
+// park's saved state before running and stores it back afterwards, so every
+// draw during a tick comes from the park's own sequence.
+let simulationState = FALLBACK_STATE;
+
+export function seedSimulationRandom(state: number): void {
+  simulationState = normalizeSeed(state);
+}
+
+export function getSimulationRandomState(): number {
+  return simulationState;
+}
+
+/**
+ * Drop-in replacement for Math.random() inside the simulation
+ */
+export function simulationRandom(): number {
+  simulationState = xorshift(simulationState);
+  return simulationState / UINT32_RANGE;
+}
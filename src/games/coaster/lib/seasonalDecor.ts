@@ -0,0 +1,61 @@
+/**
+ * IsoCoaster Seasonal Decorations
+ * Holiday decor cheers up nearby guests in its month and looks stale out of it
+ */
+
+import { Tile, getDecorationSeason } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+const SEASONAL_DECOR_REACH = 3; // Tiles from a decoration where guests notice it
+
+// =============================================================================
+// DETECTION
+// =============================================================================
+
+export interface SeasonalDecor {
+  x: number;
+  y: number;
+  month: number;
+}
+
+// Decorations only change when buildings do, and every edit produces a new grid
+let decorGrid: Tile[][] | null = null;
+let decorCache: SeasonalDecor[] = [];
+
+/**
+ * Every holiday decoration in the park (origin tiles only)
+ */
+export function findSeasonalDecor(grid: Tile[][]): SeasonalDecor[] {
+  if (grid === decorGrid) return decorCache;
+
+  const decor: SeasonalDecor[] = [];
+  for (let y = 0; y < grid.length; y++) {
+    for (let x = 0; x < grid[y].length; x++) {
+      const type = grid[y][x].building?.type;
+      if (!type || type.endsWith('_footprint')) continue;
+      const month = getDecorationSeason(type);
+      if (month !== null) decor.push({ x, y, month });
+    }
+  }
+
+  decorGrid = grid;
+  decorCache = decor;
+  return decor;
+}
+
+/**
+ * How holiday decor near a tile sits with guests this month: 1 if any in-season
+ * decoration is in view, -1 if only out-of-season ones are, 0 if there are none
+ */
+export function getSeasonalDecorEffectAt(decor: SeasonalDecor[], month: number, x: number, y: number): number {
+  let effect = 0;
+  for (const item of decor) {
+    if (Math.abs(item.x - x) > SEASONAL_DECOR_REACH || Math.abs(item.y - y) > SEASONAL_DECOR_REACH) continue;
+    if (item.month === month) return 1;
+    effect = -1;
+  }
+  return effect;
+}
@@ -0,0 +1,53 @@
+import { describe, it } from 'node:test';
+import assert from 'node:assert/strict';
+import { Tile, BuildingType, TOOL_INFO, Tool, createEmptyTile, getToolForBuilding } from '../types';
+import { TrackPiece, TrackPieceType } from '../types/tracks';
+import { getSellRefund } from './sellRefund';
+
+// Tools that place a building of the same name, with what they charge
+const BUILDING_TOOLS = (Object.keys(TOOL_INFO) as Tool[]).filter(tool =>
+  getToolForBuilding(tool as BuildingType) === tool && TOOL_INFO[tool].cost > 0
+);
+
+function buildingTile(type: BuildingType, constructionProgress: number): Tile {
+  const tile = createEmptyTile(0, 0);
+  return { ...tile, building: { ...tile.building, type, constructionProgress } };
+}
+
+function trackTile(type: TrackPieceType): Tile {
+  const trackPiece: TrackPiece = {
+    type, direction: 'south', startHeight: 0, endHeight: 0, bankAngle: 0, chainLift: false, boosted: false, strutStyle: 'metal',
+  };
+  return { ...createEmptyTile(0, 0), hasCoasterTrack: true, coasterTrackId: 'coaster', trackPiece };
+}
+
+describe('getSellRefund', () => {
+  it('loses money on every buy-and-sell round trip', () => {
+    assert.ok(BUILDING_TOOLS.includes('ride_carousel'));
+    for (const tool of BUILDING_TOOLS) {
+      let cash = 100000;
+      for (let round = 0; round < 10; round++) {
+        cash -= TOOL_INFO[tool].cost;
+        cash += getSellRefund(buildingTile(tool as BuildingType, 100));
+      }
+      assert.ok(cash < 100000, `${tool} ended a round trip with ${cash}`);
+    }
+  });
+
+  it('refunds a ride under construction in proportion to its progress', () => {
+    const cost = TOOL_INFO.ride_carousel.cost;
+    const finished = getSellRefund(buildingTile('ride_carousel', 100));
+
+    assert.equal(getSellRefund(buildingTile('ride_carousel', 0)), 0);
+    assert.equal(getSellRefund(buildingTile('ride_carousel', 40)), Math.floor(finished * 0.4));
+    assert.ok(finished < cost);
+  });
+
+  it('refunds less than each track piece cost', () => {
+    const pieces: [TrackPieceType, Tool][] = [['straight_flat', 'coaster_track'], ['loop_vertical', 'coaster_loop']];
+    for (const [type, tool] of pieces) {
+      const refund = getSellRefund(trackTile(type));
+      assert.ok(refund > 0 && refund < TOOL_INFO[tool].cost, `${type} refunded ${refund}`);
+    }
+  });
+});
@@ -0,0 +1,25 @@
+/**
+ * IsoCoaster Sell Refunds
+ * What the sell tool pays back for a tile; kept below the build cost so
+ * buying and selling the same thing never turns a profit
+ */
+
+import { Tile, BuildingType, SELL_REFUND_RATE, getBuildingCost, getTrackPieceCost } from '../types';
+
+/**
+ * Refund for selling a tile: a share of the building's cost (scaled by
+ * construction progress) plus a share of any track piece on it.
+ */
+export function getSellRefund(tile: Tile): number {
+  let value = 0;
+  const type = tile.building?.type;
+  if (type && type !== 'empty' && type !== 'grass' && type !== 'water') {
+    const baseType = type.replace('_footprint', '') as BuildingType;
+    const progress = Math.max(0, Math.min(100, tile.building.constructionProgress)) / 100;
+    value += getBuildingCost(baseType) * progress;
+  }
+  if (tile.trackPiece) {
+    value += getTrackPieceCost(tile.trackPiece.type);
+  }
+  return Math.floor(value * Math.min(1, Math.max(0, SELL_REFUND_RATE)));
+}
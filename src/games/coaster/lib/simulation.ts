@@ -0,0 +1,2091 @@
+/**
+ * IsoCoaster Simulation
+ * One park simulation step (time, weather, guests, trains, finances) and the
+ * state helpers it shares with the provider. Plain functions with no React,
+ * so headless runs can import them directly.
+ */
+
+import { GameState, Tile, Notification, GameEvent, TickBreakdown, MoneyPopup, MONEY_POPUP_TICKS, MAX_MONEY_POPUPS, MAX_PENDING_EVENTS, RideStats, createRideStats, createEmptyTile, createEmptyBuilding } from '../types';
+import { Guest, Staff, DEFAULT_PRICES, LOW_CASH_THRESHOLD, DEFAULT_BANKRUPTCY_GRACE_DAYS, DEFAULT_AUTOSAVE_INTERVAL_HOURS, DEFAULT_MAX_GUESTS, DEFAULT_SPAWN_RATE_MULTIPLIER, DEFAULT_OPEN_HOUR, DEFAULT_CLOSE_HOUR, isParkOpenAt, LOAN_CEILING, BANKRUPTCY_CASH_FLOOR, MonthlyReport, MONTHLY_REPORT_HISTORY, RECENT_THOUGHTS_LIMIT, RecentThought, WeatherState, WeatherType, WEATHER_EFFECTS, WEATHER_TRANSITIONS, getSeasonalWeatherBias, GuestThought, STALL_STOCK_CAPACITY, STALL_RESTOCK_PER_HOUR } from '../types/economy';
+import { Coaster, CoasterTrain, CoasterCar, CoasterTrainConfig, SEATS_PER_CAR, getDispatchPolicy, DEFAULT_CAR_SPACING, MIN_TRAIN_GAP, TrackDirection, TrackPiece, CoasterType, COASTER_TYPE_STATS } from '../types/tracks';
+import { getRideCapacity, isRideBuilding, getFoodProfile, getBuildingUpkeep } from '../types/buildings';
+import { spawnGuests, updateGuest, createRideQueueTracker, collectRideLines, addGuestThought, getRideBuilding, getRideFairPrice, findCoasterForRide, willPayEntranceFee, shouldGuestLeaveWhenBroke, shouldGuestLeaveForBathroom, sendGuestHome, hasGuestLeftPark, getGuestPreferenceStats, getGuestOccupancy, isStockedBuilding } from '@/components/coaster/guests';
+import { perlinNoise } from '@/lib/simulation';
+import { createSeededRandom, getSimulationRandomState, randomSeed, seedSimulationRandom, simulationRandom } from './random';
+import { findThemeZones, getThemeZoneStrengthAt } from './themeZones';
+import { findSeasonalDecor, getSeasonalDecorEffectAt } from './seasonalDecor';
+import { CoasterRatings, calculateCoasterRatings } from './coasterRatings';
+import { isNightHour, isTileLit } from './lightCoverage';
+import { getWeatherAdjustedPrice, isGuestSheltered, isWetWeather } from './weather';
+import { calculateParkRating } from './parkRating';
+import { getDirectionOffset, getExitDirection, isTrackComplete } from './trackValidation';
+import { simulateStaff, getLitterRatingPenalty } from '@/components/coaster/staff';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+export const DEFAULT_GRID_SIZE = 60;
+
+// Weather change interval in ticks (roughly every 2-4 in-game hours)
+const WEATHER_CHANGE_MIN_TICKS = 120; // ~2 hours at normal speed
+const WEATHER_CHANGE_MAX_TICKS = 240; // ~4 hours at normal speed
+
+const SPEED_TRAIN_BOOSTS = [1, 1.5, 2.0, 2.5] as const; // visual velocity boost by speed
+
+// Train physics (track pieces per tick, before the visual speed boost)
+const TRAIN_GRAVITY = 0.0015;     // Speed gained per tick per unit of height drop per piece
+const TRAIN_FRICTION = 0.00004;   // Rolling resistance per tick
+const CHAIN_LIFT_SPEED = 0.035;   // Chain lifts pull at this speed regardless of momentum
+const BOOSTER_SPEED = 0.12;       // Launch boosters bring trains up to at least this speed
+const MAX_TRAIN_SPEED = 0.3;
+const STALL_SPEED = 0.002;        // Slower than this (or rolling back) counts as stopped
+const STALL_TICKS = 240;          // Stopped this long away from the station means stalled
+const DISPATCH_BLOCK_LENGTH = 6;   // Track pieces past the station that must be clear of other trains before dispatch
+
+const THEME_ZONE_HAPPINESS_BONUS = 0.05; // happiness per tick in a full-strength theme zone
+const DARKNESS_HAPPINESS_PENALTY = 0.04; // happiness per tick for guests out of lamp light at night
+const SEASONAL_DECOR_HAPPINESS_BONUS = 0.03; // happiness per tick near holiday decor in its month
+const SEASONAL_DECOR_HAPPINESS_PENALTY = 0.01; // happiness per tick near holiday decor out of season
+export const RIDE_CYCLE_TIME = 25; // ticks between flat ride dispatches
+const SCENERY_THOUGHT_STRENGTH = 0.5; // theme zone strength that can prompt a compliment
+const SCENERY_THOUGHT_CHANCE = 0.005; // per tick
+const RIDE_OVERPRICED_RATIO = 1.25; // price / fair price above which riders feel ripped off
+const RIDE_BARGAIN_RATIO = 0.75;
+const RIDE_OVERPRICE_PENALTY = 10; // happiness lost per 100% over the fair price
+const RIDE_MAX_OVERPRICE_PENALTY = 15;
+export const GAME_VERSION = 2; // 2: rides carry their own ticket price
+
+// =============================================================================
+// WEATHER SIMULATION
+// =============================================================================
+
+export function createInitialWeather(month: number): WeatherState {
+  const initialWeather = pickNextWeather('sunny', month);
+  return {
+    current: initialWeather,
+    temperature: getTemperatureForWeather(initialWeather, month),
+    nextChange: Math.floor(simulationRandom() * (WEATHER_CHANGE_MAX_TICKS - WEATHER_CHANGE_MIN_TICKS)) + WEATHER_CHANGE_MIN_TICKS,
+    forecast: [
+      pickNextWeather(initialWeather, month),
+      pickNextWeather(initialWeather, month),
+      pickNextWeather(initialWeather, month),
+    ],
+  };
+}
+
+function pickNextWeather(current: WeatherType, month: number): WeatherType {
+  const transitions = WEATHER_TRANSITIONS[current];
+  const seasonBias = getSeasonalWeatherBias(month);
+  
+  // Build weighted probability list
+  const options: { weather: WeatherType; weight: number }[] = [];
+  for (const [weather, baseWeight] of Object.entries(transitions)) {
+    const bias = seasonBias[weather as WeatherType] ?? 1.0;
+    options.push({ weather: weather as WeatherType, weight: (baseWeight ?? 0) * bias });
+  }
+  
+  // Normalize weights
+  const totalWeight = options.reduce((sum, o) => sum + o.weight, 0);
+  const random = simulationRandom() * totalWeight;
+  
+  let cumulative = 0;
+  for (const option of options) {
+    cumulative += option.weight;
+    if (random <= cumulative) {
+      return option.weather;
+    }
+  }
+  
+  return options[options.length - 1]?.weather ?? 'sunny';
+}
+
+function getTemperatureForWeather(weather: WeatherType, month: number): number {
+  // Base temperature by season (Celsius)
+  let baseTemp: number;
+  if (month >= 6 && month <= 8) baseTemp = 28; // Summer
+  else if (month >= 3 && month <= 5) baseTemp = 18; // Spring
+  else if (month >= 9 && month <= 11) baseTemp = 14; // Fall
+  else baseTemp = 5; // Winter
+  
+  // Adjust by weather type
+  switch (weather) {
+    case 'hot': return baseTemp + 8 + simulationRandom() * 5;
+    case 'sunny': return baseTemp + 3 + simulationRandom() * 3;
+    case 'partly_cloudy': return baseTemp + simulationRandom() * 2;
+    case 'cloudy': return baseTemp - 2 + simulationRandom() * 2;
+    case 'rain': return baseTemp - 4 + simulationRandom() * 2;
+    case 'storm': return baseTemp - 5 + simulationRandom() * 3;
+    case 'cold': return baseTemp - 10 + simulationRandom() * 3;
+    default: return baseTemp;
+  }
+}
+
+function simulateWeather(weather: WeatherState, tick: number, month: number): WeatherState {
+  if (tick < weather.nextChange) {
+    return weather;
+  }
+  
+  // Time for weather change!
+  const newWeather = weather.forecast[0];
+  const nextChangeIn = Math.floor(simulationRandom() * (WEATHER_CHANGE_MAX_TICKS - WEATHER_CHANGE_MIN_TICKS)) + WEATHER_CHANGE_MIN_TICKS;
+  
+  // Shift forecast and add new prediction
+  const newForecast = [
+    weather.forecast[1],
+    weather.forecast[2],
+    pickNextWeather(weather.forecast[2], month),
+  ];
+  
+  return {
+    current: newWeather,
+    temperature: getTemperatureForWeather(newWeather, month),
+    nextChange: tick + nextChangeIn,
+    forecast: newForecast,
+  };
+}
+
+// Apply weather effects to a guest; sheltered guests stay dry in rain and storms
+function applyWeatherEffectsToGuest(guest: Guest, weather: WeatherType, sheltered: boolean): Guest {
+  const effects = WEATHER_EFFECTS[weather];
+  
+  // Clone guest to avoid mutation
+  const updatedGuest = { ...guest };
+  
+  // Apply thirst modifier
+  updatedGuest.thirst = Math.min(100, Math.max(0, updatedGuest.thirst + effects.thirstModifier));
+  
+  // Apply energy modifier (weather affects tiredness)
+  updatedGuest.energy = Math.max(0, updatedGuest.energy - effects.energyModifier);
+  
+  // Apply happiness modifier
+  const keptDry = sheltered && isWetWeather(weather);
+  if (!keptDry) {
+    updatedGuest.happiness = Math.min(100, Math.max(0, updatedGuest.happiness + effects.happinessModifier));
+  }
+  
+  // Add weather-related thoughts occasionally
+  if (!keptDry && simulationRandom() < 0.01) { // 1% chance per tick
+    let weatherThought: GuestThought | null = null;
+    
+    switch (weather) {
+      case 'sunny':
+      case 'partly_cloudy':
+        if (simulationRandom() < 0.5) weatherThought = 'weather_great';
+        else weatherThought = 'perfect_day';
+        break;
+      case 'rain':
+        weatherThought = 'getting_wet';
+        break;
+      case 'storm':
+        weatherThought = 'need_shelter';
+        break;
+      case 'hot':
+        weatherThought = 'too_hot';
+        break;
+      case 'cold':
+        weatherThought = 'too_cold';
+        break;
+    }
+    
+    if (weatherThought && !updatedGuest.thoughts.includes(weatherThought)) {
+      addGuestThought(updatedGuest, weatherThought);
+    }
+  }
+  
+  return updatedGuest;
+}
+
+// Check if a guest decides to leave due to weather
+// This is called every tick, so the chance must be VERY low
+function shouldGuestLeaveForWeather(guest: Guest, weather: WeatherType): boolean {
+  const effects = WEATHER_EFFECTS[weather];
+
+  // Base leave chance (extremely low - this is per tick!)
+  // With 50ms ticks at normal speed, this runs ~20 times per second
+  const baseChance = 0.0001;
+
+  // Only check in bad weather (storms)
+  if (weather !== 'storm' && weather !== 'rain') {
+    return false;
+  }
+
+  // Apply weather multiplier
+  let leaveChance = baseChance * effects.leaveChanceMultiplier;
+
+  // Only very unhappy guests consider leaving due to weather
+  if (guest.happiness >= 40) {
+    return false;
+  }
+  
+  // Even then, give them a further reduction
+  leaveChance *= 0.5;
+
+  // Guests who just arrived are much less likely to leave
+  if (guest.timeInPark < 600) { // Less than 10 minutes
+    leaveChance *= 0.1;
+  }
+  
+  return simulationRandom() < leaveChance;
+}
+
+// =============================================================================
+// TERRAIN GENERATION
+// =============================================================================
+
+// Generate 2-3 large, round lakes for the park terrain (the same seed always gives the same lakes)
+// Uses perlinNoise imported from @/lib/simulation
+function generateLakes(grid: Tile[][], size: number, seed: number): void {
+  const random = createSeededRandom(seed);
+  const noiseSeed = random() * 1000;
+  
+  // Use noise to find potential lake centers - look for low points
+  const lakeNoise = (x: number, y: number) => perlinNoise(x, y, noiseSeed + 1000, 3);
+  
+  // Find lake seed points (local minimums in noise)
+  const lakeCenters: { x: number; y: number; noise: number }[] = [];
+  const minDistFromEdge = Math.max(8, Math.floor(size * 0.15)); // Keep lakes away from edges
+  const minDistBetweenLakes = Math.max(size * 0.2, 10); // Adaptive but ensure minimum separation
+  
+  // Collect all potential lake centers with adaptive threshold
+  let threshold = 0.5;
+  let attempts = 0;
+  const maxAttempts = 3;
+  
+  while (lakeCenters.length < 2 && attempts < maxAttempts) {
+    lakeCenters.length = 0; // Reset for this attempt
+    
+    for (let y = minDistFromEdge; y < size - minDistFromEdge; y++) {
+      for (let x = minDistFromEdge; x < size - minDistFromEdge; x++) {
+        const noiseVal = lakeNoise(x, y);
+        
+        // Check if this is a good lake center (low noise value)
+        if (noiseVal < threshold) {
+          // Check distance from other lake centers
+          let tooClose = false;
+          for (const center of lakeCenters) {
+            const dist = Math.sqrt((x - center.x) ** 2 + (y - center.y) ** 2);
+            if (dist < minDistBetweenLakes) {
+              tooClose = true;
+              break;
+            }
+          }
+          
+          if (!tooClose) {
+            lakeCenters.push({ x, y, noise: noiseVal });
+          }
+        }
+      }
+    }
+    
+    // If we found enough centers, break
+    if (lakeCenters.length >= 2) break;
+    
+    // Otherwise, relax the threshold for next attempt
+    threshold += 0.1;
+    attempts++;
+  }
+  
+  // If still no centers found, force create at least 2 lakes at strategic positions
+  if (lakeCenters.length === 0) {
+    const safeZone = minDistFromEdge + 5;
+    const quarterSize = Math.max(safeZone, Math.floor(size / 4));
+    const threeQuarterSize = Math.min(size - safeZone, Math.floor(size * 3 / 4));
+    lakeCenters.push(
+      { x: quarterSize, y: quarterSize, noise: 0 },
+      { x: threeQuarterSize, y: threeQuarterSize, noise: 0 }
+    );
+  } else if (lakeCenters.length === 1) {
+    // If only one center found, add another at a safe distance
+    const existing = lakeCenters[0];
+    const safeZone = minDistFromEdge + 5;
+    const quarterSize = Math.max(safeZone, Math.floor(size / 4));
+    const threeQuarterSize = Math.min(size - safeZone, Math.floor(size * 3 / 4));
+    const newX = existing.x > size / 2 ? quarterSize : threeQuarterSize;
+    const newY = existing.y > size / 2 ? quarterSize : threeQuarterSize;
+    lakeCenters.push({ x: newX, y: newY, noise: 0 });
+  }
+  
+  // Sort by noise value (lowest first) and pick 2-3 best candidates
+  lakeCenters.sort((a, b) => a.noise - b.noise);
+  const numLakes = 2 + Math.floor(random() * 2); // 2 or 3 lakes
+  const selectedCenters = lakeCenters.slice(0, Math.min(numLakes, lakeCenters.length));
+  
+  // Grow lakes from each center using radial expansion for rounder shapes
+  for (const center of selectedCenters) {
+    // Target size: 40-80 tiles for bigger lakes
+    const targetSize = 40 + Math.floor(random() * 41);
+    const lakeTiles: { x: number; y: number }[] = [{ x: center.x, y: center.y }];
+    const candidates: { x: number; y: number; dist: number; noise: number }[] = [];
+    
+    // Add initial neighbors as candidates
+    const directions = [[-1, 0], [1, 0], [0, -1], [0, 1], [-1, -1], [-1, 1], [1, -1], [1, 1]];
+    for (const [dx, dy] of directions) {
+      const nx = center.x + dx;
+      const ny = center.y + dy;
+      if (nx >= minDistFromEdge && nx < size - minDistFromEdge && 
+          ny >= minDistFromEdge && ny < size - minDistFromEdge) {
+        const dist = Math.sqrt(dx * dx + dy * dy);
+        const noise = lakeNoise(nx, ny);
+        candidates.push({ x: nx, y: ny, dist, noise });
+      }
+    }
+    
+    // Grow lake by adding adjacent tiles, prioritizing:
+    // 1. Closer to center (for rounder shape)
+    // 2. Lower noise values (for organic shape)
+    while (lakeTiles.length < targetSize && candidates.length > 0) {
+      // Sort by distance from center first, then noise
+      candidates.sort((a, b) => {
+        if (Math.abs(a.dist - b.dist) < 0.5) {
+          return a.noise - b.noise;
+        }
+        return a.dist - b.dist;
+      });
+      
+      // Pick from top candidates (closest/lowest noise)
+      const pickIndex = Math.floor(random() * Math.min(5, candidates.length));
+      const picked = candidates.splice(pickIndex, 1)[0];
+      
+      // Check if already in lake
+      if (lakeTiles.some(t => t.x === picked.x && t.y === picked.y)) continue;
+      
+      // Check if tile is valid (not already water from another lake)
+      if (grid[picked.y][picked.x].building.type === 'water') continue;
+      
+      lakeTiles.push({ x: picked.x, y: picked.y });
+      
+      // Add new neighbors as candidates
+      for (const [dx, dy] of directions) {
+        const nx = picked.x + dx;
+        const ny = picked.y + dy;
+        if (nx >= minDistFromEdge && nx < size - minDistFromEdge && 
+            ny >= minDistFromEdge && ny < size - minDistFromEdge &&
+            !lakeTiles.some(t => t.x === nx && t.y === ny) &&
+            !candidates.some(c => c.x === nx && c.y === ny)) {
+          const dist = Math.sqrt((nx - center.x) ** 2 + (ny - center.y) ** 2);
+          const noise = lakeNoise(nx, ny);
+          candidates.push({ x: nx, y: ny, dist, noise });
+        }
+      }
+    }
+    
+    // Apply lake tiles to grid
+    for (const tile of lakeTiles) {
+      grid[tile.y][tile.x].terrain = 'water';
+      grid[tile.y][tile.x].building = { ...createEmptyBuilding(), type: 'water' };
+    }
+  }
+}
+
+// =============================================================================
+// PARK STATE HELPERS
+// =============================================================================
+
+export function generateUUID(): string {
+  return 'xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx'.replace(/[xy]/g, function(c) {
+    const r = Math.random() * 16 | 0;
+    const v = c === 'x' ? r : (r & 0x3 | 0x8);
+    return v.toString(16);
+  });
+}
+
+/**
+ * Return a copy of the state with a notification prepended (newest first, max 50)
+ */
+export function pushNotification(
+  state: GameState,
+  title: string,
+  description: string,
+  icon: Notification['icon'],
+  tile?: { x: number; y: number }
+): GameState {
+  const notification: Notification = {
+    id: generateUUID(),
+    title,
+    description,
+    icon,
+    timestamp: Date.now(),
+    tileX: tile?.x,
+    tileY: tile?.y,
+  };
+  return { ...state, notifications: [notification, ...state.notifications].slice(0, 50) };
+}
+
+/**
+ * Return a copy of the state with events appended to the pending queue (oldest dropped past the cap)
+ */
+export function pushEvents(state: GameState, events: GameEvent[]): GameState {
+  if (events.length === 0) return state;
+  return { ...state, events: [...(state.events ?? []), ...events].slice(-MAX_PENDING_EVENTS) };
+}
+
+/**
+ * Warn when cash runs low and track how long the park has been in debt.
+ * A brief dip below zero is harmless; staying negative for the grace period
+ * (or sinking past the cash floor with no loan left to draw on) marks the park
+ * bankrupt, which blocks construction and new guests until cash recovers.
+ */
+function updateDebtStatus(state: GameState, previousCash: number, dayChanged: boolean): GameState {
+  const { cash } = state.finances;
+  let next = state;
+
+  if (previousCash >= LOW_CASH_THRESHOLD && cash < LOW_CASH_THRESHOLD) {
+    next = pushNotification(next, 'Low on Cash', `The park has only $${Math.floor(cash).toLocaleString()} left.`, 'money');
+  }
+
+  const daysInDebt = cash >= 0 ? 0 : next.finances.daysInDebt + (dayChanged ? 1 : 0);
+  let bankrupt = next.finances.bankrupt;
+  const outOfCredit = cash < BANKRUPTCY_CASH_FLOOR && next.finances.loan >= LOAN_CEILING;
+  if (!bankrupt && outOfCredit) {
+    bankrupt = true;
+    next = pushNotification(next, 'Park Bankrupt', 'The park is deep in debt and the bank won\'t lend any more. Construction and guest arrivals are halted until cash recovers.', 'error');
+    next = pushEvents(next, [{ type: 'bankrupt', tick: next.tick }]);
+  } else if (!bankrupt && daysInDebt >= next.settings.bankruptcyGraceDays) {
+    bankrupt = true;
+    next = pushNotification(next, 'Park Bankrupt', 'The park has been in debt too long. Construction and guest arrivals are halted until cash recovers. A loan can help cover the gap.', 'error');
+    next = pushEvents(next, [{ type: 'bankrupt', tick: next.tick }]);
+  } else if (bankrupt && cash >= 0) {
+    bankrupt = false;
+    next = pushNotification(next, 'Out of Debt', 'Cash has recovered and the park is back in business.', 'success');
+  }
+
+  if (daysInDebt === next.finances.daysInDebt && bankrupt === next.finances.bankrupt) return next;
+  return { ...next, finances: { ...next.finances, daysInDebt, bankrupt } };
+}
+
+export function calculateMonthlyUpkeep(grid: Tile[][]): { upkeep: number; buildingCount: number; rideCount: number; trackCount: number } {
+  let buildingCount = 0;
+  let rideCount = 0;
+  let trackCount = 0;
+  let buildingUpkeep = 0;
+  
+  for (const row of grid) {
+    for (const tile of row) {
+      if (tile.trackPiece) trackCount += 1;
+      const type = tile.building?.type;
+      // Multi-tile buildings are charged once, at their origin tile
+      if (!type || type === 'empty' || type === 'grass' || type === 'water' || type === 'path' || type === 'queue' || type.endsWith('_footprint')) {
+        continue;
+      }
+      buildingCount += 1;
+      if (isRideBuilding(type)) rideCount += 1;
+      buildingUpkeep += getBuildingUpkeep(type);
+    }
+  }
+  
+  const upkeep = buildingUpkeep + trackCount * 2;
+  return { upkeep, buildingCount, rideCount, trackCount };
+}
+
+/**
+ * Take one item from a stall's stock (stalls missing from the map are full).
+ * Returns false when the stall has nothing left to sell.
+ */
+function takeStallStock(stock: Record<string, number>, buildingId: string | null): boolean {
+  if (!buildingId) return true;
+  const remaining = stock[buildingId] ?? STALL_STOCK_CAPACITY;
+  if (remaining <= 0) return false;
+  stock[buildingId] = remaining - 1;
+  return true;
+}
+
+/**
+ * Hourly deliveries: every stall gets a few items back. Full stalls drop out
+ * of the map, as do entries for stalls that have since been demolished.
+ */
+function restockStalls(stock: Record<string, number>, grid: Tile[][]) {
+  for (const id of Object.keys(stock)) {
+    const [x, y] = id.split(',').map(Number);
+    const restocked = stock[id] + STALL_RESTOCK_PER_HOUR;
+    if (restocked >= STALL_STOCK_CAPACITY || !isStockedBuilding(grid[y]?.[x]?.building?.type)) {
+      delete stock[id];
+    } else {
+      stock[id] = restocked;
+    }
+  }
+}
+
+function calculateStaffWages(staff: Staff[]): number {
+  const wageMap: Record<Staff['type'], number> = {
+    handyman: DEFAULT_PRICES.handymanWage,
+    mechanic: DEFAULT_PRICES.mechanicWage,
+    security: DEFAULT_PRICES.securityWage,
+    entertainer: DEFAULT_PRICES.entertainerWage,
+  };
+  return staff.reduce((sum, member) => sum + (wageMap[member.type] ?? 0), 0);
+}
+
+// =============================================================================
+// COASTER TRACK AND TRAINS
+// =============================================================================
+
+/**
+ * Find the best station tile for a coaster - prioritizes tiles with adjacent queue lines
+ * Falls back to first track tile if no queue-adjacent tile is found
+ */
+export function findStationTile(
+  grid: Tile[][],
+  trackTiles: { x: number; y: number }[],
+  gridSize: number
+): { x: number; y: number } | null {
+  if (trackTiles.length === 0) return null;
+  
+  const adjacentOffsets = [
+    { dx: -1, dy: 0 },
+    { dx: 1, dy: 0 },
+    { dx: 0, dy: -1 },
+    { dx: 0, dy: 1 },
+  ];
+  
+  // First, look for a track tile with an adjacent queue
+  for (const trackTile of trackTiles) {
+    for (const { dx, dy } of adjacentOffsets) {
+      const adjX = trackTile.x + dx;
+      const adjY = trackTile.y + dy;
+      if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
+        const adjTile = grid[adjY]?.[adjX];
+        if (adjTile?.queue) {
+          return trackTile;
+        }
+      }
+    }
+  }
+  
+  // Second, look for a track tile with an adjacent station building
+  for (const trackTile of trackTiles) {
+    for (const { dx, dy } of adjacentOffsets) {
+      const adjX = trackTile.x + dx;
+      const adjY = trackTile.y + dy;
+      if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
+        const adjTile = grid[adjY]?.[adjX];
+        if (adjTile?.building?.type?.startsWith('station_')) {
+          return trackTile;
+        }
+      }
+    }
+  }
+  
+  // Fall back to first track tile
+  return trackTiles[0];
+}
+
+/**
+ * Calculate the correct direction for a straight track piece based on the actual tile flow.
+ * This fixes pieces that have incorrect stored directions.
+ */
+function calculateCorrectDirection(
+  prevTile: { x: number; y: number } | null,
+  currTile: { x: number; y: number },
+  nextTile: { x: number; y: number } | null,
+  piece: TrackPiece
+): TrackPiece {
+  const { type } = piece;
+  
+  // For turns, we need to calculate based on entry direction
+  if (type === 'turn_left_flat' || type === 'turn_right_flat' || type === 'turn_left_large_flat' || type === 'turn_right_large_flat') {
+    if (prevTile) {
+      // Calculate entry direction (where we came FROM)
+      const dx = currTile.x - prevTile.x;
+      const dy = currTile.y - prevTile.y;
+      
+      let entryDir: TrackDirection;
+      if (dx === 1 && dy === 0) entryDir = 'north';      // came from north (lower x)
+      else if (dx === -1 && dy === 0) entryDir = 'south'; // came from south (higher x)
+      else if (dx === 0 && dy === 1) entryDir = 'east';   // came from east (lower y)
+      else if (dx === 0 && dy === -1) entryDir = 'west';  // came from west (higher y)
+      else return piece; // Can't determine, keep original
+      
+      // For turns, direction field = entry direction
+      if (piece.direction !== entryDir) {
+        return { ...piece, direction: entryDir };
+      }
+    }
+    return piece;
+  }
+  
+  // For straights, slopes, and loops, calculate based on exit direction
+  if (nextTile) {
+    const dx = nextTile.x - currTile.x;
+    const dy = nextTile.y - currTile.y;
+    
+    let exitDir: TrackDirection;
+    if (dx === 1 && dy === 0) exitDir = 'south';      // going to south (higher x)
+    else if (dx === -1 && dy === 0) exitDir = 'north'; // going to north (lower x)
+    else if (dx === 0 && dy === 1) exitDir = 'west';   // going to west (higher y)
+    else if (dx === 0 && dy === -1) exitDir = 'east';  // going to east (lower y)
+    else return piece; // Can't determine, keep original
+    
+    // For straights and slopes, direction field = exit/travel direction
+    if (piece.direction !== exitDir) {
+      // Check if direction is being flipped 180 degrees (not rotated 90 degrees)
+      const isDirectionFlipped = 
+        (piece.direction === 'north' && exitDir === 'south') ||
+        (piece.direction === 'south' && exitDir === 'north') ||
+        (piece.direction === 'east' && exitDir === 'west') ||
+        (piece.direction === 'west' && exitDir === 'east');
+      
+      // For slopes, if direction is flipped, swap startHeight and endHeight
+      // This ensures the train goes from the correct height to the correct height
+      // based on which edge it enters/exits
+      if (isDirectionFlipped && (type === 'slope_up_small' || type === 'slope_down_small')) {
+        return { 
+          ...piece, 
+          direction: exitDir,
+          startHeight: piece.endHeight,
+          endHeight: piece.startHeight,
+        };
+      }
+      
+      return { ...piece, direction: exitDir };
+    }
+  }
+  
+  return piece;
+}
+
+/**
+ * Collect a single connected component of track tiles starting from a given tile.
+ * Returns tiles in connected order following track connections.
+ * Also fixes track piece directions to match the actual flow.
+ */
+export function collectConnectedTrack(
+  grid: Tile[][],
+  startX: number,
+  startY: number,
+  visited: Set<string>
+): { tiles: { x: number; y: number }[]; pieces: TrackPiece[] } {
+  const gridSize = grid.length;
+  const orderedTiles: { x: number; y: number }[] = [];
+  const collectedPieces: TrackPiece[] = [];
+  
+  const startTile = grid[startY]?.[startX];
+  if (!startTile?.trackPiece) {
+    return { tiles: [], pieces: [] };
+  }
+  
+  let current: { x: number; y: number; piece: TrackPiece } = {
+    x: startX,
+    y: startY,
+    piece: startTile.trackPiece,
+  };
+  
+  while (current && !visited.has(`${current.x},${current.y}`)) {
+    visited.add(`${current.x},${current.y}`);
+    orderedTiles.push({ x: current.x, y: current.y });
+    collectedPieces.push(current.piece);
+    
+    // Find the next tile based on exit direction
+    const exitDir = getExitDirection(current.piece);
+    const offset = getDirectionOffset(exitDir);
+    const nx = current.x + offset.dx;
+    const ny = current.y + offset.dy;
+    const key = `${nx},${ny}`;
+    
+    let found = false;
+    
+    // First priority: tile in our exit direction
+    if (!visited.has(key) && nx >= 0 && ny >= 0 && nx < gridSize && ny < gridSize) {
+      const nextTile = grid[ny]?.[nx];
+      if (nextTile?.trackPiece) {
+        current = { x: nx, y: ny, piece: nextTile.trackPiece };
+        found = true;
+      }
+    }
+    
+    // Fallback: try all adjacent unvisited tiles (for legacy tracks with imperfect directions)
+    if (!found) {
+      const adjacentOffsets = [
+        { dx: 1, dy: 0 }, { dx: -1, dy: 0 },
+        { dx: 0, dy: 1 }, { dx: 0, dy: -1 },
+      ];
+      
+      for (const { dx, dy } of adjacentOffsets) {
+        const adjX = current.x + dx;
+        const adjY = current.y + dy;
+        const adjKey = `${adjX},${adjY}`;
+        
+        if (!visited.has(adjKey) && adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
+          const adjTile = grid[adjY]?.[adjX];
+          if (adjTile?.trackPiece) {
+            current = { x: adjX, y: adjY, piece: adjTile.trackPiece };
+            found = true;
+            break;
+          }
+        }
+      }
+    }
+    
+    if (!found) break;
+  }
+  
+  // Now fix the directions of all collected pieces to match the actual flow
+  const orderedPieces: TrackPiece[] = collectedPieces.map((piece, i) => {
+    const prevTile = i > 0 ? orderedTiles[i - 1] : 
+                     (orderedTiles.length > 1 ? orderedTiles[orderedTiles.length - 1] : null);
+    const currTile = orderedTiles[i];
+    const nextTile = i < orderedTiles.length - 1 ? orderedTiles[i + 1] :
+                     (orderedTiles.length > 1 ? orderedTiles[0] : null);
+    
+    return calculateCorrectDirection(prevTile, currTile, nextTile, piece);
+  });
+  
+  return { tiles: orderedTiles, pieces: orderedPieces };
+}
+
+/**
+ * Whether two track pieces ride the same (shape, heights, banking, lift and boost)
+ */
+function isSameTrackPiece(a: TrackPiece, b: TrackPiece | undefined): boolean {
+  if (!b) return false;
+  return a.type === b.type && a.direction === b.direction &&
+    a.startHeight === b.startHeight && a.endHeight === b.endHeight &&
+    a.bankAngle === b.bankAngle && a.chainLift === b.chainLift && a.boosted === b.boosted;
+}
+
+/**
+ * Average slope under a train's cars (height change per piece) and whether
+ * any car is on a chain lift or booster
+ */
+function getTrainTrackForces(
+  cars: CoasterCar[],
+  track: TrackPiece[],
+  trackLength: number
+): { slope: number; onChain: boolean; boosted: boolean } {
+  let slope = 0;
+  let onChain = false;
+  let boosted = false;
+  for (const car of cars) {
+    const index = Math.floor(((car.trackProgress % trackLength) + trackLength) % trackLength);
+    const piece = track[index];
+    if (!piece) continue;
+    slope += piece.endHeight - piece.startHeight;
+    if (piece.chainLift || piece.type.startsWith('lift_hill')) onChain = true;
+    if (piece.boosted) boosted = true;
+  }
+  return { slope: cars.length > 0 ? slope / cars.length : 0, onChain, boosted };
+}
+
+/**
+ * Ratings for a coaster's current layout. Unfinished circuits can't be ridden,
+ * so they rate zero until the loop is closed.
+ */
+export function rateCoasterTrack(
+  tiles: { x: number; y: number }[],
+  pieces: TrackPiece[],
+  coasterType: CoasterType
+): CoasterRatings {
+  if (!isTrackComplete(tiles, pieces)) {
+    return { excitement: 0, intensity: 0, nausea: 0 };
+  }
+  return calculateCoasterRatings(pieces, coasterType);
+}
+
+/**
+ * Collect all track tiles for a coaster from the grid.
+ * Returns tiles in connected order following the track direction.
+ */
+export function collectCoasterTrack(grid: Tile[][], coasterId: string): { tiles: { x: number; y: number }[]; pieces: TrackPiece[] } {
+  const gridSize = grid.length;
+
+  // First, find all tiles with this coaster ID
+  const coasterTiles: { x: number; y: number }[] = [];
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      const tile = grid[y][x];
+      if (tile.coasterTrackId === coasterId && tile.trackPiece) {
+        coasterTiles.push({ x, y });
+      }
+    }
+  }
+  
+  if (coasterTiles.length === 0) {
+    return { tiles: [], pieces: [] };
+  }
+  
+  // Try to find the best starting tile:
+  // 1. Prefer a tile with an adjacent queue (station area)
+  // 2. Prefer a tile with an adjacent station building
+  // 3. Fall back to first tile found
+  const adjacentOffsets = [
+    { dx: -1, dy: 0 },
+    { dx: 1, dy: 0 },
+    { dx: 0, dy: -1 },
+    { dx: 0, dy: 1 },
+  ];
+  
+  let startTile = coasterTiles[0];
+  
+  // Look for a tile with adjacent queue
+  for (const { x, y } of coasterTiles) {
+    let hasAdjacentQueue = false;
+    for (const { dx, dy } of adjacentOffsets) {
+      const adjX = x + dx;
+      const adjY = y + dy;
+      if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
+        const adjTile = grid[adjY]?.[adjX];
+        if (adjTile?.queue) {
+          hasAdjacentQueue = true;
+          break;
+        }
+      }
+    }
+    if (hasAdjacentQueue) {
+      startTile = { x, y };
+      break;
+    }
+  }
+  
+  // If no queue found, look for adjacent station building
+  if (startTile === coasterTiles[0]) {
+    for (const { x, y } of coasterTiles) {
+      let hasAdjacentStation = false;
+      for (const { dx, dy } of adjacentOffsets) {
+        const adjX = x + dx;
+        const adjY = y + dy;
+        if (adjX >= 0 && adjY >= 0 && adjX < gridSize && adjY < gridSize) {
+          const adjTile = grid[adjY]?.[adjX];
+          if (adjTile?.building?.type?.startsWith('station_')) {
+            hasAdjacentStation = true;
+            break;
+          }
+        }
+      }
+      if (hasAdjacentStation) {
+        startTile = { x, y };
+        break;
+      }
+    }
+  }
+
+  const visited = new Set<string>();
+  const result = collectConnectedTrack(grid, startTile.x, startTile.y, visited);
+  
+  // Check if track needs to be reversed based on slope directions
+  // For slopes, the original piece's direction and heights define "correct" travel direction
+  // If we're traveling through most slopes in the wrong direction, reverse the whole track
+  if (result.tiles.length >= 4) {
+    let slopesCorrect = 0;  // We travel in the direction that matches original piece
+    let slopesReversed = 0; // We travel opposite to original piece direction
+    
+    for (let i = 0; i < result.tiles.length; i++) {
+      const tile = result.tiles[i];
+      const originalPiece = grid[tile.y]?.[tile.x]?.trackPiece;
+      
+      // Only check slope pieces - they have clear directionality
+      if (originalPiece && (originalPiece.type === 'slope_up_small' || originalPiece.type === 'slope_down_small')) {
+        // Get the next tile to determine our travel direction through this piece
+        const nextIdx = (i + 1) % result.tiles.length;
+        if (nextIdx === 0 && i === result.tiles.length - 1) {
+          // Last tile wrapping to first - only valid for closed loops
+          // Skip this check if track might not be a closed loop
+          continue;
+        }
+        const nextTile = result.tiles[nextIdx];
+        const travelDx = nextTile.x - tile.x;
+        const travelDy = nextTile.y - tile.y;
+        
+        // Get the original piece's exit direction (where train SHOULD exit for correct travel)
+        const originalExitDir = getExitDirection(originalPiece);
+        const originalExitOffset = getDirectionOffset(originalExitDir);
+        
+        // Check if we're traveling in the original's intended direction
+        if (travelDx === originalExitOffset.dx && travelDy === originalExitOffset.dy) {
+          slopesCorrect++;
+        } else {
+          slopesReversed++;
+        }
+      }
+    }
+    
+    // If more slopes are reversed than correct, we need to reverse the whole track
+    const needsReverse = slopesReversed > slopesCorrect;
+    
+    if (needsReverse) {
+      // Reverse the track so slopes are traversed correctly
+      // Use the ORIGINAL pieces from the grid and re-correct them for the new direction
+      const reversedTiles = [...result.tiles].reverse();
+      
+      // Get original pieces from grid in reversed order
+      const originalPiecesReversed = reversedTiles.map(tile => {
+        const origPiece = grid[tile.y]?.[tile.x]?.trackPiece;
+        if (!origPiece) throw new Error('Missing track piece');
+        return { ...origPiece }; // Clone to avoid mutation
+      });
+      
+      // Now correct each piece's direction for the new (reversed) travel order
+      const reversedPieces = originalPiecesReversed.map((piece, i) => {
+        const prevTile = i > 0 ? reversedTiles[i - 1] : 
+                         (reversedTiles.length > 1 ? reversedTiles[reversedTiles.length - 1] : null);
+        const currTile = reversedTiles[i];
+        const nextTile = i < reversedTiles.length - 1 ? reversedTiles[i + 1] :
+                         (reversedTiles.length > 1 ? reversedTiles[0] : null);
+        
+        return calculateCorrectDirection(prevTile, currTile, nextTile, piece);
+      });
+      
+      return { tiles: reversedTiles, pieces: reversedPieces };
+    }
+  }
+  
+  return result;
+}
+
+// Configuration for train creation
+interface TrainConfig {
+  numCars?: number;
+  carSpacing?: number;
+  startProgress?: number;
+  guestsPerCar?: number;
+}
+
+function createDefaultTrain(config: TrainConfig = {}): CoasterTrain {
+  const numCars = config.numCars ?? 6;
+  const carSpacing = config.carSpacing ?? DEFAULT_CAR_SPACING; // Spacing between cars
+  const startProgress = config.startProgress ?? 0;
+  const guestsPerCar = config.guestsPerCar ?? 4;
+  const baseVelocity = 0.06;
+  
+  const cars: CoasterCar[] = [];
+  for (let i = 0; i < numCars; i++) {
+    cars.push({
+      trackProgress: startProgress + i * carSpacing,
+      velocity: baseVelocity,
+      rotation: { pitch: 0, yaw: 0, roll: 0 },
+      screenX: 0,
+      screenY: 0,
+      screenZ: 0,
+      guests: [], // Will be filled during loading
+    });
+  }
+
+  return {
+    id: generateUUID(),
+    cars,
+    state: 'loading', // Start in loading state at station
+    stateTimer: 5 + simulationRandom() * 3, // 5-8 seconds loading time
+  };
+}
+
+/**
+ * Create multiple trains for a coaster based on track length and coaster type
+ * Uses the coaster type stats to determine min/max trains and scales by track length,
+ * unless the player has configured a train layout for the coaster
+ */
+export function createTrainsForCoaster(
+  trackLength: number,
+  coasterType: string = 'steel_sit_down',
+  trainConfig?: CoasterTrainConfig
+): CoasterTrain[] {
+  if (trackLength === 0) return [createDefaultTrain({ numCars: trainConfig?.carsPerTrain, carSpacing: trainConfig?.carSpacing })];
+  
+  if (trainConfig) {
+    // Drop trains that no longer fit if the track was shortened after configuring
+    const perTrain = (trainConfig.carsPerTrain - 1) * trainConfig.carSpacing + MIN_TRAIN_GAP;
+    const numTrains = Math.max(1, Math.min(trainConfig.numTrains, Math.floor(trackLength / perTrain)));
+    const trains: CoasterTrain[] = [];
+    for (let i = 0; i < numTrains; i++) {
+      const train = createDefaultTrain({
+        startProgress: (i * trackLength) / numTrains,
+        numCars: trainConfig.carsPerTrain,
+        carSpacing: trainConfig.carSpacing,
+      });
+      train.state = i === 0 ? 'loading' : 'running';
+      train.stateTimer = i === 0 ? (5 + simulationRandom() * 3) : 0;
+      trains.push(train);
+    }
+    return trains;
+  }
+  
+  // Get coaster type stats for train limits
+  const typeStats = COASTER_TYPE_STATS[coasterType as CoasterType];
+  const minTrains = typeStats?.trainsPerTrack?.min ?? 1;
+  const maxTrains = typeStats?.trainsPerTrack?.max ?? 3;
+  const minCars = typeStats?.trainLength?.min ?? 4;
+  const maxCars = typeStats?.trainLength?.max ?? 8;
+  
+  // Calculate number of trains based on track length
+  // Scale linearly from min at 8 tiles to max at 30+ tiles
+  let numTrains: number;
+  if (trackLength <= 8) {
+    numTrains = minTrains;
+  } else if (trackLength >= 30) {
+    numTrains = maxTrains;
+  } else {
+    // Linear interpolation between min and max
+    const t = (trackLength - 8) / (30 - 8);
+    numTrains = Math.round(minTrains + t * (maxTrains - minTrains));
+  }
+  
+  // Clamp to coaster type limits
+  numTrains = Math.max(minTrains, Math.min(maxTrains, numTrains));
+  
+  // Ensure minimum spacing between trains (at least 12 tiles per train for safety)
+  const minSpacingPerTrain = 12;
+  const maxTrainsForSpacing = Math.max(1, Math.floor(trackLength / minSpacingPerTrain));
+  numTrains = Math.min(numTrains, maxTrainsForSpacing);
+  
+  // Calculate cars per train based on track length (longer tracks = longer trains)
+  // Use smaller trains for better visual appearance
+  let numCars: number;
+  if (trackLength <= 15) {
+    numCars = Math.max(2, minCars - 2); // Smaller trains for short tracks
+  } else if (trackLength >= 50) {
+    numCars = Math.min(6, maxCars); // Cap at 6 cars even for long tracks
+  } else {
+    const t = (trackLength - 15) / (50 - 15);
+    numCars = Math.round(2 + t * 4); // Scale from 2 to 6 cars
+  }
+  numCars = Math.max(2, Math.min(6, numCars));
+  
+  const trains: CoasterTrain[] = [];
+  for (let i = 0; i < numTrains; i++) {
+    // Space trains evenly around the track
+    const startProgress = (i * trackLength) / numTrains;
+    const train = createDefaultTrain({ 
+      startProgress,
+      numCars,
+    });
+    // First train starts loading, others running
+    train.state = i === 0 ? 'loading' : 'running';
+    train.stateTimer = i === 0 ? (5 + simulationRandom() * 3) : 0; // 5-8 second stop at station for loading
+    trains.push(train);
+  }
+  
+  return trains;
+}
+
+/**
+ * Create trains for a coaster spaced evenly around the circuit, starting from the station
+ */
+export function createTrainsAtStation(
+  trackLength: number,
+  stationIndex: number,
+  coasterType: string,
+  trainConfig?: CoasterTrainConfig
+): CoasterTrain[] {
+  const trains = createTrainsForCoaster(trackLength, coasterType, trainConfig);
+  if (trackLength === 0) return trains;
+  const carSpacing = trainConfig?.carSpacing ?? DEFAULT_CAR_SPACING;
+  return trains.map((train, trainIndex) => {
+    const trainOffset = (trainIndex * trackLength) / Math.max(1, trains.length);
+    const baseProgress = (stationIndex + trainOffset) % trackLength;
+    return {
+      ...train,
+      cars: train.cars.map((car, carIndex) => ({
+        ...car,
+        trackProgress: (baseProgress + carIndex * carSpacing) % trackLength,
+      })),
+    };
+  });
+}
+
+// =============================================================================
+// INITIAL STATE
+// =============================================================================
+
+export function createInitialCoasterGameState(
+  parkName: string = 'My Theme Park',
+  gridSize: number = DEFAULT_GRID_SIZE,
+  seed: number = randomSeed()
+): GameState {
+  // Weather and everything else random at startup draw from the park's own seed
+  seedSimulationRandom(seed);
+  
+  // Create empty grid
+  const grid: Tile[][] = [];
+  for (let y = 0; y < gridSize; y++) {
+    const row: Tile[] = [];
+    for (let x = 0; x < gridSize; x++) {
+      row.push(createEmptyTile(x, y));
+    }
+    grid.push(row);
+  }
+  
+  // Generate random lakes using procedural terrain generation (2-3 lakes)
+  generateLakes(grid, gridSize, seed);
+  
+  const weather = createInitialWeather(3); // March - spring opening
+  
+  return {
+    id: generateUUID(),
+    seed,
+    rngState: getSimulationRandomState(),
+    
+    grid,
+    gridSize,
+    
+    year: 1,
+    month: 3, // March - spring opening
+    day: 1,
+    hour: 8,
+    minute: 0,
+    tick: 0,
+    speed: 1,
+    
+    settings: {
+      name: parkName,
+      entranceFee: DEFAULT_PRICES.parkEntrance,
+      payPerRide: false,
+      openHour: DEFAULT_OPEN_HOUR,
+      closeHour: DEFAULT_CLOSE_HOUR,
+      loanInterest: 0.1,
+      landCost: 100,
+      bankruptcyGraceDays: DEFAULT_BANKRUPTCY_GRACE_DAYS,
+      autosaveIntervalHours: DEFAULT_AUTOSAVE_INTERVAL_HOURS,
+      maxGuests: DEFAULT_MAX_GUESTS,
+      spawnRateMultiplier: DEFAULT_SPAWN_RATE_MULTIPLIER,
+      objectives: [],
+    },
+    
+    stats: {
+      guestsInPark: 0,
+      guestsTotal: 0,
+      guestsSatisfied: 0,
+      guestsUnsatisfied: 0,
+      averageHappiness: 0,
+      totalRides: 0,
+      totalRidesRidden: 0,
+      averageQueueTime: 0,
+      parkValue: 0,
+      companyValue: 10000,
+      parkRating: 0,
+      guestsDeparted: 0,
+      departedGuestSpending: 0,
+    },
+    
+    finances: {
+      cash: 10000,
+      incomeAdmissions: 0,
+      incomeRides: 0,
+      incomeFood: 0,
+      incomeShops: 0,
+      incomeTotal: 0,
+      expenseConstruction: 0,
+      expenseWages: 0,
+      expenseUpkeep: 0,
+      expenseMarketing: 0,
+      expenseResearch: 0,
+      expenseTotal: 0,
+      profit: 0,
+      history: [],
+      reports: [],
+      daysInDebt: 0,
+      bankrupt: false,
+      loan: 0,
+      loanMonthlyPayment: 0,
+    },
+    
+    guests: [],
+    staff: [],
+    coasters: [],
+    
+    // Weather
+    weather,
+    
+    selectedTool: 'select',
+    activePanel: 'none',
+    notifications: [],
+    
+    buildingCoasterId: null,
+    buildingCoasterPath: [],
+    buildingCoasterHeight: 0,
+    buildingCoasterLastDirection: null,
+    buildingCoasterType: null,
+    
+    gameVersion: GAME_VERSION,
+  };
+}
+
+// =============================================================================
+// SIMULATION TICK
+// =============================================================================
+
+let lastTickBreakdown: TickBreakdown = { guests: 0, spawns: 0, trains: 0, total: 0 };
+
+/**
+ * Advance the park by one simulation step: time and weather, guests, arrivals,
+ * staff, trains, finances and notifications. Nothing here touches React or the
+ * canvas, so the same step drives the provider and headless runs (tests,
+ * server-side simulation).
+ */
+export function simulateTick(prev: GameState): GameState {
+  const tickStart = performance.now();
+  // Replay this park's random stream (older saves pick up from their seed)
+  seedSimulationRandom(prev.rngState ?? prev.seed ?? randomSeed());
+  const newTick = prev.tick + 1;
+  let { minute, hour, day, month, year } = prev;
+  
+  // Time progression - slower during day to make daytime last longer
+  // During day (7-18): advance 0.25 minutes per tick (longer days)
+  // During night/dawn/dusk: advance 3 minutes per tick (faster to get through night)
+  const isDaytime = hour >= 7 && hour < 18;
+  const minuteIncrement = isDaytime ? 0.25 : 3; // Much slower during day, faster at night
+  
+  minute += minuteIncrement;
+  if (minute >= 60) {
+    minute = minute - 60;
+    hour += 1;
+    if (hour >= 24) {
+      hour = 0;
+      day += 1;
+      if (day > 30) {
+        day = 1;
+        month += 1;
+        if (month > 12) {
+          month = 1;
+          year += 1;
+        }
+      }
+    }
+  }
+  
+  // Update weather
+  const newWeather = simulateWeather(prev.weather, newTick, month);
+  const weatherEffects = WEATHER_EFFECTS[newWeather.current];
+  
+  // Update guests with weather effects
+  const deltaTime = 1; // 1 game minute per tick
+  const rideQueues = createRideQueueTracker(prev.guests);
+  const parkClosed = !isParkOpenAt(hour, prev.settings.openHour, prev.settings.closeHour);
+  const occupancy = getGuestOccupancy(prev.guests, prev.gridSize);
+  const groupLeaders = new Map(prev.guests.filter(guest => guest.groupId === guest.id).map(guest => [guest.id, guest]));
+  // Empty stalls turn guests away until the next delivery
+  const stallStock = { ...(prev.stallStock ?? {}) };
+  const soldOut = new Set(Object.keys(stallStock).filter(id => stallStock[id] <= 0));
+  const guestsStart = performance.now();
+  const updatedGuestsBase = prev.guests.map(guest =>
+    updateGuest(guest, prev.grid, deltaTime, prev.coasters, rideQueues, parkClosed, prev.settings.payPerRide, occupancy, groupLeaders, soldOut)
+  );
+  const guestsTime = performance.now() - guestsStart;
+  
+  // Tally guests who turned away from full lines
+  let rideStats = prev.rideStats;
+  if (rideQueues.balks.size > 0) {
+    rideStats = { ...prev.rideStats };
+    for (const [rideId, balks] of rideQueues.balks) {
+      rideStats[rideId] = { ...createRideStats(), ...rideStats[rideId], balks: (rideStats[rideId]?.balks ?? 0) + balks };
+    }
+  }
+  
+  // Theme zones only change when buildings do, so re-scan once per game hour
+  const themeZones = hour !== prev.hour || !prev.themeZones
+    ? findThemeZones(prev.grid, prev.gridSize)
+    : prev.themeZones;
+  
+  const isNight = isNightHour(hour);
+  const seasonalDecor = findSeasonalDecor(prev.grid);
+  
+  // Apply weather effects to guests and check if they want to leave
+  const updatedGuests = updatedGuestsBase.map(guest => {
+    let weatheredGuest = applyWeatherEffectsToGuest(
+      guest,
+      newWeather.current,
+      isWetWeather(newWeather.current) && isGuestSheltered(guest, prev.grid)
+    );
+    
+    // Guests out on unlit paths after dark grow uneasy; lamp light keeps them content
+    if (
+      isNight &&
+      (weatheredGuest.state === 'walking' || weatheredGuest.state === 'queuing') &&
+      !isTileLit(prev.grid, weatheredGuest.tileX, weatheredGuest.tileY)
+    ) {
+      weatheredGuest = {
+        ...weatheredGuest,
+        happiness: Math.max(0, weatheredGuest.happiness - DARKNESS_HAPPINESS_PENALTY),
+      };
+    }
+    
+    // Guests walking or queuing through a themed area enjoy the atmosphere
+    if (themeZones.length > 0 && (weatheredGuest.state === 'walking' || weatheredGuest.state === 'queuing')) {
+      const zoneStrength = getThemeZoneStrengthAt(themeZones, weatheredGuest.tileX, weatheredGuest.tileY);
+      if (zoneStrength > 0) {
+        weatheredGuest = {
+          ...weatheredGuest,
+          happiness: Math.min(100, weatheredGuest.happiness + zoneStrength * THEME_ZONE_HAPPINESS_BONUS),
+        };
+        // Now and then a strongly themed area earns a compliment
+        if (
+          zoneStrength >= SCENERY_THOUGHT_STRENGTH &&
+          simulationRandom() < SCENERY_THOUGHT_CHANCE &&
+          !weatheredGuest.thoughts.includes('scenery_beautiful')
+        ) {
+          addGuestThought(weatheredGuest, 'scenery_beautiful');
+        }
+      }
+    }
+    
+    // Holiday decor is a treat in its month; pumpkins in spring just look neglected
+    if (seasonalDecor.length > 0 && (weatheredGuest.state === 'walking' || weatheredGuest.state === 'queuing')) {
+      const seasonal = getSeasonalDecorEffectAt(seasonalDecor, month, weatheredGuest.tileX, weatheredGuest.tileY);
+      if (seasonal !== 0) {
+        const change = seasonal > 0 ? SEASONAL_DECOR_HAPPINESS_BONUS : -SEASONAL_DECOR_HAPPINESS_PENALTY;
+        weatheredGuest = {
+          ...weatheredGuest,
+          happiness: Math.max(0, Math.min(100, weatheredGuest.happiness + change)),
+        };
+      }
+    }
+    
+    // Check if guest decides to leave due to weather (rare), because they're out of money,
+    // or because they never found a restroom. Only affects walking guests who are already unhappy
+    if (weatheredGuest.state === 'walking' && (
+      shouldGuestLeaveForWeather(weatheredGuest, newWeather.current) ||
+      shouldGuestLeaveWhenBroke(weatheredGuest) ||
+      shouldGuestLeaveForBathroom(weatheredGuest)
+    )) {
+      return sendGuestHome(weatheredGuest, prev.grid);
+    }
+    
+    return weatheredGuest;
+  }); // Don't filter out guests here - let them leave naturally through the exit
+  
+  // Spawn guests (affected by weather); a bankrupt park lets nobody new in
+  const spawnsStart = performance.now();
+  const baseSpawnedGuests = prev.finances.bankrupt ? [] : spawnGuests(prev.grid, updatedGuests, prev.stats.parkRating, hour, prev.settings.maxGuests, prev.settings.spawnRateMultiplier, prev.settings.openHour, prev.settings.closeHour);
+  const spawnsTime = performance.now() - spawnsStart;
+
+  // Apply weather spawn multiplier probabilistically
+  // Since spawns are typically 0-1 parties, we need to treat the multiplier as a probability
+  // e.g., multiplier of 0.85 means 85% chance to keep each spawned party
+  const entranceFee = prev.settings.payPerRide ? 0 : prev.settings.entranceFee;
+  // Parties arrive or turn back together: the weather roll and the leader's view of
+  // the entrance fee decide for everyone, so no follower enters without their leader
+  const admittedParties = new Set<string>();
+  for (const guest of baseSpawnedGuests) {
+    const partyId = guest.groupId ?? guest.id;
+    if (partyId !== guest.id) continue; // Followers go wherever their leader does
+    if (simulationRandom() < weatherEffects.guestSpawnMultiplier && willPayEntranceFee(guest, entranceFee)) {
+      admittedParties.add(partyId);
+    }
+  }
+  const spawnedGuestsRaw = baseSpawnedGuests.filter(guest => admittedParties.has(guest.groupId ?? guest.id));
+  const admissionRevenue = spawnedGuestsRaw.reduce((sum, guest) => sum + Math.min(guest.cash, entranceFee), 0);
+  // Money taken in this tick, summed per tile ("x,y") for the floating popups
+  const paymentsByTile = new Map<string, number>();
+  const notePayment = (tileId: string | null | undefined, fee: number) => {
+    if (!tileId || fee <= 0) return;
+    paymentsByTile.set(tileId, (paymentsByTile.get(tileId) ?? 0) + fee);
+  };
+  const spawnedGuests = spawnedGuestsRaw.map(guest => {
+    const fee = Math.min(guest.cash, entranceFee);
+    notePayment(`${guest.tileX},${guest.tileY}`, fee);
+    return {
+      ...guest,
+      cash: guest.cash - fee,
+      totalSpent: guest.totalSpent + fee,
+    };
+  });
+
+  let rideRevenue = 0;
+  let foodRevenue = 0;
+  let shopRevenue = 0;
+  let rideCompletions = 0;
+  const ridersFinished = new Map<string, number>();
+  const events: GameEvent[] = [];
+  let guests = updatedGuests.map(guest => {
+    let nextGuest = guest;
+
+    if (guest.state === 'eating' && guest.lastState !== 'eating') {
+      // Drink stands charge drink prices, everything else sells meals
+      const [stallX, stallY] = (guest.targetBuildingId ?? '').split(',').map(Number);
+      const stallProfile = getFoodProfile(prev.grid[stallY]?.[stallX]?.building?.type ?? '');
+      const price = stallProfile.thirst > stallProfile.hunger
+        ? getWeatherAdjustedPrice(DEFAULT_PRICES.drinkItem, newWeather.current, 'drink')
+        : getWeatherAdjustedPrice(DEFAULT_PRICES.foodItem, newWeather.current, 'food');
+      // A stall that sold its last item this tick has nothing to charge for
+      const fee = takeStallStock(stallStock, guest.targetBuildingId) ? Math.min(nextGuest.cash, price) : 0;
+      if (fee > 0) {
+        foodRevenue += fee;
+        notePayment(guest.targetBuildingId, fee);
+        nextGuest = { ...nextGuest, cash: nextGuest.cash - fee, totalSpent: nextGuest.totalSpent + fee };
+      }
+    }
+
+    // Restrooms are free to use
+    if (guest.state === 'shopping' && guest.lastState !== 'shopping' && guest.targetBuildingKind !== 'restroom') {
+      const fee = takeStallStock(stallStock, guest.targetBuildingId) ? Math.min(nextGuest.cash, DEFAULT_PRICES.shopItem) : 0;
+      if (fee > 0) {
+        shopRevenue += fee;
+        notePayment(guest.targetBuildingId, fee);
+        nextGuest = { ...nextGuest, cash: nextGuest.cash - fee, totalSpent: nextGuest.totalSpent + fee };
+      }
+    }
+
+    if (guest.state === 'walking' && guest.lastState === 'riding') {
+      rideCompletions += 1;
+      const rideId = guest.ridesRidden[guest.ridesRidden.length - 1];
+      if (rideId) ridersFinished.set(rideId, (ridersFinished.get(rideId) ?? 0) + 1);
+    }
+
+    return nextGuest;
+  }).concat(spawnedGuests);
+  
+  if (hour !== prev.hour) {
+    restockStalls(stallStock, prev.grid);
+  }
+
+  // Guests who reached an exit go home; keep a tally of what they spent
+  let guestsDeparted = prev.stats.guestsDeparted;
+  let departedGuestSpending = prev.stats.departedGuestSpending;
+  if (guests.some(guest => hasGuestLeftPark(guest, prev.grid))) {
+    guests = guests.filter(guest => {
+      if (!hasGuestLeftPark(guest, prev.grid)) return true;
+      guestsDeparted += 1;
+      departedGuestSpending += guest.totalSpent;
+      events.push({ type: 'guest_left', tick: newTick, guestId: guest.id, happy: guest.happiness > 40 });
+      return false;
+    });
+  }
+
+  
+  const guestsInPark = guests.length;
+  const guestsSatisfied = guests.filter(guest => guest.happiness >= 70).length;
+  const guestsUnsatisfied = guests.filter(guest => guest.happiness <= 40).length;
+  const avgHappiness = guestsInPark > 0
+    ? guests.reduce((sum, guest) => sum + guest.happiness, 0) / guestsInPark
+    : 0;
+  
+  // Janitors sweep the litter guests leave behind; dirty paths drag the rating down
+  const { staff, litter } = simulateStaff(prev.staff, guests, prev.grid, prev.litter ?? {});
+  const ratingBreakdown = calculateParkRating(prev.grid, prev.coasters, guestsInPark, avgHappiness, getLitterRatingPenalty(litter));
+  const parkRating = ratingBreakdown.total;
+
+  // Update coaster trains with state machine and station logic
+  // First, aggressively clean up coasters - recollect track from grid to get current state
+  const cleanedCoasters: Coaster[] = [];
+  for (const coaster of prev.coasters) {
+    // Always recollect from grid to ensure we have current data
+    const { tiles: currentTiles, pieces: currentPieces } = collectCoasterTrack(prev.grid, coaster.id);
+    
+    // If no valid track exists, skip this coaster entirely
+    if (currentTiles.length === 0) continue;
+    
+    // Check if track changed - if so, regenerate trains
+    const trackChanged = currentTiles.length !== coaster.trackTiles.length ||
+      currentTiles.some((t, i) => t.x !== coaster.trackTiles[i]?.x || t.y !== coaster.trackTiles[i]?.y);
+    
+    if (trackChanged) {
+      // Track changed - regenerate everything
+      const stationTile = findStationTile(prev.grid, currentTiles, prev.gridSize) || currentTiles[0];
+      const stationIdx = currentTiles.findIndex(t => t.x === stationTile.x && t.y === stationTile.y);
+      const effectiveStationIdx = stationIdx >= 0 ? stationIdx : 0;
+      
+      const newTrains = createTrainsAtStation(currentPieces.length, effectiveStationIdx, coaster.type, coaster.trainConfig);
+      
+      cleanedCoasters.push({
+        ...coaster,
+        track: currentPieces,
+        trackTiles: currentTiles,
+        stationTileX: stationTile.x,
+        stationTileY: stationTile.y,
+        trains: newTrains,
+        stalled: false,
+        ...rateCoasterTrack(currentTiles, currentPieces, coaster.type),
+      });
+    } else if (currentPieces.some((piece, i) => !isSameTrackPiece(piece, coaster.track[i]))) {
+      // Same tiles, different pieces (e.g. a chain lift added in place): trains
+      // carry on from where they are and a stalled coaster gets another try
+      cleanedCoasters.push({
+        ...coaster,
+        track: currentPieces,
+        stalled: false,
+        ...rateCoasterTrack(currentTiles, currentPieces, coaster.type),
+      });
+    } else {
+      cleanedCoasters.push(coaster);
+    }
+  }
+  
+  // Every ride's line in arrival order
+  const rideLines = collectRideLines(guests, rideStats);
+  const boardedGuestIds = new Set<string>();
+  
+  // Guests waiting at each coaster's station board trains from the front of the line;
+  // flat rides run on a fixed cycle and take a whole batch each dispatch
+  const waitingByCoaster = new Map<string, string[]>();
+  const dispatchTimers = new Map<string, number>();
+  for (const [rideId, line] of rideLines) {
+    const coasterId = findCoasterForRide(prev.grid, rideId, cleanedCoasters)?.id;
+    if (coasterId) {
+      waitingByCoaster.set(coasterId, [...(waitingByCoaster.get(coasterId) ?? []), ...line]);
+      continue;
+    }
+    const [rideX, rideY] = rideId.split(',').map(Number);
+    const building = prev.grid[rideY]?.[rideX]?.building;
+    // Coaster stations only ever board onto a loading train, never on a timer
+    if (!building || building.type.startsWith('station_')) continue;
+    let dispatchTimer = (rideStats?.[rideId]?.dispatchTimer ?? 0) - deltaTime;
+    if (dispatchTimer <= 0) {
+      line.slice(0, getRideCapacity(building)).forEach(guestId => boardedGuestIds.add(guestId));
+      dispatchTimer = RIDE_CYCLE_TIME;
+    }
+    dispatchTimers.set(rideId, dispatchTimer);
+  }
+  
+  const stalledCoasters: Coaster[] = [];
+  const trainsStart = performance.now();
+  const updatedCoasters = cleanedCoasters.map(coaster => {
+    if (coaster.track.length === 0 || coaster.trains.length === 0) return coaster;
+    // Closed coasters keep their trains parked at the station, stalled ones where they stopped
+    if (!coaster.operating || coaster.stalled) return coaster;
+    const trackLength = coaster.track.length;
+    
+    // Only run trains if the track forms a complete loop
+    const trackComplete = isTrackComplete(coaster.trackTiles, coaster.track);
+    if (!trackComplete) {
+      // Track is incomplete - reset all trains to proper positions at start of track
+      // This prevents "stuck" cars from appearing in random positions
+      const carSpacing = coaster.trainConfig?.carSpacing ?? DEFAULT_CAR_SPACING;
+      const resetTrains = coaster.trains.map((train, trainIndex) => {
+        const trainOffset = (trainIndex * trackLength) / Math.max(1, coaster.trains.length);
+        return {
+          ...train,
+          state: 'loading' as const,
+          stateTimer: 8,
+          cars: train.cars.map((car, carIndex) => ({
+            ...car,
+            trackProgress: (trainOffset + carIndex * carSpacing) % trackLength,
+            velocity: 0,
+          })),
+        };
+      });
+      return { ...coaster, trains: resetTrains };
+    }
+    
+    // Find station position - the tile with an adjacent queue
+    const stationIndex = coaster.trackTiles.findIndex(
+      t => t.x === coaster.stationTileX && t.y === coaster.stationTileY
+    );
+    const effectiveStationIndex = stationIndex >= 0 ? stationIndex : 0;
+    const stationRange = { min: effectiveStationIndex, max: effectiveStationIndex + 1.5 }; // Train is "at station" if lead car is in this range
+    
+    // Helper function to check if a position is within the station range (handles wrap-around)
+    const isPositionAtStation = (progress: number): boolean => {
+      const normalizedProgress = ((progress % trackLength) + trackLength) % trackLength;
+      // Check if within range directly
+      if (normalizedProgress >= stationRange.min && normalizedProgress <= stationRange.max) {
+        return true;
+      }
+      // Handle wrap-around case: station spans across track boundary (e.g., station at index 0)
+      if (stationRange.min < 2) {
+        // Check if we're at the end of the track, close enough to wrap to station
+        const distanceToStationViaWrap = trackLength - normalizedProgress + stationRange.min;
+        if (distanceToStationViaWrap <= 0.5 && distanceToStationViaWrap >= 0) {
+          return true;
+        }
+      }
+      return false;
+    };
+    
+    const dispatchPolicy = getDispatchPolicy(coaster);
+    const waitingGuests = waitingByCoaster.get(coaster.id) ?? [];
+    
+    // Block sections: the track pieces each train's cars occupy at the start of the tick
+    const sectionOwners = new Map<number, number>();
+    coaster.trains.forEach((train, idx) => {
+      for (const car of train.cars) {
+        sectionOwners.set(Math.floor(((car.trackProgress % trackLength) + trackLength) % trackLength), idx);
+      }
+    });
+    // A train may only leave the station once the block ahead of its front car is empty
+    const isBlockAheadClear = (trainIndex: number, frontProgress: number): boolean => {
+      for (let step = 1; step <= DISPATCH_BLOCK_LENGTH; step++) {
+        const owner = sectionOwners.get(Math.floor(frontProgress + step) % trackLength);
+        if (owner !== undefined && owner !== trainIndex) return false;
+      }
+      return true;
+    };
+    
+    const updatedTrains = coaster.trains.map((train, trainIndex) => {
+      let { state, stateTimer, cars } = train;
+      let loadingTime = train.loadingTime ?? 0;
+      let stallTimer = train.stallTimer ?? 0;
+      stateTimer -= deltaTime;
+      
+      const carSpacing = coaster.trainConfig?.carSpacing ?? DEFAULT_CAR_SPACING;
+      
+      // Validate all car positions - fix any invalid values
+      let hasInvalidCar = false;
+      for (const car of cars) {
+        if (!Number.isFinite(car.trackProgress) || car.trackProgress < 0 || car.trackProgress > trackLength * 10) {
+          hasInvalidCar = true;
+          break;
+        }
+      }
+      
+      if (hasInvalidCar) {
+        // Reset all cars to proper positions at station
+        cars = cars.map((car, idx) => ({
+          ...car,
+          trackProgress: (effectiveStationIndex + idx * carSpacing) % trackLength,
+          velocity: 0,
+        }));
+        state = 'loading';
+        stateTimer = 5 + simulationRandom() * 3; // 5-8 second stop at station
+      }
+      
+      // Get lead car's position
+      const leadCar = cars[0];
+      const leadProgress = leadCar.trackProgress % trackLength;
+      const isAtStation = isPositionAtStation(leadProgress);
+      
+      // Check for other trains ahead (collision avoidance)
+      const hasTrainAhead = coaster.trains.some((otherTrain, idx) => {
+        if (idx === trainIndex) return false;
+        const otherLead = otherTrain.cars[0].trackProgress % trackLength;
+        const distance = (otherLead - leadProgress + trackLength) % trackLength;
+        return distance < 4 && distance > 0; // Within 4 tiles ahead
+      });
+      
+      // Validate car spacing - if cars have drifted apart, reset them
+      const maxCarDrift = carSpacing * 1.5; // Allow 50% variance before resetting
+      let needsSpacingReset = false;
+      for (let i = 1; i < cars.length; i++) {
+        const prevCar = cars[i - 1];
+        const currCar = cars[i];
+        const expectedDiff = carSpacing;
+        const actualDiff = (currCar.trackProgress - prevCar.trackProgress + trackLength) % trackLength;
+        // If difference is more than half the track, the car wrapped around
+        const normalizedDiff = actualDiff > trackLength / 2 ? trackLength - actualDiff : actualDiff;
+        if (Math.abs(normalizedDiff - expectedDiff) > maxCarDrift) {
+          needsSpacingReset = true;
+          break;
+        }
+      }
+      
+      if (needsSpacingReset) {
+        // Reset cars to proper spacing from lead car
+        const leadProgress = cars[0].trackProgress;
+        cars = cars.map((car, idx) => ({
+          ...car,
+          trackProgress: (leadProgress + idx * carSpacing) % trackLength,
+          velocity: car.velocity,
+        }));
+      }
+      
+      // State machine for train operation
+      switch (state) {
+        case 'loading': {
+          // Stay at station boarding one waiting guest per tick until full
+          loadingTime += deltaTime;
+          const seats = cars.length * SEATS_PER_CAR;
+          let aboard = cars.reduce((sum, car) => sum + car.guests.length, 0);
+          if (aboard < seats && waitingGuests.length > 0) {
+            const guestId = waitingGuests.shift()!;
+            const carIndex = Math.floor(aboard / SEATS_PER_CAR);
+            cars = cars.map((car, idx) => idx === carIndex ? { ...car, guests: [...car.guests, guestId] } : car);
+            boardedGuestIds.add(guestId);
+            aboard++;
+          }
+          const isFull = aboard >= seats;
+          
+          // Dispatch when full after the minimum wait, or when the maximum wait runs out,
+          // but hold the train (still boarding) while another train occupies the block ahead
+          const readyToDispatch = loadingTime >= dispatchPolicy.maxWait || (loadingTime >= dispatchPolicy.minWait && isFull);
+          const frontProgress = effectiveStationIndex + (cars.length - 1) * carSpacing;
+          if (readyToDispatch && isBlockAheadClear(trainIndex, frontProgress)) {
+            state = 'dispatching';
+            stateTimer = 2; // 2 second dispatch
+            loadingTime = 0;
+          }
+          // Keep train stationary at station - maintain proper car positions
+          cars = cars.map((car, idx) => ({
+            ...car,
+            trackProgress: (effectiveStationIndex + idx * carSpacing) % trackLength,
+            velocity: 0,
+          }));
+          break;
+        }
+          
+        case 'dispatching':
+          // Accelerating from station
+          if (stateTimer <= 0) {
+            state = 'running';
+            stateTimer = 0;
+          }
+          // Slow acceleration - boost velocity at higher game speeds for visual feedback
+          // (car.velocity keeps the unboosted speed so physics can pick up from it)
+          const speedBoostDispatch = SPEED_TRAIN_BOOSTS[prev.speed];
+          const dispatchSpeed = 0.02 + (1 - stateTimer / 2) * 0.04;
+          cars = cars.map(car => {
+            // Check if car is on a loop - slow down on loops
+            const carTrackIdx = Math.floor(car.trackProgress % trackLength);
+            const trackPiece = coaster.track[carTrackIdx];
+            const isOnLoop = trackPiece?.type === 'loop_vertical';
+            const velocityMultiplier = isOnLoop ? 0.5 : 1.0;
+            const dispatchVelocity = dispatchSpeed * speedBoostDispatch * velocityMultiplier;
+            
+            let nextProgress = car.trackProgress + dispatchVelocity * deltaTime;
+            nextProgress = nextProgress % trackLength;
+            if (nextProgress < 0) nextProgress += trackLength;
+            return { ...car, trackProgress: nextProgress, velocity: dispatchSpeed };
+          });
+          break;
+          
+        case 'running': {
+          // Check if approaching station and should brake
+          const distanceToStation = (effectiveStationIndex - leadProgress + trackLength) % trackLength;
+          const shouldBrake = distanceToStation < 3 && distanceToStation > 0.5 && cars[0].velocity > 0;
+          
+          if (shouldBrake || hasTrainAhead) {
+            state = 'braking';
+            stateTimer = 0;
+            stallTimer = 0;
+          } else {
+            // Gravity drives the whole train from the slope under its cars; chain
+            // lifts pull at a fixed speed and a stalled train rolls back downhill
+            const forces = getTrainTrackForces(cars, coaster.track, trackLength);
+            let velocity = cars[0].velocity;
+            if (forces.onChain) {
+              velocity = CHAIN_LIFT_SPEED;
+            } else {
+              velocity -= (TRAIN_GRAVITY * forces.slope + Math.sign(velocity) * TRAIN_FRICTION) * deltaTime;
+              if (forces.boosted) velocity = Math.max(velocity, BOOSTER_SPEED);
+            }
+            velocity = Math.max(-MAX_TRAIN_SPEED, Math.min(MAX_TRAIN_SPEED, velocity));
+            
+            // Time spent stopped or rolling back builds up (and forward motion only
+            // slowly drains it), so a train rocking back and forth in a dip stalls too
+            stallTimer = velocity <= STALL_SPEED
+              ? stallTimer + deltaTime
+              : Math.max(0, stallTimer - deltaTime * 0.5);
+            if (stallTimer >= STALL_TICKS) velocity = 0;
+            
+            const speedBoostRun = SPEED_TRAIN_BOOSTS[prev.speed];
+            cars = cars.map(car => {
+              // Check if car is on a loop - loops are much longer so slow down
+              const carTrackIdx = Math.floor(car.trackProgress % trackLength);
+              const trackPiece = coaster.track[carTrackIdx];
+              const isOnLoop = trackPiece?.type === 'loop_vertical';
+              // Loops are ~3x longer than straight, so reduce speed
+              const velocityMultiplier = isOnLoop ? 0.5 : 1.0;
+              const runVelocity = velocity * speedBoostRun * velocityMultiplier;
+              
+              let nextProgress = car.trackProgress + runVelocity * deltaTime;
+              nextProgress = nextProgress % trackLength;
+              if (nextProgress < 0) nextProgress += trackLength;
+              return { ...car, trackProgress: nextProgress, velocity };
+            });
+          }
+          break;
+        }
+          
+        case 'braking':
+          // Slow down approaching station - boost velocity at higher game speeds for visual feedback
+          const speedBoostBrake = SPEED_TRAIN_BOOSTS[prev.speed];
+          const baseBrakeVelocity = (hasTrainAhead ? 0.01 : 0.03) * speedBoostBrake;
+          const leadProgressNow = cars[0].trackProgress % trackLength;
+          const atStation = isPositionAtStation(leadProgressNow);
+          
+          if (atStation && !hasTrainAhead) {
+            state = 'loading';
+            stateTimer = dispatchPolicy.maxWait;
+            loadingTime = 0;
+            // Snap to station position (use actual station index, not always 0) and unload riders
+            cars = cars.map((car, idx) => ({
+              ...car,
+              trackProgress: (effectiveStationIndex + idx * carSpacing) % trackLength,
+              velocity: 0,
+              guests: [],
+            }));
+          } else if (hasTrainAhead) {
+            // Wait for train ahead to clear
+            cars = cars.map(car => ({ ...car, velocity: 0 }));
+          } else {
+            cars = cars.map(car => {
+              // Check if car is on a loop - slow down on loops
+              const carTrackIdx = Math.floor(car.trackProgress % trackLength);
+              const trackPiece = coaster.track[carTrackIdx];
+              const isOnLoop = trackPiece?.type === 'loop_vertical';
+              const velocityMultiplier = isOnLoop ? 0.5 : 1.0;
+              const brakeVelocity = baseBrakeVelocity * velocityMultiplier;
+              
+              let nextProgress = car.trackProgress + brakeVelocity * deltaTime;
+              nextProgress = nextProgress % trackLength;
+              if (nextProgress < 0) nextProgress += trackLength;
+              return { ...car, trackProgress: nextProgress, velocity: brakeVelocity };
+            });
+          }
+          break;
+          
+        case 'returning':
+          // Legacy state - treat as running
+          state = 'running';
+          break;
+      }
+      
+      return { ...train, state, stateTimer, loadingTime, stallTimer, cars };
+    });
+    
+    if (updatedTrains.some(train => (train.stallTimer ?? 0) >= STALL_TICKS)) {
+      stalledCoasters.push(coaster);
+      return { ...coaster, trains: updatedTrains, stalled: true };
+    }
+    return { ...coaster, trains: updatedTrains };
+  });
+  const trainsTime = performance.now() - trainsStart;
+  
+  // Record what's left of each line and how many riders every ride has taken
+  if (rideLines.size > 0 || Object.values(rideStats ?? {}).some(stats => stats.queue?.length)) {
+    const nextRideStats: Record<string, RideStats> = {};
+    for (const rideId in rideStats ?? {}) {
+      nextRideStats[rideId] = { ...createRideStats(), ...rideStats?.[rideId], queue: [] };
+    }
+    for (const [rideId, line] of rideLines) {
+      const stats = nextRideStats[rideId] ?? createRideStats();
+      const queue = line.filter(guestId => !boardedGuestIds.has(guestId));
+      nextRideStats[rideId] = {
+        ...stats,
+        queue,
+        ridersServed: stats.ridersServed + line.length - queue.length,
+        dispatchTimer: dispatchTimers.get(rideId) ?? stats.dispatchTimer,
+      };
+    }
+    rideStats = nextRideStats;
+  }
+  
+  // Guests who boarded a ride this tick start riding. With ticketed rides they pay
+  // that ride's price and judge it against what the ride seems worth
+  if (boardedGuestIds.size > 0) {
+    guests = guests.map(guest => {
+      if (!boardedGuestIds.has(guest.id)) return guest;
+      let fee = 0;
+      let valueHappiness = 0;
+      let valueThought: GuestThought | null = null;
+      if (prev.settings.payPerRide && guest.queueRideId) {
+        const price = getRideBuilding(prev.grid, guest.queueRideId)?.price ?? 0;
+        fee = Math.min(guest.cash, price);
+        const fairPrice = getRideFairPrice(prev.grid, guest.queueRideId, updatedCoasters);
+        if (price > fairPrice * RIDE_OVERPRICED_RATIO) {
+          valueHappiness = -Math.min(RIDE_MAX_OVERPRICE_PENALTY, (price / fairPrice - 1) * RIDE_OVERPRICE_PENALTY);
+          valueThought = 'spent_too_much';
+        } else if (price < fairPrice * RIDE_BARGAIN_RATIO) {
+          valueThought = 'good_value';
+        }
+      }
+      rideRevenue += fee;
+      notePayment(guest.queueRideId, fee);
+      const rider: Guest = {
+        ...guest,
+        state: 'riding' as const,
+        queueTimer: 10 + simulationRandom() * 20,
+        happiness: Math.max(0, Math.min(100, guest.happiness + 8 + valueHappiness)),
+        cash: guest.cash - fee,
+        totalSpent: guest.totalSpent + fee,
+      };
+      if (valueThought) addGuestThought(rider, valueThought);
+      return rider;
+    });
+  }
+  
+  // Everyone still in line learns their new place and shuffles up to it (see updateGuest)
+  if (rideStats) {
+    const linePositions = new Map<string, number>();
+    for (const rideId in rideStats) {
+      (rideStats[rideId].queue ?? []).forEach((guestId, index) => linePositions.set(guestId, index));
+    }
+    guests = guests.map(guest => {
+      const position = linePositions.get(guest.id);
+      return position === undefined || position === guest.queuePosition ? guest : { ...guest, queuePosition: position };
+    });
+  }
+  
+  const incomeAdmissions = prev.finances.incomeAdmissions + admissionRevenue;
+  const incomeRides = prev.finances.incomeRides + rideRevenue;
+  const incomeFood = prev.finances.incomeFood + foodRevenue;
+  const incomeShops = prev.finances.incomeShops + shopRevenue;
+  const incomeTotal = incomeAdmissions + incomeRides + incomeFood + incomeShops;
+  const expenseTotal = prev.finances.expenseConstruction + prev.finances.expenseWages + prev.finances.expenseUpkeep + prev.finances.expenseMarketing + prev.finances.expenseResearch;
+  const profit = incomeTotal - expenseTotal;
+
+  const monthChanged = month !== prev.month || year !== prev.year;
+  let finances = {
+    ...prev.finances,
+    cash: prev.finances.cash + admissionRevenue + rideRevenue + foodRevenue + shopRevenue,
+    incomeAdmissions,
+    incomeRides,
+    incomeFood,
+    incomeShops,
+    incomeTotal,
+    expenseTotal,
+    profit,
+  };
+
+  if (monthChanged) {
+    const { upkeep } = calculateMonthlyUpkeep(prev.grid);
+    const wages = calculateStaffWages(prev.staff);
+    // Loan interest compounds monthly; the payment covers interest first, then principal
+    const loanInterest = prev.finances.loan * (prev.settings.loanInterest / 12);
+    const loanPayment = Math.min(prev.finances.loan + loanInterest, prev.finances.loanMonthlyPayment);
+    const loan = Math.max(0, prev.finances.loan + loanInterest - loanPayment);
+    const monthlyExpenses = upkeep + wages + prev.finances.expenseMarketing + prev.finances.expenseResearch + loanInterest;
+    const monthlyProfit = incomeTotal - monthlyExpenses;
+    
+    // Construction was paid as it happened; it only enters the month's report
+    const reportExpenses = monthlyExpenses + prev.finances.expenseConstruction;
+    const report: MonthlyReport = {
+      month: prev.month,
+      year: prev.year,
+      income: { admissions: incomeAdmissions, rides: incomeRides, food: incomeFood, shops: incomeShops },
+      expenses: {
+        construction: prev.finances.expenseConstruction,
+        upkeep,
+        wages,
+        marketing: prev.finances.expenseMarketing,
+        research: prev.finances.expenseResearch,
+        loanInterest,
+      },
+      incomeTotal,
+      expenseTotal: reportExpenses,
+      profit: incomeTotal - reportExpenses,
+    };
+
+    finances = {
+      ...prev.finances,
+      cash: prev.finances.cash + admissionRevenue + rideRevenue + foodRevenue + shopRevenue - monthlyExpenses - (loanPayment - loanInterest),
+      loan,
+      loanMonthlyPayment: loan > 0 ? prev.finances.loanMonthlyPayment : 0,
+      incomeAdmissions: 0,
+      incomeRides: 0,
+      incomeFood: 0,
+      incomeShops: 0,
+      incomeTotal: 0,
+      expenseConstruction: 0,
+      expenseWages: 0,
+      expenseUpkeep: 0,
+      expenseMarketing: 0,
+      expenseResearch: 0,
+      expenseTotal: 0,
+      profit: 0,
+      history: [
+        ...prev.finances.history,
+        {
+          month: prev.month,
+          year: prev.year,
+          income: incomeTotal,
+          expenses: monthlyExpenses,
+          profit: monthlyProfit,
+          guests: guestsInPark,
+          parkValue: prev.stats.parkValue,
+        },
+      ].slice(-24),
+      reports: [...prev.finances.reports, report].slice(-MONTHLY_REPORT_HISTORY),
+    };
+    events.push({ type: 'month_ended', tick: newTick, month: prev.month, year: prev.year, profit: report.profit });
+  }
+  
+  for (const [rideId, riders] of ridersFinished) {
+    events.push({ type: 'ride_completed', tick: newTick, rideId, riders });
+  }
+  for (const coaster of stalledCoasters) {
+    events.push({ type: 'coaster_stalled', tick: newTick, coasterId: coaster.id });
+  }
+
+  // Feed each guest's newest thought into the park-wide log
+  const previousThoughts = new Map(prev.guests.map(guest => [guest.id, guest.thoughts]));
+  const newThoughts: RecentThought[] = [];
+  for (const guest of guests) {
+    if (guest.thoughts.length === 0 || guest.thoughts === previousThoughts.get(guest.id)) continue;
+    newThoughts.push({
+      guestId: guest.id,
+      guestName: guest.name,
+      thought: guest.thoughts[guest.thoughts.length - 1],
+      tick: newTick,
+    });
+  }
+  const recentThoughts = newThoughts.length > 0
+    ? [...(prev.recentThoughts ?? []), ...newThoughts].slice(-RECENT_THOUGHTS_LIMIT)
+    : prev.recentThoughts;
+  
+  // Float this tick's takings over where they were paid; older popups fade out
+  const newPopups: MoneyPopup[] = [];
+  paymentsByTile.forEach((amount, tileId) => {
+    const [x, y] = tileId.split(',').map(Number);
+    if (!isNaN(x) && !isNaN(y)) newPopups.push({ x, y, amount, tick: newTick });
+  });
+  const livePopups = (prev.moneyPopups ?? []).filter(popup => newTick - popup.tick < MONEY_POPUP_TICKS);
+  const moneyPopups = newPopups.length > 0 || livePopups.length !== (prev.moneyPopups?.length ?? 0)
+    ? [...livePopups, ...newPopups].slice(-MAX_MONEY_POPUPS)
+    : prev.moneyPopups;
+  
+  const nextState: GameState = {
+    ...prev,
+    rngState: getSimulationRandomState(),
+    tick: newTick,
+    minute,
+    hour,
+    day,
+    month,
+    year,
+    weather: newWeather,
+    guests,
+    staff,
+    litter,
+    stallStock,
+    coasters: updatedCoasters,
+    themeZones,
+    rideStats,
+    recentThoughts,
+    moneyPopups,
+    stats: {
+      ...prev.stats,
+      guestsInPark,
+      guestsTotal: prev.stats.guestsTotal + spawnedGuests.length,
+      guestsSatisfied,
+      guestsUnsatisfied,
+      averageHappiness: avgHappiness,
+      parkRating,
+      ratingBreakdown,
+      totalRidesRidden: prev.stats.totalRidesRidden + rideCompletions,
+      guestsDeparted,
+      departedGuestSpending,
+      guestPreferences: getGuestPreferenceStats(guests),
+    },
+    finances,
+  };
+  
+  let notifiedState = stalledCoasters.reduce((acc, coaster) => pushNotification(
+    acc,
+    'Coaster Stalled',
+    `A train on ${coaster.name} ran out of speed. Add a lift hill or rebuild the track to get it moving.`,
+    'warning',
+    { x: coaster.stationTileX, y: coaster.stationTileY }
+  ), pushEvents(nextState, events));
+  
+  // Announce the gates opening and closing
+  const wasClosed = !isParkOpenAt(prev.hour, prev.settings.openHour, prev.settings.closeHour);
+  if (parkClosed !== wasClosed) {
+    notifiedState = parkClosed
+      ? pushNotification(notifiedState, 'Park Closed', 'The gates are closed for the day. Guests are heading for the exits.', 'info')
+      : pushNotification(notifiedState, 'Park Open', 'The gates are open and guests are arriving.', 'info');
+  }
+  
+  const result = updateDebtStatus(notifiedState, prev.finances.cash, day !== prev.day);
+  lastTickBreakdown = {
+    guests: guestsTime,
+    spawns: spawnsTime,
+    trains: trainsTime,
+    total: performance.now() - tickStart,
+  };
+  return result;
+}
+
+/**
+ * Where the most recent simulateTick spent its time (all zero before the first tick)
+ */
+export function getLastTickBreakdown(): TickBreakdown {
+  return { ...lastTickBreakdown };
+}
+
+/**
+ * Run ticks back to back without rendering, e.g. to fast-forward a park
+ * created with createInitialCoasterGameState in a test or on a server
+ */
+export function runSimulationTicks(state: GameState, ticks: number): GameState {
+  let next = state;
+  for (let i = 0; i < ticks; i++) {
+    next = simulateTick(next);
+  }
+  return next;
+}
@@ -0,0 +1,126 @@
+/**
+ * IsoCoaster Terrain
+ * Raising and lowering land with automatic smoothing of neighboring tiles,
+ * and which terrain each building can stand on
+ */
+
+import { BuildingType, Tile, isValidTerrain, needsAdjacentWater, requiresWater } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+export const MIN_ELEVATION = 0;
+export const MAX_ELEVATION = 8;
+const MAX_ELEVATION_STEP = 1; // Largest height difference allowed between neighbors
+
+const NEIGHBORS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+// =============================================================================
+// BUILDING TERRAIN
+// =============================================================================
+
+/**
+ * Why a building can't go on this footprint's terrain, or null if it can:
+ * every tile must suit it (see isValidTerrain), and waterside buildings need
+ * water touching at least one edge of the footprint
+ */
+export function getTerrainRejection(
+  grid: Tile[][],
+  type: BuildingType | string,
+  x: number,
+  y: number,
+  size: { width: number; height: number } = { width: 1, height: 1 }
+): string | null {
+  const gridSize = grid.length;
+  let touchesWater = false;
+  for (let dy = 0; dy < size.height; dy++) {
+    for (let dx = 0; dx < size.width; dx++) {
+      const tile = grid[y + dy]?.[x + dx];
+      if (!tile) continue;
+      if (!isValidTerrain(type, tile.terrain)) {
+        return tile.terrain === 'water' ? 'This can\'t be built on water.' : `This can't be built on ${tile.terrain}.`;
+      }
+      for (const { dx: nx, dy: ny } of NEIGHBORS) {
+        const checkX = x + dx + nx;
+        const checkY = y + dy + ny;
+        if (checkX < 0 || checkY < 0 || checkX >= gridSize || checkY >= gridSize) continue;
+        if (grid[checkY][checkX].terrain === 'water') touchesWater = true;
+      }
+    }
+  }
+  if (requiresWater(type) && !touchesWater) return 'Water rides need a lake or river beside them.';
+  if (needsAdjacentWater(type) && !touchesWater) return 'This has to be built next to water.';
+  return null;
+}
+
+// =============================================================================
+// RESHAPING
+// =============================================================================
+
+/**
+ * Whether a tile's elevation can change: bare land only, since buildings,
+ * paths and track would otherwise be left floating or buried
+ */
+export function canReshapeTile(tile: Tile): boolean {
+  if (tile.terrain === 'water') return false;
+  if (tile.building.type !== 'empty' && tile.building.type !== 'grass') return false;
+  return !tile.path && !tile.queue && !tile.hasCoasterTrack && !tile.trackPiece;
+}
+
+/**
+ * Work out the elevation changes needed to move (x, y) by `delta`, pulling or
+ * pushing neighbors along so no two adjacent tiles differ by more than one
+ * step. Returns the new elevation for every affected tile, or null when the
+ * tile is already at the limit or the slope would run into a tile that can't
+ * be reshaped.
+ */
+export function planElevationChange(
+  grid: Tile[][],
+  x: number,
+  y: number,
+  delta: number
+): Map<string, { x: number; y: number; elevation: number }> | null {
+  const gridSize = grid.length;
+  const origin = grid[y]?.[x];
+  if (!origin || !canReshapeTile(origin)) return null;
+
+  const target = Math.max(MIN_ELEVATION, Math.min(MAX_ELEVATION, origin.elevation + delta));
+  if (target === origin.elevation) return null;
+
+  const changes = new Map<string, { x: number; y: number; elevation: number }>();
+  const elevationAt = (tx: number, ty: number) =>
+    changes.get(`${tx},${ty}`)?.elevation ?? grid[ty][tx].elevation;
+
+  changes.set(`${x},${y}`, { x, y, elevation: target });
+  const queue = [{ x, y }];
+
+  while (queue.length > 0) {
+    const current = queue.shift()!;
+    const currentElevation = elevationAt(current.x, current.y);
+
+    for (const { dx, dy } of NEIGHBORS) {
+      const nx = current.x + dx;
+      const ny = current.y + dy;
+      if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+
+      const neighborElevation = elevationAt(nx, ny);
+      if (Math.abs(neighborElevation - currentElevation) <= MAX_ELEVATION_STEP) continue;
+
+      // Neighbor is too steep - bring it within one step of this tile
+      if (!canReshapeTile(grid[ny][nx])) return null;
+      const smoothed = neighborElevation > currentElevation
+        ? currentElevation + MAX_ELEVATION_STEP
+        : currentElevation - MAX_ELEVATION_STEP;
+      changes.set(`${nx},${ny}`, { x: nx, y: ny, elevation: smoothed });
+      queue.push({ x: nx, y: ny });
+    }
+  }
+
+  return changes;
+}
@@ -0,0 +1,112 @@
+/**
+ * IsoCoaster Theme Zones
+ * Groups nearby buildings of the same theme into zones that cheer guests up
+ */
+
+import { Tile, ParkTheme, ThemeZone, getBuildingTheme } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+const ZONE_LINK_DISTANCE = 2;   // Max tile gap between two buildings in the same zone
+const ZONE_MIN_MEMBERS = 3;     // Fewer themed buildings than this is just decoration
+const ZONE_FULL_STRENGTH = 10;  // Members needed for a zone to reach full strength
+const ZONE_REACH = 2;           // Tiles beyond the zone's bounds where guests still feel it
+
+// =============================================================================
+// DETECTION
+// =============================================================================
+
+/**
+ * Find clusters of same-theme buildings. Two buildings join the same cluster
+ * when they are within ZONE_LINK_DISTANCE tiles of each other (in any
+ * direction), so a cohesive corner forms one zone while the same items
+ * scattered around the park form none.
+ */
+export function findThemeZones(grid: Tile[][], gridSize: number): ThemeZone[] {
+  // Collect themed building origins (footprint tiles belong to their origin)
+  const themeAt = new Map<number, ParkTheme>();
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      const type = grid[y][x].building?.type;
+      if (!type || type.endsWith('_footprint')) continue;
+      const theme = getBuildingTheme(type);
+      if (theme) themeAt.set(y * gridSize + x, theme);
+    }
+  }
+
+  const visited = new Set<number>();
+  const zones: ThemeZone[] = [];
+
+  for (const [startKey, theme] of themeAt) {
+    if (visited.has(startKey)) continue;
+
+    // Flood fill same-theme buildings within link distance
+    const tiles: { x: number; y: number }[] = [];
+    const stack = [startKey];
+    visited.add(startKey);
+
+    while (stack.length > 0) {
+      const key = stack.pop()!;
+      const x = key % gridSize;
+      const y = Math.floor(key / gridSize);
+      tiles.push({ x, y });
+
+      for (let dy = -ZONE_LINK_DISTANCE; dy <= ZONE_LINK_DISTANCE; dy++) {
+        for (let dx = -ZONE_LINK_DISTANCE; dx <= ZONE_LINK_DISTANCE; dx++) {
+          const nx = x + dx;
+          const ny = y + dy;
+          if (nx < 0 || ny < 0 || nx >= gridSize || ny >= gridSize) continue;
+          const neighborKey = ny * gridSize + nx;
+          if (visited.has(neighborKey) || themeAt.get(neighborKey) !== theme) continue;
+          visited.add(neighborKey);
+          stack.push(neighborKey);
+        }
+      }
+    }
+
+    if (tiles.length < ZONE_MIN_MEMBERS) continue;
+
+    let minX = gridSize, maxX = 0, minY = gridSize, maxY = 0, sumX = 0, sumY = 0;
+    for (const tile of tiles) {
+      minX = Math.min(minX, tile.x);
+      maxX = Math.max(maxX, tile.x);
+      minY = Math.min(minY, tile.y);
+      maxY = Math.max(maxY, tile.y);
+      sumX += tile.x;
+      sumY += tile.y;
+    }
+
+    zones.push({
+      id: `${theme}-${minX}-${minY}`,
+      theme,
+      tiles,
+      centerX: sumX / tiles.length,
+      centerY: sumY / tiles.length,
+      minX,
+      maxX,
+      minY,
+      maxY,
+      strength: Math.min(1, (tiles.length - ZONE_MIN_MEMBERS + 1) / (ZONE_FULL_STRENGTH - ZONE_MIN_MEMBERS + 1)),
+    });
+  }
+
+  return zones.sort((a, b) => b.strength - a.strength);
+}
+
+/**
+ * Strength of the strongest theme zone covering a tile (0 if none)
+ */
+export function getThemeZoneStrengthAt(zones: ThemeZone[], x: number, y: number): number {
+  let strength = 0;
+  for (const zone of zones) {
+    if (
+      x >= zone.minX - ZONE_REACH && x <= zone.maxX + ZONE_REACH &&
+      y >= zone.minY - ZONE_REACH && y <= zone.maxY + ZONE_REACH
+    ) {
+      strength = Math.max(strength, zone.strength);
+    }
+  }
+  return strength;
+}
@@ -0,0 +1,69 @@
+import { describe, it } from 'node:test';
+import assert from 'node:assert/strict';
+import { Tile, createEmptyTile } from '../types';
+import { TrackDirection, TrackHeight, TrackPiece, TrackPieceType } from '../types/tracks';
+import { chainTrackHeights, createDefaultCoaster, splitCoasterTrack } from './trackFragments';
+
+const COASTER_ID = 'loop';
+
+function piece(type: TrackPieceType, direction: TrackDirection, startHeight: TrackHeight, endHeight: TrackHeight): TrackPiece {
+  return { type, direction, startHeight, endHeight, bankAngle: 0, chainLift: false, boosted: false, strutStyle: 'metal' };
+}
+
+/**
+ * A closed ring around (2, 2) on a 5x5 grid that climbs one level on the
+ * near side and drops back down on the far side
+ */
+function buildLoop(): Tile[][] {
+  const grid = Array.from({ length: 5 }, (_, y) => Array.from({ length: 5 }, (_, x) => createEmptyTile(x, y)));
+  const ring: [number, number, TrackPiece][] = [
+    [2, 1, piece('slope_up_small', 'south', 0, 1)],
+    [3, 1, piece('turn_right_flat', 'south', 1, 1)],
+    [3, 2, piece('straight_flat', 'west', 1, 1)],
+    [3, 3, piece('turn_right_flat', 'west', 1, 1)],
+    [2, 3, piece('slope_down_small', 'north', 1, 0)],
+    [1, 3, piece('turn_right_flat', 'north', 0, 0)],
+    [1, 2, piece('straight_flat', 'east', 0, 0)],
+    [1, 1, piece('turn_right_flat', 'east', 0, 0)],
+  ];
+  for (const [x, y, trackPiece] of ring) {
+    grid[y][x] = { ...grid[y][x], hasCoasterTrack: true, coasterTrackId: COASTER_ID, trackPiece };
+  }
+  return grid;
+}
+
+function assertContinuousHeights(pieces: TrackPiece[]) {
+  for (let i = 1; i < pieces.length; i++) {
+    assert.equal(pieces[i].startHeight, pieces[i - 1].endHeight);
+  }
+}
+
+describe('splitCoasterTrack', () => {
+  it('keeps heights continuous after a middle tile of a loop is removed', () => {
+    const grid = buildLoop();
+    const coaster = createDefaultCoaster(COASTER_ID, { x: 2, y: 1 }, 8);
+    grid[2][3] = { ...grid[2][3], hasCoasterTrack: false, coasterTrackId: null, trackPiece: null };
+
+    const coasters = splitCoasterTrack(grid, [coaster], COASTER_ID);
+
+    assert.equal(coasters.length, 1);
+    assert.equal(coasters[0].id, COASTER_ID);
+    assert.equal(coasters[0].track.length, 7);
+    assertContinuousHeights(coasters[0].track);
+    coasters[0].trackTiles.forEach((tile, i) => {
+      assert.deepEqual(grid[tile.y][tile.x].trackPiece, coasters[0].track[i]);
+    });
+  });
+});
+
+describe('chainTrackHeights', () => {
+  it('moves each piece to start where the previous one ended, keeping its rise', () => {
+    const pieces = chainTrackHeights([
+      piece('slope_up_small', 'south', 0, 1),
+      piece('slope_up_small', 'south', 3, 4),
+      piece('straight_flat', 'south', 0, 0),
+    ]);
+
+    assert.deepEqual(pieces.map(p => [p.startHeight, p.endHeight]), [[0, 1], [1, 2], [2, 2]]);
+  });
+});
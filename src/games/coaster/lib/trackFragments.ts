@@ -0,0 +1,246 @@
+/**
+ * IsoCoaster Track Fragments
+ * Splitting a coaster's track into connected runs after a piece is removed,
+ * and rebuilding each run as a coaster with continuous heights
+ */
+
+import { Tile } from '../types';
+import { Coaster, TrackHeight, TrackPiece, CoasterType, COASTER_TYPE_STATS } from '../types/tracks';
+import { generateUUID, findStationTile, collectConnectedTrack, rateCoasterTrack, createTrainsForCoaster, createTrainsAtStation } from './simulation';
+import { getDirectionOffset, getExitDirection } from './trackValidation';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+const TRACK_NEIGHBOR_OFFSETS = [
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+/** Unique colors for each coaster type - gives each coaster its own distinct look */
+const COASTER_TYPE_COLORS: Record<CoasterType, { primary: string; secondary: string; supports: string }> = {
+  // Wooden coasters - natural wood tones
+  wooden_classic: { primary: '#8B4513', secondary: '#D2691E', supports: '#5C3317' },    // Classic brown wood
+  wooden_twister: { primary: '#A0522D', secondary: '#CD853F', supports: '#654321' },    // Sienna/tan wood
+  
+  // Steel coasters - vibrant modern colors
+  steel_sit_down: { primary: '#dc2626', secondary: '#fbbf24', supports: '#374151' },    // Classic red/yellow
+  steel_standup: { primary: '#7c3aed', secondary: '#c084fc', supports: '#4c1d95' },     // Purple/violet
+  steel_inverted: { primary: '#2563eb', secondary: '#60a5fa', supports: '#1e3a8a' },    // Blue scheme
+  steel_floorless: { primary: '#059669', secondary: '#34d399', supports: '#064e3b' },   // Emerald green
+  steel_wing: { primary: '#ea580c', secondary: '#fb923c', supports: '#7c2d12' },        // Orange/flame
+  steel_flying: { primary: '#0891b2', secondary: '#22d3ee', supports: '#164e63' },      // Cyan/sky
+  steel_4d: { primary: '#be123c', secondary: '#fb7185', supports: '#881337' },          // Rose/magenta
+  steel_spinning: { primary: '#65a30d', secondary: '#a3e635', supports: '#365314' },    // Lime green
+  launch_coaster: { primary: '#e11d48', secondary: '#fda4af', supports: '#9f1239' },    // Hot pink/red
+  hyper_coaster: { primary: '#0d9488', secondary: '#5eead4', supports: '#134e4a' },     // Teal
+  giga_coaster: { primary: '#4f46e5', secondary: '#a5b4fc', supports: '#312e81' },      // Indigo
+  
+  // Water coaster - aquatic blues
+  water_coaster: { primary: '#0ea5e9', secondary: '#38bdf8', supports: '#0c4a6e' },     // Sky blue
+  
+  // Specialty coasters - themed colors
+  mine_train: { primary: '#92400e', secondary: '#fcd34d', supports: '#451a03' },        // Rust/gold (mining theme)
+  bobsled: { primary: '#1d4ed8', secondary: '#93c5fd', supports: '#1e3a8a' },           // Ice blue
+  suspended: { primary: '#b45309', secondary: '#fcd34d', supports: '#78350f' },         // Amber/bronze
+};
+
+// =============================================================================
+// COASTERS
+// =============================================================================
+
+/**
+ * A fresh coaster of the given type with no track yet, colored for its type
+ */
+export function createDefaultCoaster(
+  id: string, 
+  startTile: { x: number; y: number }, 
+  trackLength: number = 0,
+  coasterType: CoasterType = 'steel_sit_down'
+): Coaster {
+  const colors = COASTER_TYPE_COLORS[coasterType] ?? COASTER_TYPE_COLORS.steel_sit_down;
+  const typeStats = COASTER_TYPE_STATS[coasterType];
+  
+  return {
+    id,
+    name: typeStats?.name ?? 'Custom Coaster',
+    type: coasterType,
+    color: colors,
+    track: [],
+    trackTiles: [],
+    stationTileX: startTile.x,
+    stationTileY: startTile.y,
+    trains: createTrainsForCoaster(trackLength, coasterType),
+    operating: true,
+    broken: false,
+    excitement: 0,
+    intensity: 0,
+    nausea: 0,
+    ridersTotal: 0,
+    income: 0,
+    upkeep: 0,
+  };
+}
+
+// =============================================================================
+// HEIGHTS
+// =============================================================================
+
+/**
+ * Clamp a track height to the buildable range (0-10)
+ */
+export function clampHeight(height: number): TrackHeight {
+  if (height <= 0) return 0;
+  if (height >= 10) return 10;
+  return height as TrackHeight;
+}
+
+// =============================================================================
+// FRAGMENTS
+// =============================================================================
+
+/**
+ * Group a coaster's remaining track tiles into physically connected runs.
+ * Removing an interior piece leaves two runs; removing an end leaves one.
+ */
+export function findTrackFragments(grid: Tile[][], coasterId: string): { x: number; y: number }[][] {
+  const gridSize = grid.length;
+  const isCoasterTrack = (x: number, y: number) =>
+    x >= 0 && y >= 0 && x < gridSize && y < gridSize &&
+    grid[y][x].coasterTrackId === coasterId && !!grid[y][x].trackPiece;
+  
+  const seen = new Set<string>();
+  const fragments: { x: number; y: number }[][] = [];
+  for (let y = 0; y < gridSize; y++) {
+    for (let x = 0; x < gridSize; x++) {
+      if (!isCoasterTrack(x, y) || seen.has(`${x},${y}`)) continue;
+      const fragment: { x: number; y: number }[] = [];
+      const stack = [{ x, y }];
+      seen.add(`${x},${y}`);
+      while (stack.length > 0) {
+        const tile = stack.pop()!;
+        fragment.push(tile);
+        for (const { dx, dy } of TRACK_NEIGHBOR_OFFSETS) {
+          const nx = tile.x + dx;
+          const ny = tile.y + dy;
+          if (isCoasterTrack(nx, ny) && !seen.has(`${nx},${ny}`)) {
+            seen.add(`${nx},${ny}`);
+            stack.push({ x: nx, y: ny });
+          }
+        }
+      }
+      fragments.push(fragment);
+    }
+  }
+  return fragments;
+}
+
+/**
+ * Chain heights along an open run: each piece starts where the previous one
+ * ended and keeps its own rise or drop.
+ */
+export function chainTrackHeights(pieces: TrackPiece[]): TrackPiece[] {
+  const chained: TrackPiece[] = [];
+  for (const piece of pieces) {
+    const previous = chained[chained.length - 1];
+    if (!previous || previous.endHeight === piece.startHeight) {
+      chained.push(piece);
+      continue;
+    }
+    const startHeight = previous.endHeight;
+    chained.push({
+      ...piece,
+      startHeight,
+      endHeight: clampHeight(startHeight + piece.endHeight - piece.startHeight),
+    });
+  }
+  return chained;
+}
+
+/**
+ * Order the pieces of one run of track. Open runs are walked from the end
+ * whose exit leads back into the run, so the original direction of travel is
+ * kept, and their directions and heights are recomputed from their neighbors.
+ */
+function collectTrackFragment(
+  grid: Tile[][],
+  fragment: { x: number; y: number }[]
+): { tiles: { x: number; y: number }[]; pieces: TrackPiece[] } {
+  const keys = new Set(fragment.map(t => `${t.x},${t.y}`));
+  
+  // Track outside the run counts as visited so the walk can't wander onto a neighboring coaster
+  const visited = new Set<string>();
+  for (let y = 0; y < grid.length; y++) {
+    for (let x = 0; x < grid.length; x++) {
+      if (grid[y][x].trackPiece && !keys.has(`${x},${y}`)) visited.add(`${x},${y}`);
+    }
+  }
+  
+  const ends = fragment.filter(t =>
+    TRACK_NEIGHBOR_OFFSETS.filter(({ dx, dy }) => keys.has(`${t.x + dx},${t.y + dy}`)).length < 2
+  );
+  const head = ends.find(t => {
+    const offset = getDirectionOffset(getExitDirection(grid[t.y][t.x].trackPiece!));
+    return keys.has(`${t.x + offset.dx},${t.y + offset.dy}`);
+  }) ?? ends[0] ?? fragment[0];
+  
+  const { tiles, pieces } = collectConnectedTrack(grid, head.x, head.y, visited);
+  return { tiles, pieces: ends.length > 0 ? chainTrackHeights(pieces) : pieces };
+}
+
+/**
+ * Rebuild a coaster after one of its track tiles was removed. The run holding
+ * the station keeps the coaster (shortened); any other run becomes a coaster
+ * of its own with the same type, so no track is left orphaned on the grid.
+ * Mutates the grid's track pieces and ids; returns the new coasters list.
+ */
+export function splitCoasterTrack(grid: Tile[][], coasters: Coaster[], coasterId: string): Coaster[] {
+  const coaster = coasters.find(c => c.id === coasterId);
+  const runs = findTrackFragments(grid, coasterId)
+    .map(fragment => collectTrackFragment(grid, fragment))
+    .filter(run => run.tiles.length > 0);
+  if (!coaster || runs.length === 0) {
+    return coasters.filter(c => c.id !== coasterId);
+  }
+  
+  let mainIndex = runs.findIndex(run =>
+    run.tiles.some(t => t.x === coaster.stationTileX && t.y === coaster.stationTileY)
+  );
+  if (mainIndex < 0) {
+    mainIndex = runs.reduce((best, run, i) => run.tiles.length > runs[best].tiles.length ? i : best, 0);
+  }
+  
+  const rebuilt = runs.map(({ tiles, pieces }, i): Coaster => {
+    const id = i === mainIndex ? coasterId : generateUUID();
+    tiles.forEach((t, j) => {
+      grid[t.y][t.x].coasterTrackId = id;
+      grid[t.y][t.x].trackPiece = pieces[j];
+    });
+    
+    const stationTile = findStationTile(grid, tiles, grid.length) || tiles[0];
+    const stationIdx = tiles.findIndex(t => t.x === stationTile.x && t.y === stationTile.y);
+    const base = i === mainIndex
+      ? coaster
+      : {
+          ...createDefaultCoaster(id, stationTile, pieces.length, coaster.type),
+          trainConfig: coaster.trainConfig,
+          dispatchPolicy: coaster.dispatchPolicy,
+        };
+    
+    return {
+      ...base,
+      track: pieces,
+      trackTiles: tiles,
+      stationTileX: stationTile.x,
+      stationTileY: stationTile.y,
+      // Always regenerate trains when track changes to prevent orphaned cars
+      trains: createTrainsAtStation(pieces.length, Math.max(0, stationIdx), base.type, base.trainConfig),
+      ...rateCoasterTrack(tiles, pieces, base.type),
+    };
+  });
+  
+  return coasters.flatMap(c => c.id === coasterId ? rebuilt : [c]);
+}
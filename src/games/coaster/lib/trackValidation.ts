@@ -0,0 +1,133 @@
+/**
+ * IsoCoaster Track Validation
+ * Checks whether a coaster's track forms a rideable circuit and explains why not
+ */
+
+import { TrackDirection, TrackPiece } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+export const MIN_CIRCUIT_PIECES = 4; // Fewest pieces that can close a loop
+
+// =============================================================================
+// TRACK GEOMETRY
+// =============================================================================
+
+/**
+ * Get the exit direction for a track piece.
+ * For straight pieces, exit direction = entry direction.
+ * For curves, exit direction is rotated based on turn type.
+ */
+export function getExitDirection(piece: TrackPiece): TrackDirection {
+  const { type, direction } = piece;
+
+  if (type === 'turn_right_flat' || type === 'turn_right_large_flat') {
+    // Right turn: north->east, east->south, south->west, west->north
+    const rightTurn: Record<TrackDirection, TrackDirection> = {
+      north: 'east', east: 'south', south: 'west', west: 'north'
+    };
+    return rightTurn[direction];
+  }
+
+  if (type === 'turn_left_flat' || type === 'turn_left_large_flat') {
+    // Left turn: north->west, west->south, south->east, east->north
+    const leftTurn: Record<TrackDirection, TrackDirection> = {
+      north: 'west', west: 'south', south: 'east', east: 'north'
+    };
+    return leftTurn[direction];
+  }
+
+  // Straight pieces, slopes, loops - exit in same direction
+  return direction;
+}
+
+/**
+ * Get the grid offset to the next tile based on direction.
+ * In our isometric grid:
+ * - North: x-1, y unchanged (moving up-left visually)
+ * - South: x+1, y unchanged (moving down-right visually)
+ * - East: y-1, x unchanged (moving up-right visually)
+ * - West: y+1, x unchanged (moving down-left visually)
+ */
+export function getDirectionOffset(dir: TrackDirection): { dx: number; dy: number } {
+  const offsets: Record<TrackDirection, { dx: number; dy: number }> = {
+    north: { dx: -1, dy: 0 },
+    south: { dx: 1, dy: 0 },
+    east: { dx: 0, dy: -1 },
+    west: { dx: 0, dy: 1 },
+  };
+  return offsets[dir];
+}
+
+/**
+ * Whether the piece on `from` exits onto the tile `to`
+ */
+function exitsOnto(piece: TrackPiece, from: { x: number; y: number }, to: { x: number; y: number }): boolean {
+  const offset = getDirectionOffset(getExitDirection(piece));
+  return from.x + offset.dx === to.x && from.y + offset.dy === to.y;
+}
+
+// =============================================================================
+// VALIDATION
+// =============================================================================
+
+/**
+ * Check if a track forms a complete loop (closed circuit).
+ * A track is complete if the last piece's exit connects back to the first piece's entry.
+ */
+export function isTrackComplete(
+  tiles: { x: number; y: number }[],
+  pieces: TrackPiece[]
+): boolean {
+  if (tiles.length < MIN_CIRCUIT_PIECES || pieces.length < MIN_CIRCUIT_PIECES) {
+    return false;
+  }
+  return exitsOnto(pieces[pieces.length - 1], tiles[tiles.length - 1], tiles[0]);
+}
+
+/**
+ * Everything stopping a track (in ride order, as collected from the grid) from
+ * running: too short, pieces that lead nowhere, height jumps between pieces,
+ * and a circuit that doesn't make it back to the station. Empty when rideable.
+ */
+export function getTrackValidationErrors(
+  tiles: { x: number; y: number }[],
+  pieces: TrackPiece[]
+): string[] {
+  const errors: string[] = [];
+  if (pieces.length === 0) return ['No track has been built'];
+
+  if (pieces.length < MIN_CIRCUIT_PIECES) {
+    errors.push(`Track needs at least ${MIN_CIRCUIT_PIECES} pieces to form a circuit`);
+  }
+
+  for (let i = 0; i < pieces.length - 1; i++) {
+    const tile = tiles[i];
+    if (!exitsOnto(pieces[i], tile, tiles[i + 1])) {
+      errors.push(`Dead-end piece at (${tile.x}, ${tile.y})`);
+    }
+    if (pieces[i].endHeight !== pieces[i + 1].startHeight) {
+      errors.push(
+        `Height mismatch between pieces at (${tile.x}, ${tile.y}) and (${tiles[i + 1].x}, ${tiles[i + 1].y}): ` +
+        `ends at ${pieces[i].endHeight}, next starts at ${pieces[i + 1].startHeight}`
+      );
+    }
+  }
+
+  if (!isTrackComplete(tiles, pieces)) {
+    errors.push('Track does not return to the station');
+  } else {
+    const last = pieces[pieces.length - 1];
+    if (last.endHeight !== pieces[0].startHeight) {
+      const lastTile = tiles[tiles.length - 1];
+      errors.push(
+        `Height mismatch where the circuit closes at (${lastTile.x}, ${lastTile.y}): ` +
+        `ends at ${last.endHeight}, station starts at ${pieces[0].startHeight}`
+      );
+    }
+  }
+
+  return errors;
+}
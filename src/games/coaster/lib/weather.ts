@@ -0,0 +1,93 @@
+/**
+ * IsoCoaster Weather
+ * Shelter from rain, weather-driven stall sales and the scene tint
+ */
+
+import { Guest, Tile, WeatherType, WEATHER_EFFECTS } from '../types';
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+// Queue scenery that keeps the rain off guests standing beside it
+const SHELTER_BUILDINGS = new Set<string>([
+  'queue_canopy',
+  'queue_pergola',
+  'queue_tunnel',
+  'queue_covered',
+  'queue_umbrella',
+  'queue_cave',
+]);
+
+// Guests inside a ride, shop or stall are out of the weather entirely
+const INDOOR_STATES = new Set<Guest['state']>(['riding', 'shopping', 'eating']);
+
+const NEIGHBORS = [
+  { dx: 0, dy: 0 },
+  { dx: 1, dy: 0 },
+  { dx: -1, dy: 0 },
+  { dx: 0, dy: 1 },
+  { dx: 0, dy: -1 },
+];
+
+// Overcast and wet weather greys the scene out a little
+const WEATHER_TINTS: Partial<Record<WeatherType, { r: number; g: number; b: number; alpha: number }>> = {
+  cloudy: { r: 120, g: 130, b: 145, alpha: 0.08 },
+  rain: { r: 70, g: 90, b: 120, alpha: 0.16 },
+  storm: { r: 40, g: 50, b: 80, alpha: 0.26 },
+  hot: { r: 255, g: 170, b: 60, alpha: 0.06 },
+  cold: { r: 180, g: 210, b: 255, alpha: 0.08 },
+};
+
+// =============================================================================
+// SHELTER
+// =============================================================================
+
+/**
+ * Whether weather only hurts guests who are out in the open
+ */
+export function isWetWeather(weather: WeatherType): boolean {
+  return weather === 'rain' || weather === 'storm';
+}
+
+/**
+ * Whether a guest is under cover: indoors, or on a tile at or next to a
+ * canopy, tunnel or other covered queue piece
+ */
+export function isGuestSheltered(guest: Guest, grid: Tile[][]): boolean {
+  if (INDOOR_STATES.has(guest.state)) return true;
+
+  const gridSize = grid.length;
+  for (const { dx, dy } of NEIGHBORS) {
+    const x = guest.tileX + dx;
+    const y = guest.tileY + dy;
+    if (x < 0 || y < 0 || x >= gridSize || y >= gridSize) continue;
+    if (SHELTER_BUILDINGS.has(grid[y][x].building.type)) return true;
+  }
+  return false;
+}
+
+// =============================================================================
+// SALES
+// =============================================================================
+
+/**
+ * What a guest pays at a stall in this weather - bigger orders on sunny and
+ * hot days, smaller ones when it pours
+ */
+export function getWeatherAdjustedPrice(price: number, weather: WeatherType, kind: 'food' | 'drink'): number {
+  const effects = WEATHER_EFFECTS[weather];
+  const multiplier = kind === 'drink' ? effects.drinkSalesMultiplier : effects.foodSalesMultiplier;
+  return Math.max(1, Math.round(price * multiplier));
+}
+
+// =============================================================================
+// RENDERING
+// =============================================================================
+
+/**
+ * Full-screen tint for the current weather, or null for clear skies
+ */
+export function getWeatherTint(weather: WeatherType): { r: number; g: number; b: number; alpha: number } | null {
+  return WEATHER_TINTS[weather] ?? null;
+}
@@ -89,6 +89,43 @@ export function removeSavedParkMeta(id: string, parks?: SavedParkMeta[]): SavedP
   return list.filter((park) => park.id !== id);
 }
 
+export type ParseCoasterStateResult =
+  | { ok: true; state: GameState }
+  | { ok: false; error: string };
+
+/**
+ * Serialize a park to JSON (the same format used for storage and export)
+ */
+export function serializeCoasterState(state: GameState): string {
+  return JSON.stringify(state);
+}
+
+/**
+ * Parse a serialized park, checking the parts every save must have.
+ * Callers should still run the result through the context's load
+ * normalization to fill in fields added since the save was made.
+ */
+export function parseCoasterState(json: string): ParseCoasterStateResult {
+  let parsed: Partial<GameState>;
+  try {
+    parsed = JSON.parse(json);
+  } catch (e) {
+    return { ok: false, error: `Invalid JSON: ${e instanceof Error ? e.message : String(e)}` };
+  }
+  if (!parsed || typeof parsed !== 'object') return { ok: false, error: 'Save is not an object.' };
+
+  const { grid, gridSize } = parsed;
+  if (typeof gridSize !== 'number' || gridSize <= 0) return { ok: false, error: 'Save is missing a valid gridSize.' };
+  if (!Array.isArray(grid) || grid.length !== gridSize || grid.some(row => !Array.isArray(row) || row.length !== gridSize)) {
+    return { ok: false, error: `Grid does not match gridSize ${gridSize}.` };
+  }
+  if (!Array.isArray(parsed.guests)) return { ok: false, error: 'Save is missing the guest list.' };
+  if (!Array.isArray(parsed.coasters)) return { ok: false, error: 'Save is missing the coaster list.' };
+  if (typeof parsed.finances?.cash !== 'number') return { ok: false, error: 'Save is missing park finances.' };
+
+  return { ok: true, state: parsed as GameState };
+}
+
 export function loadCoasterStateFromStorage(key: string): GameState | null {
   if (typeof window === 'undefined') return null;
   try {
@@ -102,9 +139,9 @@ export function loadCoasterStateFromStorage(key: string): GameState | null {
         return null;
       }
     }
-    const parsed = JSON.parse(jsonString);
-    if (parsed?.grid && parsed?.gridSize) {
-      return parsed as GameState;
+    const result = parseCoasterState(jsonString);
+    if (result.ok) {
+      return result.state;
     }
   } catch {
     return null;
@@ -115,7 +152,7 @@ export function loadCoasterStateFromStorage(key: string): GameState | null {
 export function saveCoasterStateToStorage(key: string, state: GameState): boolean {
   if (typeof window === 'undefined') return false;
   try {
-    const compressed = compressToUTF16(JSON.stringify(state));
+    const compressed = compressToUTF16(serializeCoasterState(state));
     localStorage.setItem(key, compressed);
     return true;
   } catch {
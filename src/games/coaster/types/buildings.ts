@@ -130,6 +130,198 @@ export interface Building {
   broken: boolean; // Is the ride broken down
   age: number; // Age in game days
   constructionProgress: number; // 0-100
+  maxQueueLength?: number; // Guests allowed in line before new arrivals balk
+  orientation?: BuildingOrientation; // Which way the building's front faces
+}
+
+/**
+ * Quarter turns from the sprite's drawn facing. Sprites only have one view,
+ * so odd orientations are drawn mirrored and opposite facings look alike.
+ */
+export type BuildingOrientation = 0 | 1 | 2 | 3;
+
+export function rotateOrientation(orientation: BuildingOrientation): BuildingOrientation {
+  return ((orientation + 1) % 4) as BuildingOrientation;
+}
+
+export function isOrientationMirrored(orientation: BuildingOrientation): boolean {
+  return orientation === 1 || orientation === 3;
+}
+
+export const DEFAULT_MAX_QUEUE_LENGTH = 24;
+
+/**
+ * How many guests can wait for a ride before new arrivals give up
+ */
+export function getMaxQueueLength(building: Building): number {
+  return building.maxQueueLength ?? DEFAULT_MAX_QUEUE_LENGTH;
+}
+
+export const DEFAULT_RIDE_CAPACITY = 8;
+
+// Seats per cycle for rides that differ from the default
+const RIDE_CAPACITIES: Partial<Record<BuildingType, number>> = {
+  ride_kiddie_planes: 6,
+  ride_kiddie_cars: 6,
+  ride_go_karts: 4,
+  ride_antique_cars: 4,
+  ride_bumper_cars: 10,
+  ride_bumper_boats: 6,
+  ride_paddle_boats: 4,
+  ride_simulator: 12,
+  ride_motion_theater: 20,
+  ride_4d_theater: 24,
+  ride_carousel: 24,
+  ride_teacups: 16,
+  ride_ferris_classic: 24,
+  ride_ferris_modern: 24,
+  ride_ferris_observation: 32,
+  ride_ferris_double: 32,
+  ride_ferris_led: 24,
+  ride_drop_tower: 12,
+  ride_space_shot: 12,
+  ride_observation_tower: 30,
+  ride_sky_swing: 24,
+  ride_star_flyer: 24,
+  ride_swing_ride: 24,
+  ride_wave_swinger: 24,
+  ride_enterprise: 20,
+  ride_loop_o_plane: 4,
+  ride_top_spin: 20,
+  ride_frisbee: 20,
+  ride_log_flume: 4,
+  ride_rapids: 8,
+  show_4d: 30,
+  show_stunt: 40,
+  show_dolphin: 40,
+  show_amphitheater: 60,
+};
+
+/**
+ * Guests a flat ride takes on each dispatch (a per-building override wins over the type's seats)
+ */
+export function getRideCapacity(building: Building): number {
+  if (building.capacity > 0) return building.capacity;
+  return RIDE_CAPACITIES[building.type] ?? DEFAULT_RIDE_CAPACITY;
+}
+
+/**
+ * Whether guests can queue for and ride a building (rides, shows and coaster stations)
+ */
+export function isRideBuilding(type: BuildingType | string | undefined): boolean {
+  return Boolean(type && (type.startsWith('ride_') || type.startsWith('show_') || type.startsWith('station_')));
+}
+
+/**
+ * Whether a building sells food or drink
+ */
+export function isFoodBuilding(type: BuildingType | string | undefined): boolean {
+  return Boolean(type && (type.startsWith('food_') || type.startsWith('drink_') || type.startsWith('snack_') || type.startsWith('cart_')));
+}
+
+/**
+ * Whether guests can sit on a building to rest
+ */
+export function isBenchBuilding(type: BuildingType | string | undefined): boolean {
+  return Boolean(type && type.startsWith('bench_'));
+}
+
+// Rides whose boats and logs run on real water fed from a lake beside them
+const WATER_RIDES = new Set<string>([
+  'ride_log_flume', 'ride_rapids', 'ride_lazy_river', 'ride_paddle_boats',
+]);
+
+// Other buildings that float on or draw from open water, so they need a lake beside them
+const WATERSIDE_BUILDINGS = new Set<string>([
+  'ride_kiddie_boats', 'ride_bumper_boats', 'pond_koi',
+]);
+
+/**
+ * Whether a building is a water ride (flumes, rapids, lazy river, paddle boats)
+ */
+export function requiresWater(type: BuildingType | string | undefined): boolean {
+  return Boolean(type && WATER_RIDES.has(type));
+}
+
+/**
+ * Whether a building only makes sense next to water (water rides, boat rides, water coaster stations)
+ */
+export function needsAdjacentWater(type: BuildingType | string | undefined): boolean {
+  return Boolean(type && (requiresWater(type) || WATERSIDE_BUILDINGS.has(type) || type.startsWith('station_water')));
+}
+
+/**
+ * Whether a building is a plant (trees, bushes, topiaries, flowers)
+ */
+export function isPlantBuilding(type: BuildingType | string | undefined): boolean {
+  return Boolean(type && (
+    type.startsWith('tree_') || type.startsWith('bush_') || type.startsWith('topiary_') ||
+    type.startsWith('flowers_') || type === 'ground_cover'
+  ));
+}
+
+/**
+ * Whether a building can stand on a tile of this terrain. Nothing is built
+ * on open water, and nothing grows on bare rock.
+ */
+export function isValidTerrain(type: BuildingType | string, terrain: 'grass' | 'water' | 'sand' | 'rock'): boolean {
+  if (terrain === 'water') return false;
+  if (terrain === 'rock' && isPlantBuilding(type)) return false;
+  return true;
+}
+
+/**
+ * Whether a building lights up the paths around it at night
+ */
+export function isLampBuilding(type: BuildingType | string | undefined): boolean {
+  return Boolean(type?.startsWith('lamp_'));
+}
+
+/**
+ * How much hunger and thirst a visit to a food or drink stall takes away
+ */
+export interface FoodProfile {
+  hunger: number;
+  thirst: number;
+}
+
+// Stalls that don't fit their category's usual split
+const FOOD_PROFILE_OVERRIDES: Partial<Record<BuildingType, FoodProfile>> = {
+  drink_smoothie: { hunger: 20, thirst: 55 },
+  drink_coffee: { hunger: 5, thirst: 45 },
+  food_icecream: { hunger: 25, thirst: 30 },
+  food_cotton_candy: { hunger: 25, thirst: 0 },
+  food_candy_apple: { hunger: 25, thirst: 5 },
+  snack_pizza: { hunger: 65, thirst: 5 },
+  snack_nachos: { hunger: 50, thirst: 5 },
+};
+
+/**
+ * Hunger/thirst restored by a stall: drinks mostly quench thirst, meals mostly
+ * fill hunger, snacks take the edge off and themed carts sell a bit of both
+ */
+export function getFoodProfile(type: BuildingType | string): FoodProfile {
+  const override = FOOD_PROFILE_OVERRIDES[type as BuildingType];
+  if (override) return override;
+  if (type.startsWith('drink_')) return { hunger: 5, thirst: 70 };
+  if (type.startsWith('snack_')) return { hunger: 35, thirst: 5 };
+  if (type.startsWith('cart_')) return { hunger: 35, thirst: 35 };
+  return { hunger: 60, thirst: 15 };
+}
+
+/**
+ * Width of the ground shadow under a building, as a fraction of one tile
+ * (before multi-tile scaling). 0 means flat items that cast no shadow.
+ */
+export function getShadowSize(type: BuildingType | string | undefined): number {
+  if (!type || type === 'empty' || type === 'grass') return 0;
+  if (type.startsWith('flowers_') || type === 'ground_cover' || type === 'ground_stones') return 0;
+  if (type === 'tree_redwood' || type === 'tree_cedar') return 0.5;
+  if (type === 'tree_palm' || type === 'tree_coconut' || type === 'tree_bamboo') return 0.3;
+  if (type.startsWith('tree_')) return 0.4;
+  if (type.startsWith('bush_') || type.startsWith('topiary_')) return 0.45;
+  if (type.startsWith('lamp_') || type.startsWith('queue_')) return 0.25;
+  return 0.8;
 }
 
 // =============================================================================
@@ -199,6 +391,23 @@ export const LARGE_RIDES: BuildingType[] = [
   'show_4d', 'show_stunt', 'show_dolphin', 'show_amphitheater', 'show_parade_float',
 ];
 
+/**
+ * Monthly running cost of one placed building. Big rides cost the most to
+ * operate; trees, flowers and most path furniture cost nothing.
+ */
+export function getBuildingUpkeep(type: BuildingType | string): number {
+  if (LARGE_RIDES.includes(type as BuildingType)) return 60;
+  if (SMALL_RIDES.includes(type as BuildingType)) return 30;
+  if (type.startsWith('station_')) return 25;
+  if (type.startsWith('infra_')) return 15;
+  if (isFoodBuilding(type)) return 10;
+  if (SHOPS.includes(type as BuildingType)) return 8;
+  if (type.startsWith('fountain_')) return 3;
+  if (isLampBuilding(type)) return 1;
+  if (isPlantBuilding(type) || PATH_FURNITURE.includes(type as BuildingType)) return 0;
+  return 2;
+}
+
 // =============================================================================
 // BUILDING STATS
 // =============================================================================
@@ -227,3 +436,187 @@ export const DEFAULT_BUILDING_STATS: BuildingStats = {
   cycleTime: 0,
   category: 'furniture',
 };
+
+// =============================================================================
+// RIDE PROFILES
+// =============================================================================
+
+export interface RideProfile {
+  excitement: number; // 0-10
+  intensity: number; // 0-10
+  nausea: number; // 0-10
+}
+
+// Fallback for ride buildings without a tuned profile
+export const DEFAULT_RIDE_PROFILE: RideProfile = { excitement: 4, intensity: 3, nausea: 2 };
+
+// Per-ride excitement/intensity/nausea used when a guest finishes a ride
+export const RIDE_PROFILES: Partial<Record<BuildingType, RideProfile>> = {
+  // Kiddie rides - gentle
+  ride_kiddie_coaster: { excitement: 3, intensity: 2, nausea: 0.8 },
+  ride_kiddie_train: { excitement: 2, intensity: 0.5, nausea: 0.2 },
+  ride_kiddie_planes: { excitement: 2.5, intensity: 1, nausea: 0.6 },
+  ride_kiddie_boats: { excitement: 2, intensity: 0.5, nausea: 0.3 },
+  ride_kiddie_cars: { excitement: 2, intensity: 0.5, nausea: 0.2 },
+  // Spinning rides - low intensity, high nausea
+  ride_teacups: { excitement: 3.5, intensity: 3, nausea: 5 },
+  ride_scrambler: { excitement: 4.5, intensity: 4.5, nausea: 5 },
+  ride_tilt_a_whirl: { excitement: 4.5, intensity: 4, nausea: 5.5 },
+  ride_spinning_apples: { excitement: 3, intensity: 2.5, nausea: 3.5 },
+  ride_whirlwind: { excitement: 5, intensity: 5, nausea: 6 },
+  // Classic rides
+  ride_carousel: { excitement: 3, intensity: 0.8, nausea: 0.3 },
+  ride_antique_cars: { excitement: 2.5, intensity: 0.5, nausea: 0.2 },
+  ride_monorail_car: { excitement: 3, intensity: 0.5, nausea: 0.2 },
+  ride_sky_ride_car: { excitement: 3.5, intensity: 1, nausea: 0.4 },
+  ride_train_car: { excitement: 3, intensity: 0.5, nausea: 0.2 },
+  // Vehicle and theater rides
+  ride_bumper_cars: { excitement: 4, intensity: 3.5, nausea: 1.5 },
+  ride_go_karts: { excitement: 5, intensity: 4, nausea: 1.5 },
+  ride_simulator: { excitement: 5, intensity: 5, nausea: 4.5 },
+  ride_motion_theater: { excitement: 4.5, intensity: 4, nausea: 3.5 },
+  ride_4d_theater: { excitement: 4.5, intensity: 2.5, nausea: 1.5 },
+  // Water rides
+  ride_bumper_boats: { excitement: 3.5, intensity: 2.5, nausea: 1 },
+  ride_paddle_boats: { excitement: 2.5, intensity: 0.5, nausea: 0.5 },
+  ride_lazy_river: { excitement: 3, intensity: 0.5, nausea: 0.3 },
+  ride_water_play: { excitement: 2.5, intensity: 1, nausea: 0.3 },
+  ride_splash_zone: { excitement: 3, intensity: 1.5, nausea: 0.5 },
+  // Dark rides
+  ride_haunted_house: { excitement: 5, intensity: 3, nausea: 0.8 },
+  ride_ghost_train: { excitement: 4.5, intensity: 3, nausea: 1 },
+  ride_dark_ride: { excitement: 5, intensity: 2.5, nausea: 1 },
+  ride_tunnel: { excitement: 3.5, intensity: 1.5, nausea: 0.5 },
+  ride_themed_facade: { excitement: 3, intensity: 1, nausea: 0.3 },
+  // Ferris wheels
+  ride_ferris_classic: { excitement: 4, intensity: 1, nausea: 0.5 },
+  ride_ferris_modern: { excitement: 4.5, intensity: 1, nausea: 0.5 },
+  ride_ferris_observation: { excitement: 5, intensity: 1.5, nausea: 0.6 },
+  ride_ferris_double: { excitement: 5, intensity: 2, nausea: 1 },
+  ride_ferris_led: { excitement: 5, intensity: 1, nausea: 0.5 },
+  // Drop/tower rides
+  ride_drop_tower: { excitement: 7, intensity: 8.5, nausea: 5 },
+  ride_space_shot: { excitement: 7.5, intensity: 9, nausea: 5.5 },
+  ride_observation_tower: { excitement: 4.5, intensity: 1, nausea: 0.5 },
+  ride_sky_swing: { excitement: 6, intensity: 6, nausea: 4.5 },
+  ride_star_flyer: { excitement: 6.5, intensity: 6.5, nausea: 4.5 },
+  // Swing rides
+  ride_swing_ride: { excitement: 5, intensity: 4, nausea: 3.5 },
+  ride_wave_swinger: { excitement: 5.5, intensity: 4.5, nausea: 4 },
+  ride_flying_scooters: { excitement: 5, intensity: 4.5, nausea: 4 },
+  ride_enterprise: { excitement: 6.5, intensity: 7.5, nausea: 7 },
+  ride_loop_o_plane: { excitement: 6, intensity: 8, nausea: 8 },
+  // Thrill rides
+  ride_top_spin: { excitement: 7, intensity: 8.5, nausea: 8 },
+  ride_frisbee: { excitement: 7, intensity: 8, nausea: 7 },
+  ride_afterburner: { excitement: 7, intensity: 8.5, nausea: 7.5 },
+  ride_inversion: { excitement: 7, intensity: 9, nausea: 8.5 },
+  ride_meteorite: { excitement: 6.5, intensity: 7.5, nausea: 7.5 },
+  // Transport/water rides
+  ride_log_flume: { excitement: 6, intensity: 4.5, nausea: 2 },
+  ride_rapids: { excitement: 6, intensity: 4.5, nausea: 2.5 },
+  ride_train_station: { excitement: 3.5, intensity: 0.5, nausea: 0.2 },
+  ride_monorail_station: { excitement: 3.5, intensity: 0.5, nausea: 0.2 },
+  ride_chairlift: { excitement: 4, intensity: 1, nausea: 0.4 },
+  // Shows
+  show_4d: { excitement: 5, intensity: 2, nausea: 1 },
+  show_stunt: { excitement: 6, intensity: 2, nausea: 0.3 },
+  show_dolphin: { excitement: 5, intensity: 1, nausea: 0.2 },
+  show_amphitheater: { excitement: 4.5, intensity: 0.5, nausea: 0.1 },
+  show_parade_float: { excitement: 4, intensity: 0.5, nausea: 0.1 },
+};
+
+/**
+ * Get the excitement/intensity/nausea profile for a ride building
+ */
+export function getRideProfile(type: BuildingType): RideProfile {
+  return RIDE_PROFILES[type] ?? DEFAULT_RIDE_PROFILE;
+}
+
+// =============================================================================
+// THEMES
+// =============================================================================
+
+export type ParkTheme =
+  | 'medieval' | 'pirate' | 'western' | 'jungle' | 'space' | 'ocean'
+  | 'spooky' | 'winter' | 'spring' | 'circus' | 'sports' | 'modern';
+
+const THEME_MEMBERS: Record<ParkTheme, string[]> = {
+  medieval: ['theme_castle_tower', 'theme_castle_wall', 'theme_drawbridge', 'theme_knight_statue', 'theme_dragon_statue', 'cart_medieval', 'queue_castle'],
+  pirate: ['theme_pirate_ship', 'theme_treasure_chest', 'theme_skull_rock', 'theme_cannon', 'theme_anchor', 'cart_pirate'],
+  western: ['theme_saloon', 'theme_water_tower', 'theme_wagon_wheel', 'theme_cactus', 'theme_windmill', 'cart_western'],
+  jungle: ['theme_temple_ruins', 'theme_tiki_statue', 'theme_safari_jeep', 'theme_elephant_statue', 'theme_bamboo_hut', 'cart_tropical', 'queue_jungle'],
+  space: ['theme_rocket_ship', 'theme_ufo', 'theme_robot_statue', 'theme_portal', 'theme_satellite', 'cart_space', 'queue_space'],
+  ocean: ['theme_coral_reef', 'theme_submarine', 'theme_diving_helmet', 'theme_treasure', 'theme_seashell'],
+  spooky: ['theme_haunted_tree', 'theme_gravestone', 'theme_pumpkin', 'theme_witch_cauldron', 'theme_skeleton', 'ride_haunted_house', 'ride_ghost_train'],
+  winter: ['theme_christmas_tree', 'theme_snowman', 'theme_presents', 'theme_candy_cane', 'theme_ice_sculpture'],
+  spring: ['theme_giant_egg', 'theme_bunny_statue', 'theme_flower_arch', 'theme_butterfly', 'theme_bird_bath'],
+  circus: ['theme_circus_tent', 'theme_strongman', 'theme_clown_statue', 'theme_balloon_arch', 'theme_carnival_banner'],
+  sports: ['theme_trophy', 'theme_mascot', 'theme_scoreboard', 'theme_goal_post', 'theme_checkered_flag'],
+  modern: ['theme_geometric', 'theme_water_wall', 'theme_led_cube', 'theme_mirror_ball', 'theme_kinetic'],
+};
+
+// Holiday decorations and the month (1-12) they belong to
+const DECORATION_SEASONS: Partial<Record<BuildingType, number>> = {
+  theme_haunted_tree: 10,
+  theme_gravestone: 10,
+  theme_pumpkin: 10,
+  theme_witch_cauldron: 10,
+  theme_skeleton: 10,
+  theme_christmas_tree: 12,
+  theme_snowman: 12,
+  theme_presents: 12,
+  theme_candy_cane: 12,
+  theme_ice_sculpture: 12,
+  theme_giant_egg: 4,
+  theme_bunny_statue: 4,
+};
+
+/**
+ * The month a holiday decoration is in season, or null for year-round buildings
+ */
+export function getDecorationSeason(type: BuildingType | string): number | null {
+  return DECORATION_SEASONS[type.replace('_footprint', '') as BuildingType] ?? null;
+}
+
+/** A cluster of nearby same-theme buildings that boosts guest happiness */
+export interface ThemeZone {
+  id: string;
+  theme: ParkTheme;
+  /** Origin tile of every themed building in the zone */
+  tiles: { x: number; y: number }[];
+  centerX: number;
+  centerY: number;
+  minX: number;
+  maxX: number;
+  minY: number;
+  maxY: number;
+  /** 0-1, grows with the number of buildings in the zone */
+  strength: number;
+}
+
+// Coaster stations take the theme of their station style (any rotation)
+const STATION_THEMES: Record<string, ParkTheme> = {
+  station_water: 'pirate',
+  station_mine: 'western',
+  station_futuristic: 'space',
+  station_inverted: 'modern',
+};
+
+const BUILDING_THEMES = new Map<string, ParkTheme>();
+for (const [theme, members] of Object.entries(THEME_MEMBERS) as [ParkTheme, string[]][]) {
+  for (const member of members) BUILDING_THEMES.set(member, theme);
+}
+
+/**
+ * Get the theme a building belongs to (footprint tiles share their building's theme)
+ */
+export function getBuildingTheme(type: BuildingType | string): ParkTheme | null {
+  const baseType = type.replace('_footprint', '');
+  const theme = BUILDING_THEMES.get(baseType);
+  if (theme) return theme;
+  if (baseType.startsWith('station_')) {
+    return STATION_THEMES[baseType.replace(/_\d+$/, '')] ?? null;
+  }
+  return null;
+}
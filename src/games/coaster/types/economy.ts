@@ -38,7 +38,7 @@ export const WEATHER_EFFECTS: Record<WeatherType, WeatherEffects> = {
     outdoorRidePopularity: 1.1,
     waterRidePopularity: 1.2,
     indoorRidePopularity: 0.95,
-    foodSalesMultiplier: 1.0,
+    foodSalesMultiplier: 1.1,
     drinkSalesMultiplier: 1.2,
     leaveChanceMultiplier: 0.9,
   },
@@ -161,6 +161,30 @@ export function getSeasonalWeatherBias(month: number): Partial<Record<WeatherTyp
 // GUEST TYPES
 // =============================================================================
 
+export type GuestBudgetTier = 'low' | 'medium' | 'high';
+export type GuestThrillLevel = 'timid' | 'moderate' | 'thrill_seeker';
+
+/** Spending money a guest brings, by budget tier */
+export const BUDGET_TIER_CASH: Record<GuestBudgetTier, { min: number; max: number }> = {
+  low: { min: 20, max: 45 },
+  medium: { min: 40, max: 90 },
+  high: { min: 80, max: 160 },
+};
+
+/** How far above default prices a guest will still pay (1 = default price) */
+export const BUDGET_TIER_PRICE_TOLERANCE: Record<GuestBudgetTier, number> = {
+  low: 0.8,
+  medium: 1.5,
+  high: 3,
+};
+
+/** Bucket a 0-1 thrill preference into a thrill level */
+export function getGuestThrillLevel(thrillPreference: number): GuestThrillLevel {
+  if (thrillPreference < 0.33) return 'timid';
+  if (thrillPreference < 0.67) return 'moderate';
+  return 'thrill_seeker';
+}
+
 export type GuestState =
   | 'entering'        // Walking into park
   | 'walking'         // Walking on paths
@@ -193,6 +217,7 @@ export type GuestThought =
   | 'cant_find_exit'
   | 'spent_too_much'
   | 'good_value'
+  | 'sold_out'
   // Weather-related thoughts
   | 'weather_great'
   | 'getting_wet'
@@ -220,6 +245,7 @@ export const GUEST_THOUGHT_TEXT: Record<GuestThought, string> = {
   cant_find_exit: "Where's the exit?",
   spent_too_much: "I've spent too much money.",
   good_value: "Great value for money!",
+  sold_out: "They've sold out!",
   weather_great: "What lovely weather!",
   getting_wet: "I'm getting soaked!",
   too_hot: "It's so hot today!",
@@ -228,6 +254,17 @@ export const GUEST_THOUGHT_TEXT: Record<GuestThought, string> = {
   perfect_day: "Perfect day for the park!",
 };
 
+export const MAX_GUEST_THOUGHTS = 5; // Thoughts each guest remembers
+export const RECENT_THOUGHTS_LIMIT = 50; // Park-wide thought feed length
+
+/** A thought a guest just had, as shown in the park-wide feed */
+export interface RecentThought {
+  guestId: string;
+  guestName: string;
+  thought: GuestThought;
+  tick: number;
+}
+
 export interface Guest {
   id: string;
   name: string;
@@ -242,7 +279,7 @@ export interface Guest {
   state: GuestState;
   lastState: GuestState;
   targetBuildingId: string | null;
-  targetBuildingKind: 'ride' | 'food' | 'shop' | null;
+  targetBuildingKind: 'ride' | 'food' | 'shop' | 'restroom' | 'rest' | null;
   targetTileX: number;
   targetTileY: number;
   path: { x: number; y: number }[];
@@ -257,7 +294,6 @@ export interface Guest {
   // Approach state (for walking into shops/food stands)
   approachProgress: number; // 0 = on path, 1 = at building
   initialActivityTime: number; // Total time for eating/shopping (to calculate approach progress)
-  activityStartTime: number; // Real-time timestamp when activity started (for smooth animation)
   
   // Needs (0-100, higher = more urgent)
   hunger: number;
@@ -271,6 +307,11 @@ export interface Guest {
   preferExcitement: number;  // 0-10
   preferIntensity: number;   // 0-10
   nauseaTolerance: number;   // 0-10
+  thrillPreference: number;  // 0 (timid) - 1 (thrill-seeker), set at spawn
+  budgetTier: GuestBudgetTier;
+  
+  // Party: members of a family or group share the leader's id and mostly go where the leader goes
+  groupId?: string;
   
   // Money
   cash: number;
@@ -290,6 +331,44 @@ export interface Guest {
   walkOffset: number;
 }
 
+/** Park-wide numbers gathered in one pass, for analytics panels that poll */
+export interface ParkStatsSnapshot {
+  guests: number;
+  guestsByState: Record<GuestState, number>;
+  averageHappiness: number;
+  averageHunger: number;
+  averageThirst: number;
+  nauseousGuests: number; // Too sick to ride (see isGuestNauseous)
+  averageGroupSize: number; // Guests per party, counting solo visitors as parties of one
+  rides: number; // Flat rides and shows plus coasters
+  foodStalls: number;
+  cash: number;
+  debt: number;
+  rating: number;
+}
+
+/** Snapshot of one guest for inspection and follow-cam UIs */
+export interface GuestInfo {
+  id: string;
+  name: string;
+  tileX: number;
+  tileY: number;
+  state: GuestState;
+  hunger: number;
+  thirst: number;
+  energy: number;
+  happiness: number;
+  nausea: number;
+  cash: number;
+  totalSpent: number;
+  target: {
+    kind: Guest['targetBuildingKind'];
+    buildingId: string | null;
+    x: number;
+    y: number;
+  } | null;
+}
+
 // =============================================================================
 // PARK FINANCES
 // =============================================================================
@@ -306,6 +385,7 @@ export interface ParkFinances {
   incomeTotal: number;
   
   // Expenses (per month)
+  expenseConstruction: number; // Building costs less sell refunds, charged as they happen
   expenseWages: number;
   expenseUpkeep: number;
   expenseMarketing: number;
@@ -317,6 +397,76 @@ export interface ParkFinances {
   
   // Historical
   history: FinanceHistoryPoint[];
+  reports: MonthlyReport[]; // Breakdown of the last MONTHLY_REPORT_HISTORY completed months, oldest first
+  
+  // Debt tracking
+  daysInDebt: number; // Consecutive days the balance has been negative
+  bankrupt: boolean;  // Construction and guest arrivals are halted until cash recovers
+  
+  // Loan
+  loan: number;               // Outstanding principal
+  loanMonthlyPayment: number; // Deducted at each month rollover
+}
+
+export const LOW_CASH_THRESHOLD = 1000; // Warn when cash drops below this
+export const DEFAULT_BANKRUPTCY_GRACE_DAYS = 30; // Days of sustained debt before bankruptcy
+export const DEFAULT_AUTOSAVE_INTERVAL_HOURS = 3;
+export const DEFAULT_MAX_GUESTS = 50000; // Guests allowed in the park at once
+export const DEFAULT_SPAWN_RATE_MULTIPLIER = 1;
+export const MAX_SPAWN_RATE_MULTIPLIER = 20;
+export const DEFAULT_OPEN_HOUR = 9;
+export const DEFAULT_CLOSE_HOUR = 22;
+export const LOAN_CEILING = 50000; // Max outstanding loan principal
+export const BANKRUPTCY_CASH_FLOOR = -10000; // Below this with the loan maxed out, the park goes bankrupt at once
+export const LOAN_TERM_MONTHS = 24; // Loans are paid off over this many months
+
+/**
+ * Whether the gates are open at a given hour (open from openHour up to, not including, closeHour)
+ */
+export function isParkOpenAt(hour: number, openHour: number, closeHour: number): boolean {
+  return hour >= openHour && hour < closeHour;
+}
+
+export interface LoanSummary {
+  principal: number;
+  interestRate: number; // Annual rate
+  monthlyPayment: number;
+  ceiling: number;
+}
+
+/**
+ * Fixed monthly payment that pays off a loan over the given term with monthly compounding
+ */
+export function calculateLoanPayment(principal: number, annualRate: number, months: number = LOAN_TERM_MONTHS): number {
+  if (principal <= 0) return 0;
+  const monthlyRate = annualRate / 12;
+  if (monthlyRate <= 0) return principal / months;
+  return (principal * monthlyRate) / (1 - Math.pow(1 + monthlyRate, -months));
+}
+
+export const MONTHLY_REPORT_HISTORY = 12;
+
+/** Income and expenses of one completed month, by source */
+export interface MonthlyReport {
+  month: number;
+  year: number;
+  income: {
+    admissions: number;
+    rides: number;
+    food: number;
+    shops: number;
+  };
+  expenses: {
+    construction: number;
+    upkeep: number;
+    wages: number;
+    marketing: number;
+    research: number;
+    loanInterest: number;
+  };
+  incomeTotal: number;
+  expenseTotal: number;
+  profit: number;
 }
 
 export interface FinanceHistoryPoint {
@@ -333,6 +483,17 @@ export interface FinanceHistoryPoint {
 // PARK STATS
 // =============================================================================
 
+/** Where the park rating's points come from (each part is already weighted) */
+export interface RatingBreakdown {
+  happiness: number;    // Average guest happiness, up to 450
+  guests: number;       // Crowd size, up to 200
+  variety: number;      // Distinct kinds of ride, up to 150
+  connectivity: number; // Share of rides, stalls and shops guests can walk to, up to 150
+  scenery: number;      // Decorations spread across the park (diminishing per area), up to 50
+  cleanliness: number;  // Points lost to dirty paths (zero or negative)
+  total: number;        // Sum, clamped to 0-1000
+}
+
 export interface ParkStats {
   // Guest stats
   guestsInPark: number;
@@ -352,6 +513,30 @@ export interface ParkStats {
   
   // Rating
   parkRating: number; // 0-1000
+  ratingBreakdown?: RatingBreakdown;
+  
+  // Guests who have gone home, and their combined lifetime spending
+  guestsDeparted: number;
+  departedGuestSpending: number;
+  
+  // Guest preference distribution (guests currently in park)
+  guestPreferences?: GuestPreferenceStats;
+}
+
+export interface GuestPreferenceStats {
+  thrill: Record<GuestThrillLevel, number>;
+  budget: Record<GuestBudgetTier, number>;
+}
+
+// Aggregated answers from a random sample of guests (shares are 0-1)
+export interface GuestSurvey {
+  sampleSize: number;
+  hungry: number;
+  thirsty: number;
+  needBathroom: number;
+  nauseous: number;
+  bored: number; // No ride within walking distance
+  happy: number;
 }
 
 // =============================================================================
@@ -370,6 +555,14 @@ export interface ParkSettings {
   // Difficulty
   loanInterest: number;
   landCost: number;
+  bankruptcyGraceDays: number; // Days the park can stay in debt before going bankrupt
+  
+  // Autosave every N game hours (0 disables)
+  autosaveIntervalHours: number;
+  
+  // Guest arrivals: lower the cap on slow devices, raise it (and the rate) for stress tests
+  maxGuests: number;
+  spawnRateMultiplier: number; // Scales the whole arrival chance, rating and peak-hour bonuses included
   
   // Objectives (optional)
   objectives: ParkObjective[];
@@ -397,7 +590,20 @@ export interface Staff {
   patrol: { x: number; y: number }[]; // Patrol area corners
   wage: number;
   energy: number;
-  working: boolean;
+  working: boolean; // Busy with a job (e.g. a janitor sweeping)
+  
+  // Staff building the member works out of (origin tile)
+  homeX: number;
+  homeY: number;
+  
+  // Movement along paths (same scheme as guests)
+  targetTileX: number;
+  targetTileY: number;
+  progress: number;
+  path: { x: number; y: number }[];
+  pathIndex: number;
+  direction: 'north' | 'south' | 'east' | 'west';
+  decisionCooldown: number;
 }
 
 // =============================================================================
@@ -418,6 +624,23 @@ export const DEFAULT_PRICES = {
   entertainerWage: 55,
 };
 
+// =============================================================================
+// STALL STOCK
+// =============================================================================
+
+export const STALL_STOCK_CAPACITY = 60; // Items a food stall or shop holds when fully supplied
+export const STALL_LOW_STOCK = 10; // At or below this a stall is flagged as running low
+export const STALL_RESTOCK_PER_HOUR = 4; // Items delivered to each stall every game hour
+
+/** A food stall or shop that is running out of stock */
+export interface LowStockStall {
+  id: string; // Building id ("x,y")
+  x: number;
+  y: number;
+  type: string;
+  stock: number;
+}
+
 // =============================================================================
 // GUEST NAME GENERATOR
 // =============================================================================
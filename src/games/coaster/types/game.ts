@@ -2,9 +2,10 @@
  * IsoCoaster Game State Types
  */
 
-import { Building, BuildingType } from './buildings';
-import { Coaster, TrackPiece, TrackDirection, CoasterType, CoasterCategory } from './tracks';
-import { Guest, ParkFinances, ParkStats, ParkSettings, Staff, WeatherState } from './economy';
+import { Building, BuildingType, BuildingOrientation, ThemeZone } from './buildings';
+import { Coaster, TrackPiece, TrackPieceType, TrackDirection, TrackHeight, CoasterType, CoasterCategory } from './tracks';
+import { Guest, ParkFinances, ParkStats, ParkSettings, RecentThought, Staff, WeatherState } from './economy';
+import { ColorblindMode } from '@/lib/colorPalettes';
 
 // =============================================================================
 // TOOL TYPES
@@ -14,12 +15,18 @@ export type Tool =
   // Basic tools
   | 'select'
   | 'bulldoze'
+  | 'sell'
   | 'path'
   | 'queue'
+  | 'auto_path'
   
   // Terrain/Zoning
   | 'zone_water'
   | 'zone_land'
+  | 'terrain_raise'
+  | 'terrain_lower'
+  | 'terrain_sand'
+  | 'terrain_rock'
   
   // Coaster building - track pieces
   | 'coaster_build'
@@ -162,12 +169,18 @@ export type ToolCategory =
 export const TOOL_INFO: Record<Tool, ToolInfo> = {
   select: { name: 'Select', cost: 0, description: 'Select and inspect', category: 'tools' },
   bulldoze: { name: 'Bulldoze', cost: 10, description: 'Remove objects', category: 'tools' },
+  sell: { name: 'Sell', cost: 0, description: 'Remove objects for a partial refund', category: 'tools' },
   path: { name: 'Path', cost: 10, description: 'Build guest walkways', category: 'paths' },
   queue: { name: 'Queue Line', cost: 15, description: 'Build ride queues', category: 'paths' },
+  auto_path: { name: 'Auto Path', cost: 10, description: 'Click two tiles to connect them with path (cost per tile)', category: 'paths' },
   
   // Terrain/Zoning
   zone_water: { name: 'Water Terraform', cost: 500, description: 'Terraform land into water', category: 'terrain' },
   zone_land: { name: 'Land Terraform', cost: 500, description: 'Terraform water into land', category: 'terrain' },
+  terrain_raise: { name: 'Raise Land', cost: 50, description: 'Raise the ground one step (cost per tile reshaped)', category: 'terrain' },
+  terrain_lower: { name: 'Lower Land', cost: 50, description: 'Lower the ground one step (cost per tile reshaped)', category: 'terrain' },
+  terrain_sand: { name: 'Sand', cost: 20, description: 'Cover open ground with sand for beaches and deserts', category: 'terrain' },
+  terrain_rock: { name: 'Rock', cost: 30, description: 'Cover open ground with bare rock', category: 'terrain' },
   
   coaster_build: { name: 'Coaster Build Mode', cost: 0, description: 'Start building a coaster', category: 'coasters' },
   coaster_track: { name: 'Track: Straight', cost: 20, description: 'Place straight track segments', category: 'coasters' },
@@ -438,6 +451,57 @@ export const TOOL_INFO: Record<Tool, ToolInfo> = {
   staff_building: { name: 'Staff Building', cost: 500, description: 'Staff facilities', category: 'infrastructure', size: { width: 2, height: 2 } },
 };
 
+// =============================================================================
+// BUILDING COSTS & SELLING
+// =============================================================================
+
+// Fraction of the build cost refunded by the sell tool (must stay below 1 so selling never profits)
+export const SELL_REFUND_RATE = 0.5;
+
+// Building types placed by a tool with a different name
+const BUILDING_TOOL_OVERRIDES: Partial<Record<BuildingType, Tool>> = {
+  shop_souvenir_1: 'shop_souvenir',
+  shop_souvenir_2: 'shop_souvenir',
+  shop_popcorn: 'shop_popcorn_shop',
+  infra_main_entrance: 'park_entrance',
+  infra_office: 'staff_building',
+};
+
+// Track piece types placed by each track tool
+const TRACK_PIECE_TOOLS: Partial<Record<TrackPieceType, Tool>> = {
+  straight_flat: 'coaster_track',
+  turn_left_flat: 'coaster_turn_left',
+  turn_right_flat: 'coaster_turn_right',
+  slope_up_small: 'coaster_slope_up',
+  slope_down_small: 'coaster_slope_down',
+  loop_vertical: 'coaster_loop',
+};
+
+/**
+ * Get the tool that places a building type (null for non-placeable types)
+ */
+export function getToolForBuilding(type: BuildingType): Tool | null {
+  if (type.startsWith('station_')) return 'coaster_station';
+  const override = BUILDING_TOOL_OVERRIDES[type];
+  if (override) return override;
+  return type in TOOL_INFO ? (type as Tool) : null;
+}
+
+/**
+ * Get the build cost of a building type
+ */
+export function getBuildingCost(type: BuildingType): number {
+  const tool = getToolForBuilding(type);
+  return tool ? TOOL_INFO[tool].cost : 0;
+}
+
+/**
+ * Get the build cost of a single track piece
+ */
+export function getTrackPieceCost(type: TrackPieceType): number {
+  return TOOL_INFO[TRACK_PIECE_TOOLS[type] ?? 'coaster_track'].cost;
+}
+
 // =============================================================================
 // TILE TYPE
 // =============================================================================
@@ -456,6 +520,36 @@ export interface Tile {
   elevation: number; // For terrain height
 }
 
+/** Everything on one tile in a single read-only snapshot, for debug panels and tooling */
+export interface TileInspection {
+  x: number;
+  y: number;
+  terrain: Tile['terrain'];
+  elevation: number;
+  path: boolean;
+  queue: boolean;
+  queueRideId: string | null;
+  buildingType: BuildingType; // Footprint tiles report the building they belong to
+  buildingOrigin: { x: number; y: number } | null; // Origin tile of that building
+  buildingCost: number;
+  hasCoasterTrack: boolean;
+  coasterId: string | null;
+  coasterName: string | null;
+  trackPieceType: TrackPieceType | null;
+  litter: number;
+  reachableFromEntrance: boolean; // Guests can walk here (or to the building's access tile) from an entrance
+}
+
+/** What a track tool would lay on a tile, checked before committing */
+export interface TrackPlacementPreview {
+  valid: boolean;
+  reason: string | null; // Why the piece can't go here (occupied, water, not adjacent...)
+  pieceType: TrackPieceType | null;
+  direction: TrackDirection | null;
+  startHeight: TrackHeight | null;
+  endHeight: TrackHeight | null;
+}
+
 // =============================================================================
 // NOTIFICATION TYPE
 // =============================================================================
@@ -470,13 +564,107 @@ export interface Notification {
   tileY?: number;
 }
 
+// =============================================================================
+// GAME EVENTS
+// =============================================================================
+
+export const MAX_PENDING_EVENTS = 200; // Oldest events are dropped if nobody drains the queue
+
+/** Something notable that happened during a tick, for the UI to react to (sounds, toasts) */
+export type GameEvent = { tick: number } & (
+  | { type: 'ride_completed'; rideId: string; riders: number }
+  | { type: 'guest_left'; guestId: string; happy: boolean }
+  | { type: 'month_ended'; month: number; year: number; profit: number }
+  | { type: 'coaster_stalled'; coasterId: string }
+  | { type: 'bankrupt' }
+  | { type: 'placement_rejected'; tool: Tool; x: number; y: number; reason: string }
+);
+
+// =============================================================================
+// MONEY POPUPS
+// =============================================================================
+
+export const MONEY_POPUP_TICKS = 20; // Ticks a popup floats before it's gone (about a second at 1x)
+export const MAX_MONEY_POPUPS = 60; // Oldest popups are dropped past this
+
+/** A floating "+$15" over the tile where money was just taken in */
+export interface MoneyPopup {
+  x: number;
+  y: number;
+  amount: number;
+  tick: number; // Tick the money came in
+}
+
+// =============================================================================
+// TICK PROFILING
+// =============================================================================
+
+/** Milliseconds the last simulation tick spent in each subsystem */
+export interface TickBreakdown {
+  guests: number; // Guest AI (updateGuest for every guest)
+  spawns: number; // Arrivals at the gate
+  trains: number; // Coaster train physics and dispatch
+  total: number; // The whole tick, including everything not broken out above
+}
+
+// =============================================================================
+// CAMERA VIEWPOINTS
+// =============================================================================
+
+export const VIEWPOINT_SLOTS = 9; // Bookmark slots 1-9
+
+export interface CameraViewpoint {
+  slot: number;
+  offsetX: number;
+  offsetY: number;
+  zoom: number;
+}
+
+// =============================================================================
+// RIDE STATS
+// =============================================================================
+
+export interface RideStats {
+  balks: number; // Guests who arrived to a full line and gave up
+  queue: string[]; // Ids of guests waiting in line, front first
+  ridersServed: number; // Guests who have boarded since the ride was built
+  dispatchTimer: number; // Ticks until a flat ride can send its next batch
+}
+
+export function createRideStats(): RideStats {
+  return { balks: 0, queue: [], ridersServed: 0, dispatchTimer: 0 };
+}
+
+/** Snapshot of a ride's line for the UI */
+export interface RideInfo {
+  rideId: string;
+  queueLength: number;
+  maxQueueLength: number;
+  ridersServed: number;
+  balks: number;
+  capacity: number; // Guests boarded per cycle
+  waitTime: number | null; // Ticks the back of the line will wait to board; null for coaster stations (trains board one by one)
+  price: number; // Ticket price (charged when the park is pay-per-ride)
+  fairPrice: number; // What guests think the ride is worth
+}
+
 // =============================================================================
 // GAME STATE
 // =============================================================================
 
+/** Data overlays that can be tinted over the park */
+export type OverlayMode = 'none' | 'guest_density';
+
+/** Tint colors for overlays, placement previews and guests; the colorblind palettes avoid red/green pairs */
+export type OverlayPalette = ColorblindMode;
+
 export interface GameState {
   id: string;
   
+  // Randomness - the same seed regenerates the same lakes and replays the same simulation
+  seed?: number;
+  rngState?: number; // Position in the simulation's random stream, saved after every tick
+  
   // Grid
   grid: Tile[][];
   gridSize: number;
@@ -503,11 +691,40 @@ export interface GameState {
   staff: Staff[];
   coasters: Coaster[];
   
+  // Clusters of same-theme buildings (recomputed hourly by the simulation)
+  themeZones?: ThemeZone[];
+  
   // UI State
   selectedTool: Tool;
+  placementOrientation?: BuildingOrientation; // Facing given to the next placed building
   activePanel: 'none' | 'finances' | 'guests' | 'rides' | 'staff' | 'settings';
+  overlayMode?: OverlayMode; // Data overlay tinted over the park
+  overlayPalette?: OverlayPalette;
   notifications: Notification[];
   
+  // Income from the last MONEY_POPUP_TICKS ticks, shown floating over where it was paid
+  moneyPopups?: MoneyPopup[];
+  
+  // Events from recent ticks the UI hasn't drained yet (at most MAX_PENDING_EVENTS)
+  events?: GameEvent[];
+  
+  // Per-ride counters, keyed by ride building id ("x,y")
+  rideStats?: Record<string, RideStats>;
+  
+  // Litter on path tiles (0-100), keyed by "x,y"; clean tiles are left out.
+  // Kept off the grid so sweeping doesn't count as a park edit.
+  litter?: Record<string, number>;
+  
+  // Items left at food stalls and shops, keyed by building id ("x,y");
+  // fully stocked stalls are left out
+  stallStock?: Record<string, number>;
+  
+  // Latest guest thoughts across the park, oldest first
+  recentThoughts?: RecentThought[];
+  
+  // Saved camera bookmarks
+  viewpoints?: CameraViewpoint[];
+  
   // Active coaster building (if any)
   buildingCoasterId: string | null;
   buildingCoasterPath: { x: number; y: number }[];
@@ -191,6 +191,41 @@ export function areCoasterTypesCompatible(type1: CoasterType, type2: CoasterType
   return COASTER_TYPE_CATEGORY[type1] === COASTER_TYPE_CATEGORY[type2];
 }
 
+/** Station sprite families (one row each in the stations sheet) */
+export type StationStyle = 'wooden' | 'steel' | 'inverted' | 'water' | 'mine' | 'futuristic';
+
+/**
+ * Which station sprites suit a coaster type
+ */
+export function getStationStyleForCoasterType(coasterType: CoasterType): StationStyle {
+  switch (coasterType) {
+    case 'wooden_classic':
+    case 'wooden_twister':
+      return 'wooden';
+    case 'water_coaster':
+      return 'water';
+    case 'mine_train':
+      return 'mine';
+    case 'steel_inverted':
+    case 'suspended':
+    case 'steel_flying':
+    case 'steel_wing':
+      return 'inverted';
+    case 'launch_coaster':
+    case 'steel_4d':
+      return 'futuristic';
+    default:
+      return 'steel';
+  }
+}
+
+/**
+ * Whether a string names a known coaster type
+ */
+export function isCoasterType(value: string): value is CoasterType {
+  return Object.hasOwn(COASTER_TYPE_CATEGORY, value);
+}
+
 export interface CoasterTypeStats {
   name: string;
   maxSpeed: number; // km/h
@@ -461,6 +496,12 @@ export interface Coaster {
   stationTileX: number;
   stationTileY: number;
   trains: CoasterTrain[];
+  /** Player-configured train layout (auto-sized from track length when unset) */
+  trainConfig?: CoasterTrainConfig;
+  /** Player-configured station timing (defaults apply when unset) */
+  dispatchPolicy?: CoasterDispatchPolicy;
+  /** A train ran out of energy on the circuit; trains stop until the track changes */
+  stalled?: boolean;
   operating: boolean;
   broken: boolean;
   excitement: number;
@@ -471,6 +512,45 @@ export interface Coaster {
   upkeep: number;
 }
 
+/** One entry in the coaster picker: enough to label it and find its station */
+export interface CoasterSummary {
+  id: string;
+  name: string;
+  type: CoasterType;
+  stationTileX: number;
+  stationTileY: number;
+  /** Whether new track extends this coaster */
+  active: boolean;
+}
+
+export const MAX_COASTER_NAME_LENGTH = 40;
+
+/**
+ * Whether a string is a #rgb or #rrggbb color a coaster can be painted in
+ */
+export function isHexColor(value: string): boolean {
+  return /^#([0-9a-f]{3}|[0-9a-f]{6})$/i.test(value);
+}
+
+/**
+ * Lowercase #rrggbb form of a hex color; #rgb shorthand is expanded so the
+ * renderer can always read two digits per channel
+ */
+export function normalizeHexColor(value: string): string {
+  const hex = value.toLowerCase();
+  if (hex.length !== 4) return hex;
+  return `#${hex[1]}${hex[1]}${hex[2]}${hex[2]}${hex[3]}${hex[3]}`;
+}
+
+/** Snapshot of whether a coaster is running, for UI and scripting */
+export interface CoasterOperatingState {
+  operating: boolean;
+  /** Open but the circuit isn't complete yet, so trains can't run */
+  underTest: boolean;
+  broken: boolean;
+  trainCount: number;
+}
+
 // =============================================================================
 // COASTER TRAIN & CAR
 // =============================================================================
@@ -490,6 +570,33 @@ export interface CoasterCar {
   guests: string[]; // Guest IDs
 }
 
+export const DEFAULT_CAR_SPACING = 0.18; // Track units between consecutive cars
+export const MIN_TRAIN_GAP = 4; // Track units kept clear ahead of each train (matches collision check)
+
+export interface CoasterTrainConfig {
+  numTrains: number;
+  carsPerTrain: number;
+  /** Track units between consecutive cars */
+  carSpacing: number;
+}
+
+/**
+ * Check that a train layout fits on a circuit of the given length.
+ * Each train needs room for its cars plus a clear gap before the next train.
+ * Returns an error message, or null if the layout is valid.
+ */
+export function validateTrainConfig(trackLength: number, config: CoasterTrainConfig): string | null {
+  if (!Number.isInteger(config.numTrains) || config.numTrains < 1) return 'A coaster needs at least one train.';
+  if (!Number.isInteger(config.carsPerTrain) || config.carsPerTrain < 1) return 'Each train needs at least one car.';
+  if (!(config.carSpacing > 0)) return 'Car spacing must be greater than zero.';
+  const trainLength = (config.carsPerTrain - 1) * config.carSpacing;
+  const required = config.numTrains * (trainLength + MIN_TRAIN_GAP);
+  if (required > trackLength) {
+    return `${config.numTrains} train(s) of ${config.carsPerTrain} cars need ${Math.ceil(required)} track pieces, but this track has ${trackLength}.`;
+  }
+  return null;
+}
+
 export interface CoasterTrain {
   id: string;
   cars: CoasterCar[];
@@ -497,4 +604,28 @@ export interface CoasterTrain {
   state: 'loading' | 'dispatching' | 'running' | 'braking' | 'returning';
   /** Time until next state change */
   stateTimer: number;
+  /** Time spent at the station in the current loading cycle */
+  loadingTime?: number;
+  /** Builds up while the train is stopped or rolling back; stalls the coaster when too high */
+  stallTimer?: number;
+}
+
+export const SEATS_PER_CAR = 4;
+export const DEFAULT_DISPATCH_MIN_WAIT = 5; // Game minutes a train always waits at the station
+export const DEFAULT_DISPATCH_MAX_WAIT = 8; // Game minutes after which a train leaves even if not full
+
+/**
+ * Station dispatch timing: a train leaves once it is full and has waited
+ * minWait, or after maxWait regardless of how many guests boarded.
+ */
+export interface CoasterDispatchPolicy {
+  minWait: number;
+  maxWait: number;
+}
+
+/**
+ * Resolve a coaster's dispatch policy, falling back to the defaults
+ */
+export function getDispatchPolicy(coaster: Coaster): CoasterDispatchPolicy {
+  return coaster.dispatchPolicy ?? { minWait: DEFAULT_DISPATCH_MIN_WAIT, maxWait: DEFAULT_DISPATCH_MAX_WAIT };
 }
@@ -75,6 +75,9 @@ import { saveParkToIndex } from '@/games/coaster/saveUtils';
          coaster.setTool(savedTool);
          break;
        }
+       case 'bulldozeArea':
+         coaster.bulldozeArea(action.start, action.end, true);
+         break;
        case 'setSpeed':
          coaster.setSpeed(action.speed, true);
          break;
@@ -185,15 +188,20 @@ import { saveParkToIndex } from '@/games/coaster/saveUtils';
    useEffect(() => {
      if (!multiplayer || multiplayer.connectionState !== 'connected') {
        coaster.setBulldozeCallback(null);
+       coaster.setBulldozeAreaCallback(null);
        return;
      }
 
      coaster.setBulldozeCallback(({ x, y }) => {
        broadcastAction({ type: 'bulldoze', x, y });
      });
+     coaster.setBulldozeAreaCallback(({ start, end }) => {
+       broadcastAction({ type: 'bulldozeArea', start, end });
+     });
 
      return () => {
        coaster.setBulldozeCallback(null);
+       coaster.setBulldozeAreaCallback(null);
      };
    }, [multiplayer, multiplayer?.connectionState, coaster, broadcastAction]);
 
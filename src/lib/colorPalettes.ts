@@ -0,0 +1,226 @@
+/**
+ * Color palette selection for overlays and people, shared by IsoCity and IsoCoaster.
+ * Provides colorblind-safe alternatives to the default red/green color choices.
+ * All overlay rendering should read colors from the palette here.
+ */
+
+import { OverlayMode } from '@/components/game/types';
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/** Supported color vision modes */
+export type ColorblindMode = 'none' | 'deuteranopia' | 'protanopia';
+
+/** List of all colorblind modes (for iteration) */
+export const COLORBLIND_MODES: ColorblindMode[] = ['none', 'deuteranopia', 'protanopia'];
+
+/** Fill and outline of a tile tint */
+export type TintPair = {
+  fill: string;
+  stroke: string;
+};
+
+/** Full set of colors used by overlays and pedestrians for one color vision mode */
+export type ColorPalette = {
+  /** Tile tint for buildings missing a service */
+  uncoveredWarning: string;
+  /** Subway overlay tint for tiles with subway */
+  subwayPresent: string;
+  /** Subway overlay tint for the "underground" view */
+  subwayAbsent: string;
+  /** Service radius stroke colors */
+  circleColors: Record<OverlayMode, string>;
+  /** Service radius fill colors (subtle, for area visibility) */
+  circleFillColors: Record<OverlayMode, string>;
+  /** Service building highlight glow colors */
+  highlightColors: Record<OverlayMode, string>;
+  /** Placement preview tint where the tool can build */
+  placementValid: TintPair;
+  /** Placement preview tint where it can't */
+  placementInvalid: TintPair;
+  /** Crowd heatmap tint from 0 (empty) to 1 (crowded) */
+  heatmap: (level: number) => string;
+  /** Shirt colors for pedestrians and park guests (null keeps each game's own) */
+  shirtColors: string[] | null;
+};
+
+type Rgb = [number, number, number];
+
+/**
+ * Heatmap ramp between two colors that stay apart for red-green colorblind eyes
+ */
+function rampHeatmap(empty: Rgb, crowded: Rgb): (level: number) => string {
+  return level => {
+    const mix = (a: number, b: number) => Math.round(a + (b - a) * level);
+    return `rgba(${mix(empty[0], crowded[0])}, ${mix(empty[1], crowded[1])}, ${mix(empty[2], crowded[2])}, ${0.3 + level * 0.3})`;
+  };
+}
+
+// ============================================================================
+// Palettes
+// ============================================================================
+
+/** Default palette (original colors) */
+const DEFAULT_PALETTE: ColorPalette = {
+  uncoveredWarning: 'rgba(239, 68, 68, 0.45)', // Red tint
+  subwayPresent: 'rgba(245, 158, 11, 0.7)',   // Bright amber
+  subwayAbsent: 'rgba(40, 30, 20, 0.4)',      // Dark brown
+  circleColors: {
+    none: 'transparent',
+    power: 'rgba(251, 191, 36, 0.8)',    // Amber
+    water: 'rgba(96, 165, 250, 0.8)',    // Blue
+    fire: 'rgba(248, 113, 113, 0.8)',    // Light red
+    police: 'rgba(147, 197, 253, 0.8)',  // Light blue
+    health: 'rgba(134, 239, 172, 0.8)',  // Light green
+    education: 'rgba(196, 181, 253, 0.8)', // Light purple
+    subway: 'rgba(253, 224, 71, 0.8)',   // Yellow
+  },
+  circleFillColors: {
+    none: 'transparent',
+    power: 'rgba(251, 191, 36, 0.12)',
+    water: 'rgba(96, 165, 250, 0.12)',
+    fire: 'rgba(248, 113, 113, 0.12)',
+    police: 'rgba(147, 197, 253, 0.12)',
+    health: 'rgba(134, 239, 172, 0.12)',
+    education: 'rgba(196, 181, 253, 0.12)',
+    subway: 'rgba(253, 224, 71, 0.12)',
+  },
+  highlightColors: {
+    none: 'transparent',
+    power: 'rgba(251, 191, 36, 1)',      // Amber
+    water: 'rgba(96, 165, 250, 1)',      // Blue
+    fire: 'rgba(239, 68, 68, 1)',        // Red
+    police: 'rgba(59, 130, 246, 1)',     // Blue
+    health: 'rgba(34, 197, 94, 1)',      // Green
+    education: 'rgba(168, 85, 247, 1)',  // Purple
+    subway: 'rgba(234, 179, 8, 1)',      // Yellow
+  },
+  placementValid: { fill: 'rgba(34, 197, 94, 0.3)', stroke: '#22c55e' },   // Green
+  placementInvalid: { fill: 'rgba(239, 68, 68, 0.35)', stroke: '#ef4444' }, // Red
+  heatmap: level => `hsla(${Math.round(240 * (1 - level))}, 85%, 50%, ${0.3 + level * 0.3})`, // Blue to red
+  shirtColors: null,
+};
+
+/**
+ * Deuteranopia (green-weak) palette based on the Okabe-Ito colors.
+ * Red/green pairs are replaced with orange/blue, and each overlay color sits at
+ * a different luminance so coverage stays readable without relying on hue.
+ */
+const DEUTERANOPIA_PALETTE: ColorPalette = {
+  uncoveredWarning: 'rgba(230, 159, 0, 0.55)', // Orange tint
+  subwayPresent: 'rgba(240, 228, 66, 0.7)',   // Yellow
+  subwayAbsent: 'rgba(20, 30, 60, 0.45)',     // Dark navy
+  circleColors: {
+    none: 'transparent',
+    power: 'rgba(240, 228, 66, 0.85)',   // Yellow (lightest)
+    water: 'rgba(86, 180, 233, 0.85)',   // Sky blue
+    fire: 'rgba(213, 94, 0, 0.85)',      // Vermillion
+    police: 'rgba(0, 114, 178, 0.85)',   // Blue (darkest)
+    health: 'rgba(255, 255, 255, 0.85)', // White
+    education: 'rgba(204, 121, 167, 0.85)', // Reddish purple
+    subway: 'rgba(240, 228, 66, 0.85)',  // Yellow
+  },
+  circleFillColors: {
+    none: 'transparent',
+    power: 'rgba(240, 228, 66, 0.14)',
+    water: 'rgba(86, 180, 233, 0.14)',
+    fire: 'rgba(213, 94, 0, 0.14)',
+    police: 'rgba(0, 114, 178, 0.14)',
+    health: 'rgba(255, 255, 255, 0.14)',
+    education: 'rgba(204, 121, 167, 0.14)',
+    subway: 'rgba(240, 228, 66, 0.14)',
+  },
+  highlightColors: {
+    none: 'transparent',
+    power: 'rgba(240, 228, 66, 1)',
+    water: 'rgba(86, 180, 233, 1)',
+    fire: 'rgba(213, 94, 0, 1)',
+    police: 'rgba(0, 114, 178, 1)',
+    health: 'rgba(255, 255, 255, 1)',
+    education: 'rgba(204, 121, 167, 1)',
+    subway: 'rgba(240, 228, 66, 1)',
+  },
+  placementValid: { fill: 'rgba(59, 130, 246, 0.3)', stroke: '#3b82f6' },   // Blue
+  placementInvalid: { fill: 'rgba(249, 115, 22, 0.4)', stroke: '#f97316' },  // Orange
+  heatmap: rampHeatmap([37, 99, 235], [249, 115, 22]), // Blue to orange
+  shirtColors: ['#e69f00', '#56b4e9', '#f0e442', '#0072b2', '#d55e00', '#cc79a7', '#ffffff', '#1f2937'],
+};
+
+/**
+ * Protanopia (red-weak) palette.
+ * Reds read as dark for protanopes, so warnings use a bright yellow tint and
+ * red-coded overlays are shifted towards orange/yellow with higher luminance.
+ */
+const PROTANOPIA_PALETTE: ColorPalette = {
+  uncoveredWarning: 'rgba(250, 204, 21, 0.55)', // Bright yellow tint
+  subwayPresent: 'rgba(86, 180, 233, 0.7)',    // Sky blue
+  subwayAbsent: 'rgba(20, 30, 60, 0.45)',      // Dark navy
+  circleColors: {
+    none: 'transparent',
+    power: 'rgba(250, 204, 21, 0.85)',   // Yellow (lightest)
+    water: 'rgba(86, 180, 233, 0.85)',   // Sky blue
+    fire: 'rgba(230, 159, 0, 0.85)',     // Orange
+    police: 'rgba(0, 114, 178, 0.85)',   // Blue (darkest)
+    health: 'rgba(255, 255, 255, 0.85)', // White
+    education: 'rgba(170, 140, 230, 0.85)', // Lavender
+    subway: 'rgba(86, 180, 233, 0.85)',  // Sky blue
+  },
+  circleFillColors: {
+    none: 'transparent',
+    power: 'rgba(250, 204, 21, 0.14)',
+    water: 'rgba(86, 180, 233, 0.14)',
+    fire: 'rgba(230, 159, 0, 0.14)',
+    police: 'rgba(0, 114, 178, 0.14)',
+    health: 'rgba(255, 255, 255, 0.14)',
+    education: 'rgba(170, 140, 230, 0.14)',
+    subway: 'rgba(86, 180, 233, 0.14)',
+  },
+  highlightColors: {
+    none: 'transparent',
+    power: 'rgba(250, 204, 21, 1)',
+    water: 'rgba(86, 180, 233, 1)',
+    fire: 'rgba(230, 159, 0, 1)',
+    police: 'rgba(0, 114, 178, 1)',
+    health: 'rgba(255, 255, 255, 1)',
+    education: 'rgba(170, 140, 230, 1)',
+    subway: 'rgba(86, 180, 233, 1)',
+  },
+  placementValid: { fill: 'rgba(59, 130, 246, 0.3)', stroke: '#3b82f6' },   // Blue
+  placementInvalid: { fill: 'rgba(234, 179, 8, 0.4)', stroke: '#eab308' },   // Bright yellow
+  heatmap: rampHeatmap([30, 64, 175], [250, 204, 21]), // Dark blue to bright yellow
+  shirtColors: ['#e69f00', '#56b4e9', '#facc15', '#0072b2', '#aa8ce6', '#ffffff', '#1f2937'],
+};
+
+/** Palette for each colorblind mode */
+export const COLOR_PALETTES: Record<ColorblindMode, ColorPalette> = {
+  none: DEFAULT_PALETTE,
+  deuteranopia: DEUTERANOPIA_PALETTE,
+  protanopia: PROTANOPIA_PALETTE,
+};
+
+// ============================================================================
+// Active Palette
+// ============================================================================
+
+let _activeColorblindMode: ColorblindMode = 'none';
+
+/** Set the colorblind mode used by rendering code that reads the active palette */
+export function setActiveColorblindMode(mode: ColorblindMode) {
+  _activeColorblindMode = mode;
+}
+
+export function getActiveColorblindMode(): ColorblindMode {
+  return _activeColorblindMode;
+}
+
+/** Get the palette for a mode (defaults to the active mode; unknown modes get the default) */
+export function getColorPalette(mode: ColorblindMode = _activeColorblindMode): ColorPalette {
+  return COLOR_PALETTES[mode] ?? DEFAULT_PALETTE;
+}
+
+/** Check whether a string is a valid colorblind mode */
+export function isColorblindMode(value: unknown): value is ColorblindMode {
+  return typeof value === 'string' && (COLORBLIND_MODES as string[]).includes(value);
+}
@@ -19,6 +19,7 @@ export type GameAction =
   | (BaseAction & { type: 'place'; x: number; y: number; tool: MultiplayerTool })
   | (BaseAction & { type: 'placeBatch'; placements: Array<{ x: number; y: number; tool: MultiplayerTool }> })
   | (BaseAction & { type: 'bulldoze'; x: number; y: number })
+  | (BaseAction & { type: 'bulldozeArea'; start: { x: number; y: number }; end: { x: number; y: number } })
   | (BaseAction & { type: 'setTaxRate'; rate: number })
   | (BaseAction & { type: 'setBudget'; key: keyof Budget; funding: number })
   | (BaseAction & { type: 'setSpeed'; speed: 0 | 1 | 2 | 3 })
@@ -35,6 +36,7 @@ export type GameAction =
 export type PlaceAction = { type: 'place'; x: number; y: number; tool: MultiplayerTool };
 export type PlaceBatchAction = { type: 'placeBatch'; placements: Array<{ x: number; y: number; tool: MultiplayerTool }> };
 export type BulldozeAction = { type: 'bulldoze'; x: number; y: number };
+export type BulldozeAreaAction = { type: 'bulldozeArea'; start: { x: number; y: number }; end: { x: number; y: number } };
 export type SetTaxRateAction = { type: 'setTaxRate'; rate: number };
 export type SetBudgetAction = { type: 'setBudget'; key: keyof Budget; funding: number };
 export type SetSpeedAction = { type: 'setSpeed'; speed: 0 | 1 | 2 | 3 };
@@ -51,6 +53,7 @@ export type GameActionInput =
   | PlaceAction
   | PlaceBatchAction
   | BulldozeAction
+  | BulldozeAreaAction
   | SetTaxRateAction
   | SetBudgetAction
   | SetSpeedAction